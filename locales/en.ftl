@@ -0,0 +1,650 @@
+# Fabrica reply messages - English (reference catalog)
+#
+# Every message here must also exist in the other catalogs; untranslated
+# messages fall back to this file.
+
+## General
+
+fabrica-intro = Palace Fabrica - Use `/fabrica help` for available commands
+error-server-only = ⚠️ This command is only available in servers.
+error-command = ⚠️ Something went wrong while running that command. Please try again.
+error-admin-required = ⚠️ You need **ADMINISTRATOR** permission to manage server settings.
+
+## Status
+
+status-available = 🟢 You're now **available**
+status-available-message = 🟢 You're now **available** - { $message }
+status-busy = 🟡 You're now **busy**
+status-busy-message = 🟡 You're now **busy** - { $message }
+status-away = 🔴 You're now **away**
+status-away-message = 🔴 You're now **away** - { $message }
+status-cleared = ⚫ Your status has been cleared.
+status-label-available = 🟢 **available**
+status-label-busy = 🟡 **busy**
+status-label-away = 🔴 **away**
+status-reminder = 👋 You've been { $status } for { $hours } hours. Still there? If not, your status will be cleared soon.
+status-reminder-keep = Still here
+status-reminder-clear = Clear
+status-reminder-kept = ✅ Keeping you { $status }.
+status-reminder-gone = ⚫ Your status was already cleared. Set it again with `/fabrica available`.
+
+## Who / team
+
+who-available = 🟢 **Available** ({ $count })
+who-none-available = No one currently available
+who-busy = 🟡 **Busy** ({ $count })
+who-none-busy = No one currently busy
+team-server-only = ⚠️ Team status can only be viewed in a server.
+team-available = 🟢 **Available**
+team-busy = 🟡 **Busy**
+team-none-visible = No team members are currently visible.
+team-until = (until { $time })
+team-auto = ⏰ *auto*
+team-out = 🌴 **Out today**
+team-holiday = 🎉 { $name } ({ $regions })
+overlap-server-only = ⚠️ Overlap can only be checked in a server.
+overlap-need-two = ⚠️ Mention at least two different people.
+overlap-title-today = 🤝 **Shared hours today:** { $users }
+overlap-title-week = 🤝 **Shared hours this week:** { $users }
+overlap-timezone = -# Times are in { $timezone }, longest first
+overlap-window = { $day } { $start } – { $end } ({ $length })
+overlap-none = No shared working hours in this period.
+overlap-suggest = 📅 **Suggested slots**
+overlap-off = -# No working hours in this period: { $users }
+leave-server-only = ⚠️ Leave and holidays can only be managed in a server.
+leave-invalid-dates = ⚠️ { $error }. Use dates like `2026-12-20`, `20 Dec` or `tomorrow`.
+leave-added = 🌴 Leave saved: **{ $dates }** (#{ $id }). You're off those days for team, overlap and auto status.
+leave-reason-team = -# Teammates see the reason.
+leave-reason-private = -# Teammates see that you're out, not why.
+leave-removed = 🗑️ Removed leave #{ $id }
+leave-remove-none = ⚠️ You have no leave #{ $id } in this server.
+leave-list-title = 🌴 **Out this week and next** ({ $from } – { $to })
+leave-list-none = Nobody has leave booked.
+leave-list-holidays = 🎉 **Public holidays**
+leave-holiday-line = { $date } · { $name } ({ $regions })
+leave-list-remove-hint = -# Remove your own with `/fabrica leave remove <number>`.
+holiday-admin-only = ⚠️ Only server admins can change public holidays.
+holiday-invalid-region = ⚠️ Region codes look like `IN`, `FR` or `CA`.
+holiday-invalid-date = ⚠️ Invalid date: { $date }
+holiday-set = 🎉 **{ $name }** on **{ $date }** is a public holiday for **{ $region }**
+holiday-removed = 🗑️ Removed the **{ $region }** holiday on **{ $date }**
+holiday-remove-none = ⚠️ There's no **{ $region }** holiday on **{ $date }**.
+region-set = 🎉 Public holidays for **{ $region }** now apply to you.
+region-cleared = 🎉 Regional public holidays no longer apply to you.
+region-none = None
+calendar-server-only = ⚠️ Calendar links are per server. Run this in a server.
+calendar-link-team =
+    📅 **Team calendar** for this server: hours, leave and public holidays
+    <{ $url }>
+    -# Add it to your calendar app as a subscription. Anyone with the link can read it, so keep it private. Running this again replaces the link, and `revoke` stops it.
+calendar-link-user =
+    📅 **Your calendar** for this server: your hours, leave and holidays
+    <{ $url }>
+    -# Add it to your calendar app as a subscription. Anyone with the link can read it, so keep it private. Running this again replaces the link, and `revoke` stops it.
+calendar-link-revoked = 📅 Your calendar links in this server have stopped working.
+calendar-link-none = 📅 You have no calendar links in this server.
+calendar-team-admin-only = ⚠️ Only server admins can get the team calendar. Use `/fabrica settings calendar-link me` for your own.
+calendar-name-team = Team hours
+calendar-name-user = { $name }'s hours
+calendar-working = { $name }: working
+calendar-leave = { $name }: on leave
+calendar-leave-reason = { $name }: on leave ({ $reason })
+calendar-holiday = 🎉 { $name } ({ $region })
+import-server-only = ⚠️ Calendar imports are per server. Run this in a server.
+import-bad-url = ⚠️ That doesn't look like a calendar address. Use the `https://` or `webcal://` link your calendar app gives for sharing (often called "secret address in iCal format"), or `off` to stop importing.
+import-bad-file = ⚠️ Upload an `.ics` calendar file of at most 2 MB.
+import-fetch-failed = ⚠️ Couldn't read that calendar. Check it's the address your calendar app gives for sharing and that it's reachable from the internet.
+import-done = 📥 Imported from your calendar: **{ $leave }** leave, **{ $overrides }** days with shorter hours.
+import-url-saved = -# Fabrica checks this calendar again regularly and keeps your leave in step. Use `off` to stop.
+import-off = 📥 Stopped importing your calendar and removed the leave and hours it added.
+import-none = 📥 You aren't importing a calendar in this server. Add one with `/fabrica settings calendar-import <url>` or upload an `.ics` file.
+import-status = 📥 Importing your calendar from **{ $host }**
+import-last-synced = Last checked { $time }
+import-last-error = ⚠️ The last check failed. If the address changed, add it again.
+
+## Settings
+
+settings-title = ⚙️ **Your Settings**
+settings-body =
+    **Timezone:** { $timezone }
+    **Time format:** { $format }
+    **Always show me:** { $always }
+    **Auto status:** { $auto }
+    **Holiday region:** { $region }
+
+    Use `/fabrica settings timezone <zone>` to change timezone
+    Use `/fabrica settings format 24h` or `/fabrica settings format 12h` to change format
+    Use `/fabrica settings always-show-me` to toggle visibility in /team
+    Use `/fabrica settings auto-status` to follow your working hours automatically
+    Use `/fabrica settings region IN` to take public holidays for your region
+format-12h = 12-hour (am/pm)
+format-24h = 24-hour
+yes = Yes
+no = No
+always-show-on =
+    ✅ **Always show me** is now **ON**.
+    You'll appear in `/team` even when busy for a long time or away.
+always-show-off =
+    ✅ **Always show me** is now **OFF**.
+    You'll be hidden from `/team` when busy for more than 15 minutes or away.
+auto-status-on =
+    ✅ **Auto status** is now **ON**.
+    You'll become available when your working hours start and away when they end. A status you set yourself lasts until the next start or end.
+auto-status-off =
+    ✅ **Auto status** is now **OFF**.
+    Your status only changes when you set it, and any status set by auto status was cleared.
+timezone-admin-only = ⚠️ Only admins can set timezone for other users.
+timezone-unknown =
+    ⚠️ Unknown timezone: `{ $timezone }`
+
+    Examples: `London`, `New York`, `Tokyo`, `Europe/Paris`, `America/Los_Angeles`
+timezone-set = 🌍 Timezone set to **{ $timezone }**
+timezone-set-for = 🌍 Set { $user } timezone to **{ $timezone }**
+time-format-invalid = ⚠️ Invalid format. Use `24h` or `12h`
+time-format-set = 🕐 Time format set to **{ $format }**
+
+## Hours
+
+hours-server-only = ⚠️ Hours can only be set in a server.
+hours-view-server-only = ⚠️ Hours can only be viewed in a server.
+hours-set-weekly = ⏰ Set schedule: **{ $days }**, { $ranges }
+hours-set-today = ⏰ Set for today: **{ $start }** to **{ $end }**
+hours-set-until = ⏰ Available today until **{ $end }**
+hours-parse-error =
+    ⚠️ Couldn't parse schedule: { $error }
+
+    **Examples:**
+    • `/fabrica hours Mon,Tue,Wed,Thu,Fri 9:30 to 23:30`
+    • `/fabrica hours M-F 9:30 to 23:30`
+    • `/fabrica hours M-F 10-14, 20-2`
+    • `/fabrica hours today 9:30 to 23:30`
+    • `/fabrica hours today until 23:30`
+    • `/fabrica hours until 23:30`
+    • `/fabrica hours remove Sat` or `/fabrica hours remove Tue 20-2`
+hours-overnight-clash = ⚠️ { $first } runs into { $second }. Change one of them first.
+hours-title = ⏰ **Your Schedule**
+hours-timezone = -# Times are in { $timezone } (change with `/fabrica settings timezone`)
+hours-today-range = **Today:** { $start } to { $end }
+hours-today-until = **Today:** until { $end }
+hours-none = No weekly schedule set.
+hours-weekly = **Weekly:**
+hours-weekly-day = { $day }: { $ranges }
+hours-range = **{ $start }** to **{ $end }**
+hours-range-overnight = **{ $start }** to **{ $end }** (next day)
+hours-removed-days = 🗑️ Removed your hours for **{ $days }**
+hours-removed-range = 🗑️ Removed { $range } from **{ $days }**
+hours-removed-today = 🗑️ Removed today's hours; your weekly schedule applies again
+hours-remove-none = ⚠️ No matching hours to remove.
+
+day-0 = Monday
+day-1 = Tuesday
+day-2 = Wednesday
+day-3 = Thursday
+day-4 = Friday
+day-5 = Saturday
+day-6 = Sunday
+day-short-0 = Mon
+day-short-1 = Tue
+day-short-2 = Wed
+day-short-3 = Thu
+day-short-4 = Fri
+day-short-5 = Sat
+day-short-6 = Sun
+month-short-1 = Jan
+month-short-2 = Feb
+month-short-3 = Mar
+month-short-4 = Apr
+month-short-5 = May
+month-short-6 = Jun
+month-short-7 = Jul
+month-short-8 = Aug
+month-short-9 = Sep
+month-short-10 = Oct
+month-short-11 = Nov
+month-short-12 = Dec
+days-weekdays = Mon-Fri
+days-weekend = Sat-Sun
+days-every-day = Every day
+
+## Standup
+
+standup-server-only = ⚠️ Standups are per server. Run this in a server.
+standup-admin-only = ⚠️ Only server admins can change the standup.
+standup-not-set-up = 📝 There's no standup in this server. An admin can start one with `/fabrica standup setup`.
+standup-no-participants = nobody yet, add people with `/fabrica standup add`
+standup-show =
+    📝 **Standup**
+    **Summary:** { $channel }, { $days } at { $time } ({ $timezone })
+    **Questions:**
+    { $questions }
+    **Participants:** { $users }
+standup-set-up =
+    📝 Standup summaries go to { $channel }, { $days } at { $time } ({ $timezone }).
+    -# Participants are asked when their working hours start. Add them with `/fabrica standup add` and change the questions with `/fabrica standup questions`.
+standup-invalid-days = ⚠️ { $error }. Use `weekdays`, `daily` or days like `mon,wed,fri`.
+standup-invalid-time = ⚠️ { $error }. Use a time like `17:00` or `5pm`.
+standup-invalid-questions = ⚠️ { $error }. Separate up to 5 questions of at most 45 characters with `|`.
+standup-questions-empty = No questions given
+standup-questions-too-many = At most { $max } questions
+standup-questions-too-long = "{ $question }" is longer than { $max } characters
+standup-questions-set = 📝 The standup now asks { $count } questions.
+standup-added = 📝 { $user } is now in the standup.
+standup-removed = 📝 { $user } is no longer in the standup.
+standup-remove-none = ⚠️ { $user } isn't in the standup.
+standup-off = 📝 Standup turned off. Its participants are kept if you set it up again.
+standup-default-question-1 = What did you get done since last time?
+standup-default-question-2 = What are you working on today?
+standup-default-question-3 = Is anything blocking you?
+standup-prompt =
+    📝 **Standup** for { $channel }
+    { $questions }
+    -# Answers are posted at { $time }.
+standup-nudge =
+    ⏰ Your standup for { $channel } isn't in yet.
+    { $questions }
+    -# Answers are posted at { $time }.
+standup-answer = Answer
+standup-modal-title = Standup
+standup-empty = ⚠️ All answers were empty, so nothing was saved.
+standup-saved = ✅ Thanks! Your answers go into the summary at { $time }. Press **Answer** again to change them until then.
+standup-closed = ⚠️ This standup's summary has already been posted.
+standup-summary-title = 📝 **Standup · { $date }**
+standup-summary-none = Nobody answered.
+standup-summary-missing = -# No answer: { $users }
+standup-summary-off = -# Off: { $users }
+
+## Status board
+
+board-server-only = ⚠️ Status boards are per server. Run this in a server.
+board-admin-only = ⚠️ Only server admins can change the status board.
+board-none-yet = 📋 This server has no status board. Admins can post one with `/fabrica board here`.
+board-show = 📋 The status board is here: { $link }
+board-posted = 📋 Status board posted and pinned. It updates itself whenever someone's status or hours change.
+board-posted-unpinned = 📋 Status board posted. I couldn't pin it, so pin it yourself or give me the Manage Messages permission.
+board-removed = 📋 Status board removed.
+board-title = 📋 **Team status**
+board-available = 🟢 **Available** ({ $count })
+board-busy = 🟡 **Busy** ({ $count })
+board-away = 🔴 **Away** ({ $count })
+board-none = -# Nobody
+board-hint = -# Use the buttons to set your own status. Times are each person's local time.
+board-button-available = Available
+board-button-busy = Busy
+board-button-away = Away
+
+## Translation subscriptions
+
+language-en = English
+language-hi = Hindi
+language-fr = French
+language-es = Spanish
+language-de = German
+language-fil = Filipino
+language-pt = Portuguese
+language-ko = Korean
+
+translate-server-only = ⚠️ Translation subscriptions are only available in servers.
+translate-unsupported =
+    Language '{ $language }' is not currently supported.
+    Supported: English (en), Hindi (hi), French (fr), Spanish (es), German (de), Filipino (fil), Portuguese (pt), Korean (ko)
+translate-already-subscribed = 📖 You're already subscribed to **{ $language }** translations in this channel.
+translate-subscribed =
+    ✅ Subscribed to **{ $language }** translations in this channel.
+    Your subscriptions: **{ $languages }**
+
+    ⚠️ *Translations are machine-generated and may contain inaccuracies.*
+translate-unsubscribed-all = ✅ Unsubscribed from all translation DMs in this channel.
+translate-not-subscribed = 📖 You're not subscribed to **{ $language }** translations in this channel.
+translate-unsubscribed-none-left = ✅ Unsubscribed from **{ $language }** translations. You have no remaining subscriptions in this channel.
+translate-unsubscribed =
+    ✅ Unsubscribed from **{ $language }** translations.
+    Remaining subscriptions: **{ $languages }**
+translate-no-subscriptions = 📖 You have no translation subscriptions in this channel.
+translate-unsubscribe-help =
+    📖 Your subscriptions: **{ $languages }**
+    To unsubscribe, use `/fabrica translate unsubscribe <language>` or `all` to remove all.
+scope-category = this category
+scope-guild = this server
+translate-invalid-scope = ⚠️ Unknown scope `{ $scope }`. Use `channel`, `category` or `server`.
+translate-no-category = ⚠️ This channel isn't in a category.
+translate-already-subscribed-scope = 📖 You're already subscribed to **{ $language }** translations in { $scope }.
+translate-subscribed-scope =
+    ✅ Subscribed to **{ $language }** translations in every channel in { $scope }.
+    Your subscriptions there: **{ $languages }**
+    Use `/fabrica translate optout` in a channel to stop them there.
+
+    ⚠️ *Translations are machine-generated and may contain inaccuracies.*
+translate-not-subscribed-scope = 📖 You're not subscribed to **{ $language }** translations in { $scope }.
+translate-unsubscribed-scope = ✅ Unsubscribed from **{ $language }** translations in { $scope }.
+translate-unsubscribed-all-scope = ✅ Unsubscribed from all translation DMs in { $scope }.
+translate-no-subscriptions-scope = 📖 You have no translation subscriptions in { $scope }.
+translate-unsubscribe-help-scope =
+    📖 Your subscriptions in { $scope }: **{ $languages }**
+    To unsubscribe, name a language or `all`.
+translate-opted-out = 🔕 Your category- and server-wide subscriptions won't send translations from this channel. Run this again to undo.
+translate-opted-in = 🔔 Your category- and server-wide subscriptions apply in this channel again.
+understand-none =
+    🗣️ You haven't listed any languages you understand.
+
+    Use `/fabrica translate understand english, hindi` to stop getting translations of messages already in those languages.
+understand-show =
+    🗣️ Languages you understand: **{ $languages }**
+
+    Use `/fabrica translate understand none` to clear.
+understand-cleared = 🗣️ Cleared your understood languages. You'll get translations for every subscribed language again.
+understand-set =
+    🗣️ Languages you understand: **{ $languages }**
+    You won't get translation DMs for messages already in these languages, and `/fabrica translate last` shows them as written.
+default-set =
+    🌐 Default language set to **{ $language }**!
+
+    Now `/fabrica translate last` will translate to { $language } by default.
+default-show = 🌐 Your default translation language is **{ $language }**.
+default-none =
+    🌐 You haven't set a default language yet.
+
+    Use `/fabrica translate default <language>` to set one.
+    Without a default, `/fabrica translate last` uses your first subscription.
+
+## Translation settings
+
+translation-public = 🌐 **Translation:** { $translation }
+translation-dm-jump = [Jump to message]({ $link })
+translation-dm-footer = Reply to this message to answer in the channel
+translate-status-none = 📖 You have no translation subscriptions in this channel.
+translate-status-subscriptions = 📖 Your subscriptions: **{ $languages }**
+translate-status-scope-guild = server-wide
+translate-status-scope-category = category-wide
+translate-status-scope-channel = this channel
+translate-status-mode = Channel mode: **{ $mode }**
+translate-status-debug = 🔧 Debug mode: **ON**
+translate-status-opted-out = 🔕 Category- and server-wide subscriptions are off in this channel
+translate-status-understood = 🗣️ Understood: **{ $languages }**
+translate-status-dialect = dialect: { $value }
+translate-status-formality = formality: { $value }
+translate-status-script = script: { $value }
+debug-not-subscribed = ⚠️ You need to subscribe to translations first with `/fabrica translate subscribe <language>`
+debug-on = 🔧 Debug mode **ON** - You'll receive DM translations of your own messages in this channel.
+debug-off = 🔧 Debug mode **OFF** - You won't receive translations of your own messages in this channel.
+dialect-set =
+    🗣️ Dialect preference set!
+    **Language:** { $language }
+    **Dialect:** { $dialect }
+
+    When others translate to { $language } for you, they'll use your preferred dialect.
+dialect-none =
+    🗣️ You have no dialect preferences set.
+
+    Use `/fabrica translate dialect <language> <dialect>` to set one.
+    Examples:
+    • `/fabrica translate dialect filipino bisaya`
+    • `/fabrica translate dialect chinese cantonese`
+    • `/fabrica translate dialect spanish mexican`
+dialect-show =
+    🗣️ **Your Dialect Preferences**
+
+    { $list }
+
+    Use `/fabrica translate dialect <language> <dialect>` to change.
+dialect-cleared = 🗣️ Cleared dialect preference for **{ $language }**. Default dialect will be used.
+formality-none = 🎩 You have no formality preferences set.
+formality-show =
+    🎩 **Your Formality Preferences**
+
+    { $list }
+formality-help =
+    Use `/fabrica translate formality <language> <formal|neutral|casual|default>` to change.
+    Examples:
+    • `/fabrica translate formality hindi formal` (आप)
+    • `/fabrica translate formality french casual` (tu)
+formality-invalid = ⚠️ Invalid formality. Use `formal`, `neutral`, `casual`, or `default` to clear.
+formality-set =
+    🎩 Formality preference set!
+    **Language:** { $language }
+    **Formality:** { $formality }
+
+    Translations into { $language } for you will use this register.
+formality-cleared = 🎩 Cleared formality preference for **{ $language }**. The model's default register will be used.
+script-none = 🔤 You have no script preferences set.
+script-show =
+    🔤 **Your Script Preferences**
+
+    { $list }
+script-help =
+    Use `/fabrica translate script <language> <native|romanized|both|default>` to change.
+    Examples:
+    • `/fabrica translate script hindi romanized` (namaste)
+    • `/fabrica translate script hindi both` (नमस्ते + namaste)
+script-single = ⚠️ **{ $language }** is only offered in one script, so there is nothing to choose.
+script-invalid = ⚠️ Invalid script. Use `native`, `romanized`, `both`, or `default` to clear.
+script-set-native =
+    🔤 Script preference set!
+    **Language:** { $language }
+    **Script:** native
+
+    Translations into { $language } will use the native script.
+script-set-romanized =
+    🔤 Script preference set!
+    **Language:** { $language }
+    **Script:** romanized
+
+    Translations into { $language } will be written in Latin letters.
+script-set-both =
+    🔤 Script preference set!
+    **Language:** { $language }
+    **Script:** both
+
+    Translations into { $language } will show the native script followed by a romanized line.
+script-cleared = 🔤 Cleared script preference for **{ $language }**. The native script will be used.
+mode-invalid =
+    ⚠️ Invalid mode. Available modes:
+    • **off** - No translation
+    • **silent** - DM translations only (subscribe to `en` for English translations)
+    • **on** - DM translations + public English translations
+    • **transparent** - All translations shown publicly
+    • **mirror** - Repost translated messages in linked channels (`/fabrica translate mirror`)
+mode-set-off = ✅ Translation is **disabled**. Messages will not be processed.
+mode-set-silent =
+    ✅ Translation mode: **silent**
+    • Non-English → English: DM to English subscribers only
+    • English → Other: DM to language subscribers
+mode-set-on =
+    ✅ Translation mode: **on**
+    • Non-English → English: Posted publicly
+    • English → Other: DM to language subscribers
+mode-set-transparent =
+    ✅ Translation mode: **transparent**
+    • All translations posted publicly in channel
+mode-set-mirror =
+    ✅ Translation mode: **mirror**
+    • Messages reposted, translated, in the linked channels
+mode-show = 📖 Channel translation mode: { $mode }
+mode-describe-off = **off** - No translation
+mode-describe-silent = **silent** - DM translations only
+mode-describe-on = **on** - DM + public English translations
+mode-describe-transparent = **transparent** - All translations public
+mode-show-attachments = 📎 Attachments, embeds and image captions are translated too
+attachments-show-on = 📎 Attachment translation is **on** in this channel.
+attachments-show-off = 📎 Attachment translation is **off** in this channel.
+attachments-invalid = ⚠️ Use `on` or `off`.
+attachments-on =
+    ✅ Attachment translation **on**
+    • Embed titles, descriptions and image alt text are translated with the message
+    • `.txt` and `.md` files up to { $size } KB are sent back translated
+attachments-off = ✅ Attachment translation **off**
+last-no-language = ⚠️ Set a default language with `/fabrica translate default <language>`, or specify one: `/fabrica translate last <count> <language>`
+last-no-new = 📭 No new messages to show.
+last-nothing = 📭 No messages to translate.
+last-header = 📜 **Last { $count } messages translated to { $language }:**
+last-truncated = _...truncated due to length_
+last-none-translatable = 📭 No translatable messages found.
+
+## Server administration
+
+server-status-title = 📊 **Server Status**
+server-permissions-title = 📊 **Server Permissions**
+server-permissions-none =
+    No custom role permissions configured.
+    Only users with **MANAGE_CHANNELS** or **ADMINISTRATOR** can manage translation settings.
+
+    Use `/fabrica server allow mode @role` to grant a role permission to change translation modes.
+server-permissions-admin = **Admin:** { $roles }
+server-permissions-mode = **Mode:** { $roles }
+server-permissions-legend = _Admin can manage all settings. Mode can change translation modes._
+server-everyone = **everyone**
+server-queue =
+    **Translation queue:** { $queued }/{ $capacity } queued (peak { $peak }) across { $workers } worker(s)
+    Processed { $completed } • Failed { $failed } • Backpressure waits { $waits }
+server-invalid-permission =
+    ⚠️ Invalid permission. Available permissions:
+    • **mode** - Can change translation modes
+    • **admin** - Can manage all Fabrica settings
+server-invalid-target = ⚠️ Invalid target. Use `everyone` or select a role.
+server-allow-missing-target =
+    ⚠️ Please specify a role or use `everyone` to grant permission to all users.
+    Example: `/fabrica server allow mode @role` or `/fabrica server allow mode everyone:everyone`
+server-deny-missing-target =
+    ⚠️ Please specify a role or use `everyone` to revoke permission.
+    Example: `/fabrica server deny mode @role` or `/fabrica server deny mode everyone:everyone`
+server-allowed-mode = ✅ { $target } can now change translation modes.
+server-allowed-admin = ✅ { $target } can now manage all Fabrica settings.
+server-denied-mode = ✅ { $target } can no longer change translation modes.
+server-denied-admin = ✅ { $target } can no longer manage all Fabrica settings.
+
+## Integrations
+
+watch-unknown-type = ⚠️ Unknown watch type. Use `github` or `plane`.
+watch-stopped = ✅ This channel is no longer watching **{ $name }**.
+github-repo =
+    📦 **Repository: { $repo }**
+
+    ⚠️ GitHub integration coming soon!
+    This will show repo stats, recent activity, and open PRs.
+github-commits =
+    📝 **Recent { $count } commits for { $repo }**
+
+    ⚠️ GitHub integration coming soon!
+github-prs =
+    🔀 **Open PRs for { $repo }**
+
+    ⚠️ GitHub integration coming soon!
+github-watching-all =
+    ✅ This channel is now watching **{ $repo }** at **all** level.
+    You'll receive notifications about:
+    • All pushes, PRs, issues, comments, and releases
+github-watching-important =
+    ✅ This channel is now watching **{ $repo }** at **important** level.
+    You'll receive notifications about:
+    • PRs (opened, merged, closed)
+    • Releases
+    • Milestones
+github-watching-minimal =
+    ✅ This channel is now watching **{ $repo }** at **minimal** level.
+    You'll receive notifications about:
+    • Releases only
+    • Merged PRs
+github-watching-off =
+    ✅ This channel is now watching **{ $repo }** at **off** level.
+    You'll receive notifications about:
+    • Nothing (muted)
+plane-project =
+    📊 **{ $project }**
+
+    ⚠️ Plane integration coming soon!
+    This will show project status, sprint progress, and open issues.
+plane-all-projects = all projects
+plane-current-project = current
+plane-issues =
+    📋 **Issues for { $project }** (filter: { $filter })
+
+    ⚠️ Plane integration coming soon!
+plane-sprint =
+    🏃 **Sprint Status** for { $project }
+
+    ⚠️ Plane integration coming soon!
+plane-watching =
+    ✅ This channel is now watching **{ $project }** at **{ $level }** level.
+    You'll receive notifications about project activity.
+
+## Mirror channels
+
+translate-settings-server-only = ⚠️ Translation settings are only available in servers.
+translate-no-permission =
+    ⚠️ You need a configured role or MANAGE_CHANNELS permission to change translation settings.
+    Server admins can configure roles with `/fabrica server allow mode @role`
+mirror-not-linked-yet =
+    ⚠️ This channel isn't linked to a mirror group yet.
+    Use `/fabrica translate mirror <name> <language>` to link it.
+mirror-invalid-name = ⚠️ Mirror names must be 1-32 characters, e.g. `team`.
+mirror-webhook-failed = ⚠️ Couldn't create a webhook in this channel. Fabrica needs the **Manage Webhooks** permission here.
+mirror-linked =
+    🪞 This channel is now part of mirror group **{ $name }** in **{ $language }**.
+    { $channels }
+mirror-link-another = Link another channel with `/fabrica translate mirror { $name } <language>` to start mirroring.
+mirror-not-linked = 📖 This channel isn't linked to a mirror group.
+mirror-unlinked = ✅ This channel was removed from mirror group **{ $name }**. Translation mode is now **off**.
+mirror-describe =
+    **mirror** - Group **{ $name }**, this channel in **{ $language }**
+    { $channels }
+mirror-describe-unlinked = **mirror** - Not linked; use `/fabrica translate mirror <name> <language>`
+mirror-channels = Linked channels: { $channels }
+mirror-replying-to = -# ↪ Replying to **{ $name }**
+mirror-replying-to-link = -# ↪ Replying to **{ $name }**: { $link }
+mirror-reply-someone = a message
+
+## Corrections and glossary
+
+correction-suggest = Suggest correction
+correction-modal-title = Suggest a correction
+correction-modal-text = Better translation
+correction-modal-term = Term fix (optional)
+correction-modal-term-placeholder = e.g. sprint = the right { $language } word
+correction-save-failed = ⚠️ Couldn't save your correction. Please try again.
+correction-not-enabled = ⚠️ Corrections aren't enabled for that server.
+correction-empty = ⚠️ The correction was empty, so nothing was saved.
+correction-term-format = ⚠️ Write the term fix as `term = translation`, e.g. `sprint = स्प्रिंट`.
+correction-submitted = ✅ Thanks! Correction #{ $id } was sent to the server admins for review.
+corrections-server-only = ⚠️ Corrections are only available in servers.
+corrections-admin-only = ⚠️ Only server admins can review corrections.
+corrections-none = 📝 No corrections are waiting for review.
+corrections-title = 📝 **Pending corrections**
+corrections-entry =
+    **#{ $id }** { $from } → { $to } by { $user } (`{ $model }`)
+    > { $translation }
+    ✏️ { $correction }
+corrections-term-fix = Term fix: **{ $term }** = **{ $translation }**
+corrections-recurring = 🔁 **Recurring term fixes**
+corrections-recurring-entry = • **{ $term }** = **{ $translation }** ({ $from } → { $to }) - suggested { $count } times, e.g. #{ $id }
+corrections-review-hint = Use `/fabrica translate correction <id> promote` or `dismiss`.
+correction-missing = ⚠️ There is no correction #{ $id } in this server.
+correction-already-reviewed = ⚠️ Correction #{ $id } was already reviewed.
+correction-no-term = ⚠️ Correction #{ $id } doesn't suggest a term fix, so there's nothing to add to the glossary.
+correction-promoted = ✅ Added **{ $term }** = **{ $translation }** ({ $from } → { $to }) to the glossary and closed correction #{ $id }.
+correction-dismissed = 🗑️ Dismissed correction #{ $id }.
+glossary-not-found = ⚠️ **{ $term }** isn't in the glossary.
+glossary-removed = 🗑️ Removed **{ $term }** from the glossary.
+glossary-empty = 📚 The glossary is empty. Promote term fixes from `/fabrica translate corrections`.
+glossary-title = 📚 **Glossary**
+
+## DM replies
+
+dm-reply-preview =
+    💬 This will be posted in { $channel } in **{ $language }**:
+
+    { $content }
+dm-reply-post = Post
+dm-reply-cancel = Cancel
+dm-reply-failed = ⚠️ Translation failed, so nothing was posted. Please try again.
+dm-reply-not-member = ⚠️ You can't send messages in that channel, so nothing was posted.
+dm-reply-already-done = ⚠️ This reply was already posted or cancelled.
+dm-reply-not-yours = ⚠️ This reply isn't yours to post.
+dm-reply-cancelled = ❌ Cancelled - nothing was posted.
+dm-reply-posted = ✅ Posted: { $link }
+dm-reply-webhook-failed = ⚠️ Couldn't post in the channel. Fabrica needs the **Manage Webhooks** permission there.
+
+## Command descriptions
+#
+# English command names and descriptions come from the doc comments in
+# src/bot.rs. Other catalogs localize them with `command-<path>` entries,
+# e.g. `command-fabrica-translate-subscribe` with `.description` and
+# `.param-<name>` attributes.
@@ -0,0 +1,816 @@
+# Fabrica reply messages - Hindi (हिन्दी)
+#
+# Keep placeholders like { $message } unchanged. Missing messages fall
+# back to en.ftl.
+
+## General
+
+fabrica-intro = Palace Fabrica - उपलब्ध कमांड देखने के लिए `/fabrica help` का उपयोग करें
+error-server-only = ⚠️ यह कमांड केवल सर्वर में उपलब्ध है।
+error-command = ⚠️ यह कमांड चलाते समय कुछ गड़बड़ हो गई। कृपया फिर से प्रयास करें।
+error-admin-required = ⚠️ सर्वर सेटिंग्स बदलने के लिए आपको **ADMINISTRATOR** अनुमति चाहिए।
+
+## Status
+
+status-available = 🟢 अब आप **उपलब्ध** हैं
+status-available-message = 🟢 अब आप **उपलब्ध** हैं - { $message }
+status-busy = 🟡 अब आप **व्यस्त** हैं
+status-busy-message = 🟡 अब आप **व्यस्त** हैं - { $message }
+status-away = 🔴 अब आप **दूर** हैं
+status-away-message = 🔴 अब आप **दूर** हैं - { $message }
+status-cleared = ⚫ आपका स्टेटस हटा दिया गया है।
+status-label-available = 🟢 **उपलब्ध**
+status-label-busy = 🟡 **व्यस्त**
+status-label-away = 🔴 **दूर**
+status-reminder = 👋 आप { $hours } घंटे से { $status } हैं। क्या आप अभी भी हैं? अगर नहीं, तो आपका स्टेटस जल्द ही हटा दिया जाएगा।
+status-reminder-keep = अभी भी हूँ
+status-reminder-clear = हटाएँ
+status-reminder-kept = ✅ आप { $status } बने रहेंगे।
+status-reminder-gone = ⚫ आपका स्टेटस पहले ही हटा दिया गया था। इसे `/fabrica available` से फिर सेट करें।
+
+## Who / team
+
+who-available = 🟢 **उपलब्ध** ({ $count })
+who-none-available = अभी कोई उपलब्ध नहीं है
+who-busy = 🟡 **व्यस्त** ({ $count })
+who-none-busy = अभी कोई व्यस्त नहीं है
+team-server-only = ⚠️ टीम स्टेटस केवल सर्वर में देखा जा सकता है।
+team-available = 🟢 **उपलब्ध**
+team-busy = 🟡 **व्यस्त**
+team-none-visible = अभी टीम का कोई सदस्य दिखाई नहीं दे रहा है।
+team-until = ({ $time } तक)
+team-auto = ⏰ *ऑटो*
+team-out = 🌴 **आज छुट्टी पर**
+team-holiday = 🎉 { $name } ({ $regions })
+overlap-server-only = ⚠️ साझा घंटे केवल सर्वर में देखे जा सकते हैं।
+overlap-need-two = ⚠️ कम से कम दो अलग-अलग लोगों का उल्लेख करें।
+overlap-title-today = 🤝 **आज के साझा घंटे:** { $users }
+overlap-title-week = 🤝 **इस हफ़्ते के साझा घंटे:** { $users }
+overlap-timezone = -# समय { $timezone } में हैं, सबसे लंबे पहले
+overlap-window = { $day } { $start } – { $end } ({ $length })
+overlap-none = इस अवधि में कोई साझा काम के घंटे नहीं हैं।
+overlap-suggest = 📅 **सुझाए गए समय**
+overlap-off = -# इस अवधि में काम के घंटे नहीं: { $users }
+leave-server-only = ⚠️ छुट्टियाँ केवल सर्वर में प्रबंधित की जा सकती हैं।
+leave-invalid-dates = ⚠️ { $error }। `2026-12-20`, `20 Dec` या `tomorrow` जैसी तारीखें लिखें।
+leave-added = 🌴 छुट्टी सहेजी गई: **{ $dates }** (#{ $id })। इन दिनों आप टीम, साझा घंटों और ऑटो स्टेटस में छुट्टी पर रहेंगे।
+leave-reason-team = -# साथियों को कारण दिखेगा।
+leave-reason-private = -# साथियों को दिखेगा कि आप छुट्टी पर हैं, कारण नहीं।
+leave-removed = 🗑️ छुट्टी #{ $id } हटा दी गई
+leave-remove-none = ⚠️ इस सर्वर में आपकी कोई छुट्टी #{ $id } नहीं है।
+leave-list-title = 🌴 **इस हफ़्ते और अगले हफ़्ते छुट्टी पर** ({ $from } – { $to })
+leave-list-none = किसी ने छुट्टी बुक नहीं की है।
+leave-list-holidays = 🎉 **सार्वजनिक अवकाश**
+leave-holiday-line = { $date } · { $name } ({ $regions })
+leave-list-remove-hint = -# अपनी छुट्टी `/fabrica leave remove <number>` से हटाएँ।
+holiday-admin-only = ⚠️ सिर्फ़ सर्वर एडमिन सार्वजनिक अवकाश बदल सकते हैं।
+holiday-invalid-region = ⚠️ क्षेत्र कोड `IN`, `FR` या `CA` जैसे होते हैं।
+holiday-invalid-date = ⚠️ अमान्य तारीख: { $date }
+holiday-set = 🎉 **{ $date }** को **{ $name }**, **{ $region }** के लिए सार्वजनिक अवकाश है
+holiday-removed = 🗑️ **{ $date }** का **{ $region }** अवकाश हटा दिया गया
+holiday-remove-none = ⚠️ **{ $date }** को **{ $region }** का कोई अवकाश नहीं है।
+region-set = 🎉 अब **{ $region }** के सार्वजनिक अवकाश आप पर लागू होते हैं।
+region-cleared = 🎉 अब कोई क्षेत्रीय सार्वजनिक अवकाश आप पर लागू नहीं होता।
+region-none = कोई नहीं
+calendar-server-only = ⚠️ कैलेंडर लिंक हर सर्वर के लिए अलग होते हैं। इसे सर्वर में चलाएँ।
+calendar-link-team =
+    📅 इस सर्वर का **टीम कैलेंडर**: काम के घंटे, छुट्टियाँ और सार्वजनिक अवकाश
+    <{ $url }>
+    -# इसे अपने कैलेंडर ऐप में सब्सक्रिप्शन के रूप में जोड़ें। लिंक वाला कोई भी इसे पढ़ सकता है, इसलिए इसे निजी रखें। दोबारा चलाने पर लिंक बदल जाता है, और `revoke` से यह बंद हो जाता है।
+calendar-link-user =
+    📅 इस सर्वर का **आपका कैलेंडर**: आपके काम के घंटे, छुट्टियाँ और अवकाश
+    <{ $url }>
+    -# इसे अपने कैलेंडर ऐप में सब्सक्रिप्शन के रूप में जोड़ें। लिंक वाला कोई भी इसे पढ़ सकता है, इसलिए इसे निजी रखें। दोबारा चलाने पर लिंक बदल जाता है, और `revoke` से यह बंद हो जाता है।
+calendar-link-revoked = 📅 इस सर्वर में आपके कैलेंडर लिंक अब काम नहीं करेंगे।
+calendar-link-none = 📅 इस सर्वर में आपका कोई कैलेंडर लिंक नहीं है।
+calendar-team-admin-only = ⚠️ टीम कैलेंडर सिर्फ़ सर्वर एडमिन ले सकते हैं। अपने लिए `/fabrica settings calendar-link me` इस्तेमाल करें।
+calendar-name-team = टीम के घंटे
+calendar-name-user = { $name } के घंटे
+calendar-working = { $name }: काम पर
+calendar-leave = { $name }: छुट्टी पर
+calendar-leave-reason = { $name }: छुट्टी पर ({ $reason })
+calendar-holiday = 🎉 { $name } ({ $region })
+import-server-only = ⚠️ कैलेंडर इंपोर्ट हर सर्वर के लिए अलग होता है। इसे किसी सर्वर में चलाएँ।
+import-bad-url = ⚠️ यह कैलेंडर का पता नहीं लगता। अपने कैलेंडर ऐप का शेयर करने वाला `https://` या `webcal://` लिंक (अक्सर "iCal फ़ॉर्मैट में गुप्त पता") दें, या इंपोर्ट रोकने के लिए `off`।
+import-bad-file = ⚠️ ज़्यादा से ज़्यादा 2 MB की `.ics` कैलेंडर फ़ाइल अपलोड करें।
+import-fetch-failed = ⚠️ वह कैलेंडर पढ़ा नहीं जा सका। जाँच लें कि यह वही पता है जो आपका कैलेंडर ऐप शेयर करने के लिए देता है और इंटरनेट से खुलता है।
+import-done = 📥 आपके कैलेंडर से इंपोर्ट हुआ: **{ $leave }** छुट्टियाँ, **{ $overrides }** दिन कम घंटों वाले।
+import-url-saved = -# Fabrica इस कैलेंडर को नियमित रूप से फिर देखता है और आपकी छुट्टियाँ अपडेट रखता है। रोकने के लिए `off` लिखें।
+import-off = 📥 आपके कैलेंडर का इंपोर्ट रोक दिया गया और उससे जुड़ी छुट्टियाँ और घंटे हटा दिए गए।
+import-none = 📥 आप इस सर्वर में कोई कैलेंडर इंपोर्ट नहीं कर रहे। `/fabrica settings calendar-import <url>` से जोड़ें या `.ics` फ़ाइल अपलोड करें।
+import-status = 📥 आपका कैलेंडर **{ $host }** से इंपोर्ट हो रहा है
+import-last-synced = आख़िरी बार देखा गया { $time }
+import-last-error = ⚠️ पिछली जाँच विफल रही। पता बदल गया हो तो उसे फिर से जोड़ें।
+
+## Settings
+
+settings-title = ⚙️ **आपकी सेटिंग्स**
+settings-body =
+    **टाइमज़ोन:** { $timezone }
+    **समय प्रारूप:** { $format }
+    **हमेशा दिखाएँ:** { $always }
+    **ऑटो स्टेटस:** { $auto }
+    **अवकाश क्षेत्र:** { $region }
+
+    टाइमज़ोन बदलने के लिए `/fabrica settings timezone <zone>` का उपयोग करें
+    प्रारूप बदलने के लिए `/fabrica settings format 24h` या `/fabrica settings format 12h` का उपयोग करें
+    /team में दिखने की सेटिंग बदलने के लिए `/fabrica settings always-show-me` का उपयोग करें
+    काम के घंटों के अनुसार अपने आप स्टेटस बदलने के लिए `/fabrica settings auto-status` का उपयोग करें
+    अपने क्षेत्र के सार्वजनिक अवकाश पाने के लिए `/fabrica settings region IN` का उपयोग करें
+format-12h = 12-घंटे (am/pm)
+format-24h = 24-घंटे
+yes = हाँ
+no = नहीं
+always-show-on =
+    ✅ **हमेशा दिखाएँ** अब **चालू** है।
+    लंबे समय तक व्यस्त या दूर होने पर भी आप `/team` में दिखेंगे।
+always-show-off =
+    ✅ **हमेशा दिखाएँ** अब **बंद** है।
+    15 मिनट से अधिक व्यस्त रहने या दूर होने पर आप `/team` में नहीं दिखेंगे।
+auto-status-on =
+    ✅ **ऑटो स्टेटस** अब **चालू** है।
+    काम के घंटे शुरू होने पर आप उपलब्ध और खत्म होने पर दूर हो जाएँगे। खुद सेट किया गया स्टेटस अगली शुरुआत या समाप्ति तक रहेगा।
+auto-status-off =
+    ✅ **ऑटो स्टेटस** अब **बंद** है।
+    आपका स्टेटस केवल तब बदलेगा जब आप उसे सेट करेंगे, और ऑटो स्टेटस द्वारा सेट किया गया स्टेटस हटा दिया गया है।
+timezone-admin-only = ⚠️ केवल एडमिन ही दूसरे उपयोगकर्ताओं का टाइमज़ोन सेट कर सकते हैं।
+timezone-unknown =
+    ⚠️ अज्ञात टाइमज़ोन: `{ $timezone }`
+
+    उदाहरण: `London`, `New York`, `Tokyo`, `Europe/Paris`, `America/Los_Angeles`
+timezone-set = 🌍 टाइमज़ोन **{ $timezone }** पर सेट किया गया
+timezone-set-for = 🌍 { $user } का टाइमज़ोन **{ $timezone }** पर सेट किया गया
+time-format-invalid = ⚠️ अमान्य प्रारूप। `24h` या `12h` का उपयोग करें
+time-format-set = 🕐 समय प्रारूप **{ $format }** पर सेट किया गया
+
+## Hours
+
+hours-server-only = ⚠️ काम के घंटे केवल सर्वर में सेट किए जा सकते हैं।
+hours-view-server-only = ⚠️ काम के घंटे केवल सर्वर में देखे जा सकते हैं।
+hours-set-weekly = ⏰ शेड्यूल सेट किया गया: **{ $days }**, { $ranges }
+hours-set-today = ⏰ आज के लिए सेट किया गया: **{ $start }** से **{ $end }** तक
+hours-set-until = ⏰ आज **{ $end }** तक उपलब्ध
+hours-parse-error =
+    ⚠️ शेड्यूल समझ नहीं आया: { $error }
+
+    **उदाहरण:**
+    • `/fabrica hours Mon,Tue,Wed,Thu,Fri 9:30 to 23:30`
+    • `/fabrica hours M-F 9:30 to 23:30`
+    • `/fabrica hours M-F 10-14, 20-2`
+    • `/fabrica hours today 9:30 to 23:30`
+    • `/fabrica hours today until 23:30`
+    • `/fabrica hours until 23:30`
+    • `/fabrica hours remove Sat` या `/fabrica hours remove Tue 20-2`
+hours-overnight-clash = ⚠️ { $first } और { $second } आपस में टकराते हैं। पहले इनमें से एक बदलें।
+hours-title = ⏰ **आपका शेड्यूल**
+hours-timezone = -# समय { $timezone } में हैं (`/fabrica settings timezone` से बदलें)
+hours-today-range = **आज:** { $start } से { $end } तक
+hours-today-until = **आज:** { $end } तक
+hours-none = कोई साप्ताहिक शेड्यूल सेट नहीं है।
+hours-weekly = **साप्ताहिक:**
+hours-weekly-day = { $day }: { $ranges }
+hours-range = **{ $start }** से **{ $end }** तक
+hours-range-overnight = **{ $start }** से **{ $end }** (अगले दिन) तक
+hours-removed-days = 🗑️ **{ $days }** के आपके घंटे हटा दिए गए
+hours-removed-range = 🗑️ **{ $days }** से { $range } हटा दिया गया
+hours-removed-today = 🗑️ आज के घंटे हटा दिए गए; अब आपका साप्ताहिक शेड्यूल लागू है
+hours-remove-none = ⚠️ हटाने के लिए कोई मेल खाते घंटे नहीं मिले।
+
+day-0 = सोमवार
+day-1 = मंगलवार
+day-2 = बुधवार
+day-3 = गुरुवार
+day-4 = शुक्रवार
+day-5 = शनिवार
+day-6 = रविवार
+day-short-0 = सोम
+day-short-1 = मंगल
+day-short-2 = बुध
+day-short-3 = गुरु
+day-short-4 = शुक्र
+day-short-5 = शनि
+day-short-6 = रवि
+month-short-1 = जन॰
+month-short-2 = फ़र॰
+month-short-3 = मार्च
+month-short-4 = अप्रैल
+month-short-5 = मई
+month-short-6 = जून
+month-short-7 = जुल॰
+month-short-8 = अग॰
+month-short-9 = सित॰
+month-short-10 = अक्तू॰
+month-short-11 = नव॰
+month-short-12 = दिस॰
+days-weekdays = सोम-शुक्र
+days-weekend = शनि-रवि
+days-every-day = हर दिन
+
+## Standup
+
+standup-server-only = ⚠️ स्टैंडअप हर सर्वर के लिए अलग होता है। इसे किसी सर्वर में चलाएँ।
+standup-admin-only = ⚠️ सिर्फ़ सर्वर एडमिन स्टैंडअप बदल सकते हैं।
+standup-not-set-up = 📝 इस सर्वर में कोई स्टैंडअप नहीं है। एडमिन `/fabrica standup setup` से शुरू कर सकते हैं।
+standup-no-participants = अभी कोई नहीं, `/fabrica standup add` से लोगों को जोड़ें
+standup-show =
+    📝 **स्टैंडअप**
+    **सारांश:** { $channel }, { $days } को { $time } ({ $timezone })
+    **सवाल:**
+    { $questions }
+    **प्रतिभागी:** { $users }
+standup-set-up =
+    📝 स्टैंडअप सारांश { $channel } में जाएँगे, { $days } को { $time } ({ $timezone })।
+    -# प्रतिभागियों से उनके काम के घंटे शुरू होने पर पूछा जाता है। `/fabrica standup add` से उन्हें जोड़ें और `/fabrica standup questions` से सवाल बदलें।
+standup-invalid-days = ⚠️ { $error }। `weekdays`, `daily` या `mon,wed,fri` जैसे दिन लिखें।
+standup-invalid-time = ⚠️ { $error }। `17:00` या `5pm` जैसा समय लिखें।
+standup-invalid-questions = ⚠️ { $error }। ज़्यादा से ज़्यादा 45 अक्षरों के 5 सवाल `|` से अलग करके लिखें।
+standup-questions-empty = कोई सवाल नहीं दिया गया
+standup-questions-too-many = ज़्यादा से ज़्यादा { $max } सवाल
+standup-questions-too-long = "{ $question }" { $max } अक्षरों से लंबा है
+standup-questions-set = 📝 स्टैंडअप अब { $count } सवाल पूछता है।
+standup-added = 📝 { $user } अब स्टैंडअप में हैं।
+standup-removed = 📝 { $user } अब स्टैंडअप में नहीं हैं।
+standup-remove-none = ⚠️ { $user } स्टैंडअप में नहीं हैं।
+standup-off = 📝 स्टैंडअप बंद किया गया। फिर से शुरू करने पर प्रतिभागी वही रहेंगे।
+standup-default-question-1 = पिछली बार के बाद आपने क्या पूरा किया?
+standup-default-question-2 = आज आप किस पर काम कर रहे हैं?
+standup-default-question-3 = क्या कुछ आपको रोक रहा है?
+standup-prompt =
+    📝 { $channel } के लिए **स्टैंडअप**
+    { $questions }
+    -# जवाब { $time } पर पोस्ट किए जाएँगे।
+standup-nudge =
+    ⏰ { $channel } के लिए आपका स्टैंडअप अभी बाकी है।
+    { $questions }
+    -# जवाब { $time } पर पोस्ट किए जाएँगे।
+standup-answer = जवाब दें
+standup-modal-title = स्टैंडअप
+standup-empty = ⚠️ सभी जवाब खाली थे, इसलिए कुछ सेव नहीं हुआ।
+standup-saved = ✅ धन्यवाद! आपके जवाब { $time } पर सारांश में जाएँगे। तब तक बदलने के लिए फिर से **जवाब दें** दबाएँ।
+standup-closed = ⚠️ इस स्टैंडअप का सारांश पहले ही पोस्ट हो चुका है।
+standup-summary-title = 📝 **स्टैंडअप · { $date }**
+standup-summary-none = किसी ने जवाब नहीं दिया।
+standup-summary-missing = -# जवाब नहीं आया: { $users }
+standup-summary-off = -# छुट्टी पर: { $users }
+
+## Status board
+
+board-server-only = ⚠️ स्टेटस बोर्ड हर सर्वर के लिए अलग होता है। इसे किसी सर्वर में चलाएँ।
+board-admin-only = ⚠️ सिर्फ़ सर्वर एडमिन स्टेटस बोर्ड बदल सकते हैं।
+board-none-yet = 📋 इस सर्वर में कोई स्टेटस बोर्ड नहीं है। एडमिन `/fabrica board here` से पोस्ट कर सकते हैं।
+board-show = 📋 स्टेटस बोर्ड यहाँ है: { $link }
+board-posted = 📋 स्टेटस बोर्ड पोस्ट और पिन किया गया। किसी का स्टेटस या घंटे बदलने पर यह अपने-आप अपडेट होता है।
+board-posted-unpinned = 📋 स्टेटस बोर्ड पोस्ट किया गया। मैं इसे पिन नहीं कर सका, इसलिए खुद पिन करें या मुझे Manage Messages अनुमति दें।
+board-removed = 📋 स्टेटस बोर्ड हटाया गया।
+board-title = 📋 **टीम का स्टेटस**
+board-available = 🟢 **उपलब्ध** ({ $count })
+board-busy = 🟡 **व्यस्त** ({ $count })
+board-away = 🔴 **दूर** ({ $count })
+board-none = -# कोई नहीं
+board-hint = -# अपना स्टेटस बदलने के लिए बटन दबाएँ। समय हर व्यक्ति का अपना स्थानीय समय है।
+board-button-available = उपलब्ध
+board-button-busy = व्यस्त
+board-button-away = दूर
+
+## Translation subscriptions
+
+language-en = अंग्रेज़ी
+language-hi = हिन्दी
+language-fr = फ़्रेंच
+language-es = स्पेनिश
+language-de = जर्मन
+language-fil = फ़िलिपिनो
+language-pt = पुर्तगाली
+language-ko = कोरियाई
+
+translate-server-only = ⚠️ अनुवाद सदस्यताएँ केवल सर्वर में उपलब्ध हैं।
+translate-unsupported =
+    भाषा '{ $language }' अभी समर्थित नहीं है।
+    समर्थित: English (en), Hindi (hi), French (fr), Spanish (es), German (de), Filipino (fil), Portuguese (pt), Korean (ko)
+translate-already-subscribed = 📖 आप इस चैनल में पहले से **{ $language }** अनुवाद के सदस्य हैं।
+translate-subscribed =
+    ✅ इस चैनल में **{ $language }** अनुवाद की सदस्यता ली गई।
+    आपकी सदस्यताएँ: **{ $languages }**
+
+    ⚠️ *अनुवाद मशीन द्वारा बनाए जाते हैं और उनमें गलतियाँ हो सकती हैं।*
+translate-unsubscribed-all = ✅ इस चैनल के सभी अनुवाद DM की सदस्यता हटा दी गई।
+translate-not-subscribed = 📖 आप इस चैनल में **{ $language }** अनुवाद के सदस्य नहीं हैं।
+translate-unsubscribed-none-left = ✅ **{ $language }** अनुवाद की सदस्यता हटा दी गई। इस चैनल में आपकी कोई सदस्यता बाकी नहीं है।
+translate-unsubscribed =
+    ✅ **{ $language }** अनुवाद की सदस्यता हटा दी गई।
+    बाकी सदस्यताएँ: **{ $languages }**
+translate-no-subscriptions = 📖 इस चैनल में आपकी कोई अनुवाद सदस्यता नहीं है।
+translate-unsubscribe-help =
+    📖 आपकी सदस्यताएँ: **{ $languages }**
+    सदस्यता हटाने के लिए `/fabrica translate unsubscribe <language>` या सभी हटाने के लिए `all` का उपयोग करें।
+scope-category = इस श्रेणी
+scope-guild = इस सर्वर
+translate-invalid-scope = ⚠️ अज्ञात दायरा `{ $scope }`। `channel`, `category` या `server` का उपयोग करें।
+translate-no-category = ⚠️ यह चैनल किसी श्रेणी में नहीं है।
+translate-already-subscribed-scope = 📖 आप { $scope } में पहले से **{ $language }** अनुवाद के सदस्य हैं।
+translate-subscribed-scope =
+    ✅ { $scope } के हर चैनल में **{ $language }** अनुवाद की सदस्यता ली गई।
+    वहाँ आपकी सदस्यताएँ: **{ $languages }**
+    किसी चैनल में इन्हें रोकने के लिए वहाँ `/fabrica translate optout` का उपयोग करें।
+
+    ⚠️ *अनुवाद मशीन द्वारा बनाए जाते हैं और उनमें गलतियाँ हो सकती हैं।*
+translate-not-subscribed-scope = 📖 आप { $scope } में **{ $language }** अनुवाद के सदस्य नहीं हैं।
+translate-unsubscribed-scope = ✅ { $scope } में **{ $language }** अनुवाद की सदस्यता हटा दी गई।
+translate-unsubscribed-all-scope = ✅ { $scope } के सभी अनुवाद DM की सदस्यता हटा दी गई।
+translate-no-subscriptions-scope = 📖 { $scope } में आपकी कोई अनुवाद सदस्यता नहीं है।
+translate-unsubscribe-help-scope =
+    📖 { $scope } में आपकी सदस्यताएँ: **{ $languages }**
+    सदस्यता हटाने के लिए कोई भाषा या `all` बताएँ।
+translate-opted-out = 🔕 आपकी श्रेणी- और सर्वर-व्यापी सदस्यताएँ इस चैनल से अनुवाद नहीं भेजेंगी। वापस चालू करने के लिए इसे फिर से चलाएँ।
+translate-opted-in = 🔔 आपकी श्रेणी- और सर्वर-व्यापी सदस्यताएँ इस चैनल में फिर से लागू हैं।
+understand-none =
+    🗣️ आपने अभी तक कोई भाषा नहीं बताई है जो आप समझते हैं।
+
+    उन भाषाओं में पहले से लिखे संदेशों के अनुवाद बंद करने के लिए `/fabrica translate understand english, hindi` का उपयोग करें।
+understand-show =
+    🗣️ आप ये भाषाएँ समझते हैं: **{ $languages }**
+
+    हटाने के लिए `/fabrica translate understand none` का उपयोग करें।
+understand-cleared = 🗣️ आपकी समझी जाने वाली भाषाएँ हटा दी गईं। अब आपको हर सदस्य भाषा के अनुवाद फिर से मिलेंगे।
+understand-set =
+    🗣️ आप ये भाषाएँ समझते हैं: **{ $languages }**
+    इन भाषाओं में पहले से लिखे संदेशों के अनुवाद DM आपको नहीं मिलेंगे, और `/fabrica translate last` उन्हें वैसे ही दिखाएगा।
+default-set =
+    🌐 डिफ़ॉल्ट भाषा **{ $language }** पर सेट की गई!
+
+    अब `/fabrica translate last` डिफ़ॉल्ट रूप से { $language } में अनुवाद करेगा।
+default-show = 🌐 आपकी डिफ़ॉल्ट अनुवाद भाषा **{ $language }** है।
+default-none =
+    🌐 आपने अभी तक कोई डिफ़ॉल्ट भाषा सेट नहीं की है।
+
+    सेट करने के लिए `/fabrica translate default <language>` का उपयोग करें।
+    डिफ़ॉल्ट के बिना, `/fabrica translate last` आपकी पहली सदस्यता का उपयोग करता है।
+
+## Translation settings
+
+translation-public = 🌐 **अनुवाद:** { $translation }
+translation-dm-jump = [संदेश पर जाएँ]({ $link })
+translation-dm-footer = चैनल में जवाब देने के लिए इस संदेश का जवाब दें
+translate-status-none = 📖 इस चैनल में आपकी कोई अनुवाद सदस्यता नहीं है।
+translate-status-subscriptions = 📖 आपकी सदस्यताएँ: **{ $languages }**
+translate-status-scope-guild = पूरे सर्वर में
+translate-status-scope-category = पूरी श्रेणी में
+translate-status-scope-channel = यह चैनल
+translate-status-mode = चैनल मोड: **{ $mode }**
+translate-status-debug = 🔧 डीबग मोड: **चालू**
+translate-status-opted-out = 🔕 इस चैनल में श्रेणी- और सर्वर-व्यापी सदस्यताएँ बंद हैं
+translate-status-understood = 🗣️ समझी जाने वाली भाषाएँ: **{ $languages }**
+translate-status-dialect = बोली: { $value }
+translate-status-formality = औपचारिकता: { $value }
+translate-status-script = लिपि: { $value }
+debug-not-subscribed = ⚠️ पहले `/fabrica translate subscribe <language>` से अनुवाद की सदस्यता लें
+debug-on = 🔧 डीबग मोड **चालू** - इस चैनल में आपको अपने संदेशों के अनुवाद भी DM में मिलेंगे।
+debug-off = 🔧 डीबग मोड **बंद** - इस चैनल में आपको अपने संदेशों के अनुवाद नहीं मिलेंगे।
+dialect-set =
+    🗣️ बोली की पसंद सेट की गई!
+    **भाषा:** { $language }
+    **बोली:** { $dialect }
+
+    जब दूसरे आपके लिए { $language } में अनुवाद करेंगे, तो आपकी पसंद की बोली इस्तेमाल होगी।
+dialect-none =
+    🗣️ आपने कोई बोली की पसंद सेट नहीं की है।
+
+    सेट करने के लिए `/fabrica translate dialect <language> <dialect>` का उपयोग करें।
+    उदाहरण:
+    • `/fabrica translate dialect filipino bisaya`
+    • `/fabrica translate dialect chinese cantonese`
+    • `/fabrica translate dialect spanish mexican`
+dialect-show =
+    🗣️ **आपकी बोली की पसंद**
+
+    { $list }
+
+    बदलने के लिए `/fabrica translate dialect <language> <dialect>` का उपयोग करें।
+dialect-cleared = 🗣️ **{ $language }** के लिए बोली की पसंद हटा दी गई। डिफ़ॉल्ट बोली इस्तेमाल होगी।
+formality-none = 🎩 आपने कोई औपचारिकता की पसंद सेट नहीं की है।
+formality-show =
+    🎩 **आपकी औपचारिकता की पसंद**
+
+    { $list }
+formality-help =
+    बदलने के लिए `/fabrica translate formality <language> <formal|neutral|casual|default>` का उपयोग करें।
+    उदाहरण:
+    • `/fabrica translate formality hindi formal` (आप)
+    • `/fabrica translate formality french casual` (tu)
+formality-invalid = ⚠️ अमान्य औपचारिकता। `formal`, `neutral`, `casual` या हटाने के लिए `default` का उपयोग करें।
+formality-set =
+    🎩 औपचारिकता की पसंद सेट की गई!
+    **भाषा:** { $language }
+    **औपचारिकता:** { $formality }
+
+    आपके लिए { $language } में अनुवाद इसी लहजे में होंगे।
+formality-cleared = 🎩 **{ $language }** के लिए औपचारिकता की पसंद हटा दी गई। मॉडल का डिफ़ॉल्ट लहजा इस्तेमाल होगा।
+script-none = 🔤 आपने कोई लिपि की पसंद सेट नहीं की है।
+script-show =
+    🔤 **आपकी लिपि की पसंद**
+
+    { $list }
+script-help =
+    बदलने के लिए `/fabrica translate script <language> <native|romanized|both|default>` का उपयोग करें।
+    उदाहरण:
+    • `/fabrica translate script hindi romanized` (namaste)
+    • `/fabrica translate script hindi both` (नमस्ते + namaste)
+script-single = ⚠️ **{ $language }** सिर्फ़ एक लिपि में उपलब्ध है, इसलिए चुनने को कुछ नहीं है।
+script-invalid = ⚠️ अमान्य लिपि। `native`, `romanized`, `both` या हटाने के लिए `default` का उपयोग करें।
+script-set-native =
+    🔤 लिपि की पसंद सेट की गई!
+    **भाषा:** { $language }
+    **लिपि:** native
+
+    { $language } में अनुवाद मूल लिपि में होंगे।
+script-set-romanized =
+    🔤 लिपि की पसंद सेट की गई!
+    **भाषा:** { $language }
+    **लिपि:** romanized
+
+    { $language } में अनुवाद लैटिन अक्षरों में लिखे जाएँगे।
+script-set-both =
+    🔤 लिपि की पसंद सेट की गई!
+    **भाषा:** { $language }
+    **लिपि:** both
+
+    { $language } में अनुवाद मूल लिपि में होंगे, और उसके बाद रोमन लिपि में एक पंक्ति।
+script-cleared = 🔤 **{ $language }** के लिए लिपि की पसंद हटा दी गई। मूल लिपि इस्तेमाल होगी।
+mode-invalid =
+    ⚠️ अमान्य मोड। उपलब्ध मोड:
+    • **off** - कोई अनुवाद नहीं
+    • **silent** - सिर्फ़ DM अनुवाद (अंग्रेज़ी अनुवाद के लिए `en` की सदस्यता लें)
+    • **on** - DM अनुवाद + सार्वजनिक अंग्रेज़ी अनुवाद
+    • **transparent** - सभी अनुवाद सार्वजनिक रूप से दिखाए जाते हैं
+    • **mirror** - जुड़े चैनलों में अनुवादित संदेश दोबारा पोस्ट करें (`/fabrica translate mirror`)
+mode-set-off = ✅ अनुवाद **बंद** है। संदेशों पर कोई कार्रवाई नहीं होगी।
+mode-set-silent =
+    ✅ अनुवाद मोड: **silent**
+    • गैर-अंग्रेज़ी → अंग्रेज़ी: सिर्फ़ अंग्रेज़ी सदस्यों को DM
+    • अंग्रेज़ी → अन्य: भाषा के सदस्यों को DM
+mode-set-on =
+    ✅ अनुवाद मोड: **on**
+    • गैर-अंग्रेज़ी → अंग्रेज़ी: सार्वजनिक रूप से पोस्ट
+    • अंग्रेज़ी → अन्य: भाषा के सदस्यों को DM
+mode-set-transparent =
+    ✅ अनुवाद मोड: **transparent**
+    • सभी अनुवाद चैनल में सार्वजनिक रूप से पोस्ट होते हैं
+mode-set-mirror =
+    ✅ अनुवाद मोड: **mirror**
+    • संदेश अनुवाद होकर जुड़े चैनलों में दोबारा पोस्ट होते हैं
+mode-show = 📖 चैनल का अनुवाद मोड: { $mode }
+mode-describe-off = **off** - कोई अनुवाद नहीं
+mode-describe-silent = **silent** - सिर्फ़ DM अनुवाद
+mode-describe-on = **on** - DM + सार्वजनिक अंग्रेज़ी अनुवाद
+mode-describe-transparent = **transparent** - सभी अनुवाद सार्वजनिक
+mode-show-attachments = 📎 अटैचमेंट, एम्बेड और तस्वीरों के कैप्शन का भी अनुवाद होता है
+attachments-show-on = 📎 इस चैनल में अटैचमेंट अनुवाद **चालू** है।
+attachments-show-off = 📎 इस चैनल में अटैचमेंट अनुवाद **बंद** है।
+attachments-invalid = ⚠️ `on` या `off` का उपयोग करें।
+attachments-on =
+    ✅ अटैचमेंट अनुवाद **चालू**
+    • एम्बेड के शीर्षक, विवरण और तस्वीरों का alt टेक्स्ट संदेश के साथ अनुवादित होते हैं
+    • { $size } KB तक की `.txt` और `.md` फ़ाइलें अनुवादित करके वापस भेजी जाती हैं
+attachments-off = ✅ अटैचमेंट अनुवाद **बंद**
+last-no-language = ⚠️ `/fabrica translate default <language>` से डिफ़ॉल्ट भाषा सेट करें, या भाषा बताएँ: `/fabrica translate last <count> <language>`
+last-no-new = 📭 दिखाने के लिए कोई नया संदेश नहीं है।
+last-nothing = 📭 अनुवाद करने के लिए कोई संदेश नहीं है।
+last-header = 📜 **पिछले { $count } संदेश, { $language } में अनुवादित:**
+last-truncated = _...लंबाई के कारण छोटा किया गया_
+last-none-translatable = 📭 अनुवाद योग्य कोई संदेश नहीं मिला।
+
+## Server administration
+
+server-status-title = 📊 **सर्वर स्टेटस**
+server-permissions-title = 📊 **सर्वर अनुमतियाँ**
+server-permissions-none =
+    कोई कस्टम भूमिका अनुमति तय नहीं है।
+    सिर्फ़ **MANAGE_CHANNELS** या **ADMINISTRATOR** वाले लोग अनुवाद सेटिंग बदल सकते हैं।
+
+    किसी भूमिका को अनुवाद मोड बदलने की अनुमति देने के लिए `/fabrica server allow mode @role` का उपयोग करें।
+server-permissions-admin = **एडमिन:** { $roles }
+server-permissions-mode = **मोड:** { $roles }
+server-permissions-legend = _एडमिन सभी सेटिंग बदल सकते हैं। मोड वाले अनुवाद मोड बदल सकते हैं।_
+server-everyone = **सभी**
+server-queue =
+    **अनुवाद कतार:** { $queued }/{ $capacity } कतार में (अधिकतम { $peak }), { $workers } वर्कर
+    पूरे { $completed } • विफल { $failed } • प्रतीक्षा { $waits }
+server-invalid-permission =
+    ⚠️ अमान्य अनुमति। उपलब्ध अनुमतियाँ:
+    • **mode** - अनुवाद मोड बदल सकते हैं
+    • **admin** - Fabrica की सभी सेटिंग बदल सकते हैं
+server-invalid-target = ⚠️ अमान्य लक्ष्य। `everyone` का उपयोग करें या कोई भूमिका चुनें।
+server-allow-missing-target =
+    ⚠️ कोई भूमिका बताएँ या सभी को अनुमति देने के लिए `everyone` का उपयोग करें।
+    उदाहरण: `/fabrica server allow mode @role` या `/fabrica server allow mode everyone:everyone`
+server-deny-missing-target =
+    ⚠️ अनुमति हटाने के लिए कोई भूमिका बताएँ या `everyone` का उपयोग करें।
+    उदाहरण: `/fabrica server deny mode @role` या `/fabrica server deny mode everyone:everyone`
+server-allowed-mode = ✅ { $target } अब अनुवाद मोड बदल सकते हैं।
+server-allowed-admin = ✅ { $target } अब Fabrica की सभी सेटिंग बदल सकते हैं।
+server-denied-mode = ✅ { $target } अब अनुवाद मोड नहीं बदल सकते।
+server-denied-admin = ✅ { $target } अब Fabrica की सेटिंग नहीं बदल सकते।
+
+## Integrations
+
+watch-unknown-type = ⚠️ अज्ञात प्रकार। `github` या `plane` का उपयोग करें।
+watch-stopped = ✅ यह चैनल अब **{ $name }** को नहीं देख रहा है।
+github-repo =
+    📦 **रिपॉज़िटरी: { $repo }**
+
+    ⚠️ GitHub इंटीग्रेशन जल्द आ रहा है!
+    इसमें रिपो के आँकड़े, हाल की गतिविधि और खुले PR दिखेंगे।
+github-commits =
+    📝 **{ $repo } के हाल के { $count } कमिट**
+
+    ⚠️ GitHub इंटीग्रेशन जल्द आ रहा है!
+github-prs =
+    🔀 **{ $repo } के खुले PR**
+
+    ⚠️ GitHub इंटीग्रेशन जल्द आ रहा है!
+github-watching-all =
+    ✅ यह चैनल अब **{ $repo }** को **all** स्तर पर देख रहा है।
+    आपको इनकी सूचनाएँ मिलेंगी:
+    • सभी push, PR, issue, टिप्पणियाँ और रिलीज़
+github-watching-important =
+    ✅ यह चैनल अब **{ $repo }** को **important** स्तर पर देख रहा है।
+    आपको इनकी सूचनाएँ मिलेंगी:
+    • PR (खुले, मर्ज हुए, बंद हुए)
+    • रिलीज़
+    • माइलस्टोन
+github-watching-minimal =
+    ✅ यह चैनल अब **{ $repo }** को **minimal** स्तर पर देख रहा है।
+    आपको इनकी सूचनाएँ मिलेंगी:
+    • सिर्फ़ रिलीज़
+    • मर्ज हुए PR
+github-watching-off =
+    ✅ यह चैनल अब **{ $repo }** को **off** स्तर पर देख रहा है।
+    आपको इनकी सूचनाएँ मिलेंगी:
+    • कुछ नहीं (म्यूट)
+plane-project =
+    📊 **{ $project }**
+
+    ⚠️ Plane इंटीग्रेशन जल्द आ रहा है!
+    इसमें प्रोजेक्ट का स्टेटस, स्प्रिंट की प्रगति और खुले issue दिखेंगे।
+plane-all-projects = सभी प्रोजेक्ट
+plane-current-project = मौजूदा
+plane-issues =
+    📋 **{ $project } के issue** (फ़िल्टर: { $filter })
+
+    ⚠️ Plane इंटीग्रेशन जल्द आ रहा है!
+plane-sprint =
+    🏃 **{ $project } का स्प्रिंट स्टेटस**
+
+    ⚠️ Plane इंटीग्रेशन जल्द आ रहा है!
+plane-watching =
+    ✅ यह चैनल अब **{ $project }** को **{ $level }** स्तर पर देख रहा है।
+    आपको प्रोजेक्ट गतिविधि की सूचनाएँ मिलेंगी।
+
+## Mirror channels
+
+translate-settings-server-only = ⚠️ अनुवाद सेटिंग केवल सर्वर में उपलब्ध हैं।
+translate-no-permission =
+    ⚠️ अनुवाद सेटिंग बदलने के लिए आपके पास कोई तय भूमिका या MANAGE_CHANNELS अनुमति होनी चाहिए।
+    सर्वर एडमिन `/fabrica server allow mode @role` से भूमिकाएँ तय कर सकते हैं।
+mirror-not-linked-yet =
+    ⚠️ यह चैनल अभी किसी मिरर समूह से जुड़ा नहीं है।
+    इसे जोड़ने के लिए `/fabrica translate mirror <name> <language>` का उपयोग करें।
+mirror-invalid-name = ⚠️ मिरर का नाम 1-32 अक्षरों का होना चाहिए, जैसे `team`।
+mirror-webhook-failed = ⚠️ इस चैनल में वेबहुक नहीं बन सका। Fabrica को यहाँ **Manage Webhooks** अनुमति चाहिए।
+mirror-linked =
+    🪞 यह चैनल अब मिरर समूह **{ $name }** में **{ $language }** भाषा के साथ है।
+    { $channels }
+mirror-link-another = मिररिंग शुरू करने के लिए `/fabrica translate mirror { $name } <language>` से दूसरा चैनल जोड़ें।
+mirror-not-linked = 📖 यह चैनल किसी मिरर समूह से जुड़ा नहीं है।
+mirror-unlinked = ✅ यह चैनल मिरर समूह **{ $name }** से हटा दिया गया। अनुवाद मोड अब **off** है।
+mirror-describe =
+    **mirror** - समूह **{ $name }**, यह चैनल **{ $language }** में
+    { $channels }
+mirror-describe-unlinked = **mirror** - जुड़ा नहीं है; `/fabrica translate mirror <name> <language>` का उपयोग करें
+mirror-channels = जुड़े चैनल: { $channels }
+mirror-replying-to = -# ↪ **{ $name }** को जवाब
+mirror-replying-to-link = -# ↪ **{ $name }** को जवाब: { $link }
+mirror-reply-someone = एक संदेश
+
+## Corrections and glossary
+
+correction-suggest = सुधार सुझाएँ
+correction-modal-title = सुधार सुझाएँ
+correction-modal-text = बेहतर अनुवाद
+correction-modal-term = शब्द सुधार (वैकल्पिक)
+correction-modal-term-placeholder = जैसे sprint = सही { $language } शब्द
+correction-save-failed = ⚠️ आपका सुधार सहेजा नहीं जा सका। कृपया फिर से कोशिश करें।
+correction-not-enabled = ⚠️ उस सर्वर के लिए सुधार चालू नहीं हैं।
+correction-empty = ⚠️ सुधार खाली था, इसलिए कुछ सहेजा नहीं गया।
+correction-term-format = ⚠️ शब्द सुधार `term = translation` की तरह लिखें, जैसे `sprint = स्प्रिंट`।
+correction-submitted = ✅ धन्यवाद! सुधार #{ $id } सर्वर एडमिन को समीक्षा के लिए भेज दिया गया।
+corrections-server-only = ⚠️ सुधार सिर्फ़ सर्वर में उपलब्ध हैं।
+corrections-admin-only = ⚠️ सिर्फ़ सर्वर एडमिन सुधारों की समीक्षा कर सकते हैं।
+corrections-none = 📝 कोई सुधार समीक्षा की प्रतीक्षा में नहीं है।
+corrections-title = 📝 **लंबित सुधार**
+corrections-entry =
+    **#{ $id }** { $from } → { $to }, { $user } द्वारा (`{ $model }`)
+    > { $translation }
+    ✏️ { $correction }
+corrections-term-fix = शब्द सुधार: **{ $term }** = **{ $translation }**
+corrections-recurring = 🔁 **बार-बार सुझाए गए शब्द सुधार**
+corrections-recurring-entry = • **{ $term }** = **{ $translation }** ({ $from } → { $to }) - { $count } बार सुझाया गया, जैसे #{ $id }
+corrections-review-hint = `/fabrica translate correction <id> promote` या `dismiss` का उपयोग करें।
+correction-missing = ⚠️ इस सर्वर में सुधार #{ $id } नहीं है।
+correction-already-reviewed = ⚠️ सुधार #{ $id } की समीक्षा पहले ही हो चुकी है।
+correction-no-term = ⚠️ सुधार #{ $id } में कोई शब्द सुधार नहीं है, इसलिए शब्दावली में जोड़ने को कुछ नहीं है।
+correction-promoted = ✅ **{ $term }** = **{ $translation }** ({ $from } → { $to }) शब्दावली में जोड़ा गया और सुधार #{ $id } बंद किया गया।
+correction-dismissed = 🗑️ सुधार #{ $id } खारिज किया गया।
+glossary-not-found = ⚠️ **{ $term }** शब्दावली में नहीं है।
+glossary-removed = 🗑️ **{ $term }** शब्दावली से हटाया गया।
+glossary-empty = 📚 शब्दावली खाली है। `/fabrica translate corrections` से शब्द सुधार जोड़ें।
+glossary-title = 📚 **शब्दावली**
+
+## DM replies
+
+dm-reply-preview =
+    💬 यह { $channel } में **{ $language }** में पोस्ट होगा:
+
+    { $content }
+dm-reply-post = पोस्ट करें
+dm-reply-cancel = रद्द करें
+dm-reply-failed = ⚠️ अनुवाद विफल रहा, इसलिए कुछ पोस्ट नहीं हुआ। कृपया फिर से कोशिश करें।
+dm-reply-not-member = ⚠️ आप उस चैनल में संदेश नहीं भेज सकते, इसलिए कुछ पोस्ट नहीं हुआ।
+dm-reply-already-done = ⚠️ यह जवाब पहले ही पोस्ट या रद्द हो चुका है।
+dm-reply-not-yours = ⚠️ यह जवाब आपका नहीं है।
+dm-reply-cancelled = ❌ रद्द किया गया - कुछ पोस्ट नहीं हुआ।
+dm-reply-posted = ✅ पोस्ट किया गया: { $link }
+dm-reply-webhook-failed = ⚠️ चैनल में पोस्ट नहीं हो सका। Fabrica को वहाँ **Manage Webhooks** अनुमति चाहिए।
+
+## Command descriptions (Discord localizations)
+
+command-fabrica =
+    .description = Palace Fabrica - टीम समन्वय
+command-fabrica-translate =
+    .description = अनुवाद कमांड
+command-fabrica-translate-subscribe =
+    .description = अपनी पसंदीदा भाषा में अनुवाद पाएँ
+    .param-language = जिस भाषा में अनुवाद चाहिए (en, hi, fr)
+    .param-scope = कहाँ: channel (डिफ़ॉल्ट), category या server
+command-fabrica-translate-unsubscribe =
+    .description = अनुवाद DM बंद करें (भाषा या 'all' बताएँ)
+    .param-language = जिस भाषा की सदस्यता हटानी है (या 'all')
+    .param-scope = कहाँ: channel (डिफ़ॉल्ट), category या server
+command-fabrica-translate-optout =
+    .description = इस चैनल में अपनी श्रेणी- और सर्वर-व्यापी सदस्यताएँ बंद/चालू करें
+command-fabrica-translate-attachments =
+    .description = इस चैनल में अटैचमेंट, एम्बेड और इमेज कैप्शन का अनुवाद (on/off)
+    .param-enabled = on या off (मौजूदा सेटिंग देखने के लिए खाली छोड़ें)
+command-fabrica-translate-corrections =
+    .description = सुझाए गए अनुवाद सुधारों की समीक्षा करें (एडमिन)
+command-fabrica-translate-correction =
+    .description = सुधार के शब्द को शब्दावली में जोड़ें या उसे खारिज करें (एडमिन)
+    .param-id = /fabrica translate corrections से सुधार संख्या
+    .param-action = promote या dismiss
+command-fabrica-translate-glossary =
+    .description = हर अनुवाद में इस्तेमाल होने वाली सर्वर शब्दावली देखें (एडमिन)
+    .param-remove = शब्दावली से हटाने वाला शब्द
+command-fabrica-translate-status =
+    .description = अपनी अनुवाद सेटिंग्स देखें
+command-fabrica-translate-mode =
+    .description = इस चैनल का अनुवाद मोड सेट करें (off/silent/on/transparent)
+    .param-mode = अनुवाद मोड: off, silent, on या transparent
+command-fabrica-translate-info =
+    .description = इस चैनल का मौजूदा अनुवाद मोड देखें
+command-fabrica-translate-debug =
+    .description = डीबग मोड चालू/बंद करें (अपने संदेशों का अनुवाद पाएँ)
+command-fabrica-translate-last =
+    .description = हाल के संदेश अपनी भाषा में अनुवादित देखें
+    .param-count = कितने संदेश दिखाने हैं (अधिकतम 100)
+    .param-language = लक्ष्य भाषा (जैसे 'filipino', 'hindi')
+command-fabrica-translate-dialect =
+    .description = किसी भाषा के लिए अपनी पसंदीदा बोली सेट करें
+    .param-language = भाषा (जैसे filipino, chinese, spanish)
+    .param-dialect = बोली (जैसे bisaya, cantonese, mexican)
+command-fabrica-translate-dialects =
+    .description = अपनी बोली की पसंद देखें
+command-fabrica-translate-dialect-clear =
+    .description = किसी भाषा के लिए बोली की पसंद हटाएँ
+    .param-language = जिस भाषा की बोली हटानी है
+command-fabrica-translate-formality =
+    .description = किसी भाषा के लिए औपचारिकता चुनें (formal, neutral, casual)
+    .param-language = भाषा (जैसे hindi, french, korean)
+    .param-level = औपचारिकता: formal, neutral, casual, या हटाने के लिए default
+command-fabrica-translate-script =
+    .description = लिपि चुनें: देवनागरी, रोमन, या दोनों
+    .param-language = भाषा (जैसे hindi, korean)
+    .param-script = लिपि: native, romanized, both, या हटाने के लिए default
+command-fabrica-translate-default =
+    .description = अपनी डिफ़ॉल्ट अनुवाद भाषा सेट करें (इसी भाषा में जवाब भी मिलेंगे)
+    .param-language = डिफ़ॉल्ट भाषा (जैसे 'en', 'filipino', 'hindi')
+command-fabrica-server =
+    .description = सर्वर प्रबंधन कमांड
+command-fabrica-server-status =
+    .description = सर्वर अनुवाद स्टेटस देखें
+command-fabrica-server-permissions =
+    .description = इस सर्वर की अनुमतियाँ देखें
+command-fabrica-last =
+    .description = हाल के संदेश अपनी भाषा में अनुवादित देखें
+    .param-count = कितने संदेश दिखाने हैं (अधिकतम 100)
+    .param-language = लक्ष्य भाषा (जैसे 'filipino', 'hindi')
+command-fabrica-available =
+    .description = खुद को उपलब्ध के रूप में चिह्नित करें
+    .param-message = आप किस पर काम कर रहे हैं (वैकल्पिक)
+command-fabrica-busy =
+    .description = खुद को व्यस्त के रूप में चिह्नित करें
+    .param-message = आप किस पर ध्यान दे रहे हैं (वैकल्पिक)
+command-fabrica-away =
+    .description = खुद को दूर के रूप में चिह्नित करें
+    .param-message = आप कब लौटेंगे (वैकल्पिक)
+command-fabrica-clear =
+    .description = अपना स्टेटस हटाएँ
+command-fabrica-hours =
+    .description = अपने काम के घंटे सेट करें
+    .param-schedule = शेड्यूल (जैसे 'M-F 10-14, 20-2', 'until 23:30' या 'remove Sat')
+command-fabrica-settings =
+    .description = उपयोगकर्ता सेटिंग्स
+command-fabrica-settings-timezone =
+    .description = अपना टाइमज़ोन सेट करें (एडमिन दूसरों के लिए भी कर सकते हैं)
+    .param-timezone = टाइमज़ोन (जैसे 'London', 'New York', 'Asia/Kolkata')
+    .param-user = जिस उपयोगकर्ता का टाइमज़ोन सेट करना है (केवल एडमिन)
+command-fabrica-settings-format =
+    .description = अपना समय प्रारूप सेट करें
+    .param-format = समय प्रारूप: 24h या 12h
+command-fabrica-settings-always-show-me =
+    .description = हमेशा दिखाएँ चालू/बंद करें (15 मिनट से अधिक व्यस्त होने पर भी /team में दिखें)
+command-fabrica-settings-auto-status =
+    .description = ऑटो स्टेटस चालू/बंद करें (काम के घंटों में उपलब्ध, बाकी समय दूर)
+command-fabrica-settings-region =
+    .description = चुनें कि किस क्षेत्र के सार्वजनिक अवकाश आप पर लागू हों
+    .param-region = क्षेत्र कोड (जैसे IN, FR, CA) या हटाने के लिए 'none'
+command-fabrica-settings-calendar-link =
+    .description = टीम के घंटों और छुट्टियों का निजी कैलेंडर लिंक पाएँ (सिर्फ़ अपने लिए 'me')
+command-fabrica-settings-calendar-import =
+    .description = अपने कैलेंडर (URL या .ics फ़ाइल) से छुट्टियाँ इंपोर्ट करें, रोकने के लिए 'off'
+    .param-scope = 'team' (डिफ़ॉल्ट) या सिर्फ़ अपने घंटों के लिए 'me'
+command-fabrica-who =
+    .description = देखें कि अभी कौन उपलब्ध है
+command-fabrica-team =
+    .description = उपलब्ध टीम सदस्य देखें (सबको दिखाने के लिए 'public')
+    .param-visibility = सबको दिखाएँ ('public' लिखें)
+command-fabrica-overlap =
+    .description = देखें कि लोगों के काम के घंटे कब मिलते हैं (7 दिनों के लिए 'week')
+    .param-first = पहला व्यक्ति
+    .param-second = दूसरा व्यक्ति
+    .param-third = तीसरा व्यक्ति
+    .param-fourth = चौथा व्यक्ति
+    .param-fifth = पाँचवाँ व्यक्ति
+    .param-period = अगले 7 दिन देखें ('week' लिखें)
+command-fabrica-leave =
+    .description = छुट्टियाँ: इस हफ़्ते और अगले हफ़्ते कौन बाहर है
+command-fabrica-leave-add =
+    .description = छुट्टी जोड़ें (जैसे '20 Dec' से '27 Dec' तक)
+    .param-from = छुट्टी का पहला दिन (जैसे '20 Dec', '2026-12-20', 'tomorrow')
+    .param-to = छुट्टी का आखिरी दिन (डिफ़ॉल्ट: पहला दिन)
+    .param-reason = कारण (वैकल्पिक)
+    .param-visibility = कारण कौन देखे: 'team' या 'private' (डिफ़ॉल्ट)
+command-fabrica-leave-list =
+    .description = देखें कि इस हफ़्ते और अगले हफ़्ते कौन छुट्टी पर है
+command-fabrica-leave-remove =
+    .description = अपनी कोई छुट्टी हटाएँ
+    .param-id = /fabrica leave list में दिखाया गया छुट्टी नंबर
+command-fabrica-holiday =
+    .description = क्षेत्र के अनुसार सार्वजनिक अवकाश
+command-fabrica-holiday-add =
+    .description = किसी क्षेत्र के लिए सार्वजनिक अवकाश जोड़ें (सर्वर प्रबंधक)
+    .param-region = क्षेत्र कोड (जैसे IN, FR, CA)
+    .param-date = तारीख (जैसे '25 Dec' या '2026-12-25')
+    .param-name = अवकाश का नाम
+command-fabrica-holiday-remove =
+    .description = किसी क्षेत्र का सार्वजनिक अवकाश हटाएँ (सर्वर प्रबंधक)
+    .param-region = क्षेत्र कोड (जैसे IN, FR, CA)
+    .param-date = तारीख (जैसे '25 Dec' या '2026-12-25')
+command-fabrica-standup =
+    .description = स्टैंडअप की सेटिंग और प्रतिभागी देखें
+command-fabrica-standup-setup =
+    .description = स्टैंडअप शुरू करें या उसका चैनल, दिन और सारांश का समय बदलें (एडमिन)
+    .param-channel = सारांश का चैनल (डिफ़ॉल्ट: यह चैनल)
+    .param-days = 'weekdays', 'daily' या 'mon,wed,fri' जैसे दिन
+    .param-time = सारांश का समय, आपके टाइमज़ोन में (जैसे 17:00)
+command-fabrica-standup-questions =
+    .description = स्टैंडअप के सवाल बदलें (एडमिन)
+    .param-questions = '|' से अलग किए गए 5 तक सवाल
+command-fabrica-standup-add =
+    .description = किसी को स्टैंडअप में जोड़ें (एडमिन)
+    .param-user = जोड़ने वाला व्यक्ति
+command-fabrica-standup-remove =
+    .description = किसी को स्टैंडअप से हटाएँ (एडमिन)
+    .param-user = हटाने वाला व्यक्ति
+command-fabrica-standup-off =
+    .description = स्टैंडअप बंद करें (एडमिन)
+command-fabrica-board =
+    .description = देखें कि स्टेटस बोर्ड कहाँ है
+command-fabrica-board-here =
+    .description = इस चैनल में स्टेटस बोर्ड पोस्ट करें (एडमिन)
+command-fabrica-board-off =
+    .description = स्टेटस बोर्ड हटाएँ (एडमिन)
+command-who =
+    .description = देखें कि अभी कौन उपलब्ध है
+command-team =
+    .description = पूरी टीम का स्टेटस देखें
+    .param-visibility = सबको दिखाएँ ('public' लिखें)
@@ -2,21 +2,70 @@
 
 use crate::config::Config;
 use crate::db::Database;
-use crate::modules::{github, plane, status, translation};
+use crate::modules::{auto_status, board, calendar_import, corrections, dm_reply, expiry, github, leave, mirror, overlap, plane, standup, status, translation};
+use crate::services::i18n::{self, locale_for, tr, tr_args};
+use crate::services::pipeline::{PipelineStats, TranslationPipeline};
+use crate::services::translator::TranslatorService;
+use crate::webhooks;
 use anyhow::Result;
 use poise::serenity_prelude::{self as serenity, Mentionable};
+use std::sync::Arc;
 use tracing::{error, info};
 
 /// Shared state across all commands
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Data {
     pub config: Config,
     pub db: Database,
+    /// Translator shared by all commands and workers (one HTTP connection pool)
+    pub translator: Arc<TranslatorService>,
+    /// Bounded queue feeding the translation workers
+    pub pipeline: TranslationPipeline,
+    /// Asks the status boards to redraw after a change
+    pub boards: board::BoardRefresh,
 }
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
 pub type Context<'a> = poise::Context<'a, Data, Error>;
 
+/// Discord HTTP client and cache, for work handed off from the gateway handler
+#[derive(Clone)]
+pub struct Discord {
+    pub http: Arc<serenity::Http>,
+    pub cache: Arc<serenity::Cache>,
+}
+
+impl From<&serenity::Context> for Discord {
+    fn from(ctx: &serenity::Context) -> Self {
+        Self {
+            http: ctx.http.clone(),
+            cache: ctx.cache.clone(),
+        }
+    }
+}
+
+impl serenity::CacheHttp for Discord {
+    fn http(&self) -> &serenity::Http {
+        &self.http
+    }
+
+    fn cache(&self) -> Option<&Arc<serenity::Cache>> {
+        Some(&self.cache)
+    }
+}
+
+impl AsRef<serenity::Http> for Discord {
+    fn as_ref(&self) -> &serenity::Http {
+        &self.http
+    }
+}
+
+impl AsRef<serenity::Cache> for Discord {
+    fn as_ref(&self) -> &serenity::Cache {
+        &self.cache
+    }
+}
+
 /// Run the Discord bot
 pub async fn run(config: Config, db: Database) -> Result<()> {
     let token = config.discord.token.clone();
@@ -25,29 +74,40 @@ pub async fn run(config: Config, db: Database) -> Result<()> {
         | serenity::GatewayIntents::MESSAGE_CONTENT
         | serenity::GatewayIntents::GUILDS;
 
+    let (pipeline, workers) = TranslationPipeline::new(&config.translation);
     let data = Data {
         config: config.clone(),
         db: db.clone(),
+        translator: Arc::new(TranslatorService::new(&config.translation)),
+        pipeline,
+        boards: board::BoardRefresh::default(),
     };
 
     // Capture guild_ids before the closure
     let guild_ids = config.discord.guild_ids.clone();
 
+    let mut commands = vec![
+        // Root command group
+        fabrica(),
+        // Convenience aliases
+        who(),
+        team(),
+    ];
+    i18n::localize_commands(&mut commands);
+
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
-            commands: vec![
-                // Root command group
-                fabrica(),
-                // Convenience aliases
-                who(),
-                team(),
-            ],
+            commands,
             event_handler: |ctx, event, framework, data| {
                 Box::pin(event_handler(ctx, event, framework, data))
             },
             on_error: |error| {
                 Box::pin(async move {
                     error!("Command error: {:?}", error);
+                    if let poise::FrameworkError::Command { ctx, .. } = error {
+                        let msg = tr(locale_for(ctx).await, "error-command");
+                        let _ = ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await;
+                    }
                 })
             },
             ..Default::default()
@@ -77,6 +137,14 @@ pub async fn run(config: Config, db: Database) -> Result<()> {
                 }
 
                 info!("Bot restricted to {} guild(s)", guild_ids.len());
+
+                workers.spawn(data.clone());
+                expiry::spawn(ctx.clone(), data.clone());
+                auto_status::spawn(data.clone());
+                calendar_import::spawn(data.clone());
+                standup::spawn(ctx.clone(), data.clone());
+                board::spawn(Discord::from(ctx), data.clone());
+                dm_reply::spawn(data.clone());
                 Ok(data)
             })
         })
@@ -86,8 +154,14 @@ pub async fn run(config: Config, db: Database) -> Result<()> {
         .framework(framework)
         .await?;
 
+    // Webhooks and calendar feeds share the bot's HTTP client and cache
+    let webhook_handle = webhooks::start_server(config.clone(), db, client.http.clone(), client.cache.clone());
+    info!("Webhook server starting on port {}", config.webhooks.port);
+
     info!("Palace Fabrica connected to Discord");
-    client.start().await?;
+    let result = client.start().await;
+    webhook_handle.abort();
+    result?;
 
     Ok(())
 }
@@ -113,24 +187,64 @@ async fn event_handler(
                     return Ok(());
                 }
             } else {
-                // Skip DMs
+                // DMs only matter when they reply to a translation DM
+                if new_message.message_reference.is_some() {
+                    data.pipeline.submit_dm_reply(&Discord::from(ctx), new_message).await;
+                }
                 return Ok(());
             }
 
-            // Spawn translation as a separate task for concurrent processing
-            // This allows multiple messages to be processed simultaneously
-            let ctx = ctx.clone();
-            let message = new_message.clone();
-            let data_clone = Data {
-                config: data.config.clone(),
-                db: data.db.clone(),
-            };
-
-            tokio::spawn(async move {
-                if let Err(e) = translation::handle_message(&ctx, &message, &data_clone).await {
-                    error!("Translation error for message {}: {}", message.id, e);
-                }
-            });
+            // Hand off to the translation workers; waits here if the queue is full
+            data.pipeline.submit(&Discord::from(ctx), new_message).await;
+        }
+        serenity::FullEvent::MessageUpdate { event, .. } => {
+            // Only content changes matter; embed unfurls also arrive as updates
+            if event.content.is_none() || event.author.as_ref().is_some_and(|a| a.bot) {
+                return Ok(());
+            }
+            if let Some(guild_id) = event.guild_id.filter(|gid| is_allowed_guild(data, *gid)) {
+                data.pipeline.submit_edit(&Discord::from(ctx), guild_id, event.channel_id, event.id).await;
+            }
+        }
+        serenity::FullEvent::MessageDelete { channel_id, deleted_message_id, guild_id } => {
+            if let Some(guild_id) = guild_id.filter(|gid| is_allowed_guild(data, *gid)) {
+                data.pipeline.submit_delete(&Discord::from(ctx), guild_id, *channel_id, *deleted_message_id).await;
+            }
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Component(component) }
+            if component.data.custom_id.starts_with(dm_reply::BUTTON_PREFIX) =>
+        {
+            dm_reply::handle_component(&Discord::from(ctx), component, data).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Component(component) }
+            if component.data.custom_id.starts_with(expiry::BUTTON_PREFIX) =>
+        {
+            expiry::handle_component(ctx, component, data).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Component(component) }
+            if component.data.custom_id.starts_with(corrections::CUSTOM_ID_PREFIX) =>
+        {
+            corrections::handle_component(ctx, component, data).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Modal(modal) }
+            if modal.data.custom_id.starts_with(corrections::CUSTOM_ID_PREFIX) =>
+        {
+            corrections::handle_modal(ctx, modal, data).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Component(component) }
+            if component.data.custom_id.starts_with(standup::CUSTOM_ID_PREFIX) =>
+        {
+            standup::handle_component(ctx, component, data).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Modal(modal) }
+            if modal.data.custom_id.starts_with(standup::CUSTOM_ID_PREFIX) =>
+        {
+            standup::handle_modal(ctx, modal, data).await?;
+        }
+        serenity::FullEvent::InteractionCreate { interaction: serenity::Interaction::Component(component) }
+            if component.data.custom_id.starts_with(board::BUTTON_PREFIX) =>
+        {
+            board::handle_component(ctx, component, data).await?;
         }
         serenity::FullEvent::Ready { data_about_bot } => {
             info!("Bot ready as {}", data_about_bot.user.name);
@@ -140,6 +254,11 @@ async fn event_handler(
     Ok(())
 }
 
+/// Whether a guild is one of the configured guilds
+fn is_allowed_guild(data: &Data, guild_id: serenity::GuildId) -> bool {
+    data.config.discord.guild_ids.contains(&guild_id.to_string())
+}
+
 // ==================== Root Command ====================
 
 /// Palace Fabrica - Coordination infrastructure
@@ -158,6 +277,11 @@ async fn event_handler(
         "settings_cmd",
         "who_cmd",
         "team_cmd",
+        "overlap_cmd",
+        "leave_cmd",
+        "holiday_cmd",
+        "standup_cmd",
+        "board_cmd",
         "project_cmd",
         "issues_cmd",
         "sprint_cmd",
@@ -169,14 +293,14 @@ async fn event_handler(
     )
 )]
 pub async fn fabrica(ctx: Context<'_>) -> Result<(), Error> {
-    ctx.say("Palace Fabrica - Use `/fabrica help` for available commands").await?;
+    ctx.say(tr(locale_for(ctx).await, "fabrica-intro")).await?;
     Ok(())
 }
 
 // ==================== Translation Commands ====================
 
 /// Translation commands
-#[poise::command(slash_command, prefix_command, subcommands("subscribe", "unsubscribe", "status_sub", "mode_set", "mode_show", "debug_mode", "last_cmd", "dialect_set", "dialect_show", "dialect_clear", "default_set"), rename = "translate")]
+#[poise::command(slash_command, prefix_command, subcommands("subscribe", "unsubscribe", "optout_toggle", "status_sub", "mode_set", "mode_show", "debug_mode", "last_cmd", "dialect_set", "dialect_show", "dialect_clear", "formality_set", "script_set", "understand_set", "default_set", "mirror_link", "mirror_unlink", "attachments_set", "corrections_queue", "correction_review", "glossary_show"), rename = "translate")]
 pub async fn translate_cmd(_ctx: Context<'_>) -> Result<(), Error> {
     Ok(())
 }
@@ -200,107 +324,94 @@ pub async fn last_fabrica(
 /// Show server translation status
 #[poise::command(slash_command, prefix_command, rename = "status")]
 pub async fn server_status(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match ctx.guild_id() {
         Some(gid) => gid.to_string(),
         None => {
-            ctx.say("⚠️ This command is only available in servers.").await?;
+            ctx.say(tr(locale, "error-server-only")).await?;
             return Ok(());
         }
     };
 
     // Get all permissions for this guild
     let permissions = ctx.data().db.get_guild_permissions(&guild_id).await?;
+    let queue = format_queue_stats(locale, &ctx.data().pipeline.stats());
 
-    if permissions.is_empty() {
-        ctx.say("📊 **Server Status**\n\n\
-                 No custom role permissions configured.\n\
-                 Only users with **MANAGE_CHANNELS** or **ADMINISTRATOR** can manage translation settings.\n\n\
-                 Use `/fabrica server allow mode @role` to grant a role permission to change translation modes.")
-            .await?;
-    } else {
-        let mut mode_targets = Vec::new();
-        let mut admin_targets = Vec::new();
-
-        for (role_id, permission) in permissions {
-            let target_display = if role_id == "everyone" {
-                "**everyone**".to_string()
-            } else {
-                format!("<@&{}>", role_id)
-            };
-            match permission.as_str() {
-                "mode" => mode_targets.push(target_display),
-                "admin" => admin_targets.push(target_display),
-                _ => {}
-            }
-        }
+    let msg = format!(
+        "{}\n\n{}\n\n{}",
+        tr(locale, "server-status-title"),
+        format_permissions(locale, permissions),
+        queue
+    );
+    ctx.say(msg).await?;
 
-        let mut msg = String::from("📊 **Server Status**\n\n");
+    Ok(())
+}
 
-        if !admin_targets.is_empty() {
-            msg.push_str(&format!("**Admin:** {}\n", admin_targets.join(", ")));
-        }
-        if !mode_targets.is_empty() {
-            msg.push_str(&format!("**Mode:** {}\n", mode_targets.join(", ")));
-        }
+/// Format translation queue metrics for the server status display
+fn format_queue_stats(locale: &str, stats: &PipelineStats) -> String {
+    tr_args(locale, "server-queue", &[
+        ("queued", &stats.queued.to_string()),
+        ("capacity", &stats.capacity.to_string()),
+        ("peak", &stats.max_depth.to_string()),
+        ("workers", &stats.workers.to_string()),
+        ("completed", &stats.completed.to_string()),
+        ("failed", &stats.failed.to_string()),
+        ("waits", &stats.backpressure_waits.to_string()),
+    ])
+}
 
-        msg.push_str("\n_Admin can manage all settings. Mode can change translation modes._");
+/// List who holds each role permission, or explain the defaults when none are set
+fn format_permissions(locale: &str, permissions: Vec<(String, String)>) -> String {
+    if permissions.is_empty() {
+        return tr(locale, "server-permissions-none");
+    }
 
-        ctx.say(msg).await?;
+    let mut mode_targets = Vec::new();
+    let mut admin_targets = Vec::new();
+    for (role_id, permission) in permissions {
+        let target_display = if role_id == "everyone" {
+            tr(locale, "server-everyone")
+        } else {
+            format!("<@&{}>", role_id)
+        };
+        match permission.as_str() {
+            "mode" => mode_targets.push(target_display),
+            "admin" => admin_targets.push(target_display),
+            _ => {}
+        }
     }
 
-    Ok(())
+    let mut msg = String::new();
+    if !admin_targets.is_empty() {
+        msg.push_str(&tr_args(locale, "server-permissions-admin", &[("roles", &admin_targets.join(", "))]));
+        msg.push('\n');
+    }
+    if !mode_targets.is_empty() {
+        msg.push_str(&tr_args(locale, "server-permissions-mode", &[("roles", &mode_targets.join(", "))]));
+        msg.push('\n');
+    }
+    msg.push('\n');
+    msg.push_str(&tr(locale, "server-permissions-legend"));
+    msg
 }
 
 /// Show configured permissions for this server
 #[poise::command(slash_command, prefix_command, rename = "permissions")]
 pub async fn server_permissions(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match ctx.guild_id() {
         Some(gid) => gid.to_string(),
         None => {
-            ctx.say("⚠️ This command is only available in servers.").await?;
+            ctx.say(tr(locale, "error-server-only")).await?;
             return Ok(());
         }
     };
 
     // Get all permissions for this guild
     let permissions = ctx.data().db.get_guild_permissions(&guild_id).await?;
-
-    if permissions.is_empty() {
-        ctx.say("📊 **Server Permissions**\n\n\
-                 No custom role permissions configured.\n\
-                 Only users with **MANAGE_CHANNELS** or **ADMINISTRATOR** can manage translation settings.\n\n\
-                 Use `/fabrica server allow mode @role` to grant a role permission to change translation modes.")
-            .await?;
-    } else {
-        let mut mode_targets = Vec::new();
-        let mut admin_targets = Vec::new();
-
-        for (role_id, permission) in permissions {
-            let target_display = if role_id == "everyone" {
-                "**everyone**".to_string()
-            } else {
-                format!("<@&{}>", role_id)
-            };
-            match permission.as_str() {
-                "mode" => mode_targets.push(target_display),
-                "admin" => admin_targets.push(target_display),
-                _ => {}
-            }
-        }
-
-        let mut msg = String::from("📊 **Server Permissions**\n\n");
-
-        if !admin_targets.is_empty() {
-            msg.push_str(&format!("**Admin:** {}\n", admin_targets.join(", ")));
-        }
-        if !mode_targets.is_empty() {
-            msg.push_str(&format!("**Mode:** {}\n", mode_targets.join(", ")));
-        }
-
-        msg.push_str("\n_Admin can manage all settings. Mode can change translation modes._");
-
-        ctx.say(msg).await?;
-    }
+    let msg = format!("{}\n\n{}", tr(locale, "server-permissions-title"), format_permissions(locale, permissions));
+    ctx.say(msg).await?;
 
     Ok(())
 }
@@ -313,55 +424,17 @@ pub async fn server_allow(
     #[description = "Role to grant permission (or 'everyone')"] role: Option<serenity::Role>,
     #[description = "Grant to everyone (type 'everyone')"] everyone: Option<String>,
 ) -> Result<(), Error> {
-    let guild_id = match ctx.guild_id() {
-        Some(gid) => gid.to_string(),
-        None => {
-            ctx.say("⚠️ This command is only available in servers.").await?;
-            return Ok(());
-        }
-    };
-
-    // Check if user has admin permission
-    if !translation::has_admin_permission(&ctx, &guild_id).await {
-        ctx.say("⚠️ You need **ADMINISTRATOR** permission to manage server settings.").await?;
-        return Ok(());
-    }
-
-    let permission_lower = permission.to_lowercase();
-    if !matches!(permission_lower.as_str(), "mode" | "admin") {
-        ctx.say("⚠️ Invalid permission. Available permissions:\n\
-                 • **mode** - Can change translation modes\n\
-                 • **admin** - Can manage all Fabrica settings").await?;
-        return Ok(());
-    }
-
-    // Determine target: "everyone" or a specific role
-    let (target_id, target_display) = if let Some(ref everyone_str) = everyone {
-        if everyone_str.to_lowercase() == "everyone" {
-            ("everyone".to_string(), "**everyone**".to_string())
-        } else {
-            ctx.say("⚠️ Invalid target. Use `everyone` or select a role.").await?;
-            return Ok(());
-        }
-    } else if let Some(ref role) = role {
-        (role.id.to_string(), role.mention().to_string())
-    } else {
-        ctx.say("⚠️ Please specify a role or use `everyone` to grant permission to all users.\n\
-                 Example: `/fabrica server allow mode @role` or `/fabrica server allow mode everyone:everyone`").await?;
+    let locale = locale_for(ctx).await;
+    let Some((guild_id, permission, target_id, target_display)) =
+        permission_target(ctx, locale, &permission, role, everyone, "server-allow-missing-target").await?
+    else {
         return Ok(());
     };
 
     let granted_by = ctx.author().id.to_string();
+    ctx.data().db.add_guild_permission(&guild_id, &target_id, &permission, &granted_by).await?;
 
-    ctx.data().db.add_guild_permission(&guild_id, &target_id, &permission_lower, &granted_by).await?;
-
-    let permission_desc = match permission_lower.as_str() {
-        "mode" => "change translation modes",
-        "admin" => "manage all Fabrica settings",
-        _ => "unknown",
-    };
-
-    ctx.say(format!("✅ {} can now {}.", target_display, permission_desc)).await?;
+    ctx.say(tr_args(locale, &format!("server-allowed-{}", permission), &[("target", &target_display)])).await?;
 
     Ok(())
 }
@@ -374,55 +447,65 @@ pub async fn server_deny(
     #[description = "Role to revoke permission from"] role: Option<serenity::Role>,
     #[description = "Revoke from everyone (type 'everyone')"] everyone: Option<String>,
 ) -> Result<(), Error> {
-    let guild_id = match ctx.guild_id() {
-        Some(gid) => gid.to_string(),
-        None => {
-            ctx.say("⚠️ This command is only available in servers.").await?;
-            return Ok(());
-        }
+    let locale = locale_for(ctx).await;
+    let Some((guild_id, permission, target_id, target_display)) =
+        permission_target(ctx, locale, &permission, role, everyone, "server-deny-missing-target").await?
+    else {
+        return Ok(());
+    };
+
+    ctx.data().db.remove_guild_permission(&guild_id, &target_id, &permission).await?;
+
+    ctx.say(tr_args(locale, &format!("server-denied-{}", permission), &[("target", &target_display)])).await?;
+
+    Ok(())
+}
+
+/// Check an allow/deny request: run in a server by an admin, with a valid
+/// permission and a role or `everyone`. Returns the guild ID, permission,
+/// target ID and target display, or replies and returns `None`.
+async fn permission_target(
+    ctx: Context<'_>,
+    locale: &str,
+    permission: &str,
+    role: Option<serenity::Role>,
+    everyone: Option<String>,
+    missing_target: &str,
+) -> Result<Option<(String, String, String, String)>, Error> {
+    let Some(guild_id) = ctx.guild_id().map(|gid| gid.to_string()) else {
+        ctx.say(tr(locale, "error-server-only")).await?;
+        return Ok(None);
     };
 
     // Check if user has admin permission
     if !translation::has_admin_permission(&ctx, &guild_id).await {
-        ctx.say("⚠️ You need **ADMINISTRATOR** permission to manage server settings.").await?;
-        return Ok(());
+        ctx.say(tr(locale, "error-admin-required")).await?;
+        return Ok(None);
     }
 
-    let permission_lower = permission.to_lowercase();
-    if !matches!(permission_lower.as_str(), "mode" | "admin") {
-        ctx.say("⚠️ Invalid permission. Available permissions:\n\
-                 • **mode** - Can change translation modes\n\
-                 • **admin** - Can manage all Fabrica settings").await?;
-        return Ok(());
+    let permission = permission.to_lowercase();
+    if !matches!(permission.as_str(), "mode" | "admin") {
+        ctx.say(tr(locale, "server-invalid-permission")).await?;
+        return Ok(None);
     }
 
     // Determine target: "everyone" or a specific role
-    let (target_id, target_display) = if let Some(ref everyone_str) = everyone {
-        if everyone_str.to_lowercase() == "everyone" {
-            ("everyone".to_string(), "**everyone**".to_string())
-        } else {
-            ctx.say("⚠️ Invalid target. Use `everyone` or select a role.").await?;
-            return Ok(());
+    let (target_id, target_display) = match (everyone, role) {
+        (Some(everyone), _) if everyone.eq_ignore_ascii_case("everyone") => {
+            ("everyone".to_string(), tr(locale, "server-everyone"))
+        }
+        (Some(_), _) => {
+            ctx.say(tr(locale, "server-invalid-target")).await?;
+            return Ok(None);
+        }
+        (None, Some(role)) => (role.id.to_string(), role.mention().to_string()),
+        (None, None) => {
+            ctx.say(tr(locale, missing_target)).await?;
+            return Ok(None);
         }
-    } else if let Some(ref role) = role {
-        (role.id.to_string(), role.mention().to_string())
-    } else {
-        ctx.say("⚠️ Please specify a role or use `everyone` to revoke permission.\n\
-                 Example: `/fabrica server deny mode @role` or `/fabrica server deny mode everyone:everyone`").await?;
-        return Ok(());
-    };
-
-    ctx.data().db.remove_guild_permission(&guild_id, &target_id, &permission_lower).await?;
-
-    let permission_desc = match permission_lower.as_str() {
-        "mode" => "change translation modes",
-        "admin" => "manage all Fabrica settings",
-        _ => "unknown",
     };
 
-    ctx.say(format!("✅ {} can no longer {}.", target_display, permission_desc)).await?;
-
-    Ok(())
+    Ok(Some((guild_id, permission, target_id, target_display)))
 }
 
 /// Subscribe to receive translations in your preferred language (en, hi, fr)
@@ -430,8 +513,9 @@ pub async fn server_deny(
 pub async fn subscribe(
     ctx: Context<'_>,
     #[description = "Language to receive translations in (en, hi, fr)"] language: String,
+    #[description = "Where: channel (default), category, or server"] scope: Option<String>,
 ) -> Result<(), Error> {
-    translation::subscribe(ctx, language).await
+    translation::subscribe(ctx, language, scope).await
 }
 
 /// Stop receiving translation DMs (specify language or 'all')
@@ -439,8 +523,15 @@ pub async fn subscribe(
 pub async fn unsubscribe(
     ctx: Context<'_>,
     #[description = "Language to unsubscribe from (or 'all')"] language: Option<String>,
+    #[description = "Where: channel (default), category, or server"] scope: Option<String>,
 ) -> Result<(), Error> {
-    translation::unsubscribe(ctx, language).await
+    translation::unsubscribe(ctx, language, scope).await
+}
+
+/// Toggle your category- and server-wide subscriptions off in this channel
+#[poise::command(slash_command, prefix_command, rename = "optout")]
+pub async fn optout_toggle(ctx: Context<'_>) -> Result<(), Error> {
+    translation::opt_out(ctx).await
 }
 
 /// Show your translation settings
@@ -453,11 +544,61 @@ pub async fn status_sub(ctx: Context<'_>) -> Result<(), Error> {
 #[poise::command(slash_command, prefix_command, rename = "mode")]
 pub async fn mode_set(
     ctx: Context<'_>,
-    #[description = "Translation mode: off, silent, on, or transparent"] mode: String,
+    #[description = "Translation mode: off, silent, on, transparent, or mirror"] mode: String,
 ) -> Result<(), Error> {
     translation::set_mode(ctx, mode).await
 }
 
+/// Link this channel to others so messages are reposted, translated, in each
+#[poise::command(slash_command, prefix_command, rename = "mirror")]
+pub async fn mirror_link(
+    ctx: Context<'_>,
+    #[description = "Mirror group name shared by the linked channels (e.g., 'team')"] name: String,
+    #[description = "Language this channel is written in (e.g., 'hindi', 'en')"] language: String,
+) -> Result<(), Error> {
+    mirror::link(ctx, name, language).await
+}
+
+/// Remove this channel from its mirror group
+#[poise::command(slash_command, prefix_command, rename = "unmirror")]
+pub async fn mirror_unlink(ctx: Context<'_>) -> Result<(), Error> {
+    mirror::unlink(ctx).await
+}
+
+/// Review suggested translation corrections (admins)
+#[poise::command(slash_command, prefix_command, rename = "corrections")]
+pub async fn corrections_queue(ctx: Context<'_>) -> Result<(), Error> {
+    corrections::queue(ctx).await
+}
+
+/// Promote a correction's term fix into the glossary, or dismiss it (admins)
+#[poise::command(slash_command, prefix_command, rename = "correction")]
+pub async fn correction_review(
+    ctx: Context<'_>,
+    #[description = "Correction number from /fabrica translate corrections"] id: i64,
+    #[description = "promote or dismiss"] action: corrections::ReviewAction,
+) -> Result<(), Error> {
+    corrections::review(ctx, id, action).await
+}
+
+/// Show the server glossary used in every translation (admins)
+#[poise::command(slash_command, prefix_command, rename = "glossary")]
+pub async fn glossary_show(
+    ctx: Context<'_>,
+    #[description = "Term to remove from the glossary"] remove: Option<String>,
+) -> Result<(), Error> {
+    corrections::glossary(ctx, remove).await
+}
+
+/// Translate attachments, embeds and image alt text in this channel (on/off)
+#[poise::command(slash_command, prefix_command, rename = "attachments")]
+pub async fn attachments_set(
+    ctx: Context<'_>,
+    #[description = "on or off (leave empty to show the current setting)"] enabled: Option<String>,
+) -> Result<(), Error> {
+    translation::set_attachments(ctx, enabled).await
+}
+
 /// Show current translation mode for this channel
 #[poise::command(slash_command, prefix_command, rename = "info")]
 pub async fn mode_show(ctx: Context<'_>) -> Result<(), Error> {
@@ -505,6 +646,35 @@ pub async fn dialect_clear(
     translation::clear_dialect(ctx, language).await
 }
 
+/// Set your preferred formality for a language (formal, neutral, casual)
+#[poise::command(slash_command, prefix_command, rename = "formality")]
+pub async fn formality_set(
+    ctx: Context<'_>,
+    #[description = "Language (e.g., hindi, french, korean)"] language: Option<String>,
+    #[description = "Formality: formal, neutral, casual, or default to clear"] level: Option<String>,
+) -> Result<(), Error> {
+    translation::set_formality(ctx, language, level).await
+}
+
+/// Choose native script, romanized, or both for a language (e.g. Hindi)
+#[poise::command(slash_command, prefix_command, rename = "script")]
+pub async fn script_set(
+    ctx: Context<'_>,
+    #[description = "Language (e.g., hindi, korean)"] language: Option<String>,
+    #[description = "Script: native, romanized, both, or default to clear"] script: Option<String>,
+) -> Result<(), Error> {
+    translation::set_script(ctx, language, script).await
+}
+
+/// Set the languages you read without translation (no DMs for messages already in them)
+#[poise::command(slash_command, prefix_command, rename = "understand")]
+pub async fn understand_set(
+    ctx: Context<'_>,
+    #[description = "Languages, comma-separated (e.g., 'english, hindi'), or 'none' to clear"] languages: Option<String>,
+) -> Result<(), Error> {
+    translation::set_understood(ctx, languages).await
+}
+
 /// Set your default translation language (used by /fabrica translate last)
 #[poise::command(slash_command, prefix_command, rename = "default")]
 pub async fn default_set(
@@ -556,7 +726,7 @@ pub async fn clear_cmd(ctx: Context<'_>) -> Result<(), Error> {
 #[poise::command(slash_command, prefix_command, rename = "hours")]
 pub async fn hours_cmd(
     ctx: Context<'_>,
-    #[description = "Schedule (e.g., 'M-F 9:30 to 17:30' or 'until 23:30')"]
+    #[description = "Schedule (e.g., 'M-F 10-14, 20-2', 'until 23:30' or 'remove Sat')"]
     #[rest]
     schedule: Option<String>,
 ) -> Result<(), Error> {
@@ -571,7 +741,7 @@ pub async fn hours_cmd(
     slash_command,
     prefix_command,
     rename = "settings",
-    subcommands("settings_timezone", "settings_format", "settings_always_show_me"),
+    subcommands("settings_timezone", "settings_format", "settings_always_show_me", "settings_auto_status", "settings_region", "settings_calendar_link", "settings_calendar_import"),
 )]
 pub async fn settings_cmd(ctx: Context<'_>) -> Result<(), Error> {
     status::show_settings(ctx).await
@@ -605,6 +775,44 @@ pub async fn settings_always_show_me(ctx: Context<'_>) -> Result<(), Error> {
     status::toggle_always_show_me(ctx).await
 }
 
+/// Toggle auto status (available during your working hours, away outside them)
+#[poise::command(slash_command, prefix_command, rename = "auto-status")]
+pub async fn settings_auto_status(ctx: Context<'_>) -> Result<(), Error> {
+    status::toggle_auto_status(ctx).await
+}
+
+/// Choose the region whose public holidays apply to you
+#[poise::command(slash_command, prefix_command, rename = "region")]
+pub async fn settings_region(
+    ctx: Context<'_>,
+    #[description = "Region code (e.g., IN, FR, CA) or 'none' to clear"]
+    region: Option<String>,
+) -> Result<(), Error> {
+    status::set_holiday_region(ctx, region).await
+}
+
+/// Get a private calendar link to your hours and leave (admins: the whole team's)
+#[poise::command(slash_command, prefix_command, rename = "calendar-link")]
+pub async fn settings_calendar_link(
+    ctx: Context<'_>,
+    #[description = "team (admins), me, or revoke to stop your links"]
+    scope: Option<status::CalendarScope>,
+) -> Result<(), Error> {
+    status::calendar_link(ctx, scope).await
+}
+
+/// Import your out-of-office from your own calendar (a URL, or an uploaded .ics file)
+#[poise::command(slash_command, prefix_command, rename = "calendar-import")]
+pub async fn settings_calendar_import(
+    ctx: Context<'_>,
+    #[description = "Your calendar's iCal address, or 'off' to stop importing"]
+    url: Option<String>,
+    #[description = "An .ics file to import once"]
+    file: Option<serenity::Attachment>,
+) -> Result<(), Error> {
+    calendar_import::import(ctx, url, file).await
+}
+
 /// Show who's currently available
 #[poise::command(slash_command, prefix_command, rename = "who")]
 pub async fn who_cmd(ctx: Context<'_>) -> Result<(), Error> {
@@ -622,6 +830,156 @@ pub async fn team_cmd(
     status::team(ctx, public).await
 }
 
+/// Find when people's working hours overlap (add 'week' to look 7 days ahead)
+#[poise::command(slash_command, prefix_command, rename = "overlap")]
+pub async fn overlap_cmd(
+    ctx: Context<'_>,
+    #[description = "First person"] first: serenity::User,
+    #[description = "Second person"] second: serenity::User,
+    #[description = "Third person"] third: Option<serenity::User>,
+    #[description = "Fourth person"] fourth: Option<serenity::User>,
+    #[description = "Fifth person"] fifth: Option<serenity::User>,
+    #[description = "today, or week for the next 7 days"] period: Option<overlap::Period>,
+) -> Result<(), Error> {
+    let users = [Some(first), Some(second), third, fourth, fifth].into_iter().flatten().collect();
+    overlap::overlap(ctx, users, period.unwrap_or(overlap::Period::Today)).await
+}
+
+/// Leave: who's out this week and next
+#[poise::command(slash_command, prefix_command, rename = "leave", subcommands("leave_add", "leave_list", "leave_remove"))]
+pub async fn leave_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    leave::list(ctx).await
+}
+
+/// Add leave (e.g., from '20 Dec' to '27 Dec')
+#[poise::command(slash_command, prefix_command, rename = "add")]
+pub async fn leave_add(
+    ctx: Context<'_>,
+    #[description = "First day off (e.g., '20 Dec', '2026-12-20', 'tomorrow')"] from: String,
+    #[description = "Last day off (defaults to the first)"] to: Option<String>,
+    #[description = "Reason (optional)"] reason: Option<String>,
+    #[description = "Who sees the reason: 'team' or 'private' (default)"] visibility: Option<String>,
+) -> Result<(), Error> {
+    leave::add(ctx, from, to, reason, visibility).await
+}
+
+/// Show who's out this week and next
+#[poise::command(slash_command, prefix_command, rename = "list")]
+pub async fn leave_list(ctx: Context<'_>) -> Result<(), Error> {
+    leave::list(ctx).await
+}
+
+/// Remove one of your leave entries
+#[poise::command(slash_command, prefix_command, rename = "remove")]
+pub async fn leave_remove(
+    ctx: Context<'_>,
+    #[description = "Leave number shown in /fabrica leave list"] id: i64,
+) -> Result<(), Error> {
+    leave::remove(ctx, id).await
+}
+
+/// Async standup: show its settings and participants
+#[poise::command(
+    slash_command,
+    prefix_command,
+    rename = "standup",
+    subcommands("standup_setup", "standup_questions", "standup_add", "standup_remove", "standup_off")
+)]
+pub async fn standup_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    standup::show(ctx).await
+}
+
+/// Start the standup or change its channel, days and summary time (admins)
+#[poise::command(slash_command, prefix_command, rename = "setup")]
+pub async fn standup_setup(
+    ctx: Context<'_>,
+    #[description = "Channel for the summary (defaults to this one)"] channel: Option<serenity::GuildChannel>,
+    #[description = "'weekdays', 'daily' or days like 'mon,wed,fri'"] days: Option<String>,
+    #[description = "When the summary is posted, in your timezone (e.g., 17:00)"] time: Option<String>,
+) -> Result<(), Error> {
+    standup::setup(ctx, channel, days, time).await
+}
+
+/// Change the standup questions (admins)
+#[poise::command(slash_command, prefix_command, rename = "questions")]
+pub async fn standup_questions(
+    ctx: Context<'_>,
+    #[description = "Up to 5 questions separated by '|'"] questions: String,
+) -> Result<(), Error> {
+    standup::set_questions(ctx, questions).await
+}
+
+/// Add someone to the standup (admins)
+#[poise::command(slash_command, prefix_command, rename = "add")]
+pub async fn standup_add(
+    ctx: Context<'_>,
+    #[description = "Person to add"] user: serenity::User,
+) -> Result<(), Error> {
+    standup::add(ctx, user).await
+}
+
+/// Take someone out of the standup (admins)
+#[poise::command(slash_command, prefix_command, rename = "remove")]
+pub async fn standup_remove(
+    ctx: Context<'_>,
+    #[description = "Person to remove"] user: serenity::User,
+) -> Result<(), Error> {
+    standup::remove(ctx, user).await
+}
+
+/// Turn the standup off (admins)
+#[poise::command(slash_command, prefix_command, rename = "off")]
+pub async fn standup_off(ctx: Context<'_>) -> Result<(), Error> {
+    standup::off(ctx).await
+}
+
+/// Live status board: show where it is
+#[poise::command(slash_command, prefix_command, rename = "board", subcommands("board_here", "board_off"))]
+pub async fn board_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    board::show(ctx).await
+}
+
+/// Post the status board in this channel (admins)
+#[poise::command(slash_command, prefix_command, rename = "here")]
+pub async fn board_here(ctx: Context<'_>) -> Result<(), Error> {
+    board::here(ctx).await
+}
+
+/// Remove the status board (admins)
+#[poise::command(slash_command, prefix_command, rename = "off")]
+pub async fn board_off(ctx: Context<'_>) -> Result<(), Error> {
+    board::off(ctx).await
+}
+
+/// Public holidays per region
+#[poise::command(slash_command, prefix_command, rename = "holiday", subcommands("holiday_add", "holiday_remove"))]
+pub async fn holiday_cmd(ctx: Context<'_>) -> Result<(), Error> {
+    leave::list(ctx).await
+}
+
+/// Add a public holiday for a region (server managers)
+#[poise::command(slash_command, prefix_command, rename = "add")]
+pub async fn holiday_add(
+    ctx: Context<'_>,
+    #[description = "Region code (e.g., IN, FR, CA)"] region: String,
+    #[description = "Date (e.g., '25 Dec' or '2026-12-25')"] date: String,
+    #[description = "Holiday name"]
+    #[rest]
+    name: String,
+) -> Result<(), Error> {
+    leave::holiday_add(ctx, region, date, name).await
+}
+
+/// Remove a region's public holiday (server managers)
+#[poise::command(slash_command, prefix_command, rename = "remove")]
+pub async fn holiday_remove(
+    ctx: Context<'_>,
+    #[description = "Region code (e.g., IN, FR, CA)"] region: String,
+    #[description = "Date (e.g., '25 Dec' or '2026-12-25')"] date: String,
+) -> Result<(), Error> {
+    leave::holiday_remove(ctx, region, date).await
+}
+
 // ==================== Plane Commands ====================
 
 /// Show project overview
@@ -697,7 +1055,7 @@ pub async fn watch_cmd(
         "github" => github::watch(ctx, name, level).await,
         "plane" => plane::watch(ctx, name, level).await,
         _ => {
-            ctx.say("Unknown watch type. Use 'github' or 'plane'.").await?;
+            ctx.say(tr(locale_for(ctx).await, "watch-unknown-type")).await?;
             Ok(())
         }
     }
@@ -714,7 +1072,7 @@ pub async fn unwatch_cmd(
         "github" => github::unwatch(ctx, name).await,
         "plane" => plane::unwatch(ctx, name).await,
         _ => {
-            ctx.say("Unknown watch type. Use 'github' or 'plane'.").await?;
+            ctx.say(tr(locale_for(ctx).await, "watch-unknown-type")).await?;
             Ok(())
         }
     }
@@ -0,0 +1,177 @@
+//! Offline command-line tools
+//!
+//! Subcommands that run without connecting to Discord:
+//! - `fabrica prompt-test <from> <to> <text...>` renders a prompt template
+//! - `fabrica eval` runs the evaluation corpus against a mock or live model
+
+use crate::config::{Config, PromptConfig, TranslationConfig};
+use crate::services::eval::{self, MockModel};
+use crate::services::prompts::{PromptPurpose, PromptTemplates, PromptVars};
+use crate::services::translator::{language_name, TranslatorService};
+use anyhow::{bail, Context as _, Result};
+
+const PROMPT_TEST_USAGE: &str = "\
+Usage: fabrica prompt-test <from> <to> <text...> [options]
+
+Options:
+  --purpose <name>              translate, detect, transliterate or retry (default: translate)
+  --dialect <name>              Target dialect
+  --formality <level>           formal, neutral or casual
+  --script <pref|hint>          native, romanized, both, or a custom script hint
+  --glossary <terms>            Glossary entries, e.g. \"sprint=स्प्रिंट\"
+  --context <text>              Earlier messages for context
+  --reason <text>               Rejection reason (retry purpose)";
+
+const EVAL_USAGE: &str = "\
+Usage: fabrica eval [options]
+
+Options:
+  --corpus <path>               JSON Lines corpus (default: eval/corpus.jsonl)
+  --backend <name>              mock (answers from the corpus, offline) or config
+                                (the backend in fabrica.toml) (default: mock)
+  --out <path>                  Write per-case results here instead of stdout";
+
+/// Run a subcommand if one was given; returns false to start the bot normally
+pub async fn run(args: &[String]) -> Result<bool> {
+    match args.first().map(String::as_str) {
+        Some("prompt-test") => {
+            prompt_test(&args[1..])?;
+            Ok(true)
+        }
+        Some("eval") => {
+            run_eval(&args[1..]).await?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Render a prompt template for a sample input and print it
+fn prompt_test(args: &[String]) -> Result<()> {
+    let mut purpose = PromptPurpose::Translate;
+    let mut vars = PromptVars::default();
+    let mut script = None;
+    let mut formality = None;
+    let mut positional = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| match iter.next() {
+            Some(v) => Ok(v.clone()),
+            None => bail!("{} requires a value\n\n{}", flag, PROMPT_TEST_USAGE),
+        };
+        match arg.as_str() {
+            "--purpose" => {
+                let p = value("--purpose")?;
+                purpose = match PromptPurpose::parse(&p) {
+                    Some(p) => p,
+                    None => bail!("Unknown purpose: {}\n\n{}", p, PROMPT_TEST_USAGE),
+                };
+            }
+            "--dialect" => vars.dialect = value("--dialect")?,
+            "--script" => script = Some(value("--script")?),
+            "--formality" => formality = Some(value("--formality")?),
+            "--glossary" => vars.glossary = value("--glossary")?,
+            "--context" => vars.context = value("--context")?,
+            "--reason" => vars.reason = value("--reason")?,
+            "-h" | "--help" => {
+                println!("{}", PROMPT_TEST_USAGE);
+                return Ok(());
+            }
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    if positional.len() < 3 {
+        bail!("Missing arguments\n\n{}", PROMPT_TEST_USAGE);
+    }
+    let from = positional[0].to_lowercase();
+    let to = positional[1].to_lowercase();
+    vars.text = positional[2..].join(" ");
+
+    let prompts = PromptTemplates::load(&load_prompt_config());
+    vars.source = language_name(&from).to_string();
+    vars.target = language_name(&to).to_string();
+    vars.script = match script.as_deref() {
+        None if purpose == PromptPurpose::Transliterate => prompts.script_hint_for(&to, Some("romanized")).to_string(),
+        None => prompts.script_hint(&to).to_string(),
+        Some(pref @ ("native" | "romanized" | "both")) => prompts.script_hint_for(&to, Some(pref)).to_string(),
+        Some(hint) => hint.to_string(),
+    };
+    if let Some(level) = formality {
+        vars.formality = prompts.formality_hint(&level.to_lowercase(), &to).to_string();
+    }
+
+    println!("Template: {}", prompts.key_for(purpose, &to));
+    for message in prompts.render_chat(purpose, &to, &vars) {
+        println!("─────────────── {} ───────────────", message.role);
+        println!("{}", message.content);
+    }
+
+    Ok(())
+}
+
+/// Prompt overrides from fabrica.toml, or the environment if it can't be loaded
+fn load_prompt_config() -> PromptConfig {
+    Config::load()
+        .map(|config| config.translation.prompts)
+        .unwrap_or_else(|_| PromptConfig {
+            dir: std::env::var("TRANSLATION_PROMPTS_DIR").ok(),
+            ..Default::default()
+        })
+}
+
+/// Run the evaluation corpus and print per-case results and a summary
+async fn run_eval(args: &[String]) -> Result<()> {
+    let mut corpus_path = "eval/corpus.jsonl".to_string();
+    let mut backend = "mock".to_string();
+    let mut out = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| match iter.next() {
+            Some(v) => Ok(v.clone()),
+            None => bail!("{} requires a value\n\n{}", flag, EVAL_USAGE),
+        };
+        match arg.as_str() {
+            "--corpus" => corpus_path = value("--corpus")?,
+            "--backend" => backend = value("--backend")?,
+            "--out" => out = Some(value("--out")?),
+            "-h" | "--help" => {
+                println!("{}", EVAL_USAGE);
+                return Ok(());
+            }
+            other => bail!("Unknown argument: {}\n\n{}", other, EVAL_USAGE),
+        }
+    }
+
+    let source = std::fs::read_to_string(&corpus_path).with_context(|| format!("Failed to read {}", corpus_path))?;
+    let cases = eval::parse_corpus(&source)?;
+
+    let config = Config::load().map(|c| c.translation).unwrap_or_else(|_| default_translation_config());
+    let results = match backend.as_str() {
+        "mock" => {
+            let model = MockModel::new(&cases, &PromptTemplates::load(&config.prompts));
+            let translator = TranslatorService::with_scripted_backend(&config, model.backend());
+            eval::run(&cases, &translator, Some(&model)).await
+        }
+        "config" => eval::run(&cases, &TranslatorService::new(&config), None).await,
+        other => bail!("Unknown backend: {}\n\n{}", other, EVAL_USAGE),
+    };
+
+    let formatted = eval::format_results(&results);
+    match out {
+        Some(path) => std::fs::write(&path, formatted).with_context(|| format!("Failed to write {}", path))?,
+        None => print!("{}", formatted),
+    }
+
+    let (overall, by_category) = eval::summarize(&results);
+    println!("\n{} cases from {} ({} backend)", overall.total, corpus_path, backend);
+    print!("{}", eval::format_summary(&overall, &by_category));
+    Ok(())
+}
+
+/// Translation settings with every default, for running without fabrica.toml
+fn default_translation_config() -> TranslationConfig {
+    toml::from_str("").expect("translation config has defaults for every field")
+}
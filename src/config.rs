@@ -2,6 +2,7 @@
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Main configuration structure
@@ -13,6 +14,8 @@ pub struct Config {
     pub plane: PlaneConfig,
     pub github: GithubConfig,
     pub webhooks: WebhookConfig,
+    #[serde(default)]
+    pub status: StatusConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -53,6 +56,32 @@ pub struct TranslationConfig {
     pub default_language: String,
     #[serde(default = "default_languages")]
     pub supported_languages: Vec<String>,
+    /// Number of workers translating messages concurrently
+    #[serde(default = "default_workers")]
+    pub workers: usize,
+    /// Messages that may wait for a worker before new ones are held back
+    #[serde(default = "default_queue_size")]
+    pub queue_size: usize,
+    /// Largest text attachment (in bytes) translated in channels with attachment translation on
+    #[serde(default = "default_max_attachment_bytes")]
+    pub max_attachment_bytes: usize,
+    /// Where the "record" backend saves and the "replay" backend reads request/response fixtures
+    #[serde(default = "default_fixtures_dir")]
+    pub fixtures_dir: String,
+    /// Prompt template overrides
+    #[serde(default)]
+    pub prompts: PromptConfig,
+}
+
+/// Prompt template overrides (see `services::prompts` for keys and syntax)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PromptConfig {
+    /// Directory of `<key>.txt` template files (e.g. `translate.hi.txt`)
+    #[serde(default)]
+    pub dir: Option<String>,
+    /// Inline templates keyed like `translate`, `translate.hi`, `detect`, `script.hi`
+    #[serde(default)]
+    pub templates: HashMap<String, String>,
 }
 
 fn default_backend() -> String {
@@ -83,6 +112,22 @@ fn default_languages() -> Vec<String> {
     vec!["en".to_string(), "hi".to_string()]
 }
 
+fn default_workers() -> usize {
+    4
+}
+
+fn default_queue_size() -> usize {
+    256
+}
+
+fn default_max_attachment_bytes() -> usize {
+    8 * 1024
+}
+
+fn default_fixtures_dir() -> String {
+    "fixtures/translation".to_string()
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct PlaneConfig {
     pub url: String,
@@ -115,6 +160,63 @@ pub struct WebhookConfig {
     pub base_url: Option<String>,
 }
 
+/// Clearing of forgotten available/busy statuses
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusConfig {
+    /// Hours after which an available or busy status is cleared (0 = never)
+    #[serde(default = "default_expire_after_hours")]
+    pub expire_after_hours: u64,
+    /// Hours after which a "still here?" DM is sent (0 = never)
+    #[serde(default = "default_remind_after_hours")]
+    pub remind_after_hours: u64,
+    /// Also clear statuses when the user's scheduled hours for the day end
+    #[serde(default = "default_true")]
+    pub expire_at_schedule_end: bool,
+    /// Seconds between checks for stale statuses (0 disables the check)
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+    /// Seconds between polls of imported calendar URLs (0 disables polling)
+    #[serde(default = "default_import_interval_secs")]
+    pub import_interval_secs: u64,
+    /// Hosts calendar imports may reach even though they resolve to a private
+    /// or loopback address, such as an intranet calendar server
+    #[serde(default)]
+    pub import_allowed_hosts: Vec<String>,
+}
+
+impl Default for StatusConfig {
+    fn default() -> Self {
+        Self {
+            expire_after_hours: default_expire_after_hours(),
+            remind_after_hours: default_remind_after_hours(),
+            expire_at_schedule_end: default_true(),
+            check_interval_secs: default_check_interval_secs(),
+            import_interval_secs: default_import_interval_secs(),
+            import_allowed_hosts: Vec::new(),
+        }
+    }
+}
+
+fn default_expire_after_hours() -> u64 {
+    8
+}
+
+fn default_remind_after_hours() -> u64 {
+    7
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_check_interval_secs() -> u64 {
+    5 * 60
+}
+
+fn default_import_interval_secs() -> u64 {
+    60 * 60
+}
+
 fn default_host() -> String {
     "0.0.0.0".to_string()
 }
@@ -178,6 +280,23 @@ impl Config {
                 model: std::env::var("TRANSLATION_MODEL").unwrap_or_else(|_| default_model()),
                 default_language: default_language(),
                 supported_languages: default_languages(),
+                workers: std::env::var("TRANSLATION_WORKERS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(default_workers),
+                queue_size: std::env::var("TRANSLATION_QUEUE_SIZE")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(default_queue_size),
+                max_attachment_bytes: std::env::var("TRANSLATION_MAX_ATTACHMENT_BYTES")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(default_max_attachment_bytes),
+                fixtures_dir: std::env::var("TRANSLATION_FIXTURES_DIR").unwrap_or_else(|_| default_fixtures_dir()),
+                prompts: PromptConfig {
+                    dir: std::env::var("TRANSLATION_PROMPTS_DIR").ok(),
+                    templates: HashMap::new(),
+                },
             },
             plane: PlaneConfig {
                 url: std::env::var("PLANE_URL").unwrap_or_else(|_| "https://plane.riff.cc".to_string()),
@@ -197,6 +316,17 @@ impl Config {
                     .unwrap_or(default_port()),
                 base_url: std::env::var("WEBHOOK_BASE_URL").ok(),
             },
+            status: StatusConfig {
+                expire_after_hours: std::env::var("STATUS_EXPIRE_AFTER_HOURS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(default_expire_after_hours),
+                remind_after_hours: std::env::var("STATUS_REMIND_AFTER_HOURS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(default_remind_after_hours),
+                ..Default::default()
+            },
         })
     }
 
@@ -10,6 +10,23 @@ use std::sync::Arc;
 use tokio_rusqlite::Connection;
 use tracing::info;
 
+/// Subscriptions in effect in a channel, as `effective(discord_id, language, scope)`:
+/// the channel's own plus category- and guild-wide ones the user hasn't opted
+/// out of there. Parameters: ?1 guild, ?2 channel, ?3 category (or NULL).
+const EFFECTIVE_SUBSCRIPTIONS: &str = "
+    WITH effective AS (
+        SELECT discord_id, language, 'channel' AS scope FROM translation_subscriptions
+        WHERE guild_id = ?1 AND channel_id = ?2
+        UNION ALL
+        SELECT s.discord_id, s.language, s.scope FROM translation_scoped_subscriptions s
+        WHERE s.guild_id = ?1
+          AND ((s.scope = 'guild' AND s.scope_id = ?1) OR (s.scope = 'category' AND s.scope_id = ?3))
+          AND NOT EXISTS (
+              SELECT 1 FROM translation_subscription_opt_outs o
+              WHERE o.guild_id = ?1 AND o.discord_id = s.discord_id AND o.channel_id = ?2
+          )
+    )";
+
 /// Database handle for Fabrica
 #[derive(Clone, Debug)]
 pub struct Database {
@@ -45,12 +62,21 @@ impl Database {
                 let _ = conn.execute_batch(schema::MIGRATION_FIX_TRANSLATION_CHANNELS_PK);
                 let _ = conn.execute_batch(schema::MIGRATION_FIX_TRANSLATION_SUBS_PK);
 
-                // Add user schedule tables
-                let _ = conn.execute_batch(schema::MIGRATION_ADD_USER_SCHEDULES);
+                // Add user schedule tables; once weekly hours have moved to
+                // user_weekly_shifts the old weekly table must not come back
+                let migrated_to_shifts = table_exists(conn, "user_weekly_shifts");
+                if !migrated_to_shifts {
+                    let _ = conn.execute_batch(schema::MIGRATION_ADD_USER_SCHEDULES);
+                }
 
-                // Fix user schedule tables to include guild_id
-                let _ = conn.execute_batch(schema::MIGRATION_FIX_USER_WEEKLY_SCHEDULE_PK);
-                let _ = conn.execute_batch(schema::MIGRATION_FIX_USER_SCHEDULE_OVERRIDE_PK);
+                // Fix user schedule tables to include guild_id (only once: the rebuild
+                // can't carry guild_id over, so running it again would blank it)
+                if !migrated_to_shifts && lacks_guild_id(conn, "user_weekly_schedule") {
+                    let _ = conn.execute_batch(schema::MIGRATION_FIX_USER_WEEKLY_SCHEDULE_PK);
+                }
+                if lacks_guild_id(conn, "user_schedule_override") {
+                    let _ = conn.execute_batch(schema::MIGRATION_FIX_USER_SCHEDULE_OVERRIDE_PK);
+                }
 
                 // Add user settings table
                 let _ = conn.execute_batch(schema::MIGRATION_ADD_USER_SETTINGS);
@@ -64,6 +90,56 @@ impl Database {
                 // Add default language to user_settings
                 let _ = conn.execute_batch(schema::MIGRATION_ADD_DEFAULT_LANGUAGE);
 
+                // Add formality to dialect preferences
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_FORMALITY);
+
+                // Add script to dialect preferences
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_SCRIPT);
+
+                // Add mirror channel links
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_MIRROR_CHANNELS);
+
+                // Add understood languages
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_UNDERSTOOD_LANGUAGES);
+
+                // Add category/guild-wide subscriptions and channel opt-outs
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_SCOPED_SUBSCRIPTIONS);
+
+                // Add attachment translation opt-in
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_ATTACHMENT_CHANNELS);
+
+                // Migration: DM replies posted back to the channel
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_DM_REPLIES);
+
+                // Migration: suggested corrections and the guild glossary
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_CORRECTIONS);
+
+                // Migration: reminders for stale statuses
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_STATUS_REMINDERS);
+
+                // Migration: auto status from working hours
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_AUTO_STATUS);
+
+                // Migration: several working-hour ranges per day
+                if !migrated_to_shifts {
+                    let _ = conn.execute_batch(schema::MIGRATION_ADD_WEEKLY_SHIFTS);
+                }
+
+                // Migration: leave and public holidays
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_LEAVE);
+
+                // Migration: calendar feed links
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_CALENDAR_LINKS);
+
+                // Migration: out-of-office imported from calendars
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_CALENDAR_IMPORTS);
+
+                // Migration: async standups
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_STANDUPS);
+
+                // Migration: status boards
+                let _ = conn.execute_batch(schema::MIGRATION_ADD_STATUS_BOARDS);
+
                 Ok(())
             })
             .await?;
@@ -80,7 +156,7 @@ impl Database {
             .call(move |conn| {
                 let mut stmt = conn.prepare(
                     "SELECT discord_id, status, message, updated_at, timezone,
-                            preferred_hours_start, preferred_hours_end
+                            preferred_hours_start, preferred_hours_end, reminded_at, auto
                      FROM user_status WHERE discord_id = ?",
                 )?;
                 let result = stmt
@@ -93,6 +169,8 @@ impl Database {
                             timezone: row.get(4)?,
                             preferred_hours_start: row.get(5)?,
                             preferred_hours_end: row.get(6)?,
+                            reminded_at: row.get(7)?,
+                            auto: row.get::<_, i32>(8)? != 0,
                         })
                     })
                     .optional()?;
@@ -146,7 +224,7 @@ impl Database {
             .call(move |conn| {
                 let mut stmt = conn.prepare(
                     "SELECT discord_id, status, message, updated_at, timezone,
-                            preferred_hours_start, preferred_hours_end
+                            preferred_hours_start, preferred_hours_end, reminded_at, auto
                      FROM user_status WHERE status = ?",
                 )?;
                 let rows = stmt
@@ -159,6 +237,8 @@ impl Database {
                             timezone: row.get(4)?,
                             preferred_hours_start: row.get(5)?,
                             preferred_hours_end: row.get(6)?,
+                            reminded_at: row.get(7)?,
+                            auto: row.get::<_, i32>(8)? != 0,
                         })
                     })?
                     .collect::<Result<Vec<_>, _>>()?;
@@ -174,7 +254,7 @@ impl Database {
             .call(|conn| {
                 let mut stmt = conn.prepare(
                     "SELECT discord_id, status, message, updated_at, timezone,
-                            preferred_hours_start, preferred_hours_end
+                            preferred_hours_start, preferred_hours_end, reminded_at, auto
                      FROM user_status ORDER BY status, updated_at DESC",
                 )?;
                 let rows = stmt
@@ -187,6 +267,8 @@ impl Database {
                             timezone: row.get(4)?,
                             preferred_hours_start: row.get(5)?,
                             preferred_hours_end: row.get(6)?,
+                            reminded_at: row.get(7)?,
+                            auto: row.get::<_, i32>(8)? != 0,
                         })
                     })?
                     .collect::<Result<Vec<_>, _>>()?;
@@ -196,40 +278,140 @@ impl Database {
             .map_err(Into::into)
     }
 
+    /// Set a user's status from their working hours
+    pub async fn set_auto_status(&self, discord_id: &str, status: &str) -> Result<()> {
+        let id = discord_id.to_string();
+        let status = status.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO user_status (discord_id, status, message, updated_at, auto)
+                     VALUES (?, ?, NULL, ?, 1)",
+                    rusqlite::params![id, status, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Record that a "still here?" reminder was sent for a user's status
+    pub async fn mark_status_reminded(&self, discord_id: &str, reminded_at: i64) -> Result<()> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "UPDATE user_status SET reminded_at = ? WHERE discord_id = ?",
+                    rusqlite::params![reminded_at, id],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Keep a user's status, restarting its age; returns false if it's already gone
+    pub async fn refresh_status(&self, discord_id: &str) -> Result<bool> {
+        let id = discord_id.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                let changed = conn.execute(
+                    "UPDATE user_status SET updated_at = ?, reminded_at = NULL WHERE discord_id = ?",
+                    rusqlite::params![now, id],
+                )?;
+                Ok(changed > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Clear a stale status unless it was set again since it was read
+    pub async fn expire_status(&self, discord_id: &str, updated_at: i64) -> Result<bool> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let changed = conn.execute(
+                    "DELETE FROM user_status WHERE discord_id = ? AND updated_at = ?",
+                    rusqlite::params![id, updated_at],
+                )?;
+                Ok(changed > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
     // ==================== User Schedule ====================
 
-    /// Set weekly schedule for specific days (per guild)
-    pub async fn set_weekly_schedule(&self, guild_id: &str, discord_id: &str, days: &[u8], start_time: &str, end_time: &str) -> Result<()> {
+    /// Set weekly schedule for specific days (per guild), replacing their existing ranges
+    pub async fn set_weekly_schedule(&self, guild_id: &str, discord_id: &str, days: &[u8], ranges: &[(String, String)]) -> Result<()> {
         let gid = guild_id.to_string();
         let id = discord_id.to_string();
-        let start = start_time.to_string();
-        let end = end_time.to_string();
+        let ranges = ranges.to_vec();
         let days_vec: Vec<u8> = days.to_vec();
 
         self.conn
             .call(move |conn| {
+                let tx = conn.transaction()?;
                 for day in days_vec {
-                    conn.execute(
-                        "INSERT OR REPLACE INTO user_weekly_schedule (guild_id, discord_id, day_of_week, start_time, end_time)
-                         VALUES (?, ?, ?, ?, ?)",
-                        rusqlite::params![gid, id, day, start, end],
+                    tx.execute(
+                        "DELETE FROM user_weekly_shifts WHERE guild_id = ? AND discord_id = ? AND day_of_week = ?",
+                        rusqlite::params![gid, id, day],
                     )?;
+                    for (start, end) in &ranges {
+                        tx.execute(
+                            "INSERT OR REPLACE INTO user_weekly_shifts (guild_id, discord_id, day_of_week, start_time, end_time)
+                             VALUES (?, ?, ?, ?, ?)",
+                            rusqlite::params![gid, id, day, start, end],
+                        )?;
+                    }
                 }
+                tx.commit()?;
                 Ok(())
             })
             .await?;
         Ok(())
     }
 
-    /// Get weekly schedule for a user in a guild
+    /// Remove weekly hours on some days: one range (by its times) or all of them
+    pub async fn remove_weekly_schedule(&self, guild_id: &str, discord_id: &str, days: &[u8], range: Option<(&str, &str)>) -> Result<usize> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let range = range.map(|(start, end)| (start.to_string(), end.to_string()));
+        let days_vec: Vec<u8> = days.to_vec();
+
+        self.conn
+            .call(move |conn| {
+                let mut removed = 0;
+                for day in days_vec {
+                    removed += match &range {
+                        Some((start, end)) => conn.execute(
+                            "DELETE FROM user_weekly_shifts
+                             WHERE guild_id = ? AND discord_id = ? AND day_of_week = ? AND start_time = ? AND end_time = ?",
+                            rusqlite::params![gid, id, day, start, end],
+                        )?,
+                        None => conn.execute(
+                            "DELETE FROM user_weekly_shifts WHERE guild_id = ? AND discord_id = ? AND day_of_week = ?",
+                            rusqlite::params![gid, id, day],
+                        )?,
+                    };
+                }
+                Ok(removed)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get weekly schedule for a user in a guild: `(day, start, end)` ranges by day and start
     pub async fn get_weekly_schedule(&self, guild_id: &str, discord_id: &str) -> Result<Vec<(u8, String, String)>> {
         let gid = guild_id.to_string();
         let id = discord_id.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT day_of_week, start_time, end_time FROM user_weekly_schedule
-                     WHERE guild_id = ? AND discord_id = ? ORDER BY day_of_week"
+                    "SELECT day_of_week, start_time, end_time FROM user_weekly_shifts
+                     WHERE guild_id = ? AND discord_id = ? ORDER BY day_of_week, start_time"
                 )?;
                 let rows = stmt
                     .query_map([&gid, &id], |row| {
@@ -285,6 +467,60 @@ impl Database {
             .map_err(Into::into)
     }
 
+    /// Remove the schedule override for a date
+    pub async fn remove_schedule_override(&self, guild_id: &str, discord_id: &str, date: &str) -> Result<usize> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let d = date.to_string();
+        self.conn
+            .call(move |conn| {
+                let removed = conn.execute(
+                    "DELETE FROM user_schedule_override WHERE guild_id = ? AND discord_id = ? AND date = ?",
+                    [&gid, &id, &d],
+                )?;
+                Ok(removed)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get every weekly range in a guild: (discord_id, day, start, end)
+    pub async fn get_guild_weekly_schedules(&self, guild_id: &str) -> Result<Vec<(String, u8, String, String)>> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT discord_id, day_of_week, start_time, end_time FROM user_weekly_shifts
+                     WHERE guild_id = ? ORDER BY discord_id, day_of_week, start_time",
+                )?;
+                let rows = stmt
+                    .query_map([&gid], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a guild's schedule overrides from a date on: (discord_id, date, start, end)
+    pub async fn get_guild_schedule_overrides(&self, guild_id: &str, from_date: &str) -> Result<Vec<(String, String, Option<String>, String)>> {
+        let gid = guild_id.to_string();
+        let from = from_date.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT discord_id, date, start_time, end_time FROM user_schedule_override
+                     WHERE guild_id = ? AND date >= ? ORDER BY date",
+                )?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, from], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
     /// Clear old schedule overrides (before a given date)
     pub async fn clear_old_schedule_overrides(&self, before_date: &str) -> Result<()> {
         let d = before_date.to_string();
@@ -308,7 +544,9 @@ impl Database {
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT discord_id, timezone, time_format, COALESCE(always_show_me, 0) FROM user_settings WHERE discord_id = ?",
+                    "SELECT discord_id, timezone, time_format, COALESCE(always_show_me, 0), COALESCE(auto_status, 0),
+                            holiday_region
+                     FROM user_settings WHERE discord_id = ?",
                 )?;
                 let result = stmt
                     .query_row([&id], |row| {
@@ -317,6 +555,8 @@ impl Database {
                             timezone: row.get(1)?,
                             time_format: row.get(2)?,
                             always_show_me: row.get::<_, i32>(3)? != 0,
+                            auto_status: row.get::<_, i32>(4)? != 0,
+                            holiday_region: row.get(5)?,
                         })
                     })
                     .optional()?;
@@ -380,20 +620,17 @@ impl Database {
         Ok(())
     }
 
-    // ==================== Dialect Preferences ====================
-
-    /// Set user's preferred dialect for a language
-    pub async fn set_dialect_preference(&self, discord_id: &str, language: &str, dialect: &str) -> Result<()> {
+    /// Set user auto_status preference
+    pub async fn set_user_auto_status(&self, discord_id: &str, enabled: bool) -> Result<()> {
         let id = discord_id.to_string();
-        let lang = language.to_lowercase();
-        let dial = dialect.to_string();
+        let val = if enabled { 1 } else { 0 };
         self.conn
             .call(move |conn| {
                 conn.execute(
-                    "INSERT INTO user_dialect_preferences (discord_id, language, dialect)
-                     VALUES (?, ?, ?)
-                     ON CONFLICT(discord_id, language) DO UPDATE SET dialect = excluded.dialect",
-                    rusqlite::params![id, lang, dial],
+                    "INSERT INTO user_settings (discord_id, timezone, time_format, auto_status)
+                     VALUES (?, 'UTC', '24h', ?)
+                     ON CONFLICT(discord_id) DO UPDATE SET auto_status = excluded.auto_status",
+                    rusqlite::params![id, val],
                 )?;
                 Ok(())
             })
@@ -401,133 +638,126 @@ impl Database {
         Ok(())
     }
 
-    /// Get user's preferred dialect for a language (returns None if not set)
-    pub async fn get_dialect_preference(&self, discord_id: &str, language: &str) -> Result<Option<String>> {
+    /// Set or clear the region whose public holidays apply to the user
+    pub async fn set_user_holiday_region(&self, discord_id: &str, region: Option<&str>) -> Result<()> {
         let id = discord_id.to_string();
-        let lang = language.to_lowercase();
+        let region = region.map(|r| r.to_string());
         self.conn
             .call(move |conn| {
-                let mut stmt = conn.prepare(
-                    "SELECT dialect FROM user_dialect_preferences WHERE discord_id = ? AND language = ?",
+                conn.execute(
+                    "INSERT INTO user_settings (discord_id, timezone, time_format, holiday_region)
+                     VALUES (?, 'UTC', '24h', ?)
+                     ON CONFLICT(discord_id) DO UPDATE SET holiday_region = excluded.holiday_region",
+                    rusqlite::params![id, region],
                 )?;
-                let result = stmt
-                    .query_row(rusqlite::params![id, lang], |row| row.get(0))
-                    .optional()?;
-                Ok(result)
+                Ok(())
             })
-            .await
-            .map_err(Into::into)
+            .await?;
+        Ok(())
     }
 
-    /// Get all dialect preferences for a user
-    pub async fn get_all_dialect_preferences(&self, discord_id: &str) -> Result<Vec<(String, String)>> {
-        let id = discord_id.to_string();
+    /// Get the IDs and timezones of users with auto status on
+    pub async fn get_auto_status_users(&self) -> Result<Vec<(String, String)>> {
         self.conn
-            .call(move |conn| {
+            .call(|conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT language, dialect FROM user_dialect_preferences WHERE discord_id = ?",
+                    "SELECT discord_id, COALESCE(timezone, 'UTC') FROM user_settings WHERE auto_status = 1",
                 )?;
-                let rows = stmt.query_map([&id], |row| {
-                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-                })?;
-                let mut results = Vec::new();
-                for row in rows {
-                    results.push(row?);
-                }
-                Ok(results)
+                let rows = stmt
+                    .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Clear a user's dialect preference for a language
-    pub async fn clear_dialect_preference(&self, discord_id: &str, language: &str) -> Result<()> {
-        let id = discord_id.to_string();
-        let lang = language.to_lowercase();
+    // ==================== Leave & Holidays ====================
+
+    /// Record a leave entry, returning its ID
+    pub async fn add_leave(&self, leave: &Leave) -> Result<i64> {
+        let l = leave.clone();
+        let now = chrono::Utc::now().timestamp();
         self.conn
             .call(move |conn| {
                 conn.execute(
-                    "DELETE FROM user_dialect_preferences WHERE discord_id = ? AND language = ?",
-                    rusqlite::params![id, lang],
+                    "INSERT INTO user_leave (guild_id, discord_id, start_date, end_date, reason, reason_visibility, imported, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![l.guild_id, l.discord_id, l.start_date, l.end_date, l.reason, l.reason_visibility, l.imported, now],
                 )?;
-                Ok(())
+                Ok(conn.last_insert_rowid())
             })
-            .await?;
-        Ok(())
+            .await
+            .map_err(Into::into)
     }
 
-    // ==================== Default Language ====================
-
-    /// Set user's default translation language
-    pub async fn set_default_language(&self, discord_id: &str, language: &str) -> Result<()> {
-        let id = discord_id.to_string();
-        let lang = language.to_lowercase();
+    /// Remove one of a user's leave entries, returning how many were removed
+    pub async fn remove_leave(&self, guild_id: &str, discord_id: &str, id: i64) -> Result<usize> {
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
         self.conn
             .call(move |conn| {
-                conn.execute(
-                    "INSERT INTO user_settings (discord_id, default_language)
-                     VALUES (?, ?)
-                     ON CONFLICT(discord_id) DO UPDATE SET default_language = excluded.default_language",
-                    rusqlite::params![id, lang],
+                let removed = conn.execute(
+                    "DELETE FROM user_leave WHERE guild_id = ? AND discord_id = ? AND id = ?",
+                    rusqlite::params![gid, did, id],
                 )?;
-                Ok(())
+                Ok(removed)
             })
-            .await?;
-        Ok(())
+            .await
+            .map_err(Into::into)
     }
 
-    /// Get user's default translation language (returns None if not set)
-    pub async fn get_default_language(&self, discord_id: &str) -> Result<Option<String>> {
-        let id = discord_id.to_string();
+    /// Get a guild's leave overlapping the dates `from`..=`to`, earliest first
+    pub async fn get_leave_between(&self, guild_id: &str, from: &str, to: &str) -> Result<Vec<Leave>> {
+        let gid = guild_id.to_string();
+        let from = from.to_string();
+        let to = to.to_string();
         self.conn
             .call(move |conn| {
-                let mut stmt = conn.prepare(
-                    "SELECT default_language FROM user_settings WHERE discord_id = ?",
-                )?;
-                let result: Option<Option<String>> = stmt
-                    .query_row([&id], |row| row.get(0))
-                    .optional()?;
-                // Handle NULL column vs missing row
-                Ok(result.flatten())
+                let mut stmt = conn.prepare(&format!(
+                    "SELECT {} FROM user_leave
+                     WHERE guild_id = ? AND end_date >= ? AND start_date <= ?
+                     ORDER BY start_date, id",
+                    LEAVE_COLUMNS
+                ))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, from, to], leave_from_row)?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    // ==================== Translation ====================
-
-    /// Add a translation subscription for a channel in a guild
-    pub async fn add_translation_subscription(&self, guild_id: &str, discord_id: &str, channel_id: &str, language: &str) -> Result<()> {
+    /// Whether a user is on leave in a guild on a date
+    pub async fn is_on_leave(&self, guild_id: &str, discord_id: &str, date: &str) -> Result<bool> {
         let gid = guild_id.to_string();
-        let id = discord_id.to_string();
-        let ch = channel_id.to_string();
-        let lang = language.to_string();
-        let now = chrono::Utc::now().timestamp();
+        let did = discord_id.to_string();
+        let d = date.to_string();
         self.conn
             .call(move |conn| {
-                conn.execute(
-                    "INSERT OR REPLACE INTO translation_subscriptions
-                     (guild_id, discord_id, channel_id, language, created_at, debug_mode) VALUES (?, ?, ?, ?, ?, 0)",
-                    rusqlite::params![gid, id, ch, lang, now],
+                let count: i64 = conn.query_row(
+                    "SELECT COUNT(*) FROM user_leave
+                     WHERE guild_id = ? AND discord_id = ? AND start_date <= ?3 AND end_date >= ?3",
+                    rusqlite::params![gid, did, d],
+                    |row| row.get(0),
                 )?;
-                Ok(())
+                Ok(count > 0)
             })
-            .await?;
-        Ok(())
+            .await
+            .map_err(Into::into)
     }
 
-    /// Remove a specific language subscription for a channel in a guild
-    pub async fn remove_translation_subscription(&self, guild_id: &str, discord_id: &str, channel_id: &str, language: &str) -> Result<()> {
-        let gid = guild_id.to_string();
-        let id = discord_id.to_string();
-        let ch = channel_id.to_string();
-        let lang = language.to_string();
+    /// Add or rename a public holiday for a region
+    pub async fn set_holiday(&self, holiday: &Holiday, added_by: &str) -> Result<()> {
+        let h = holiday.clone();
+        let by = added_by.to_string();
         self.conn
             .call(move |conn| {
                 conn.execute(
-                    "DELETE FROM translation_subscriptions
-                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ? AND language = ?",
-                    rusqlite::params![gid, id, ch, lang],
+                    "INSERT OR REPLACE INTO guild_holidays (guild_id, region, date, name, added_by)
+                     VALUES (?, ?, ?, ?, ?)",
+                    rusqlite::params![h.guild_id, h.region, h.date, h.name, by],
                 )?;
                 Ok(())
             })
@@ -535,132 +765,1416 @@ impl Database {
         Ok(())
     }
 
-    /// Remove all translation subscriptions for a channel in a guild
-    pub async fn remove_all_translation_subscriptions(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<()> {
+    /// Remove a region's public holiday, returning how many were removed
+    pub async fn remove_holiday(&self, guild_id: &str, region: &str, date: &str) -> Result<usize> {
         let gid = guild_id.to_string();
-        let id = discord_id.to_string();
-        let ch = channel_id.to_string();
+        let r = region.to_string();
+        let d = date.to_string();
         self.conn
             .call(move |conn| {
-                conn.execute(
-                    "DELETE FROM translation_subscriptions
-                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
-                    rusqlite::params![gid, id, ch],
+                let removed = conn.execute(
+                    "DELETE FROM guild_holidays WHERE guild_id = ? AND region = ? AND date = ?",
+                    rusqlite::params![gid, r, d],
                 )?;
-                Ok(())
+                Ok(removed)
             })
-            .await?;
-        Ok(())
+            .await
+            .map_err(Into::into)
     }
 
-    /// Get all of a user's translation subscriptions for a channel in a guild
-    pub async fn get_translation_subscriptions(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<Vec<String>> {
+    /// Get a guild's public holidays on the dates `from`..=`to`, by date then region
+    pub async fn get_holidays_between(&self, guild_id: &str, from: &str, to: &str) -> Result<Vec<Holiday>> {
         let gid = guild_id.to_string();
-        let id = discord_id.to_string();
-        let ch = channel_id.to_string();
+        let from = from.to_string();
+        let to = to.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT language FROM translation_subscriptions
-                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                    "SELECT guild_id, region, date, name FROM guild_holidays
+                     WHERE guild_id = ? AND date >= ? AND date <= ?
+                     ORDER BY date, region",
                 )?;
                 let rows = stmt
-                    .query_map(rusqlite::params![gid, id, ch], |row| row.get(0))?
-                    .collect::<Result<Vec<String>, _>>()?;
+                    .query_map(rusqlite::params![gid, from, to], |row| {
+                        Ok(Holiday {
+                            guild_id: row.get(0)?,
+                            region: row.get(1)?,
+                            date: row.get(2)?,
+                            name: row.get(3)?,
+                        })
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?;
                 Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Check if a user is subscribed to a specific language in a channel in a guild
+    /// Get the name of a region's public holiday on a date
+    pub async fn get_holiday(&self, guild_id: &str, region: &str, date: &str) -> Result<Option<String>> {
+        let gid = guild_id.to_string();
+        let r = region.to_string();
+        let d = date.to_string();
+        self.conn
+            .call(move |conn| {
+                let result = conn
+                    .query_row(
+                        "SELECT name FROM guild_holidays WHERE guild_id = ? AND region = ? AND date = ?",
+                        rusqlite::params![gid, r, d],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Calendar Links ====================
+
+    /// Store a calendar link's token hash, replacing the owner's previous link of that scope
+    pub async fn set_calendar_link(&self, link: &CalendarLink, token_hash: &str) -> Result<()> {
+        let l = link.clone();
+        let hash = token_hash.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO calendar_links (token_hash, guild_id, discord_id, scope, created_at)
+                     VALUES (?, ?, ?, ?, ?)",
+                    rusqlite::params![hash, l.guild_id, l.discord_id, l.scope, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Look up a calendar link by its token hash
+    pub async fn get_calendar_link(&self, token_hash: &str) -> Result<Option<CalendarLink>> {
+        let hash = token_hash.to_string();
+        self.conn
+            .call(move |conn| {
+                let result = conn
+                    .query_row(
+                        "SELECT guild_id, discord_id, scope FROM calendar_links WHERE token_hash = ?",
+                        [&hash],
+                        |row| {
+                            Ok(CalendarLink {
+                                guild_id: row.get(0)?,
+                                discord_id: row.get(1)?,
+                                scope: row.get(2)?,
+                            })
+                        },
+                    )
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Remove every calendar link a user issued in a guild
+    pub async fn remove_calendar_links(&self, guild_id: &str, discord_id: &str) -> Result<usize> {
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let removed = conn.execute(
+                    "DELETE FROM calendar_links WHERE guild_id = ? AND discord_id = ?",
+                    [&gid, &did],
+                )?;
+                Ok(removed)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Status Boards ====================
+
+    /// Record a guild's status board, replacing any earlier one
+    pub async fn set_status_board(&self, board: &StatusBoard, created_by: &str) -> Result<()> {
+        let b = board.clone();
+        let by = created_by.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO status_boards (guild_id, channel_id, message_id, locale, created_by, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![b.guild_id, b.channel_id, b.message_id, b.locale, by, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get a guild's status board
+    pub async fn get_status_board(&self, guild_id: &str) -> Result<Option<StatusBoard>> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let result = conn
+                    .query_row(
+                        "SELECT guild_id, channel_id, message_id, locale FROM status_boards WHERE guild_id = ?",
+                        [&gid],
+                        status_board_from_row,
+                    )
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get every status board
+    pub async fn get_status_boards(&self) -> Result<Vec<StatusBoard>> {
+        self.conn
+            .call(|conn| {
+                let mut stmt = conn.prepare("SELECT guild_id, channel_id, message_id, locale FROM status_boards")?;
+                let rows = stmt.query_map([], status_board_from_row)?.collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Forget a guild's status board
+    pub async fn remove_status_board(&self, guild_id: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute("DELETE FROM status_boards WHERE guild_id = ?", [&gid])?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    // ==================== Standups ====================
+
+    /// Create or replace a guild's standup config
+    pub async fn set_standup_config(&self, config: &StandupConfig, updated_by: &str) -> Result<()> {
+        let c = config.clone();
+        let by = updated_by.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                let days = c.days.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+                conn.execute(
+                    "INSERT OR REPLACE INTO standup_configs (guild_id, channel_id, questions, days, summary_time, timezone, updated_by, updated_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![c.guild_id, c.channel_id, c.questions.join("\n"), days, c.summary_time, c.timezone, by, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get a guild's standup config
+    pub async fn get_standup_config(&self, guild_id: &str) -> Result<Option<StandupConfig>> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let result = conn
+                    .query_row(
+                        "SELECT guild_id, channel_id, questions, days, summary_time, timezone FROM standup_configs WHERE guild_id = ?",
+                        [&gid],
+                        standup_config_from_row,
+                    )
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get every guild's standup config
+    pub async fn get_standup_configs(&self) -> Result<Vec<StandupConfig>> {
+        self.conn
+            .call(|conn| {
+                let mut stmt = conn.prepare("SELECT guild_id, channel_id, questions, days, summary_time, timezone FROM standup_configs")?;
+                let rows = stmt.query_map([], standup_config_from_row)?.collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Turn a guild's standup off, keeping its participants for next time
+    pub async fn remove_standup_config(&self, guild_id: &str) -> Result<usize> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let removed = conn.execute("DELETE FROM standup_configs WHERE guild_id = ?", [&gid])?;
+                Ok(removed)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Add someone to a guild's standup
+    pub async fn add_standup_participant(&self, guild_id: &str, discord_id: &str, added_by: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let by = added_by.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR IGNORE INTO standup_participants (guild_id, discord_id, added_by, added_at) VALUES (?, ?, ?, ?)",
+                    rusqlite::params![gid, id, by, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Remove someone from a guild's standup, returning whether they were in it
+    pub async fn remove_standup_participant(&self, guild_id: &str, discord_id: &str) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let removed = conn.execute(
+                    "DELETE FROM standup_participants WHERE guild_id = ? AND discord_id = ?",
+                    rusqlite::params![gid, id],
+                )?;
+                Ok(removed > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a guild's standup participants, in the order they were added
+    pub async fn get_standup_participants(&self, guild_id: &str) -> Result<Vec<String>> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare("SELECT discord_id FROM standup_participants WHERE guild_id = ? ORDER BY added_at, discord_id")?;
+                let rows = stmt.query_map([&gid], |row| row.get(0))?.collect::<Result<Vec<String>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Record that a participant was sent their standup for a local date,
+    /// returning false if they already were
+    pub async fn add_standup_entry(&self, guild_id: &str, discord_id: &str, local_date: &str, run_date: &str) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let local = local_date.to_string();
+        let run = run_date.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                let inserted = conn.execute(
+                    "INSERT OR IGNORE INTO standup_entries (guild_id, discord_id, local_date, run_date, prompted_at) VALUES (?, ?, ?, ?, ?)",
+                    rusqlite::params![gid, id, local, run, now],
+                )?;
+                Ok(inserted > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a participant's standup for a local date
+    pub async fn get_standup_entry(&self, guild_id: &str, discord_id: &str, local_date: &str) -> Result<Option<StandupEntry>> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let local = local_date.to_string();
+        self.conn
+            .call(move |conn| {
+                let result = conn
+                    .query_row(
+                        &format!("SELECT {} FROM standup_entries WHERE guild_id = ? AND discord_id = ? AND local_date = ?", STANDUP_ENTRY_COLUMNS),
+                        rusqlite::params![gid, id, local],
+                        standup_entry_from_row,
+                    )
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get every standup going into a guild's summary for `run_date`
+    pub async fn get_standup_entries(&self, guild_id: &str, run_date: &str) -> Result<Vec<StandupEntry>> {
+        let gid = guild_id.to_string();
+        let run = run_date.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "SELECT {} FROM standup_entries WHERE guild_id = ? AND run_date = ? ORDER BY prompted_at",
+                    STANDUP_ENTRY_COLUMNS
+                ))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, run], standup_entry_from_row)?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Save a participant's answers, returning whether their standup exists
+    pub async fn set_standup_answers(&self, guild_id: &str, discord_id: &str, local_date: &str, answers: &[String]) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let local = local_date.to_string();
+        let answers = serde_json::to_string(answers)?;
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                let updated = conn.execute(
+                    "UPDATE standup_entries SET answers = ?, answered_at = ? WHERE guild_id = ? AND discord_id = ? AND local_date = ?",
+                    rusqlite::params![answers, now, gid, id, local],
+                )?;
+                Ok(updated > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Record that a participant was nudged about their standup
+    pub async fn mark_standup_nudged(&self, guild_id: &str, discord_id: &str, local_date: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let local = local_date.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "UPDATE standup_entries SET nudged_at = ? WHERE guild_id = ? AND discord_id = ? AND local_date = ?",
+                    rusqlite::params![now, gid, id, local],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Whether a guild's summary for `run_date` has been posted
+    pub async fn is_standup_posted(&self, guild_id: &str, run_date: &str) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let run = run_date.to_string();
+        self.conn
+            .call(move |conn| {
+                let count: i64 = conn.query_row(
+                    "SELECT COUNT(*) FROM standup_runs WHERE guild_id = ? AND run_date = ?",
+                    rusqlite::params![gid, run],
+                    |row| row.get(0),
+                )?;
+                Ok(count > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Record a posted summary
+    pub async fn mark_standup_posted(&self, guild_id: &str, run_date: &str, message_id: Option<&str>) -> Result<()> {
+        let gid = guild_id.to_string();
+        let run = run_date.to_string();
+        let message_id = message_id.map(|m| m.to_string());
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO standup_runs (guild_id, run_date, message_id, posted_at) VALUES (?, ?, ?, ?)",
+                    rusqlite::params![gid, run, message_id, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    // ==================== Calendar Imports ====================
+
+    /// Register or change the calendar URL polled for a user's out-of-office
+    pub async fn set_calendar_import(&self, guild_id: &str, discord_id: &str, url: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
+        let url = url.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO calendar_imports (guild_id, discord_id, url) VALUES (?, ?, ?)",
+                    rusqlite::params![gid, did, url],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get a user's calendar import in a guild
+    pub async fn get_calendar_import(&self, guild_id: &str, discord_id: &str) -> Result<Option<CalendarImport>> {
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let result = conn
+                    .query_row(
+                        "SELECT guild_id, discord_id, url, last_synced_at, last_error FROM calendar_imports
+                         WHERE guild_id = ? AND discord_id = ?",
+                        rusqlite::params![gid, did],
+                        calendar_import_from_row,
+                    )
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get every calendar import
+    pub async fn get_calendar_imports(&self) -> Result<Vec<CalendarImport>> {
+        self.conn
+            .call(|conn| {
+                let mut stmt = conn.prepare("SELECT guild_id, discord_id, url, last_synced_at, last_error FROM calendar_imports")?;
+                let rows = stmt.query_map([], calendar_import_from_row)?.collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Record the outcome of fetching a calendar import
+    pub async fn record_calendar_import(&self, guild_id: &str, discord_id: &str, error: Option<&str>) -> Result<()> {
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
+        let error = error.map(|e| e.to_string());
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "UPDATE calendar_imports SET last_synced_at = ?, last_error = ? WHERE guild_id = ? AND discord_id = ?",
+                    rusqlite::params![now, error, gid, did],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Stop importing a user's calendar and drop what it imported from `from_date` on
+    pub async fn remove_calendar_import(&self, guild_id: &str, discord_id: &str, from_date: &str) -> Result<()> {
+        self.replace_imported_absences(guild_id, discord_id, from_date, &[], &[]).await?;
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute("DELETE FROM calendar_imports WHERE guild_id = ? AND discord_id = ?", rusqlite::params![gid, did])?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Replace a user's imported leave and overrides from `from_date` on
+    ///
+    /// Overrides set by hand with `/fabrica hours` are kept; an imported one
+    /// for the same date is dropped.
+    pub async fn replace_imported_absences(
+        &self,
+        guild_id: &str,
+        discord_id: &str,
+        from_date: &str,
+        leave: &[Leave],
+        overrides: &[(String, Option<String>, String)],
+    ) -> Result<()> {
+        let gid = guild_id.to_string();
+        let did = discord_id.to_string();
+        let from = from_date.to_string();
+        let leave = leave.to_vec();
+        let overrides = overrides.to_vec();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                tx.execute(
+                    "DELETE FROM user_leave WHERE guild_id = ? AND discord_id = ? AND imported = 1 AND end_date >= ?",
+                    rusqlite::params![gid, did, from],
+                )?;
+                tx.execute(
+                    "DELETE FROM user_schedule_override WHERE guild_id = ? AND discord_id = ? AND imported = 1 AND date >= ?",
+                    rusqlite::params![gid, did, from],
+                )?;
+                for l in &leave {
+                    tx.execute(
+                        "INSERT INTO user_leave (guild_id, discord_id, start_date, end_date, reason, reason_visibility, imported, created_at)
+                         VALUES (?, ?, ?, ?, ?, ?, 1, ?)",
+                        rusqlite::params![gid, did, l.start_date, l.end_date, l.reason, l.reason_visibility, now],
+                    )?;
+                }
+                for (date, start, end) in &overrides {
+                    tx.execute(
+                        "INSERT OR IGNORE INTO user_schedule_override (guild_id, discord_id, date, start_time, end_time, imported)
+                         VALUES (?, ?, ?, ?, ?, 1)",
+                        rusqlite::params![gid, did, date, start, end],
+                    )?;
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    // ==================== Dialect Preferences ====================
+
+    /// Set user's preferred dialect for a language
+    pub async fn set_dialect_preference(&self, discord_id: &str, language: &str, dialect: &str) -> Result<()> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        let dial = dialect.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO user_dialect_preferences (discord_id, language, dialect)
+                     VALUES (?, ?, ?)
+                     ON CONFLICT(discord_id, language) DO UPDATE SET dialect = excluded.dialect",
+                    rusqlite::params![id, lang, dial],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get user's preferred dialect for a language (returns None if not set)
+    pub async fn get_dialect_preference(&self, discord_id: &str, language: &str) -> Result<Option<String>> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT NULLIF(dialect, '') FROM user_dialect_preferences WHERE discord_id = ? AND language = ?",
+                )?;
+                let result: Option<Option<String>> = stmt
+                    .query_row(rusqlite::params![id, lang], |row| row.get(0))
+                    .optional()?;
+                Ok(result.flatten())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get all dialect preferences for a user
+    pub async fn get_all_dialect_preferences(&self, discord_id: &str) -> Result<Vec<(String, String)>> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT language, dialect FROM user_dialect_preferences WHERE discord_id = ? AND dialect != ''",
+                )?;
+                let rows = stmt.query_map([&id], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?;
+                let mut results = Vec::new();
+                for row in rows {
+                    results.push(row?);
+                }
+                Ok(results)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Clear a user's dialect preference for a language (keeps other preferences)
+    pub async fn clear_dialect_preference(&self, discord_id: &str, language: &str) -> Result<()> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "UPDATE user_dialect_preferences SET dialect = '' WHERE discord_id = ? AND language = ?",
+                    rusqlite::params![id, lang],
+                )?;
+                conn.execute(
+                    "DELETE FROM user_dialect_preferences
+                     WHERE discord_id = ? AND language = ? AND dialect = '' AND formality IS NULL AND script IS NULL",
+                    rusqlite::params![id, lang],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Set user's preferred formality (formal/neutral/casual) for a language, or clear it with None
+    pub async fn set_formality_preference(&self, discord_id: &str, language: &str, formality: Option<&str>) -> Result<()> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        let level = formality.map(|f| f.to_string());
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO user_dialect_preferences (discord_id, language, dialect, formality)
+                     VALUES (?, ?, '', ?)
+                     ON CONFLICT(discord_id, language) DO UPDATE SET formality = excluded.formality",
+                    rusqlite::params![id, lang, level],
+                )?;
+                conn.execute(
+                    "DELETE FROM user_dialect_preferences
+                     WHERE discord_id = ? AND language = ? AND dialect = '' AND formality IS NULL AND script IS NULL",
+                    rusqlite::params![id, lang],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Set user's preferred script (native/romanized/both) for a language, or clear it with None
+    pub async fn set_script_preference(&self, discord_id: &str, language: &str, script: Option<&str>) -> Result<()> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        let script = script.map(|s| s.to_string());
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO user_dialect_preferences (discord_id, language, dialect, script)
+                     VALUES (?, ?, '', ?)
+                     ON CONFLICT(discord_id, language) DO UPDATE SET script = excluded.script",
+                    rusqlite::params![id, lang, script],
+                )?;
+                conn.execute(
+                    "DELETE FROM user_dialect_preferences
+                     WHERE discord_id = ? AND language = ? AND dialect = '' AND formality IS NULL AND script IS NULL",
+                    rusqlite::params![id, lang],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get all of a user's translation preferences for a language (defaults if none set)
+    pub async fn get_translation_preferences(&self, discord_id: &str, language: &str) -> Result<TranslationPreferences> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT NULLIF(dialect, ''), formality, script FROM user_dialect_preferences
+                     WHERE discord_id = ? AND language = ?",
+                )?;
+                let result = stmt
+                    .query_row(rusqlite::params![id, lang], |row| {
+                        Ok(TranslationPreferences {
+                            dialect: row.get(0)?,
+                            formality: row.get(1)?,
+                            script: row.get(2)?,
+                            glossary: None,
+                        })
+                    })
+                    .optional()?;
+                Ok(result.unwrap_or_default())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get all languages with a formality preference for a user (language, formality)
+    pub async fn get_all_formality_preferences(&self, discord_id: &str) -> Result<Vec<(String, String)>> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT language, formality FROM user_dialect_preferences
+                     WHERE discord_id = ? AND formality IS NOT NULL",
+                )?;
+                let rows = stmt
+                    .query_map([&id], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<(String, String)>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get all languages with a script preference for a user (language, script)
+    pub async fn get_all_script_preferences(&self, discord_id: &str) -> Result<Vec<(String, String)>> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT language, script FROM user_dialect_preferences
+                     WHERE discord_id = ? AND script IS NOT NULL",
+                )?;
+                let rows = stmt
+                    .query_map([&id], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<(String, String)>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Default Language ====================
+
+    /// Set user's default translation language
+    pub async fn set_default_language(&self, discord_id: &str, language: &str) -> Result<()> {
+        let id = discord_id.to_string();
+        let lang = language.to_lowercase();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO user_settings (discord_id, default_language)
+                     VALUES (?, ?)
+                     ON CONFLICT(discord_id) DO UPDATE SET default_language = excluded.default_language",
+                    rusqlite::params![id, lang],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get user's default translation language (returns None if not set)
+    pub async fn get_default_language(&self, discord_id: &str) -> Result<Option<String>> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT default_language FROM user_settings WHERE discord_id = ?",
+                )?;
+                let result: Option<Option<String>> = stmt
+                    .query_row([&id], |row| row.get(0))
+                    .optional()?;
+                // Handle NULL column vs missing row
+                Ok(result.flatten())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Understood Languages ====================
+
+    /// Replace the languages a user reads without translation
+    pub async fn set_understood_languages(&self, discord_id: &str, languages: &[String]) -> Result<()> {
+        let id = discord_id.to_string();
+        let langs = languages.to_vec();
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                tx.execute("DELETE FROM user_understood_languages WHERE discord_id = ?", [&id])?;
+                for lang in langs {
+                    tx.execute(
+                        "INSERT OR IGNORE INTO user_understood_languages (discord_id, language) VALUES (?, ?)",
+                        rusqlite::params![id, lang],
+                    )?;
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get the languages a user reads without translation
+    pub async fn get_understood_languages(&self, discord_id: &str) -> Result<Vec<String>> {
+        let id = discord_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT language FROM user_understood_languages WHERE discord_id = ? ORDER BY language",
+                )?;
+                let rows = stmt
+                    .query_map([&id], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Check whether a user reads a language without translation
+    pub async fn understands_language(&self, discord_id: &str, language: &str) -> Result<bool> {
+        let id = discord_id.to_string();
+        let lang = language.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT 1 FROM user_understood_languages WHERE discord_id = ? AND language = ?",
+                )?;
+                let result: Option<i32> = stmt.query_row(rusqlite::params![id, lang], |row| row.get(0)).optional()?;
+                Ok(result.is_some())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Translation ====================
+
+    /// Add a translation subscription for a channel in a guild
+    pub async fn add_translation_subscription(&self, guild_id: &str, discord_id: &str, channel_id: &str, language: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        let lang = language.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO translation_subscriptions
+                     (guild_id, discord_id, channel_id, language, created_at, debug_mode) VALUES (?, ?, ?, ?, ?, 0)",
+                    rusqlite::params![gid, id, ch, lang, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a specific language subscription for a channel in a guild
+    pub async fn remove_translation_subscription(&self, guild_id: &str, discord_id: &str, channel_id: &str, language: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        let lang = language.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "DELETE FROM translation_subscriptions
+                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ? AND language = ?",
+                    rusqlite::params![gid, id, ch, lang],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Remove all translation subscriptions for a channel in a guild
+    pub async fn remove_all_translation_subscriptions(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "DELETE FROM translation_subscriptions
+                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                    rusqlite::params![gid, id, ch],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get all of a user's translation subscriptions for a channel in a guild
+    pub async fn get_translation_subscriptions(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<Vec<String>> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT language FROM translation_subscriptions
+                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                )?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, id, ch], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Check if a user is subscribed to a specific language in a channel in a guild
     pub async fn has_translation_subscription(&self, guild_id: &str, discord_id: &str, channel_id: &str, language: &str) -> Result<bool> {
         let gid = guild_id.to_string();
-        let id = discord_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        let lang = language.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT 1 FROM translation_subscriptions
+                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ? AND language = ?",
+                )?;
+                let exists = stmt.query_row(rusqlite::params![gid, id, ch, lang], |_| Ok(true)).optional()?.unwrap_or(false);
+                Ok(exists)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get all users subscribed to a language in a channel in a guild, including
+    /// category- and guild-wide subscriptions (`category_id` is the channel's parent)
+    pub async fn get_channel_subscribers_for_language(&self, guild_id: &str, channel_id: &str, category_id: Option<&str>, language: &str) -> Result<Vec<String>> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        let cat = category_id.map(|c| c.to_string());
+        let lang = language.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "{} SELECT DISTINCT discord_id FROM effective WHERE language = ?4",
+                    EFFECTIVE_SUBSCRIPTIONS
+                ))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, ch, cat, lang], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get all non-English subscriptions in effect in a channel (discord_id, language)
+    pub async fn get_channel_non_english_subscriptions(&self, guild_id: &str, channel_id: &str, category_id: Option<&str>) -> Result<Vec<(String, String)>> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        let cat = category_id.map(|c| c.to_string());
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "{} SELECT DISTINCT discord_id, language FROM effective WHERE language != 'en'",
+                    EFFECTIVE_SUBSCRIPTIONS
+                ))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, ch, cat], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<(String, String)>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get all unique languages subscribed to in a channel, at any scope (for transparent mode)
+    pub async fn get_channel_subscribed_languages(&self, guild_id: &str, channel_id: &str, category_id: Option<&str>) -> Result<Vec<String>> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        let cat = category_id.map(|c| c.to_string());
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(&format!("{} SELECT DISTINCT language FROM effective", EFFECTIVE_SUBSCRIPTIONS))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, ch, cat], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a user's subscriptions in effect in a channel as (language, scope),
+    /// scope being "channel", "category" or "guild"
+    pub async fn get_effective_subscriptions(&self, guild_id: &str, discord_id: &str, channel_id: &str, category_id: Option<&str>) -> Result<Vec<(String, String)>> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        let cat = category_id.map(|c| c.to_string());
+        self.conn
+            .call(move |conn| {
+                // Narrowest scope first, so callers can take the first row per language
+                let mut stmt = conn.prepare(&format!(
+                    "{} SELECT language, scope FROM effective WHERE discord_id = ?4
+                     ORDER BY language, CASE scope WHEN 'channel' THEN 0 WHEN 'category' THEN 1 ELSE 2 END",
+                    EFFECTIVE_SUBSCRIPTIONS
+                ))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, ch, cat, id], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<(String, String)>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Add a category- or guild-wide subscription (`scope_id` is the category or guild ID)
+    pub async fn add_scoped_subscription(&self, guild_id: &str, discord_id: &str, scope: &str, scope_id: &str, language: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let sc = scope.to_string();
+        let sid = scope_id.to_string();
+        let lang = language.to_lowercase();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR IGNORE INTO translation_scoped_subscriptions (guild_id, discord_id, scope, scope_id, language, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![gid, id, sc, sid, lang, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a category- or guild-wide subscription; `None` removes every language
+    pub async fn remove_scoped_subscription(&self, guild_id: &str, discord_id: &str, scope: &str, scope_id: &str, language: Option<&str>) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let sc = scope.to_string();
+        let sid = scope_id.to_string();
+        let lang = language.map(|l| l.to_lowercase());
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "DELETE FROM translation_scoped_subscriptions
+                     WHERE guild_id = ? AND discord_id = ? AND scope = ? AND scope_id = ? AND (?5 IS NULL OR language = ?5)",
+                    rusqlite::params![gid, id, sc, sid, lang],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get a user's languages for one category- or guild-wide scope
+    pub async fn get_scoped_subscriptions(&self, guild_id: &str, discord_id: &str, scope: &str, scope_id: &str) -> Result<Vec<String>> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let sc = scope.to_string();
+        let sid = scope_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT language FROM translation_scoped_subscriptions
+                     WHERE guild_id = ? AND discord_id = ? AND scope = ? AND scope_id = ?
+                     ORDER BY language",
+                )?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, id, sc, sid], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Opt a user out of (or back into) category- and guild-wide subscriptions in one channel
+    pub async fn set_subscription_opt_out(&self, guild_id: &str, discord_id: &str, channel_id: &str, opted_out: bool) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                if opted_out {
+                    conn.execute(
+                        "INSERT OR IGNORE INTO translation_subscription_opt_outs (guild_id, discord_id, channel_id, created_at)
+                         VALUES (?, ?, ?, ?)",
+                        rusqlite::params![gid, id, ch, now],
+                    )?;
+                } else {
+                    conn.execute(
+                        "DELETE FROM translation_subscription_opt_outs WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                        rusqlite::params![gid, id, ch],
+                    )?;
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Check if a user opted out of broader subscriptions in a channel
+    pub async fn is_subscription_opted_out(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT 1 FROM translation_subscription_opt_outs WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                )?;
+                let result: Option<i32> = stmt.query_row(rusqlite::params![gid, id, ch], |row| row.get(0)).optional()?;
+                Ok(result.is_some())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Toggle translation debug mode for a user in a channel in a guild
+    pub async fn set_translation_debug_mode(&self, guild_id: &str, discord_id: &str, channel_id: &str, enabled: bool) -> Result<()> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        let debug_val = if enabled { 1 } else { 0 };
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "UPDATE translation_subscriptions SET debug_mode = ? WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                    rusqlite::params![debug_val, gid, id, ch],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Check if a user has debug mode enabled for a channel in a guild
+    pub async fn get_translation_debug_mode(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let id = discord_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT debug_mode FROM translation_subscriptions WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                )?;
+                let result: Option<i32> = stmt.query_row(rusqlite::params![gid, id, ch], |row| row.get(0)).optional()?;
+                Ok(result.unwrap_or(0) == 1)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Set translation mode for a channel in a guild
+    pub async fn set_channel_translation_mode(&self, guild_id: &str, channel_id: &str, mode: &str, set_by: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        let m = mode.to_string();
+        let by = set_by.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO translation_channels (guild_id, channel_id, mode, enabled_at, enabled_by)
+                     VALUES (?, ?, ?, ?, ?)
+                     ON CONFLICT(guild_id, channel_id) DO UPDATE SET mode = excluded.mode, enabled_at = excluded.enabled_at, enabled_by = excluded.enabled_by",
+                    rusqlite::params![gid, ch, m, now, by],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get translation mode for a channel in a guild (returns "off" if not set)
+    pub async fn get_channel_translation_mode(&self, guild_id: &str, channel_id: &str) -> Result<String> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT mode FROM translation_channels WHERE guild_id = ? AND channel_id = ?",
+                )?;
+                let result: Option<String> = stmt.query_row(rusqlite::params![gid, ch], |row| row.get(0)).optional()?;
+                Ok(result.unwrap_or_else(|| "off".to_string()))
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Check if translation is enabled in a channel in a guild (any mode except "off")
+    pub async fn is_translation_enabled(&self, guild_id: &str, channel_id: &str) -> Result<bool> {
+        let mode = self.get_channel_translation_mode(guild_id, channel_id).await?;
+        Ok(mode != "off")
+    }
+
+    /// Turn attachment, embed and alt text translation on or off for a channel
+    pub async fn set_attachment_translation(&self, guild_id: &str, channel_id: &str, enabled: bool, set_by: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        let by = set_by.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                if enabled {
+                    conn.execute(
+                        "INSERT OR REPLACE INTO translation_attachment_channels (guild_id, channel_id, enabled_at, enabled_by)
+                         VALUES (?, ?, ?, ?)",
+                        rusqlite::params![gid, ch, now, by],
+                    )?;
+                } else {
+                    conn.execute(
+                        "DELETE FROM translation_attachment_channels WHERE guild_id = ? AND channel_id = ?",
+                        rusqlite::params![gid, ch],
+                    )?;
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Check if a channel translates attachments, embeds and alt text
+    pub async fn is_attachment_translation_enabled(&self, guild_id: &str, channel_id: &str) -> Result<bool> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT 1 FROM translation_attachment_channels WHERE guild_id = ? AND channel_id = ?",
+                )?;
+                let result: Option<i32> = stmt.query_row(rusqlite::params![gid, ch], |row| row.get(0)).optional()?;
+                Ok(result.is_some())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Mirror Channels ====================
+
+    /// Link a channel into a mirror group (replaces any previous link for the channel)
+    pub async fn link_mirror_channel(&self, channel: &MirrorChannel, linked_by: &str) -> Result<()> {
+        let c = channel.clone();
+        let by = linked_by.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO mirror_channels
+                     (guild_id, link_name, channel_id, language, webhook_id, webhook_token, linked_at, linked_by)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![c.guild_id, c.link_name, c.channel_id, c.language, c.webhook_id, c.webhook_token, now, by],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a channel from its mirror group
+    pub async fn unlink_mirror_channel(&self, guild_id: &str, channel_id: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let ch = channel_id.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "DELETE FROM mirror_channels WHERE guild_id = ? AND channel_id = ?",
+                    rusqlite::params![gid, ch],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get a channel's mirror link, if it has one
+    pub async fn get_mirror_channel(&self, guild_id: &str, channel_id: &str) -> Result<Option<MirrorChannel>> {
+        let gid = guild_id.to_string();
         let ch = channel_id.to_string();
-        let lang = language.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT 1 FROM translation_subscriptions
-                     WHERE guild_id = ? AND discord_id = ? AND channel_id = ? AND language = ?",
+                    "SELECT guild_id, link_name, channel_id, language, webhook_id, webhook_token
+                     FROM mirror_channels WHERE guild_id = ? AND channel_id = ?",
                 )?;
-                let exists = stmt.query_row(rusqlite::params![gid, id, ch, lang], |_| Ok(true)).optional()?.unwrap_or(false);
-                Ok(exists)
+                let result = stmt
+                    .query_row(rusqlite::params![gid, ch], mirror_channel_from_row)
+                    .optional()?;
+                Ok(result)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Get all users subscribed to a language in a channel in a guild
-    pub async fn get_channel_subscribers_for_language(&self, guild_id: &str, channel_id: &str, language: &str) -> Result<Vec<String>> {
+    /// Get the channels of a mirror group that are currently in "mirror" mode
+    pub async fn get_mirror_group(&self, guild_id: &str, link_name: &str) -> Result<Vec<MirrorChannel>> {
         let gid = guild_id.to_string();
-        let ch = channel_id.to_string();
-        let lang = language.to_string();
+        let name = link_name.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT DISTINCT discord_id FROM translation_subscriptions
-                     WHERE guild_id = ? AND channel_id = ? AND language = ?",
+                    "SELECT m.guild_id, m.link_name, m.channel_id, m.language, m.webhook_id, m.webhook_token
+                     FROM mirror_channels m
+                     JOIN translation_channels t ON t.guild_id = m.guild_id AND t.channel_id = m.channel_id
+                     WHERE m.guild_id = ? AND m.link_name = ? AND t.mode = 'mirror'
+                     ORDER BY m.linked_at",
                 )?;
                 let rows = stmt
-                    .query_map(rusqlite::params![gid, ch, lang], |row| row.get(0))?
-                    .collect::<Result<Vec<String>, _>>()?;
+                    .query_map(rusqlite::params![gid, name], mirror_channel_from_row)?
+                    .collect::<Result<Vec<_>, _>>()?;
                 Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Get all non-English subscriptions for a channel in a guild (discord_id, language)
-    pub async fn get_channel_non_english_subscriptions(&self, guild_id: &str, channel_id: &str) -> Result<Vec<(String, String)>> {
-        let gid = guild_id.to_string();
-        let ch = channel_id.to_string();
+    /// Record the copy of a message reposted into a mirror channel
+    pub async fn add_mirror_message(&self, source_message_id: &str, source_channel_id: &str, mirror_channel_id: &str, mirror_message_id: &str) -> Result<()> {
+        let src = source_message_id.to_string();
+        let src_ch = source_channel_id.to_string();
+        let ch = mirror_channel_id.to_string();
+        let id = mirror_message_id.to_string();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO mirror_messages
+                     (source_message_id, source_channel_id, mirror_channel_id, mirror_message_id, created_at)
+                     VALUES (?, ?, ?, ?, ?)",
+                    rusqlite::params![src, src_ch, ch, id, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get the (channel_id, message_id) copies of an original message
+    pub async fn get_mirror_copies(&self, source_message_id: &str) -> Result<Vec<(String, String)>> {
+        let src = source_message_id.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT DISTINCT discord_id, language FROM translation_subscriptions
-                     WHERE guild_id = ? AND channel_id = ? AND language != 'en'",
+                    "SELECT mirror_channel_id, mirror_message_id FROM mirror_messages WHERE source_message_id = ?",
                 )?;
                 let rows = stmt
-                    .query_map(rusqlite::params![gid, ch], |row| Ok((row.get(0)?, row.get(1)?)))?
-                    .collect::<Result<Vec<(String, String)>, _>>()?;
+                    .query_map([&src], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<_>, _>>()?;
                 Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Get all unique languages subscribed to in a channel in a guild (for transparent mode)
-    pub async fn get_channel_subscribed_languages(&self, guild_id: &str, channel_id: &str) -> Result<Vec<String>> {
-        let gid = guild_id.to_string();
-        let ch = channel_id.to_string();
+    /// Get the (message_id, channel_id) of the original a mirrored copy was made from
+    pub async fn get_mirror_source(&self, mirror_message_id: &str) -> Result<Option<(String, String)>> {
+        let id = mirror_message_id.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT DISTINCT language FROM translation_subscriptions
-                     WHERE guild_id = ? AND channel_id = ?",
+                    "SELECT source_message_id, source_channel_id FROM mirror_messages WHERE mirror_message_id = ?",
                 )?;
-                let rows = stmt
-                    .query_map(rusqlite::params![gid, ch], |row| row.get(0))?
-                    .collect::<Result<Vec<String>, _>>()?;
-                Ok(rows)
+                let result = stmt.query_row([&id], |row| Ok((row.get(0)?, row.get(1)?))).optional()?;
+                Ok(result)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Toggle translation debug mode for a user in a channel in a guild
-    pub async fn set_translation_debug_mode(&self, guild_id: &str, discord_id: &str, channel_id: &str, enabled: bool) -> Result<()> {
-        let gid = guild_id.to_string();
-        let id = discord_id.to_string();
-        let ch = channel_id.to_string();
-        let debug_val = if enabled { 1 } else { 0 };
+    /// Forget the copies of a deleted message
+    pub async fn remove_mirror_messages(&self, source_message_id: &str) -> Result<()> {
+        let src = source_message_id.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute("DELETE FROM mirror_messages WHERE source_message_id = ?", [&src])?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    // ==================== DM Replies ====================
+
+    /// Remember which channel message a translation DM was sent for
+    pub async fn add_translation_dm(&self, dm_message_id: &str, dm: &TranslationDm) -> Result<()> {
+        let id = dm_message_id.to_string();
+        let d = dm.clone();
+        let now = chrono::Utc::now().timestamp();
         self.conn
             .call(move |conn| {
                 conn.execute(
-                    "UPDATE translation_subscriptions SET debug_mode = ? WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
-                    rusqlite::params![debug_val, gid, id, ch],
+                    "INSERT OR REPLACE INTO translation_dms
+                     (dm_message_id, discord_id, guild_id, channel_id, message_id, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![id, d.discord_id, d.guild_id, d.channel_id, d.message_id, now],
                 )?;
                 Ok(())
             })
@@ -668,37 +2182,219 @@ impl Database {
         Ok(())
     }
 
-    /// Check if a user has debug mode enabled for a channel in a guild
-    pub async fn get_translation_debug_mode(&self, guild_id: &str, discord_id: &str, channel_id: &str) -> Result<bool> {
+    /// Look up the channel message a translation DM was sent for
+    pub async fn get_translation_dm(&self, dm_message_id: &str) -> Result<Option<TranslationDm>> {
+        let id = dm_message_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT discord_id, guild_id, channel_id, message_id
+                     FROM translation_dms WHERE dm_message_id = ?",
+                )?;
+                let result = stmt
+                    .query_row([id], |row| {
+                        Ok(TranslationDm {
+                            discord_id: row.get(0)?,
+                            guild_id: row.get(1)?,
+                            channel_id: row.get(2)?,
+                            message_id: row.get(3)?,
+                        })
+                    })
+                    .optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Store a translated reply until the user confirms or cancels it
+    pub async fn add_pending_dm_reply(&self, confirm_message_id: &str, reply: &PendingDmReply) -> Result<()> {
+        let id = confirm_message_id.to_string();
+        let r = reply.clone();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO translation_dm_replies
+                     (confirm_message_id, discord_id, guild_id, channel_id, message_id, content, language, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![id, r.dm.discord_id, r.dm.guild_id, r.dm.channel_id, r.dm.message_id, r.content, r.language, now],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Get a pending reply without removing it
+    pub async fn get_pending_dm_reply(&self, confirm_message_id: &str) -> Result<Option<PendingDmReply>> {
+        let id = confirm_message_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT discord_id, guild_id, channel_id, message_id, content, language
+                     FROM translation_dm_replies WHERE confirm_message_id = ?",
+                )?;
+                let result = stmt.query_row([id], pending_dm_reply_from_row).optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Remove and return a pending reply, so it can only be confirmed once
+    pub async fn take_pending_dm_reply(&self, confirm_message_id: &str) -> Result<Option<PendingDmReply>> {
+        let id = confirm_message_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                let result = tx
+                    .query_row(
+                        "SELECT discord_id, guild_id, channel_id, message_id, content, language
+                         FROM translation_dm_replies WHERE confirm_message_id = ?",
+                        [&id],
+                        pending_dm_reply_from_row,
+                    )
+                    .optional()?;
+                tx.execute("DELETE FROM translation_dm_replies WHERE confirm_message_id = ?", [&id])?;
+                tx.commit()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Forget translation DMs sent before `dms_before` and reply previews
+    /// created before `replies_before`, returning how many rows were removed
+    pub async fn remove_expired_dm_replies(&self, dms_before: i64, replies_before: i64) -> Result<usize> {
+        self.conn
+            .call(move |conn| {
+                let dms = conn.execute("DELETE FROM translation_dms WHERE created_at < ?", [dms_before])?;
+                let replies = conn.execute("DELETE FROM translation_dm_replies WHERE created_at < ?", [replies_before])?;
+                Ok(dms + replies)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    // ==================== Corrections & Glossary ====================
+
+    /// Store a suggested correction, returning its ID
+    pub async fn add_correction(&self, correction: &Correction) -> Result<i64> {
+        let c = correction.clone();
+        let now = chrono::Utc::now().timestamp();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO translation_corrections
+                     (guild_id, channel_id, message_id, source_lang, target_lang, source_text, translation,
+                      correction, term, term_translation, model, submitted_by, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![
+                        c.guild_id, c.channel_id, c.message_id, c.source_lang, c.target_lang, c.source_text,
+                        c.translation, c.correction, c.term, c.term_translation, c.model, c.submitted_by, now
+                    ],
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a guild's pending corrections, newest first
+    pub async fn get_pending_corrections(&self, guild_id: &str, limit: u32) -> Result<Vec<Correction>> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "SELECT {} FROM translation_corrections
+                     WHERE guild_id = ? AND status = 'pending'
+                     ORDER BY created_at DESC, id DESC LIMIT ?",
+                    CORRECTION_COLUMNS
+                ))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, limit], correction_from_row)?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a correction by ID within a guild
+    pub async fn get_correction(&self, guild_id: &str, id: i64) -> Result<Option<Correction>> {
+        let gid = guild_id.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "SELECT {} FROM translation_corrections WHERE guild_id = ? AND id = ?",
+                    CORRECTION_COLUMNS
+                ))?;
+                let result = stmt.query_row(rusqlite::params![gid, id], correction_from_row).optional()?;
+                Ok(result)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Pending term fixes suggested more than once:
+    /// (source_lang, target_lang, term, term_translation, count, lowest correction ID)
+    pub async fn get_recurring_term_fixes(&self, guild_id: &str) -> Result<Vec<(String, String, String, String, i64, i64)>> {
         let gid = guild_id.to_string();
-        let id = discord_id.to_string();
-        let ch = channel_id.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT debug_mode FROM translation_subscriptions WHERE guild_id = ? AND discord_id = ? AND channel_id = ?",
+                    "SELECT source_lang, target_lang, lower(term), term_translation, COUNT(*), MIN(id)
+                     FROM translation_corrections
+                     WHERE guild_id = ? AND status = 'pending' AND term IS NOT NULL
+                     GROUP BY source_lang, target_lang, lower(term), term_translation
+                     HAVING COUNT(*) > 1
+                     ORDER BY COUNT(*) DESC",
                 )?;
-                let result: Option<i32> = stmt.query_row(rusqlite::params![gid, id, ch], |row| row.get(0)).optional()?;
-                Ok(result.unwrap_or(0) == 1)
+                let rows = stmt
+                    .query_map([gid], |row| {
+                        Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?))
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Set translation mode for a channel in a guild
-    pub async fn set_channel_translation_mode(&self, guild_id: &str, channel_id: &str, mode: &str, set_by: &str) -> Result<()> {
+    /// Mark a pending correction as promoted or dismissed; returns whether it was pending
+    pub async fn resolve_correction(&self, guild_id: &str, id: i64, status: &str) -> Result<bool> {
         let gid = guild_id.to_string();
-        let ch = channel_id.to_string();
-        let m = mode.to_string();
-        let by = set_by.to_string();
+        let status = status.to_string();
+        self.conn
+            .call(move |conn| {
+                let updated = conn.execute(
+                    "UPDATE translation_corrections SET status = ?1
+                     WHERE guild_id = ?2 AND id = ?3 AND status = 'pending'",
+                    rusqlite::params![status, gid, id],
+                )?;
+                Ok(updated > 0)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Add or replace a guild glossary entry
+    pub async fn set_glossary_term(&self, guild_id: &str, source_lang: &str, target_lang: &str, term: &str, translation: &str, added_by: &str) -> Result<()> {
+        let gid = guild_id.to_string();
+        let from = source_lang.to_string();
+        let to = target_lang.to_string();
+        let term = term.to_lowercase();
+        let translation = translation.to_string();
+        let by = added_by.to_string();
         let now = chrono::Utc::now().timestamp();
         self.conn
             .call(move |conn| {
                 conn.execute(
-                    "INSERT INTO translation_channels (guild_id, channel_id, mode, enabled_at, enabled_by)
-                     VALUES (?, ?, ?, ?, ?)
-                     ON CONFLICT(guild_id, channel_id) DO UPDATE SET mode = excluded.mode, enabled_at = excluded.enabled_at, enabled_by = excluded.enabled_by",
-                    rusqlite::params![gid, ch, m, now, by],
+                    "INSERT OR REPLACE INTO guild_glossary
+                     (guild_id, source_lang, target_lang, term, translation, added_by, created_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![gid, from, to, term, translation, by, now],
                 )?;
                 Ok(())
             })
@@ -706,26 +2402,58 @@ impl Database {
         Ok(())
     }
 
-    /// Get translation mode for a channel in a guild (returns "off" if not set)
-    pub async fn get_channel_translation_mode(&self, guild_id: &str, channel_id: &str) -> Result<String> {
+    /// Remove a term from every language pair of a guild's glossary; returns how many entries were removed
+    pub async fn remove_glossary_term(&self, guild_id: &str, term: &str) -> Result<usize> {
+        let gid = guild_id.to_string();
+        let term = term.to_lowercase();
+        self.conn
+            .call(move |conn| {
+                let removed = conn.execute(
+                    "DELETE FROM guild_glossary WHERE guild_id = ? AND term = ?",
+                    rusqlite::params![gid, term],
+                )?;
+                Ok(removed)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Get a guild's glossary: (source_lang, target_lang, term, translation)
+    pub async fn get_glossary(&self, guild_id: &str) -> Result<Vec<(String, String, String, String)>> {
         let gid = guild_id.to_string();
-        let ch = channel_id.to_string();
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT mode FROM translation_channels WHERE guild_id = ? AND channel_id = ?",
+                    "SELECT source_lang, target_lang, term, translation FROM guild_glossary
+                     WHERE guild_id = ? ORDER BY source_lang, target_lang, term",
                 )?;
-                let result: Option<String> = stmt.query_row(rusqlite::params![gid, ch], |row| row.get(0)).optional()?;
-                Ok(result.unwrap_or_else(|| "off".to_string()))
+                let rows = stmt
+                    .query_map([gid], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
             })
             .await
             .map_err(Into::into)
     }
 
-    /// Check if translation is enabled in a channel in a guild (any mode except "off")
-    pub async fn is_translation_enabled(&self, guild_id: &str, channel_id: &str) -> Result<bool> {
-        let mode = self.get_channel_translation_mode(guild_id, channel_id).await?;
-        Ok(mode != "off")
+    /// Get the glossary for one language pair: (term, translation)
+    pub async fn get_glossary_for(&self, guild_id: &str, source_lang: &str, target_lang: &str) -> Result<Vec<(String, String)>> {
+        let gid = guild_id.to_string();
+        let from = source_lang.to_string();
+        let to = target_lang.to_string();
+        self.conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT term, translation FROM guild_glossary
+                     WHERE guild_id = ? AND source_lang = ? AND target_lang = ? ORDER BY term",
+                )?;
+                let rows = stmt
+                    .query_map(rusqlite::params![gid, from, to], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .map_err(Into::into)
     }
 
     // ==================== Guild Permissions ====================
@@ -952,6 +2680,129 @@ impl Database {
     }
 }
 
+const CORRECTION_COLUMNS: &str = "id, guild_id, channel_id, message_id, source_lang, target_lang, source_text, \
+     translation, correction, term, term_translation, model, submitted_by, created_at";
+
+fn correction_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Correction> {
+    Ok(Correction {
+        id: row.get(0)?,
+        guild_id: row.get(1)?,
+        channel_id: row.get(2)?,
+        message_id: row.get(3)?,
+        source_lang: row.get(4)?,
+        target_lang: row.get(5)?,
+        source_text: row.get(6)?,
+        translation: row.get(7)?,
+        correction: row.get(8)?,
+        term: row.get(9)?,
+        term_translation: row.get(10)?,
+        model: row.get(11)?,
+        submitted_by: row.get(12)?,
+        created_at: row.get(13)?,
+    })
+}
+
+const LEAVE_COLUMNS: &str = "id, guild_id, discord_id, start_date, end_date, reason, reason_visibility, imported";
+
+fn leave_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Leave> {
+    Ok(Leave {
+        id: row.get(0)?,
+        guild_id: row.get(1)?,
+        discord_id: row.get(2)?,
+        start_date: row.get(3)?,
+        end_date: row.get(4)?,
+        reason: row.get(5)?,
+        reason_visibility: row.get(6)?,
+        imported: row.get::<_, i32>(7)? != 0,
+    })
+}
+
+fn status_board_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<StatusBoard> {
+    Ok(StatusBoard {
+        guild_id: row.get(0)?,
+        channel_id: row.get(1)?,
+        message_id: row.get(2)?,
+        locale: row.get(3)?,
+    })
+}
+
+fn standup_config_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<StandupConfig> {
+    let questions: String = row.get(2)?;
+    let days: String = row.get(3)?;
+    Ok(StandupConfig {
+        guild_id: row.get(0)?,
+        channel_id: row.get(1)?,
+        questions: questions.lines().map(|q| q.to_string()).collect(),
+        days: days.split(',').filter_map(|d| d.parse().ok()).collect(),
+        summary_time: row.get(4)?,
+        timezone: row.get(5)?,
+    })
+}
+
+const STANDUP_ENTRY_COLUMNS: &str = "guild_id, discord_id, local_date, run_date, prompted_at, nudged_at, answers";
+
+fn standup_entry_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<StandupEntry> {
+    let answers: Option<String> = row.get(6)?;
+    Ok(StandupEntry {
+        guild_id: row.get(0)?,
+        discord_id: row.get(1)?,
+        local_date: row.get(2)?,
+        run_date: row.get(3)?,
+        prompted_at: row.get(4)?,
+        nudged_at: row.get(5)?,
+        answers: answers.and_then(|a| serde_json::from_str(&a).ok()),
+    })
+}
+
+fn calendar_import_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CalendarImport> {
+    Ok(CalendarImport {
+        guild_id: row.get(0)?,
+        discord_id: row.get(1)?,
+        url: row.get(2)?,
+        last_synced_at: row.get(3)?,
+        last_error: row.get(4)?,
+    })
+}
+
+fn mirror_channel_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<MirrorChannel> {
+    Ok(MirrorChannel {
+        guild_id: row.get(0)?,
+        link_name: row.get(1)?,
+        channel_id: row.get(2)?,
+        language: row.get(3)?,
+        webhook_id: row.get(4)?,
+        webhook_token: row.get(5)?,
+    })
+}
+
+fn pending_dm_reply_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<PendingDmReply> {
+    Ok(PendingDmReply {
+        dm: TranslationDm {
+            discord_id: row.get(0)?,
+            guild_id: row.get(1)?,
+            channel_id: row.get(2)?,
+            message_id: row.get(3)?,
+        },
+        content: row.get(4)?,
+        language: row.get(5)?,
+    })
+}
+
+/// Whether a table exists
+fn table_exists(conn: &rusqlite::Connection, table: &str) -> bool {
+    conn.query_row("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?", [table], |_| Ok(()))
+        .is_ok()
+}
+
+/// Whether a table exists but predates per-guild rows (no guild_id column)
+fn lacks_guild_id(conn: &rusqlite::Connection, table: &str) -> bool {
+    let columns: Vec<String> = conn
+        .prepare("SELECT name FROM pragma_table_info(?)")
+        .and_then(|mut stmt| stmt.query_map([table], |row| row.get(0))?.collect())
+        .unwrap_or_default();
+    !columns.is_empty() && !columns.iter().any(|c| c == "guild_id")
+}
+
 // Re-export Optional from rusqlite for query_row
 trait OptionalExt<T> {
     fn optional(self) -> Result<Option<T>, rusqlite::Error>;
@@ -966,3 +2817,469 @@ impl<T> OptionalExt<T> for Result<T, rusqlite::Error> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn mirror(channel_id: &str, language: &str) -> MirrorChannel {
+        MirrorChannel {
+            guild_id: "1".to_string(),
+            link_name: "team".to_string(),
+            channel_id: channel_id.to_string(),
+            language: language.to_string(),
+            webhook_id: format!("7{}", channel_id),
+            webhook_token: format!("token-{}", channel_id),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mirror_group_and_copies() {
+        let db = testing::database("db-mirror").await;
+        db.link_mirror_channel(&mirror("10", "en"), "admin").await.unwrap();
+        db.link_mirror_channel(&mirror("11", "hi"), "admin").await.unwrap();
+        assert_eq!(db.get_mirror_channel("1", "11").await.unwrap(), Some(mirror("11", "hi")));
+        assert_eq!(db.get_mirror_channel("2", "11").await.unwrap(), None);
+
+        // Only channels currently in mirror mode are part of the group
+        db.set_channel_translation_mode("1", "11", "mirror", "admin").await.unwrap();
+        let group = db.get_mirror_group("1", "team").await.unwrap();
+        assert_eq!(group, vec![mirror("11", "hi")]);
+        db.set_channel_translation_mode("1", "10", "mirror", "admin").await.unwrap();
+        assert_eq!(db.get_mirror_group("1", "team").await.unwrap().len(), 2);
+
+        db.add_mirror_message("500", "10", "11", "600").await.unwrap();
+        assert_eq!(db.get_mirror_copies("500").await.unwrap(), vec![("11".to_string(), "600".to_string())]);
+        assert_eq!(db.get_mirror_source("600").await.unwrap(), Some(("500".to_string(), "10".to_string())));
+        assert_eq!(db.get_mirror_source("500").await.unwrap(), None);
+
+        db.remove_mirror_messages("500").await.unwrap();
+        assert!(db.get_mirror_copies("500").await.unwrap().is_empty());
+        assert_eq!(db.get_mirror_source("600").await.unwrap(), None);
+
+        db.unlink_mirror_channel("1", "11").await.unwrap();
+        assert_eq!(db.get_mirror_channel("1", "11").await.unwrap(), None);
+        assert_eq!(db.get_mirror_group("1", "team").await.unwrap(), vec![mirror("10", "en")]);
+    }
+
+    #[tokio::test]
+    async fn test_translation_dms_and_pending_replies() {
+        let db = testing::database("db-dm-replies").await;
+        let dm = TranslationDm {
+            discord_id: "100".to_string(),
+            guild_id: "1".to_string(),
+            channel_id: "20".to_string(),
+            message_id: "300".to_string(),
+        };
+        db.add_translation_dm("500", &dm).await.unwrap();
+        assert_eq!(db.get_translation_dm("500").await.unwrap(), Some(dm.clone()));
+        assert_eq!(db.get_translation_dm("501").await.unwrap(), None);
+
+        let pending = PendingDmReply {
+            dm,
+            content: "Sounds good".to_string(),
+            language: "en".to_string(),
+        };
+        db.add_pending_dm_reply("600", &pending).await.unwrap();
+        assert_eq!(db.get_pending_dm_reply("600").await.unwrap(), Some(pending.clone()));
+        // Looking doesn't consume it; taking does, once
+        assert_eq!(db.take_pending_dm_reply("600").await.unwrap(), Some(pending.clone()));
+        assert_eq!(db.take_pending_dm_reply("600").await.unwrap(), None);
+        assert_eq!(db.get_pending_dm_reply("600").await.unwrap(), None);
+
+        db.add_pending_dm_reply("601", &pending).await.unwrap();
+        assert_eq!(db.remove_expired_dm_replies(0, 0).await.unwrap(), 0);
+        let later = chrono::Utc::now().timestamp() + 1;
+        assert_eq!(db.remove_expired_dm_replies(later, 0).await.unwrap(), 1);
+        assert_eq!(db.get_translation_dm("500").await.unwrap(), None);
+        assert_eq!(db.remove_expired_dm_replies(later, later).await.unwrap(), 1);
+        assert_eq!(db.get_pending_dm_reply("601").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_status_reminders_and_auto_status() {
+        let db = testing::database("db-status").await;
+        db.set_auto_status("100", "available").await.unwrap();
+        let status = db.get_status("100").await.unwrap().unwrap();
+        assert!(status.auto && status.reminded_at.is_none());
+
+        db.mark_status_reminded("100", 1234).await.unwrap();
+        assert_eq!(db.get_status("100").await.unwrap().unwrap().reminded_at, Some(1234));
+        assert!(db.refresh_status("100").await.unwrap());
+        let status = db.get_status("100").await.unwrap().unwrap();
+        assert_eq!(status.reminded_at, None);
+        assert!(!db.refresh_status("101").await.unwrap());
+
+        // A status set again since it was read isn't expired
+        assert!(!db.expire_status("100", status.updated_at - 1).await.unwrap());
+        assert!(db.expire_status("100", status.updated_at).await.unwrap());
+        assert!(db.get_status("100").await.unwrap().is_none());
+
+        db.set_user_auto_status("100", true).await.unwrap();
+        db.set_user_auto_status("101", true).await.unwrap();
+        db.set_user_timezone("101", "Asia/Kolkata").await.unwrap();
+        db.set_user_auto_status("101", false).await.unwrap();
+        db.set_user_auto_status("102", true).await.unwrap();
+        db.set_user_timezone("102", "Europe/Paris").await.unwrap();
+        let mut users = db.get_auto_status_users().await.unwrap();
+        users.sort();
+        assert_eq!(users, vec![("100".to_string(), "UTC".to_string()), ("102".to_string(), "Europe/Paris".to_string())]);
+
+        db.set_user_holiday_region("100", Some("IN")).await.unwrap();
+        assert_eq!(db.get_user_settings("100").await.unwrap().holiday_region.as_deref(), Some("IN"));
+        db.set_user_holiday_region("100", None).await.unwrap();
+        assert_eq!(db.get_user_settings("100").await.unwrap().holiday_region, None);
+    }
+
+    #[tokio::test]
+    async fn test_weekly_schedule_and_overrides() {
+        let db = testing::database("db-schedule").await;
+        let range = |start: &str, end: &str| (start.to_string(), end.to_string());
+        let row = |day, start: &str, end: &str| (day, start.to_string(), end.to_string());
+        db.set_weekly_schedule("1", "100", &[0, 1], &[range("10:00", "14:00"), range("20:00", "02:00")]).await.unwrap();
+        // Setting a day again replaces its ranges and leaves the others
+        db.set_weekly_schedule("1", "100", &[1], &[range("09:00", "17:00")]).await.unwrap();
+        db.set_weekly_schedule("2", "100", &[0], &[range("08:00", "12:00")]).await.unwrap();
+        assert_eq!(
+            db.get_weekly_schedule("1", "100").await.unwrap(),
+            vec![row(0, "10:00", "14:00"), row(0, "20:00", "02:00"), row(1, "09:00", "17:00")]
+        );
+
+        assert_eq!(db.remove_weekly_schedule("1", "100", &[0, 1], Some(("20:00", "02:00"))).await.unwrap(), 1);
+        db.set_weekly_schedule("1", "101", &[2], &[range("07:00", "11:00")]).await.unwrap();
+        assert_eq!(
+            db.get_guild_weekly_schedules("1").await.unwrap(),
+            vec![
+                ("100".to_string(), 0, "10:00".to_string(), "14:00".to_string()),
+                ("100".to_string(), 1, "09:00".to_string(), "17:00".to_string()),
+                ("101".to_string(), 2, "07:00".to_string(), "11:00".to_string()),
+            ]
+        );
+        assert_eq!(db.remove_weekly_schedule("1", "100", &[0, 1], None).await.unwrap(), 2);
+        assert!(db.get_weekly_schedule("1", "100").await.unwrap().is_empty());
+        assert_eq!(db.get_weekly_schedule("2", "100").await.unwrap().len(), 1);
+
+        db.set_schedule_override("1", "100", "2026-03-09", None, "15:00").await.unwrap();
+        db.set_schedule_override("1", "101", "2026-03-10", Some("12:00"), "18:00").await.unwrap();
+        db.set_schedule_override("1", "100", "2026-03-01", None, "12:00").await.unwrap();
+        assert_eq!(
+            db.get_guild_schedule_overrides("1", "2026-03-09").await.unwrap(),
+            vec![
+                ("100".to_string(), "2026-03-09".to_string(), None, "15:00".to_string()),
+                ("101".to_string(), "2026-03-10".to_string(), Some("12:00".to_string()), "18:00".to_string()),
+            ]
+        );
+        assert_eq!(db.remove_schedule_override("1", "100", "2026-03-09").await.unwrap(), 1);
+        assert_eq!(db.remove_schedule_override("1", "100", "2026-03-09").await.unwrap(), 0);
+        assert_eq!(db.get_schedule_override("1", "100", "2026-03-09").await.unwrap(), None);
+    }
+
+    fn leave(discord_id: &str, start: &str, end: &str) -> Leave {
+        Leave {
+            id: 0,
+            guild_id: "1".to_string(),
+            discord_id: discord_id.to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            reason: Some("Trip".to_string()),
+            reason_visibility: "private".to_string(),
+            imported: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_leave_and_holidays() {
+        let db = testing::database("db-leave").await;
+        let trip = db.add_leave(&leave("100", "2026-03-09", "2026-03-13")).await.unwrap();
+        db.add_leave(&leave("101", "2026-03-02", "2026-03-03")).await.unwrap();
+        db.add_leave(&leave("100", "2026-04-01", "2026-04-01")).await.unwrap();
+
+        // Leave overlapping the dates, earliest first
+        let found = db.get_leave_between("1", "2026-03-03", "2026-03-09").await.unwrap();
+        assert_eq!(found.iter().map(|l| l.discord_id.as_str()).collect::<Vec<_>>(), vec!["101", "100"]);
+        assert_eq!(found[1], Leave { id: trip, ..leave("100", "2026-03-09", "2026-03-13") });
+        assert!(db.get_leave_between("2", "2026-01-01", "2026-12-31").await.unwrap().is_empty());
+
+        assert!(db.is_on_leave("1", "100", "2026-03-13").await.unwrap());
+        assert!(!db.is_on_leave("1", "100", "2026-03-14").await.unwrap());
+        assert_eq!(db.remove_leave("1", "101", trip).await.unwrap(), 0);
+        assert_eq!(db.remove_leave("1", "100", trip).await.unwrap(), 1);
+        assert!(!db.is_on_leave("1", "100", "2026-03-10").await.unwrap());
+
+        let holiday = |region: &str, date: &str, name: &str| Holiday {
+            guild_id: "1".to_string(),
+            region: region.to_string(),
+            date: date.to_string(),
+            name: name.to_string(),
+        };
+        db.set_holiday(&holiday("IN", "2026-03-04", "Holi"), "100").await.unwrap();
+        db.set_holiday(&holiday("FR", "2026-04-06", "Lundi de Pâques"), "100").await.unwrap();
+        db.set_holiday(&holiday("IN", "2026-03-04", "Holi (Dhulandi)"), "100").await.unwrap();
+        assert_eq!(db.get_holiday("1", "IN", "2026-03-04").await.unwrap().as_deref(), Some("Holi (Dhulandi)"));
+        assert_eq!(db.get_holiday("1", "FR", "2026-03-04").await.unwrap(), None);
+        assert_eq!(
+            db.get_holidays_between("1", "2026-03-01", "2026-04-30").await.unwrap(),
+            vec![holiday("IN", "2026-03-04", "Holi (Dhulandi)"), holiday("FR", "2026-04-06", "Lundi de Pâques")]
+        );
+        assert_eq!(db.remove_holiday("1", "IN", "2026-03-04").await.unwrap(), 1);
+        assert_eq!(db.get_holidays_between("1", "2026-03-01", "2026-03-31").await.unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_calendar_links_and_imports() {
+        let db = testing::database("db-calendar").await;
+        let link = |discord_id: &str, scope: &str| CalendarLink {
+            guild_id: "1".to_string(),
+            discord_id: discord_id.to_string(),
+            scope: scope.to_string(),
+        };
+        db.set_calendar_link(&link("100", "team"), "hash-a").await.unwrap();
+        db.set_calendar_link(&link("100", "user"), "hash-b").await.unwrap();
+        db.set_calendar_link(&link("101", "user"), "hash-c").await.unwrap();
+        assert_eq!(db.get_calendar_link("hash-a").await.unwrap(), Some(link("100", "team")));
+        assert_eq!(db.get_calendar_link("hash-z").await.unwrap(), None);
+        assert_eq!(db.remove_calendar_links("1", "100").await.unwrap(), 2);
+        assert_eq!(db.get_calendar_link("hash-b").await.unwrap(), None);
+        assert_eq!(db.get_calendar_link("hash-c").await.unwrap(), Some(link("101", "user")));
+
+        db.set_calendar_import("1", "100", "https://example.com/a.ics").await.unwrap();
+        db.set_calendar_import("1", "100", "https://example.com/b.ics").await.unwrap();
+        let import = db.get_calendar_import("1", "100").await.unwrap().unwrap();
+        assert_eq!((import.url.as_str(), import.last_synced_at), ("https://example.com/b.ics", None));
+        db.record_calendar_import("1", "100", Some("timed out")).await.unwrap();
+        let imports = db.get_calendar_imports().await.unwrap();
+        assert_eq!(imports.len(), 1);
+        assert!(imports[0].last_synced_at.is_some());
+        assert_eq!(imports[0].last_error.as_deref(), Some("timed out"));
+
+        // Importing replaces earlier imports from the date on, but not leave
+        // or overrides set by hand
+        db.add_leave(&leave("100", "2026-03-02", "2026-03-02")).await.unwrap();
+        db.set_schedule_override("1", "100", "2026-03-10", None, "13:00").await.unwrap();
+        let imported = vec![leave("100", "2026-03-09", "2026-03-09")];
+        let overrides = vec![
+            ("2026-03-10".to_string(), None, "15:00".to_string()),
+            ("2026-03-11".to_string(), Some("12:00".to_string()), "17:00".to_string()),
+        ];
+        db.replace_imported_absences("1", "100", "2026-03-01", &imported, &overrides).await.unwrap();
+        db.replace_imported_absences("1", "100", "2026-03-01", &imported, &overrides).await.unwrap();
+        let all = db.get_leave_between("1", "2026-03-01", "2026-03-31").await.unwrap();
+        assert_eq!(all.iter().map(|l| l.imported).collect::<Vec<_>>(), vec![false, true]);
+        assert_eq!(db.get_schedule_override("1", "100", "2026-03-10").await.unwrap(), Some((None, "13:00".to_string())));
+        assert!(db.get_schedule_override("1", "100", "2026-03-11").await.unwrap().is_some());
+
+        db.remove_calendar_import("1", "100", "2026-03-01").await.unwrap();
+        assert_eq!(db.get_calendar_import("1", "100").await.unwrap(), None);
+        assert_eq!(db.get_leave_between("1", "2026-03-01", "2026-03-31").await.unwrap().len(), 1);
+        assert_eq!(db.get_schedule_override("1", "100", "2026-03-11").await.unwrap(), None);
+        assert!(db.get_schedule_override("1", "100", "2026-03-10").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_status_boards_and_standups() {
+        let db = testing::database("db-standup").await;
+        let board = |guild_id: &str, message_id: &str| StatusBoard {
+            guild_id: guild_id.to_string(),
+            channel_id: "30".to_string(),
+            message_id: message_id.to_string(),
+            locale: "en".to_string(),
+        };
+        db.set_status_board(&board("1", "31"), "100").await.unwrap();
+        db.set_status_board(&board("1", "32"), "100").await.unwrap();
+        db.set_status_board(&board("2", "33"), "100").await.unwrap();
+        assert_eq!(db.get_status_board("1").await.unwrap(), Some(board("1", "32")));
+        assert_eq!(db.get_status_boards().await.unwrap().len(), 2);
+        db.remove_status_board("1").await.unwrap();
+        assert_eq!(db.get_status_board("1").await.unwrap(), None);
+
+        let config = StandupConfig {
+            guild_id: "1".to_string(),
+            channel_id: "40".to_string(),
+            questions: vec!["Yesterday?".to_string(), "Today?".to_string()],
+            days: vec![0, 2, 4],
+            summary_time: "10:30".to_string(),
+            timezone: "Asia/Kolkata".to_string(),
+        };
+        db.set_standup_config(&config, "100").await.unwrap();
+        assert_eq!(db.get_standup_config("1").await.unwrap(), Some(config.clone()));
+        assert_eq!(db.get_standup_configs().await.unwrap(), vec![config]);
+
+        db.add_standup_participant("1", "100", "100").await.unwrap();
+        db.add_standup_participant("1", "101", "100").await.unwrap();
+        db.add_standup_participant("1", "100", "100").await.unwrap();
+        assert_eq!(db.get_standup_participants("1").await.unwrap().len(), 2);
+        assert!(db.remove_standup_participant("1", "101").await.unwrap());
+        assert!(!db.remove_standup_participant("1", "101").await.unwrap());
+
+        // Each participant is prompted once per local date
+        assert!(db.add_standup_entry("1", "100", "2026-03-09", "2026-03-09").await.unwrap());
+        assert!(!db.add_standup_entry("1", "100", "2026-03-09", "2026-03-09").await.unwrap());
+        assert!(db.add_standup_entry("1", "102", "2026-03-08", "2026-03-09").await.unwrap());
+        db.mark_standup_nudged("1", "100", "2026-03-09").await.unwrap();
+        let answers = vec!["Reviews".to_string(), "Release".to_string()];
+        assert!(db.set_standup_answers("1", "100", "2026-03-09", &answers).await.unwrap());
+        assert!(!db.set_standup_answers("1", "100", "2026-03-10", &answers).await.unwrap());
+        let entry = db.get_standup_entry("1", "100", "2026-03-09").await.unwrap().unwrap();
+        assert!(entry.nudged_at.is_some());
+        assert_eq!(entry.answers, Some(answers));
+        assert_eq!(db.get_standup_entries("1", "2026-03-09").await.unwrap().len(), 2);
+
+        assert!(!db.is_standup_posted("1", "2026-03-09").await.unwrap());
+        db.mark_standup_posted("1", "2026-03-09", Some("41")).await.unwrap();
+        assert!(db.is_standup_posted("1", "2026-03-09").await.unwrap());
+
+        // Turning it off keeps the participants
+        assert_eq!(db.remove_standup_config("1").await.unwrap(), 1);
+        assert_eq!(db.get_standup_config("1").await.unwrap(), None);
+        assert_eq!(db.get_standup_participants("1").await.unwrap(), vec!["100".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_translation_preferences() {
+        let db = testing::database("db-preferences").await;
+        db.set_formality_preference("100", "HI", Some("formal")).await.unwrap();
+        db.set_script_preference("100", "hi", Some("romanized")).await.unwrap();
+        db.set_dialect_preference("100", "fil", "bisaya").await.unwrap();
+        db.set_script_preference("100", "fil", Some("native")).await.unwrap();
+        assert_eq!(
+            db.get_translation_preferences("100", "hi").await.unwrap(),
+            TranslationPreferences {
+                formality: Some("formal".to_string()),
+                script: Some("romanized".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(db.get_all_formality_preferences("100").await.unwrap(), vec![("hi".to_string(), "formal".to_string())]);
+        let mut scripts = db.get_all_script_preferences("100").await.unwrap();
+        scripts.sort();
+        assert_eq!(scripts, vec![("fil".to_string(), "native".to_string()), ("hi".to_string(), "romanized".to_string())]);
+
+        // Clearing one preference keeps the others for the language
+        db.clear_dialect_preference("100", "fil").await.unwrap();
+        assert_eq!(db.get_dialect_preference("100", "fil").await.unwrap(), None);
+        assert_eq!(db.get_translation_preferences("100", "fil").await.unwrap().script.as_deref(), Some("native"));
+        db.set_formality_preference("100", "hi", None).await.unwrap();
+        db.set_script_preference("100", "hi", None).await.unwrap();
+        assert_eq!(db.get_translation_preferences("100", "hi").await.unwrap(), TranslationPreferences::default());
+        assert!(db.get_all_formality_preferences("100").await.unwrap().is_empty());
+
+        db.set_understood_languages("100", &["hi".to_string(), "en".to_string(), "hi".to_string()]).await.unwrap();
+        assert_eq!(db.get_understood_languages("100").await.unwrap(), vec!["en".to_string(), "hi".to_string()]);
+        assert!(db.understands_language("100", "hi").await.unwrap());
+        db.set_understood_languages("100", &["fr".to_string()]).await.unwrap();
+        assert!(!db.understands_language("100", "hi").await.unwrap());
+        assert!(!db.understands_language("101", "fr").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_scoped_subscriptions_and_opt_outs() {
+        let db = testing::database("db-subscriptions").await;
+        // 100 subscribes to channel 20, 101 to its category 50 and 102 guild-wide
+        db.add_translation_subscription("1", "100", "20", "hi").await.unwrap();
+        db.add_scoped_subscription("1", "101", "category", "50", "FR").await.unwrap();
+        db.add_scoped_subscription("1", "101", "category", "50", "en").await.unwrap();
+        db.add_scoped_subscription("1", "102", "guild", "1", "hi").await.unwrap();
+        db.add_scoped_subscription("1", "100", "guild", "1", "hi").await.unwrap();
+        assert_eq!(db.get_scoped_subscriptions("1", "101", "category", "50").await.unwrap(), vec!["en".to_string(), "fr".to_string()]);
+
+        let sorted = |mut v: Vec<String>| {
+            v.sort();
+            v
+        };
+        let hindi = db.get_channel_subscribers_for_language("1", "20", Some("50"), "hi").await.unwrap();
+        assert_eq!(sorted(hindi), vec!["100".to_string(), "102".to_string()]);
+        let languages = db.get_channel_subscribed_languages("1", "20", Some("50")).await.unwrap();
+        assert_eq!(sorted(languages), vec!["en".to_string(), "fr".to_string(), "hi".to_string()]);
+        // Outside the category only the guild-wide subscriptions apply
+        let languages = db.get_channel_subscribed_languages("1", "21", Some("51")).await.unwrap();
+        assert_eq!(languages, vec!["hi".to_string()]);
+        let mut non_english = db.get_channel_non_english_subscriptions("1", "20", Some("50")).await.unwrap();
+        non_english.sort();
+        assert_eq!(non_english.len(), 3);
+        assert!(non_english.iter().all(|(_, language)| language != "en"));
+
+        // The narrowest scope comes first
+        assert_eq!(
+            db.get_effective_subscriptions("1", "100", "20", Some("50")).await.unwrap(),
+            vec![("hi".to_string(), "channel".to_string()), ("hi".to_string(), "guild".to_string())]
+        );
+
+        // Opting out of a channel drops the broader subscriptions there only
+        db.set_subscription_opt_out("1", "102", "20", true).await.unwrap();
+        assert!(db.is_subscription_opted_out("1", "102", "20").await.unwrap());
+        assert_eq!(db.get_channel_subscribers_for_language("1", "20", Some("50"), "hi").await.unwrap(), vec!["100".to_string()]);
+        assert_eq!(db.get_channel_subscribers_for_language("1", "21", None, "hi").await.unwrap().len(), 2);
+        db.set_subscription_opt_out("1", "102", "20", false).await.unwrap();
+        assert!(!db.is_subscription_opted_out("1", "102", "20").await.unwrap());
+
+        db.remove_scoped_subscription("1", "101", "category", "50", Some("fr")).await.unwrap();
+        assert_eq!(db.get_scoped_subscriptions("1", "101", "category", "50").await.unwrap(), vec!["en".to_string()]);
+        db.remove_scoped_subscription("1", "101", "category", "50", None).await.unwrap();
+        assert!(db.get_scoped_subscriptions("1", "101", "category", "50").await.unwrap().is_empty());
+
+        db.set_attachment_translation("1", "20", true, "100").await.unwrap();
+        assert!(db.is_attachment_translation_enabled("1", "20").await.unwrap());
+        assert!(!db.is_attachment_translation_enabled("2", "20").await.unwrap());
+        db.set_attachment_translation("1", "20", false, "100").await.unwrap();
+        assert!(!db.is_attachment_translation_enabled("1", "20").await.unwrap());
+    }
+
+    fn correction(term: Option<(&str, &str)>) -> Correction {
+        Correction {
+            id: 0,
+            guild_id: "1".to_string(),
+            channel_id: "20".to_string(),
+            message_id: "300".to_string(),
+            source_lang: "en".to_string(),
+            target_lang: "hi".to_string(),
+            source_text: "Sprint review at 5".to_string(),
+            translation: "दौड़ समीक्षा 5 बजे".to_string(),
+            correction: "स्प्रिंट रिव्यू 5 बजे".to_string(),
+            term: term.map(|(t, _)| t.to_string()),
+            term_translation: term.map(|(_, t)| t.to_string()),
+            model: "test-model".to_string(),
+            submitted_by: "100".to_string(),
+            created_at: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_corrections_and_glossary() {
+        let db = testing::database("db-corrections").await;
+        let first = db.add_correction(&correction(Some(("sprint", "स्प्रिंट")))).await.unwrap();
+        let second = db.add_correction(&correction(Some(("Sprint", "स्प्रिंट")))).await.unwrap();
+        let third = db.add_correction(&correction(None)).await.unwrap();
+
+        let stored = db.get_correction("1", first).await.unwrap().unwrap();
+        assert_eq!(Correction { created_at: 0, ..stored }, Correction { id: first, ..correction(Some(("sprint", "स्प्रिंट"))) });
+        assert_eq!(db.get_correction("2", first).await.unwrap(), None);
+        let pending = db.get_pending_corrections("1", 2).await.unwrap();
+        assert_eq!(pending.iter().map(|c| c.id).collect::<Vec<_>>(), vec![third, second]);
+
+        // The same term fix suggested twice, whatever its case
+        assert_eq!(
+            db.get_recurring_term_fixes("1").await.unwrap(),
+            vec![("en".to_string(), "hi".to_string(), "sprint".to_string(), "स्प्रिंट".to_string(), 2, first)]
+        );
+        assert!(db.resolve_correction("1", first, "promoted").await.unwrap());
+        assert!(!db.resolve_correction("1", first, "dismissed").await.unwrap());
+        assert!(db.get_recurring_term_fixes("1").await.unwrap().is_empty());
+        assert_eq!(db.get_pending_corrections("1", 10).await.unwrap().len(), 2);
+
+        db.set_glossary_term("1", "en", "hi", "Sprint", "स्प्रिंट", "100").await.unwrap();
+        db.set_glossary_term("1", "en", "fr", "sprint", "sprint", "100").await.unwrap();
+        db.set_glossary_term("1", "en", "hi", "standup", "स्टैंडअप", "100").await.unwrap();
+        db.set_glossary_term("1", "en", "hi", "sprint", "स्प्रिंट (sprint)", "100").await.unwrap();
+        assert_eq!(
+            db.get_glossary_for("1", "en", "hi").await.unwrap(),
+            vec![("sprint".to_string(), "स्प्रिंट (sprint)".to_string()), ("standup".to_string(), "स्टैंडअप".to_string())]
+        );
+        assert_eq!(db.get_glossary("1").await.unwrap().len(), 3);
+        assert_eq!(db.remove_glossary_term("1", "SPRINT").await.unwrap(), 2);
+        assert_eq!(
+            db.get_glossary("1").await.unwrap(),
+            vec![("en".to_string(), "hi".to_string(), "standup".to_string(), "स्टैंडअप".to_string())]
+        );
+    }
+}
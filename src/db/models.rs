@@ -12,6 +12,10 @@ pub struct UserStatus {
     pub timezone: Option<String>,
     pub preferred_hours_start: Option<String>,
     pub preferred_hours_end: Option<String>,
+    /// When a "still here?" reminder was sent for this status
+    pub reminded_at: Option<i64>,
+    /// Set from the user's working hours rather than by hand
+    pub auto: bool,
 }
 
 impl UserStatus {
@@ -25,6 +29,8 @@ impl UserStatus {
             timezone: None,
             preferred_hours_start: None,
             preferred_hours_end: None,
+            reminded_at: None,
+            auto: false,
         }
     }
 
@@ -38,6 +44,8 @@ impl UserStatus {
             timezone: None,
             preferred_hours_start: None,
             preferred_hours_end: None,
+            reminded_at: None,
+            auto: false,
         }
     }
 
@@ -51,6 +59,8 @@ impl UserStatus {
             timezone: None,
             preferred_hours_start: None,
             preferred_hours_end: None,
+            reminded_at: None,
+            auto: false,
         }
     }
 
@@ -83,6 +93,176 @@ pub struct TranslationSubscription {
     pub created_at: i64,
 }
 
+/// Per-language translation preferences for a user
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TranslationPreferences {
+    /// Dialect/variety of the language (e.g. "bisaya" for Filipino)
+    pub dialect: Option<String>,
+    /// Register: "formal", "neutral" or "casual"
+    pub formality: Option<String>,
+    /// Script: "native", "romanized" or "both"
+    pub script: Option<String>,
+    /// Guild term list for the language pair, one `term = translation` per line
+    pub glossary: Option<String>,
+}
+
+/// A channel linked into a mirror group, with the webhook used to repost into it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MirrorChannel {
+    pub guild_id: String,
+    /// Name shared by every channel in the group
+    pub link_name: String,
+    pub channel_id: String,
+    /// Language messages are reposted in
+    pub language: String,
+    pub webhook_id: String,
+    pub webhook_token: String,
+}
+
+/// A translation DM and the channel message it was sent for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranslationDm {
+    /// Recipient of the DM
+    pub discord_id: String,
+    pub guild_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+}
+
+/// A DM reply, translated and waiting for the user to confirm posting it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingDmReply {
+    /// The translation DM being replied to
+    pub dm: TranslationDm,
+    /// Translated text to post
+    pub content: String,
+    /// Language it was translated into
+    pub language: String,
+}
+
+/// A span of days someone is out, in their own local dates
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Leave {
+    /// Assigned by the database; ignored when adding
+    pub id: i64,
+    pub guild_id: String,
+    pub discord_id: String,
+    /// First day off (`YYYY-MM-DD`)
+    pub start_date: String,
+    /// Last day off, inclusive
+    pub end_date: String,
+    pub reason: Option<String>,
+    /// Whether teammates see the reason ("team") or just that they're out ("private")
+    pub reason_visibility: String,
+    /// Came from the user's calendar rather than `/fabrica leave add`
+    pub imported: bool,
+}
+
+impl Leave {
+    /// Whether the reason may be shown to someone other than the owner
+    pub fn reason_public(&self) -> bool {
+        self.reason_visibility == "team"
+    }
+}
+
+/// A public holiday for one region of a guild
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Holiday {
+    pub guild_id: String,
+    /// Region code, e.g. "IN", "FR", "CA"
+    pub region: String,
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub name: String,
+}
+
+/// A calendar URL polled for the user's out-of-office
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarImport {
+    pub guild_id: String,
+    pub discord_id: String,
+    pub url: String,
+    pub last_synced_at: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+/// A status board message Fabrica keeps up to date
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusBoard {
+    pub guild_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+    /// Locale the board is written in (that of the admin who posted it)
+    pub locale: String,
+}
+
+/// A guild's standup: where the summary goes, what's asked and when
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandupConfig {
+    pub guild_id: String,
+    pub channel_id: String,
+    pub questions: Vec<String>,
+    /// Weekdays the standup runs (0 = Monday)
+    pub days: Vec<u8>,
+    /// When the summary is posted (`HH:MM` in `timezone`)
+    pub summary_time: String,
+    pub timezone: String,
+}
+
+impl StandupConfig {
+    /// Parsed timezone, defaulting to UTC
+    pub fn tz(&self) -> chrono_tz::Tz {
+        self.timezone.parse().unwrap_or(chrono_tz::UTC)
+    }
+}
+
+/// A participant's standup on one of their local dates
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandupEntry {
+    pub guild_id: String,
+    pub discord_id: String,
+    pub local_date: String,
+    /// Date of the summary the answers go into (the guild's standup date)
+    pub run_date: String,
+    pub prompted_at: i64,
+    pub nudged_at: Option<i64>,
+    /// One answer per question, once submitted
+    pub answers: Option<Vec<String>>,
+}
+
+/// Who a calendar feed link belongs to and what it shows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarLink {
+    pub guild_id: String,
+    pub discord_id: String,
+    /// "team" for everyone in the guild, "user" for just the owner
+    pub scope: String,
+}
+
+/// A better translation suggested by a reader
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Correction {
+    /// Assigned by the database; ignored when adding
+    pub id: i64,
+    pub guild_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+    pub source_lang: String,
+    pub target_lang: String,
+    pub source_text: String,
+    /// The translation being corrected
+    pub translation: String,
+    /// The suggested translation
+    pub correction: String,
+    /// Optional term fix, e.g. "sprint" -> "स्प्रिंट"
+    pub term: Option<String>,
+    pub term_translation: Option<String>,
+    /// Model that produced the translation
+    pub model: String,
+    pub submitted_by: String,
+    pub created_at: i64,
+}
+
 /// User settings record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserSettings {
@@ -90,6 +270,10 @@ pub struct UserSettings {
     pub timezone: String,
     pub time_format: String,
     pub always_show_me: bool,
+    /// Switch status to available/away as working hours start and end
+    pub auto_status: bool,
+    /// Region whose guild public holidays apply, e.g. "IN"
+    pub holiday_region: Option<String>,
 }
 
 impl Default for UserSettings {
@@ -99,6 +283,8 @@ impl Default for UserSettings {
             timezone: "UTC".to_string(),
             time_format: "24h".to_string(),
             always_show_me: false,
+            auto_status: false,
+            holiday_region: None,
         }
     }
 }
@@ -114,6 +300,16 @@ impl UserSettings {
     pub fn is_12h(&self) -> bool {
         self.time_format == "12h"
     }
+
+    /// The user's timezone, UTC if unset or unknown
+    pub fn tz(&self) -> chrono_tz::Tz {
+        self.timezone.parse().unwrap_or(chrono_tz::UTC)
+    }
+
+    /// The user's current local date, as schedule overrides are keyed (`YYYY-MM-DD`)
+    pub fn today(&self) -> String {
+        chrono::Utc::now().with_timezone(&self.tz()).format("%Y-%m-%d").to_string()
+    }
 }
 
 /// Watch level for notifications
@@ -140,7 +336,7 @@ impl WatchLevel {
         }
     }
 
-    pub fn from_str(s: &str) -> Option<Self> {
+    pub fn parse(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "all" => Some(WatchLevel::All),
             "important" => Some(WatchLevel::Important),
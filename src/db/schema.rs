@@ -27,7 +27,7 @@ CREATE TABLE IF NOT EXISTS translation_subscriptions (
 CREATE TABLE IF NOT EXISTS translation_channels (
     guild_id TEXT NOT NULL,
     channel_id TEXT NOT NULL,
-    mode TEXT NOT NULL DEFAULT 'off' CHECK (mode IN ('off', 'silent', 'on', 'transparent')),
+    mode TEXT NOT NULL DEFAULT 'off' CHECK (mode IN ('off', 'silent', 'on', 'transparent', 'mirror')),
     enabled_at INTEGER NOT NULL,
     enabled_by TEXT NOT NULL,
     PRIMARY KEY (guild_id, channel_id)
@@ -115,7 +115,7 @@ pub const MIGRATION_FIX_TRANSLATION_CHANNELS_PK: &str = r#"
 CREATE TABLE IF NOT EXISTS translation_channels_new (
     guild_id TEXT NOT NULL,
     channel_id TEXT NOT NULL,
-    mode TEXT NOT NULL DEFAULT 'off' CHECK (mode IN ('off', 'silent', 'on', 'transparent')),
+    mode TEXT NOT NULL DEFAULT 'off' CHECK (mode IN ('off', 'silent', 'on', 'transparent', 'mirror')),
     enabled_at INTEGER NOT NULL,
     enabled_by TEXT NOT NULL,
     PRIMARY KEY (guild_id, channel_id)
@@ -237,3 +237,281 @@ ALTER TABLE user_schedule_override_new RENAME TO user_schedule_override;
 pub const MIGRATION_ADD_DEFAULT_LANGUAGE: &str = r#"
 ALTER TABLE user_settings ADD COLUMN default_language TEXT;
 "#;
+
+/// Migration to add formality preference to user_dialect_preferences
+/// (rows with only a formality preference store an empty dialect)
+pub const MIGRATION_ADD_FORMALITY: &str = r#"
+ALTER TABLE user_dialect_preferences ADD COLUMN formality TEXT CHECK (formality IN ('formal', 'neutral', 'casual'));
+"#;
+
+/// Migration to add script preference (native/romanized/both) to user_dialect_preferences
+pub const MIGRATION_ADD_SCRIPT: &str = r#"
+ALTER TABLE user_dialect_preferences ADD COLUMN script TEXT CHECK (script IN ('native', 'romanized', 'both'));
+"#;
+
+/// Migration to add mirror channel links (channels that repost each other's
+/// messages in their own language) and the map from originals to their copies
+pub const MIGRATION_ADD_MIRROR_CHANNELS: &str = r#"
+CREATE TABLE IF NOT EXISTS mirror_channels (
+    guild_id TEXT NOT NULL,
+    link_name TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    language TEXT NOT NULL,
+    webhook_id TEXT NOT NULL,
+    webhook_token TEXT NOT NULL,
+    linked_at INTEGER NOT NULL,
+    linked_by TEXT NOT NULL,
+    PRIMARY KEY (guild_id, channel_id)
+);
+
+CREATE TABLE IF NOT EXISTS mirror_messages (
+    source_message_id TEXT NOT NULL,
+    source_channel_id TEXT NOT NULL,
+    mirror_channel_id TEXT NOT NULL,
+    mirror_message_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (source_message_id, mirror_channel_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_mirror_channels_link ON mirror_channels(guild_id, link_name);
+CREATE INDEX IF NOT EXISTS idx_mirror_messages_copy ON mirror_messages(mirror_message_id);
+"#;
+
+/// Migration to add the languages each user can read without translation
+pub const MIGRATION_ADD_UNDERSTOOD_LANGUAGES: &str = r#"
+CREATE TABLE IF NOT EXISTS user_understood_languages (
+    discord_id TEXT NOT NULL,
+    language TEXT NOT NULL,
+    PRIMARY KEY (discord_id, language)
+);
+"#;
+
+/// Migration to add category- and guild-wide translation subscriptions,
+/// plus per-channel opt-outs from them
+pub const MIGRATION_ADD_SCOPED_SUBSCRIPTIONS: &str = r#"
+-- scope_id is the category ID for 'category' and the guild ID for 'guild'
+CREATE TABLE IF NOT EXISTS translation_scoped_subscriptions (
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    scope TEXT NOT NULL CHECK (scope IN ('category', 'guild')),
+    scope_id TEXT NOT NULL,
+    language TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, discord_id, scope, scope_id, language)
+);
+
+CREATE TABLE IF NOT EXISTS translation_subscription_opt_outs (
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, discord_id, channel_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_scoped_subs_scope ON translation_scoped_subscriptions(guild_id, scope, scope_id);
+"#;
+
+/// Migration to add channels that opted in to attachment, embed and alt text translation
+pub const MIGRATION_ADD_ATTACHMENT_CHANNELS: &str = r#"
+CREATE TABLE IF NOT EXISTS translation_attachment_channels (
+    guild_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    enabled_at INTEGER NOT NULL,
+    enabled_by TEXT NOT NULL,
+    PRIMARY KEY (guild_id, channel_id)
+);
+"#;
+
+/// Migration to add translation DMs and the replies waiting to be posted from them
+pub const MIGRATION_ADD_DM_REPLIES: &str = r#"
+CREATE TABLE IF NOT EXISTS translation_dms (
+    dm_message_id TEXT PRIMARY KEY,
+    discord_id TEXT NOT NULL,
+    guild_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS translation_dm_replies (
+    confirm_message_id TEXT PRIMARY KEY,
+    discord_id TEXT NOT NULL,
+    guild_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    content TEXT NOT NULL,
+    language TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+"#;
+
+/// Migration to add suggested corrections and the guild glossary they feed
+pub const MIGRATION_ADD_CORRECTIONS: &str = r#"
+CREATE TABLE IF NOT EXISTS translation_corrections (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    source_lang TEXT NOT NULL,
+    target_lang TEXT NOT NULL,
+    source_text TEXT NOT NULL,
+    translation TEXT NOT NULL,
+    correction TEXT NOT NULL,
+    term TEXT,
+    term_translation TEXT,
+    model TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'promoted', 'dismissed')),
+    submitted_by TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_corrections_queue ON translation_corrections(guild_id, status);
+
+CREATE TABLE IF NOT EXISTS guild_glossary (
+    guild_id TEXT NOT NULL,
+    source_lang TEXT NOT NULL,
+    target_lang TEXT NOT NULL,
+    term TEXT NOT NULL,
+    translation TEXT NOT NULL,
+    added_by TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, source_lang, target_lang, term)
+);
+"#;
+
+/// Migration to track "still here?" reminders sent for stale statuses
+pub const MIGRATION_ADD_STATUS_REMINDERS: &str = r#"
+ALTER TABLE user_status ADD COLUMN reminded_at INTEGER;
+"#;
+
+/// Migration to add auto status: availability derived from working hours
+pub const MIGRATION_ADD_AUTO_STATUS: &str = r#"
+ALTER TABLE user_settings ADD COLUMN auto_status INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_status ADD COLUMN auto INTEGER NOT NULL DEFAULT 0;
+"#;
+
+/// Migration to allow several working-hour ranges per day (split shifts).
+/// Rows move over from user_weekly_schedule, which is then dropped.
+pub const MIGRATION_ADD_WEEKLY_SHIFTS: &str = r#"
+CREATE TABLE IF NOT EXISTS user_weekly_shifts (
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    day_of_week INTEGER NOT NULL CHECK (day_of_week >= 0 AND day_of_week <= 6),
+    start_time TEXT NOT NULL,
+    end_time TEXT NOT NULL,
+    PRIMARY KEY (guild_id, discord_id, day_of_week, start_time)
+);
+CREATE INDEX IF NOT EXISTS idx_user_weekly_shifts_user ON user_weekly_shifts(discord_id);
+INSERT OR IGNORE INTO user_weekly_shifts (guild_id, discord_id, day_of_week, start_time, end_time)
+    SELECT guild_id, discord_id, day_of_week, start_time, end_time FROM user_weekly_schedule;
+DROP TABLE user_weekly_schedule;
+"#;
+
+/// Migration to add leave (date ranges off) and guild public holidays per region
+pub const MIGRATION_ADD_LEAVE: &str = r#"
+CREATE TABLE IF NOT EXISTS user_leave (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT NOT NULL,
+    reason TEXT,
+    reason_visibility TEXT NOT NULL DEFAULT 'private' CHECK (reason_visibility IN ('private', 'team')),
+    created_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_user_leave_dates ON user_leave(guild_id, end_date);
+
+CREATE TABLE IF NOT EXISTS guild_holidays (
+    guild_id TEXT NOT NULL,
+    region TEXT NOT NULL,
+    date TEXT NOT NULL,
+    name TEXT NOT NULL,
+    added_by TEXT NOT NULL,
+    PRIMARY KEY (guild_id, region, date)
+);
+
+ALTER TABLE user_settings ADD COLUMN holiday_region TEXT;
+"#;
+
+/// Migration to add calendar feed links. Only a hash of each token is stored.
+pub const MIGRATION_ADD_CALENDAR_LINKS: &str = r#"
+CREATE TABLE IF NOT EXISTS calendar_links (
+    token_hash TEXT PRIMARY KEY,
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    scope TEXT NOT NULL CHECK (scope IN ('team', 'user')),
+    created_at INTEGER NOT NULL,
+    UNIQUE (guild_id, discord_id, scope)
+);
+"#;
+
+/// Migration to import out-of-office from users' calendars. Imported leave
+/// and overrides are flagged so a re-import can replace them.
+pub const MIGRATION_ADD_CALENDAR_IMPORTS: &str = r#"
+CREATE TABLE IF NOT EXISTS calendar_imports (
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    url TEXT NOT NULL,
+    last_synced_at INTEGER,
+    last_error TEXT,
+    PRIMARY KEY (guild_id, discord_id)
+);
+ALTER TABLE user_leave ADD COLUMN imported INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_schedule_override ADD COLUMN imported INTEGER NOT NULL DEFAULT 0;
+"#;
+
+/// Migration to add async standups: one config per guild, its participants,
+/// each participant's prompt and answers per local date, and posted summaries
+pub const MIGRATION_ADD_STANDUPS: &str = r#"
+CREATE TABLE IF NOT EXISTS standup_configs (
+    guild_id TEXT PRIMARY KEY,
+    channel_id TEXT NOT NULL,
+    questions TEXT NOT NULL,
+    days TEXT NOT NULL,
+    summary_time TEXT NOT NULL,
+    timezone TEXT NOT NULL,
+    updated_by TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS standup_participants (
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    added_by TEXT NOT NULL,
+    added_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, discord_id)
+);
+
+CREATE TABLE IF NOT EXISTS standup_entries (
+    guild_id TEXT NOT NULL,
+    discord_id TEXT NOT NULL,
+    local_date TEXT NOT NULL,
+    run_date TEXT NOT NULL,
+    prompted_at INTEGER NOT NULL,
+    nudged_at INTEGER,
+    answers TEXT,
+    answered_at INTEGER,
+    PRIMARY KEY (guild_id, discord_id, local_date)
+);
+CREATE INDEX IF NOT EXISTS idx_standup_entries_run ON standup_entries(guild_id, run_date);
+
+CREATE TABLE IF NOT EXISTS standup_runs (
+    guild_id TEXT NOT NULL,
+    run_date TEXT NOT NULL,
+    message_id TEXT,
+    posted_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, run_date)
+);
+"#;
+
+/// Migration to add self-updating status boards, one per guild
+pub const MIGRATION_ADD_STATUS_BOARDS: &str = r#"
+CREATE TABLE IF NOT EXISTS status_boards (
+    guild_id TEXT PRIMARY KEY,
+    channel_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    locale TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+"#;
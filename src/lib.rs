@@ -4,11 +4,15 @@
 //! for translation, status tracking, and project integration.
 
 pub mod bot;
+pub mod cli;
 pub mod config;
 pub mod db;
 pub mod modules;
 pub mod services;
 pub mod webhooks;
 
+#[cfg(test)]
+mod testing;
+
 pub use config::Config;
 pub use db::Database;
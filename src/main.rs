@@ -7,16 +7,10 @@
 //! - GitHub activity notifications
 
 use anyhow::Result;
+use fabrica::{bot, cli, config, db};
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-mod bot;
-mod config;
-mod db;
-mod modules;
-mod services;
-mod webhooks;
-
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load .env file if it exists
@@ -31,6 +25,12 @@ async fn main() -> Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // Offline subcommands (e.g. `fabrica prompt-test`, `fabrica eval`) exit without starting the bot
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if cli::run(&args).await? {
+        return Ok(());
+    }
+
     info!("Palace Fabrica starting...");
 
     // Load configuration
@@ -46,16 +46,10 @@ async fn main() -> Result<()> {
     db.migrate().await?;
     info!("Database initialized");
 
-    // Start webhook server in background
-    let webhook_handle = webhooks::start_server(config.clone(), db.clone());
-    info!("Webhook server starting on port {}", config.webhooks.port);
-
-    // Start Discord bot (blocks)
+    // Start Discord bot (blocks); it also runs the webhook server
     info!("Starting Discord bot...");
     bot::run(config, db).await?;
 
-    // Clean shutdown
-    webhook_handle.abort();
     info!("Palace Fabrica shutting down");
 
     Ok(())
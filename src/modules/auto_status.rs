@@ -0,0 +1,57 @@
+//! Auto status - Availability that follows working hours
+//!
+//! Users who opt in with `/fabrica settings auto-status` become available
+//! when their scheduled hours start and away when they end, evaluated in
+//! their own timezone, and away all day on leave or a public holiday. A
+//! status set by hand wins until the next start or end of their hours.
+
+use crate::bot::Data;
+use crate::services::schedule::{self, is_day_off, working_windows, CurrentStatus};
+use chrono::Utc;
+use chrono_tz::Tz;
+use tracing::{info, warn};
+
+/// How often working hours are checked; boundaries are honoured within this
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Start the periodic auto status check
+pub fn spawn(data: Data) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = update_all(&data).await {
+                warn!("Auto status update failed: {}", e);
+            }
+        }
+    });
+}
+
+/// Switch every opted-in user to the status their hours call for
+async fn update_all(data: &Data) -> anyhow::Result<()> {
+    let now = Utc::now();
+    for (discord_id, timezone) in data.db.get_auto_status_users().await? {
+        let tz = timezone.parse::<Tz>().unwrap_or(chrono_tz::UTC);
+        let guild_ids = &data.config.discord.guild_ids;
+        let windows = working_windows(&data.db, guild_ids, &discord_id, tz, now).await;
+        let today = now.with_timezone(&tz).date_naive();
+        let region = data.db.get_user_settings(&discord_id).await?.holiday_region;
+        let mut off = false;
+        for guild_id in guild_ids {
+            off |= is_day_off(&data.db, guild_id, &discord_id, region.as_deref(), today).await;
+        }
+        let day_off = off.then(|| schedule::local_instant(tz, today, "00:00")).flatten();
+        let current = data.db.get_status(&discord_id).await?;
+        let current_view = current.as_ref().map(|s| CurrentStatus {
+            status: &s.status,
+            auto: s.auto,
+            updated_at: s.updated_at,
+        });
+        if let Some(status) = schedule::auto_status(current_view, &windows, day_off, now) {
+            data.db.set_auto_status(&discord_id, status).await?;
+            data.boards.request();
+            info!("Auto status set user {} to {}", discord_id, status);
+        }
+    }
+    Ok(())
+}
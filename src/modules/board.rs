@@ -0,0 +1,403 @@
+//! Status board - A live who's-available message
+//!
+//! Admins post a board in a channel with `/fabrica board here`. Fabrica pins it
+//! and edits it in place whenever a status, schedule or override changes, and
+//! once a minute so local times stay current. Changes are coalesced and an
+//! unchanged board isn't edited, keeping well inside Discord's rate limits.
+//! Buttons on the board let people set their own status.
+
+use crate::bot::{Context, Data, Discord, Error};
+use crate::db::{StatusBoard, UserStatus};
+use crate::modules::leave;
+use crate::modules::status::busy_visible;
+use crate::modules::translation::has_admin_permission;
+use crate::services::i18n::{locale_for, locale_for_user, supported_locale, tr, tr_args, DEFAULT_LOCALE};
+use crate::services::messages::fit_message;
+use crate::services::schedule::working_windows;
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{
+    self as serenity, ButtonStyle, ChannelId, ComponentInteraction, CreateActionRow, CreateAllowedMentions, CreateButton,
+    CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, EditMessage, MessageId,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::{debug, info, warn};
+
+/// Prefix of the board buttons' custom IDs
+pub const BUTTON_PREFIX: &str = "fabrica-board";
+
+/// Statuses shown on the board, in order, each with a button
+const STATUSES: [&str; 3] = ["available", "busy", "away"];
+
+/// How long a burst of changes settles before the boards are redrawn; also
+/// the shortest gap between edits of a board
+const DEBOUNCE: Duration = Duration::from_secs(5);
+
+/// Boards are redrawn at least this often, for local times and auto status
+const REDRAW_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Asks the status boards to redraw; cheap to clone and call after any change
+#[derive(Debug, Clone, Default)]
+pub struct BoardRefresh(Arc<Notify>);
+
+impl BoardRefresh {
+    /// Redraw the boards soon
+    pub fn request(&self) {
+        self.0.notify_one();
+    }
+}
+
+/// Start redrawing boards on request and every minute
+pub fn spawn(discord: Discord, data: Data) {
+    tokio::spawn(async move {
+        // Last content of each board, so unchanged boards aren't edited
+        let mut shown: HashMap<String, String> = HashMap::new();
+        loop {
+            next_redraw(&data.boards).await;
+            if let Err(e) = redraw_all(&discord, &data, &mut shown, Utc::now()).await {
+                warn!("Status board update failed: {}", e);
+            }
+        }
+    });
+}
+
+/// Wait for a redraw request or the interval, then for the burst to settle
+///
+/// A request made while settling or redrawing is kept, so it gets a redraw
+/// of its own.
+async fn next_redraw(refresh: &BoardRefresh) {
+    tokio::select! {
+        _ = refresh.0.notified() => {}
+        _ = tokio::time::sleep(REDRAW_INTERVAL) => {}
+    }
+    tokio::time::sleep(DEBOUNCE).await;
+}
+
+async fn redraw_all(discord: &Discord, data: &Data, shown: &mut HashMap<String, String>, now: DateTime<Utc>) -> Result<(), Error> {
+    for board in data.db.get_status_boards().await? {
+        if !data.config.discord.guild_ids.contains(&board.guild_id) {
+            continue;
+        }
+        let content = render(data, &board, now).await?;
+        if shown.get(&board.message_id) == Some(&content) {
+            continue;
+        }
+        let channel_id = ChannelId::new(board.channel_id.parse()?);
+        let message_id = MessageId::new(board.message_id.parse()?);
+        match channel_id.edit_message(discord, message_id, EditMessage::new().content(content.clone())).await {
+            Ok(_) => {
+                debug!("Updated status board in guild {}", board.guild_id);
+                shown.insert(board.message_id, content);
+            }
+            Err(e) if is_not_found(&e) => {
+                info!("Status board in guild {} was deleted; forgetting it", board.guild_id);
+                data.db.remove_status_board(&board.guild_id).await?;
+                shown.remove(&board.message_id);
+            }
+            Err(e) => warn!("Failed to update status board in guild {}: {}", board.guild_id, e),
+        }
+    }
+    Ok(())
+}
+
+/// The board's text: everyone grouped by status with their local time, then
+/// who's out today
+///
+/// Busy members drop off as they do in `/fabrica team`. The board has no
+/// single reader, so holidays are those of today's UTC date.
+async fn render(data: &Data, board: &StatusBoard, now: DateTime<Utc>) -> Result<String, Error> {
+    let locale = supported_locale(&board.locale).unwrap_or(DEFAULT_LOCALE);
+    let guild_ids = [board.guild_id.clone()];
+    let mut content = format!("{}\n\n", tr(locale, "board-title"));
+    for status in STATUSES {
+        let mut members = Vec::new();
+        for member in data.db.get_users_by_status(status).await? {
+            let settings = data.db.get_user_settings(&member.discord_id).await?;
+            if status != "busy" || busy_visible(&member, &settings, now.timestamp()) {
+                members.push((member, settings));
+            }
+        }
+        content.push_str(&tr_args(locale, &format!("board-{}", status), &[("count", &members.len().to_string())]));
+        content.push('\n');
+        if members.is_empty() {
+            content.push_str(&format!("  {}\n", tr(locale, "board-none")));
+        }
+        for (member, settings) in &members {
+            let tz = settings.tz();
+            let local = now.with_timezone(&tz);
+            let mut line = format!("  <@{}> 🕐 {} {}", member.discord_id, local.format("%H:%M"), local.format("%Z"));
+            if member.auto {
+                line.push(' ');
+                line.push_str(&tr(locale, "team-auto"));
+            }
+            // When their current working hours end, in their own time
+            let windows = working_windows(&data.db, &guild_ids, &member.discord_id, tz, now).await;
+            if let Some((_, end)) = windows.iter().find(|(start, end)| *start <= now && now < *end) {
+                let time = end.with_timezone(&tz).format("%H:%M").to_string();
+                line.push(' ');
+                line.push_str(&tr_args(locale, "team-until", &[("time", &time)]));
+            }
+            if let Some(message) = &member.message {
+                line.push_str(&format!(" - {}", message));
+            }
+            content.push_str(&line);
+            content.push('\n');
+        }
+        content.push('\n');
+    }
+    content.push_str(&leave::team_section(&data.db, &board.guild_id, now.date_naive(), locale).await?);
+    content.push_str(&tr(locale, "board-hint"));
+    Ok(fit_message(&content))
+}
+
+/// Set the presser's status from a board button
+pub async fn handle_component(ctx: &serenity::Context, interaction: &ComponentInteraction, data: &Data) -> Result<(), Error> {
+    let Some(status) = interaction.data.custom_id.strip_prefix(BUTTON_PREFIX).and_then(|s| s.strip_prefix(':')) else {
+        return Ok(());
+    };
+    if !interaction.guild_id.is_some_and(|g| data.config.discord.guild_ids.contains(&g.to_string())) {
+        return Ok(());
+    }
+    let user_id = interaction.user.id.to_string();
+    let new_status = match status {
+        "available" => UserStatus::available(&user_id, None),
+        "busy" => UserStatus::busy(&user_id, None),
+        "away" => UserStatus::away(&user_id, None),
+        _ => return Ok(()),
+    };
+    data.db.set_status(new_status).await?;
+    data.boards.request();
+    info!("User {} set status to {} from the status board", user_id, status);
+
+    let locale = locale_for_user(&data.db, &user_id).await;
+    let response = CreateInteractionResponseMessage::new()
+        .content(tr(locale, &format!("status-{}", status)))
+        .ephemeral(true);
+    interaction.create_response(ctx, CreateInteractionResponse::Message(response)).await?;
+    Ok(())
+}
+
+// ==================== Commands ====================
+
+/// Say where this server's status board is
+pub async fn show(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = ctx.guild_id() else {
+        ctx.say(tr(locale, "board-server-only")).await?;
+        return Ok(());
+    };
+    let msg = match ctx.data().db.get_status_board(&guild_id.to_string()).await? {
+        Some(board) => tr_args(locale, "board-show", &[(
+            "link",
+            &format!("https://discord.com/channels/{}/{}/{}", board.guild_id, board.channel_id, board.message_id),
+        )]),
+        None => tr(locale, "board-none-yet"),
+    };
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Post the status board in this channel and pin it, replacing any earlier one (admins)
+pub async fn here(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    let data = ctx.data();
+    let serenity_ctx = ctx.serenity_context();
+    if let Some(old) = data.db.get_status_board(&guild_id).await? {
+        delete_board(serenity_ctx, &old).await;
+    }
+
+    let mut board = StatusBoard {
+        guild_id: guild_id.clone(),
+        channel_id: ctx.channel_id().to_string(),
+        message_id: String::new(),
+        locale: locale.to_string(),
+    };
+    let content = render(data, &board, Utc::now()).await?;
+    let buttons = STATUSES
+        .iter()
+        .map(|status| {
+            let style = if *status == "available" { ButtonStyle::Success } else { ButtonStyle::Secondary };
+            CreateButton::new(format!("{}:{}", BUTTON_PREFIX, status))
+                .label(tr(locale, &format!("board-button-{}", status)))
+                .style(style)
+        })
+        .collect();
+    let message = ctx
+        .channel_id()
+        .send_message(
+            serenity_ctx,
+            CreateMessage::new()
+                .content(content)
+                .components(vec![CreateActionRow::Buttons(buttons)])
+                .allowed_mentions(CreateAllowedMentions::new()),
+        )
+        .await?;
+    board.message_id = message.id.to_string();
+    data.db.set_status_board(&board, &ctx.author().id.to_string()).await?;
+    info!("User {} posted a status board in channel {} of guild {}", ctx.author().id, board.channel_id, guild_id);
+
+    let key = match message.pin(serenity_ctx).await {
+        Ok(()) => "board-posted",
+        Err(e) => {
+            debug!("Couldn't pin status board in channel {}: {}", board.channel_id, e);
+            "board-posted-unpinned"
+        }
+    };
+    ctx.send(poise::CreateReply::default().content(tr(locale, key)).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Delete this server's status board (admins)
+pub async fn off(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    let key = match ctx.data().db.get_status_board(&guild_id).await? {
+        Some(board) => {
+            delete_board(ctx.serenity_context(), &board).await;
+            ctx.data().db.remove_status_board(&guild_id).await?;
+            info!("User {} removed the status board in guild {}", ctx.author().id, guild_id);
+            "board-removed"
+        }
+        None => "board-none-yet",
+    };
+    ctx.send(poise::CreateReply::default().content(tr(locale, key)).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Delete a board's message, if it's still there
+async fn delete_board(ctx: &serenity::Context, board: &StatusBoard) {
+    let (Ok(channel_id), Ok(message_id)) = (board.channel_id.parse::<u64>(), board.message_id.parse::<u64>()) else {
+        return;
+    };
+    if let Err(e) = ChannelId::new(channel_id).delete_message(ctx, MessageId::new(message_id)).await {
+        debug!("Couldn't delete old status board in guild {}: {}", board.guild_id, e);
+    }
+}
+
+/// The guild ID, if run in a server by an admin; replies otherwise
+async fn admin_guild(ctx: Context<'_>, locale: &str) -> Result<Option<String>, Error> {
+    let Some(guild_id) = ctx.guild_id().map(|g| g.to_string()) else {
+        ctx.say(tr(locale, "board-server-only")).await?;
+        return Ok(None);
+    };
+    if !has_admin_permission(&ctx, &guild_id).await {
+        ctx.send(poise::CreateReply::default().content(tr(locale, "board-admin-only")).ephemeral(true)).await?;
+        return Ok(None);
+    }
+    Ok(Some(guild_id))
+}
+
+fn is_not_found(error: &serenity::Error) -> bool {
+    matches!(error, serenity::Error::Http(http) if http.status_code().map(|code| code.as_u16()) == Some(404))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use crate::testing::{self, MockDiscord, GUILD_ID};
+    use poise::serenity_prelude::Message;
+
+    fn board(message_id: u64) -> StatusBoard {
+        StatusBoard {
+            guild_id: GUILD_ID.to_string(),
+            channel_id: "30".to_string(),
+            message_id: message_id.to_string(),
+            locale: "en".to_string(),
+        }
+    }
+
+    async fn board_data(name: &str) -> (Database, Data) {
+        let db = testing::database(name).await;
+        let data = testing::data(db.clone(), testing::translator(|text| text.to_string()));
+        (db, data)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_redraw_waits_for_changes_to_settle() {
+        let refresh = BoardRefresh::default();
+        let waiting = |refresh: &BoardRefresh| {
+            let refresh = refresh.clone();
+            tokio::spawn(async move { next_redraw(&refresh).await })
+        };
+
+        let redraw = waiting(&refresh);
+        refresh.request();
+        tokio::time::sleep(DEBOUNCE - Duration::from_secs(1)).await;
+        // More changes in the burst are drawn together
+        refresh.request();
+        refresh.request();
+        assert!(!redraw.is_finished());
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        assert!(redraw.is_finished());
+
+        // ...though one made while settling still gets its own redraw
+        let redraw = waiting(&refresh);
+        tokio::time::sleep(DEBOUNCE + Duration::from_secs(1)).await;
+        assert!(redraw.is_finished());
+
+        // Without changes the boards are redrawn every minute
+        let redraw = waiting(&refresh);
+        tokio::time::sleep(REDRAW_INTERVAL).await;
+        assert!(!redraw.is_finished());
+        tokio::time::sleep(DEBOUNCE + Duration::from_secs(1)).await;
+        assert!(redraw.is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_redraw_skips_unchanged_boards_and_forgets_deleted_ones() {
+        let (db, data) = board_data("board-redraw").await;
+        let discord = MockDiscord::start().await;
+        let mut message = Message::default();
+        message.id = MessageId::new(31);
+        message.channel_id = ChannelId::new(30);
+        discord.add_message(&message);
+        db.set_status_board(&board(31), "100").await.unwrap();
+        let edits = |discord: &MockDiscord| discord.requests().iter().filter(|r| r.method == "PATCH").count();
+
+        let now = Utc::now();
+        let mut shown = HashMap::new();
+        redraw_all(&discord.discord, &data, &mut shown, now).await.unwrap();
+        redraw_all(&discord.discord, &data, &mut shown, now).await.unwrap();
+        assert_eq!(edits(&discord), 1);
+
+        db.set_status(UserStatus::available("100", None)).await.unwrap();
+        redraw_all(&discord.discord, &data, &mut shown, now).await.unwrap();
+        assert_eq!(edits(&discord), 2);
+        assert!(discord.requests().last().unwrap().body["content"].as_str().unwrap().contains("<@100>"));
+
+        // A board whose message is gone is dropped
+        db.set_status_board(&board(32), "100").await.unwrap();
+        redraw_all(&discord.discord, &data, &mut shown, now).await.unwrap();
+        assert!(db.get_status_board(&GUILD_ID.to_string()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_render_hides_members_busy_for_a_while() {
+        let (db, data) = board_data("board-render").await;
+        db.set_status(UserStatus::available("100", None)).await.unwrap();
+        db.set_status(UserStatus::busy("101", Some("deploying".to_string()))).await.unwrap();
+        db.set_status(UserStatus::busy("102", None)).await.unwrap();
+        db.set_user_always_show_me("102", true).await.unwrap();
+
+        let now = Utc::now();
+        let content = render(&data, &board(31), now).await.unwrap();
+        assert!(content.contains("**Busy** (2)"));
+        assert!(content.contains("<@100>") && content.contains("<@102>"));
+        assert!(content.contains("<@101>") && content.contains(" - deploying"));
+        assert!(content.contains("**Away** (0)\n  -# Nobody"));
+
+        // After a while busy members drop off, unless they asked to be shown
+        let later = render(&data, &board(31), now + chrono::Duration::hours(1)).await.unwrap();
+        assert!(later.contains("**Busy** (1)"));
+        assert!(!later.contains("<@101>"));
+        assert!(later.contains("<@102>"));
+    }
+}
@@ -0,0 +1,112 @@
+//! Calendar import - Out-of-office from people's own calendars
+//!
+//! `/fabrica settings calendar-import` registers a calendar's `.ics` address,
+//! which is imported right away and then polled, or takes an uploaded `.ics`
+//! file for a one-off import. Busy and out-of-office events become leave and
+//! schedule overrides (see `services::calendar_import`).
+
+use crate::bot::{Context, Data, Error};
+use crate::services::calendar_import::{self, MAX_BYTES};
+use crate::services::i18n::{locale_for, tr, tr_args};
+use chrono::Utc;
+use poise::serenity_prelude::Attachment;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Start polling registered calendars
+pub fn spawn(data: Data) {
+    let every = data.config.status.import_interval_secs;
+    if every == 0 {
+        info!("Calendar import polling disabled");
+        return;
+    }
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(every));
+        loop {
+            interval.tick().await;
+            match calendar_import::poll_all(&data.db, &data.config.status.import_allowed_hosts, Utc::now()).await {
+                Ok(_) => data.boards.request(),
+                Err(e) => warn!("Calendar import poll failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Register a calendar URL ("off" to stop), import an uploaded file, or show
+/// the current import
+pub async fn import(ctx: Context<'_>, url: Option<String>, file: Option<Attachment>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = ctx.guild_id().map(|g| g.to_string()) else {
+        ctx.say(tr(locale, "import-server-only")).await?;
+        return Ok(());
+    };
+    let user_id = ctx.author().id.to_string();
+    let db = &ctx.data().db;
+
+    let msg = match (url.as_deref().map(str::trim), file) {
+        (_, Some(file)) => {
+            let is_ics = file.filename.to_lowercase().ends_with(".ics")
+                || file.content_type.as_deref().map(|t| t.starts_with("text/calendar")).unwrap_or(false);
+            if !is_ics || file.size as usize > MAX_BYTES {
+                tr(locale, "import-bad-file")
+            } else {
+                ctx.defer_ephemeral().await?;
+                match file.download().await.ok().and_then(|bytes| String::from_utf8(bytes).ok()) {
+                    Some(text) if text.contains("BEGIN:VCALENDAR") => {
+                        let (leave, overrides) = calendar_import::sync(db, &guild_id, &user_id, &text, Utc::now()).await?;
+                        info!("User {} imported a calendar file in guild {}", user_id, guild_id);
+                        imported(locale, leave, overrides)
+                    }
+                    _ => tr(locale, "import-bad-file"),
+                }
+            }
+        }
+        (Some(url), None) if url.eq_ignore_ascii_case("off") => {
+            let today = db.get_user_settings(&user_id).await?.today();
+            db.remove_calendar_import(&guild_id, &user_id, &today).await?;
+            info!("User {} stopped importing their calendar in guild {}", user_id, guild_id);
+            tr(locale, "import-off")
+        }
+        (Some(url), None) if !calendar_import::is_calendar_url(url) => tr(locale, "import-bad-url"),
+        (Some(url), None) => {
+            ctx.defer_ephemeral().await?;
+            match calendar_import::fetch(url, &ctx.data().config.status.import_allowed_hosts).await {
+                Ok(text) => {
+                    db.set_calendar_import(&guild_id, &user_id, url).await?;
+                    let (leave, overrides) = calendar_import::sync(db, &guild_id, &user_id, &text, Utc::now()).await?;
+                    db.record_calendar_import(&guild_id, &user_id, None).await?;
+                    info!("User {} registered a calendar import in guild {}", user_id, guild_id);
+                    format!("{}\n{}", imported(locale, leave, overrides), tr(locale, "import-url-saved"))
+                }
+                Err(e) => {
+                    warn!("User {} calendar import fetch failed in guild {}: {}", user_id, guild_id, e);
+                    tr(locale, "import-fetch-failed")
+                }
+            }
+        }
+        (None, None) => match db.get_calendar_import(&guild_id, &user_id).await? {
+            None => tr(locale, "import-none"),
+            Some(import) => {
+                let host = import.url.split("://").nth(1).and_then(|rest| rest.split('/').next()).unwrap_or_default();
+                let mut msg = tr_args(locale, "import-status", &[("host", host)]);
+                if let Some(at) = import.last_synced_at {
+                    msg.push('\n');
+                    msg.push_str(&tr_args(locale, "import-last-synced", &[("time", &format!("<t:{}:R>", at))]));
+                }
+                if import.last_error.is_some() {
+                    msg.push('\n');
+                    msg.push_str(&tr(locale, "import-last-error"));
+                }
+                msg
+            }
+        },
+    };
+    ctx.data().boards.request();
+
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+    Ok(())
+}
+
+fn imported(locale: &str, leave: usize, overrides: usize) -> String {
+    tr_args(locale, "import-done", &[("leave", &leave.to_string()), ("overrides", &overrides.to_string())])
+}
@@ -0,0 +1,451 @@
+//! Corrections - Reader-suggested fixes that feed the guild glossary
+//!
+//! Translations carry a "Suggest correction" button. It opens a modal where a
+//! bilingual reader enters a better translation and, optionally, the term the
+//! model got wrong. Corrections are queued for admins, who promote term fixes
+//! into the guild glossary that every later translation prompt includes.
+
+use crate::bot::{Context, Data, Error};
+use crate::db::Correction;
+use crate::modules::translation::{get_guild_id, has_admin_permission, localized_language_name};
+use crate::services::i18n::{locale_for, locale_for_user, supported_locale, tr, tr_args, DEFAULT_LOCALE};
+use poise::serenity_prelude::{
+    self as serenity, ActionRowComponent, ButtonStyle, ChannelId, ComponentInteraction, CreateActionRow, CreateButton,
+    CreateInputText, CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, InputTextStyle, Message,
+    MessageId, ModalInteraction,
+};
+use crate::services::messages::fit_message;
+use tracing::{info, warn};
+
+/// Prefix of the button and modal custom IDs
+pub const CUSTOM_ID_PREFIX: &str = "fabrica-correction";
+/// Discord's limit on a modal text input's value
+const MAX_INPUT_CHARS: usize = 4000;
+/// Corrections shown per page of the review queue
+const QUEUE_PAGE: u32 = 10;
+
+/// What an admin does with a reviewed correction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum ReviewAction {
+    /// Add the correction's term fix to the glossary
+    #[name = "promote"]
+    Promote,
+    /// Close the correction without changing the glossary
+    #[name = "dismiss"]
+    Dismiss,
+}
+
+/// Where a translation came from, carried in the button and modal custom IDs
+struct Origin {
+    guild_id: String,
+    channel_id: String,
+    message_id: String,
+    from: String,
+    to: String,
+}
+
+impl Origin {
+    fn custom_id(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            CUSTOM_ID_PREFIX, self.guild_id, self.channel_id, self.message_id, self.from, self.to
+        )
+    }
+
+    fn parse(custom_id: &str) -> Option<Self> {
+        let mut parts = custom_id.strip_prefix(CUSTOM_ID_PREFIX)?.strip_prefix(':')?.split(':');
+        let origin = Origin {
+            guild_id: parts.next()?.to_string(),
+            channel_id: parts.next()?.to_string(),
+            message_id: parts.next()?.to_string(),
+            from: parts.next()?.to_string(),
+            to: parts.next()?.to_string(),
+        };
+        parts.next().is_none().then_some(origin)
+    }
+}
+
+/// "Suggest correction" button for a translation of `message` from `from` into `to`
+pub fn suggest_button(guild_id: &str, message: &Message, from: &str, to: &str) -> CreateActionRow {
+    let origin = Origin {
+        guild_id: guild_id.to_string(),
+        channel_id: message.channel_id.to_string(),
+        message_id: message.id.to_string(),
+        from: from.to_string(),
+        to: to.to_string(),
+    };
+    // The button sits under a translation into `to`, so it's labelled for that reader
+    let locale = supported_locale(to).unwrap_or(DEFAULT_LOCALE);
+    CreateActionRow::Buttons(vec![CreateButton::new(origin.custom_id())
+        .label(tr(locale, "correction-suggest"))
+        .emoji('✏')
+        .style(ButtonStyle::Secondary)])
+}
+
+/// Open the correction modal for a pressed "Suggest correction" button
+pub async fn handle_component(ctx: &serenity::Context, interaction: &ComponentInteraction, data: &Data) -> Result<(), Error> {
+    let Some(origin) = Origin::parse(&interaction.data.custom_id) else {
+        return Ok(());
+    };
+    let locale = locale_for_user(&data.db, &interaction.user.id.to_string()).await;
+
+    let current: String = translation_text(&interaction.message).chars().take(MAX_INPUT_CHARS).collect();
+    let mut correction = CreateInputText::new(InputTextStyle::Paragraph, tr(locale, "correction-modal-text"), "correction")
+        .max_length(MAX_INPUT_CHARS as u16);
+    if !current.is_empty() {
+        correction = correction.value(current);
+    }
+    let language = localized_language_name(locale, &origin.to);
+    let term = CreateInputText::new(InputTextStyle::Short, tr(locale, "correction-modal-term"), "term")
+        .placeholder(tr_args(locale, "correction-modal-term-placeholder", &[("language", &language)]))
+        .required(false)
+        .max_length(200);
+
+    let modal = CreateModal::new(origin.custom_id(), tr(locale, "correction-modal-title")).components(vec![
+        CreateActionRow::InputText(correction),
+        CreateActionRow::InputText(term),
+    ]);
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Modal(modal))
+        .await?;
+    Ok(())
+}
+
+/// Store a submitted correction
+pub async fn handle_modal(ctx: &serenity::Context, interaction: &ModalInteraction, data: &Data) -> Result<(), Error> {
+    let Some(origin) = Origin::parse(&interaction.data.custom_id) else {
+        return Ok(());
+    };
+
+    let locale = locale_for_user(&data.db, &interaction.user.id.to_string()).await;
+    let reply = match submit(ctx, interaction, data, locale, origin).await {
+        Ok(reply) => reply,
+        Err(e) => {
+            warn!("Failed to store correction from {}: {}", interaction.user.id, e);
+            tr(locale, "correction-save-failed")
+        }
+    };
+
+    let response = CreateInteractionResponseMessage::new().content(reply).ephemeral(true);
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Message(response))
+        .await?;
+    Ok(())
+}
+
+async fn submit(
+    ctx: &serenity::Context,
+    interaction: &ModalInteraction,
+    data: &Data,
+    locale: &str,
+    origin: Origin,
+) -> Result<String, Error> {
+    if !data.config.discord.guild_ids.contains(&origin.guild_id) {
+        return Ok(tr(locale, "correction-not-enabled"));
+    }
+
+    let correction = input_value(interaction, "correction");
+    if correction.is_empty() {
+        return Ok(tr(locale, "correction-empty"));
+    }
+    let term_input = input_value(interaction, "term");
+    let (term, term_translation) = match parse_term_fix(&term_input) {
+        Some((term, translation)) => (Some(term), Some(translation)),
+        None if term_input.is_empty() => (None, None),
+        None => return Ok(tr(locale, "correction-term-format")),
+    };
+
+    let channel_id = ChannelId::new(origin.channel_id.parse()?);
+    let source_text = channel_id
+        .message(ctx, MessageId::new(origin.message_id.parse()?))
+        .await
+        .map(|m| m.content)
+        .unwrap_or_default();
+    let translation = interaction.message.as_deref().map(translation_text).unwrap_or_default();
+
+    let record = Correction {
+        id: 0,
+        guild_id: origin.guild_id,
+        channel_id: origin.channel_id,
+        message_id: origin.message_id,
+        source_lang: origin.from,
+        target_lang: origin.to,
+        source_text,
+        translation,
+        correction,
+        term,
+        term_translation,
+        model: data.config.translation.model.clone(),
+        submitted_by: interaction.user.id.to_string(),
+        created_at: 0,
+    };
+    let id = data.db.add_correction(&record).await?;
+    info!("Correction #{} submitted by {} ({} -> {})", id, record.submitted_by, record.source_lang, record.target_lang);
+
+    Ok(tr_args(locale, "correction-submitted", &[("id", &id.to_string())]))
+}
+
+/// The value a modal input was submitted with
+fn input_value(interaction: &ModalInteraction, custom_id: &str) -> String {
+    interaction
+        .data
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == custom_id => input.value.clone(),
+            _ => None,
+        })
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// Parse "term = translation"
+fn parse_term_fix(input: &str) -> Option<(String, String)> {
+    let (term, translation) = input.split_once('=')?;
+    let (term, translation) = (term.trim(), translation.trim());
+    (!term.is_empty() && !translation.is_empty()).then(|| (term.to_string(), translation.to_string()))
+}
+
+/// The translated text of a translation DM or public translation post
+fn translation_text(message: &Message) -> String {
+    let text = match message.embeds.first().and_then(|e| e.description.as_deref()) {
+        // DM embeds quote the replied-to message first and end with a jump link,
+        // whose label is in the reader's language
+        Some(description) => description
+            .lines()
+            .filter(|line| !line.starts_with("> ") && !is_jump_link(line))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        // Public posts start with "🌐 **<label>:**"
+        None => {
+            let content = message.content.trim_start_matches('🌐').trim_start();
+            match content.strip_prefix("**").and_then(|rest| rest.split_once(":**")) {
+                Some((label, rest)) if !label.contains('\n') => rest.to_string(),
+                _ => content.to_string(),
+            }
+        }
+    };
+    text.trim().to_string()
+}
+
+fn is_jump_link(line: &str) -> bool {
+    line.starts_with('[') && line.contains("](https://discord.com/channels/") && line.ends_with(')')
+}
+
+// ==================== Commands ====================
+
+/// Show the queue of pending corrections and recurring term fixes
+pub async fn queue(ctx: Context<'_>) -> Result<(), Error> {
+    let Some(guild_id) = admin_guild(&ctx).await? else {
+        return Ok(());
+    };
+
+    let locale = locale_for(ctx).await;
+    let pending = ctx.data().db.get_pending_corrections(&guild_id, QUEUE_PAGE).await?;
+    if pending.is_empty() {
+        ctx.send(poise::CreateReply::default().content(tr(locale, "corrections-none")).ephemeral(true)).await?;
+        return Ok(());
+    }
+
+    let mut msg = tr(locale, "corrections-title");
+    msg.push('\n');
+    for c in &pending {
+        msg.push('\n');
+        msg.push_str(&tr_args(locale, "corrections-entry", &[
+            ("id", &c.id.to_string()),
+            ("from", &localized_language_name(locale, &c.source_lang)),
+            ("to", &localized_language_name(locale, &c.target_lang)),
+            ("user", &format!("<@{}>", c.submitted_by)),
+            ("model", &c.model),
+            ("translation", &truncate(&c.translation.replace('\n', " "), 150)),
+            ("correction", &truncate(&c.correction.replace('\n', " "), 150)),
+        ]));
+        msg.push('\n');
+        if let (Some(term), Some(translation)) = (&c.term, &c.term_translation) {
+            msg.push_str(&tr_args(locale, "corrections-term-fix", &[("term", term), ("translation", translation)]));
+            msg.push('\n');
+        }
+    }
+
+    let recurring = ctx.data().db.get_recurring_term_fixes(&guild_id).await?;
+    if !recurring.is_empty() {
+        msg.push('\n');
+        msg.push_str(&tr(locale, "corrections-recurring"));
+        msg.push('\n');
+        for (from, to, term, translation, count, id) in recurring {
+            msg.push_str(&tr_args(locale, "corrections-recurring-entry", &[
+                ("term", &term),
+                ("translation", &translation),
+                ("from", &from),
+                ("to", &to),
+                ("count", &count.to_string()),
+                ("id", &id.to_string()),
+            ]));
+            msg.push('\n');
+        }
+    }
+
+    msg.push('\n');
+    msg.push_str(&tr(locale, "corrections-review-hint"));
+    // Corrections quote messages from any channel, so only the admin sees them
+    ctx.send(poise::CreateReply::default().content(fit_message(&msg)).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Promote a correction's term fix into the glossary, or dismiss it
+pub async fn review(ctx: Context<'_>, id: i64, action: ReviewAction) -> Result<(), Error> {
+    let Some(guild_id) = admin_guild(&ctx).await? else {
+        return Ok(());
+    };
+    let locale = locale_for(ctx).await;
+    let id_arg = id.to_string();
+
+    let Some(correction) = ctx.data().db.get_correction(&guild_id, id).await? else {
+        ctx.say(tr_args(locale, "correction-missing", &[("id", &id_arg)])).await?;
+        return Ok(());
+    };
+    let reviewer = ctx.author().id.to_string();
+    match action {
+        ReviewAction::Promote => {
+            let (Some(term), Some(translation)) = (&correction.term, &correction.term_translation) else {
+                ctx.say(tr_args(locale, "correction-no-term", &[("id", &id_arg)])).await?;
+                return Ok(());
+            };
+            if !ctx.data().db.resolve_correction(&guild_id, id, "promoted").await? {
+                ctx.say(tr_args(locale, "correction-already-reviewed", &[("id", &id_arg)])).await?;
+                return Ok(());
+            }
+            ctx.data()
+                .db
+                .set_glossary_term(&guild_id, &correction.source_lang, &correction.target_lang, term, translation, &reviewer)
+                .await?;
+            info!("Correction #{} promoted to the glossary by {} (guild {})", id, reviewer, guild_id);
+            ctx.say(tr_args(locale, "correction-promoted", &[
+                ("id", &id_arg),
+                ("term", &term.to_lowercase()),
+                ("translation", translation),
+                ("from", &correction.source_lang),
+                ("to", &correction.target_lang),
+            ]))
+            .await?;
+        }
+        ReviewAction::Dismiss => {
+            if !ctx.data().db.resolve_correction(&guild_id, id, "dismissed").await? {
+                ctx.say(tr_args(locale, "correction-already-reviewed", &[("id", &id_arg)])).await?;
+                return Ok(());
+            }
+            info!("Correction #{} dismissed by {} (guild {})", id, reviewer, guild_id);
+            ctx.say(tr_args(locale, "correction-dismissed", &[("id", &id_arg)])).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Show the guild glossary, or remove a term from it
+pub async fn glossary(ctx: Context<'_>, remove: Option<String>) -> Result<(), Error> {
+    let Some(guild_id) = admin_guild(&ctx).await? else {
+        return Ok(());
+    };
+
+    let locale = locale_for(ctx).await;
+
+    if let Some(term) = remove {
+        let removed = ctx.data().db.remove_glossary_term(&guild_id, term.trim()).await?;
+        if removed == 0 {
+            ctx.say(tr_args(locale, "glossary-not-found", &[("term", term.trim())])).await?;
+        } else {
+            ctx.say(tr_args(locale, "glossary-removed", &[("term", &term.trim().to_lowercase())])).await?;
+        }
+        return Ok(());
+    }
+
+    let terms = ctx.data().db.get_glossary(&guild_id).await?;
+    if terms.is_empty() {
+        ctx.say(tr(locale, "glossary-empty")).await?;
+        return Ok(());
+    }
+
+    let mut msg = tr(locale, "glossary-title");
+    msg.push('\n');
+    for (from, to, term, translation) in terms {
+        msg.push_str(&format!("• **{}** = **{}** ({} → {})\n", term, translation, from, to));
+    }
+    ctx.say(fit_message(&msg)).await?;
+    Ok(())
+}
+
+/// Guild ID if the command runs in a server and the author is a Fabrica admin there
+async fn admin_guild(ctx: &Context<'_>) -> Result<Option<String>, Error> {
+    let locale = locale_for(*ctx).await;
+    let Some(guild_id) = get_guild_id(ctx) else {
+        ctx.say(tr(locale, "corrections-server-only")).await?;
+        return Ok(None);
+    };
+    if !has_admin_permission(ctx, &guild_id).await {
+        ctx.say(tr(locale, "corrections-admin-only")).await?;
+        return Ok(None);
+    }
+    Ok(Some(guild_id))
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+    let mut truncated: String = s.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poise::serenity_prelude::Embed;
+
+    #[test]
+    fn test_origin_round_trip() {
+        let origin = Origin {
+            guild_id: "1".to_string(),
+            channel_id: "2".to_string(),
+            message_id: "3".to_string(),
+            from: "hi".to_string(),
+            to: "en".to_string(),
+        };
+        let parsed = Origin::parse(&origin.custom_id()).unwrap();
+        assert_eq!(
+            (parsed.guild_id, parsed.channel_id, parsed.message_id, parsed.from, parsed.to),
+            ("1".into(), "2".into(), "3".into(), "hi".into(), "en".into())
+        );
+        assert!(Origin::parse("fabrica-correction:1:2:3:hi").is_none());
+        assert!(Origin::parse("fabrica-correction:1:2:3:hi:en:extra").is_none());
+        assert!(Origin::parse("fabrica-standup:1:2:3:hi:en").is_none());
+    }
+
+    #[test]
+    fn test_parse_term_fix() {
+        assert_eq!(parse_term_fix(" sprint = स्प्रिंट "), Some(("sprint".into(), "स्प्रिंट".into())));
+        assert_eq!(parse_term_fix("a = b = c"), Some(("a".into(), "b = c".into())));
+        assert_eq!(parse_term_fix("sprint"), None);
+        assert_eq!(parse_term_fix("sprint ="), None);
+        assert_eq!(parse_term_fix("= स्प्रिंट"), None);
+    }
+
+    #[test]
+    fn test_translation_text() {
+        let mut public = Message::default();
+        public.content = "🌐 **Translation:** Ship it today".to_string();
+        assert_eq!(translation_text(&public), "Ship it today");
+        public.content = "🌐 **अनुवाद:** आज भेज दो".to_string();
+        assert_eq!(translation_text(&public), "आज भेज दो");
+
+        let mut embed = Embed::default();
+        embed.description = Some(
+            "> Are we shipping?\n\nआज भेज दो\n\n[संदेश पर जाएँ](https://discord.com/channels/1/2/3)".to_string(),
+        );
+        let mut dm = Message::default();
+        dm.embeds = vec![embed];
+        assert_eq!(translation_text(&dm), "आज भेज दो");
+    }
+}
@@ -0,0 +1,401 @@
+//! DM replies - Answer a translation DM back in the original channel
+//!
+//! Replying to one of Fabrica's translation DMs translates the reply into the
+//! channel's dominant language and shows a preview with Post / Cancel
+//! buttons. Once confirmed, it is posted in the channel through a webhook
+//! under the user's name and avatar, pointing at the original message.
+//!
+//! DM replies are queued on the translation workers like channel messages,
+//! and only people who can still post in the channel can answer there.
+
+use crate::bot::{Data, Discord, Error};
+use crate::db::{PendingDmReply, TranslationPreferences};
+use crate::modules::translation::{detect_language, localized_language_name, with_glossary};
+use crate::services::i18n::{locale_for_user, supported_locale, tr, tr_args, DEFAULT_LOCALE};
+use crate::services::language;
+use crate::services::messages::{fit_message, fit_username};
+use poise::serenity_prelude::{
+    ButtonStyle, ChannelId, ChannelType, ComponentInteraction, CreateActionRow,
+    CreateAllowedMentions, CreateButton, CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage,
+    CreateWebhook, ExecuteWebhook, GetMessages, GuildId, Member, Message, MessageId, Permissions, UserId, Webhook,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Prefix of the confirmation buttons' custom IDs
+pub const BUTTON_PREFIX: &str = "fabrica-dm-reply";
+/// Name of the webhook replies are posted through
+const WEBHOOK_NAME: &str = "Fabrica";
+/// Recent messages sampled to find a channel's dominant language
+const LANGUAGE_SAMPLE: u8 = 50;
+/// Translation DMs can be replied to for this long
+const TRANSLATION_DM_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+/// Previews that are neither posted nor cancelled are dropped after this long
+const PENDING_REPLY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// How often expired DMs and previews are removed
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Start the periodic removal of expired translation DMs and previews
+pub fn spawn(data: Data) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+        loop {
+            interval.tick().await;
+            let now = chrono::Utc::now().timestamp();
+            let dms_before = now - TRANSLATION_DM_TTL.as_secs() as i64;
+            let replies_before = now - PENDING_REPLY_TTL.as_secs() as i64;
+            match data.db.remove_expired_dm_replies(dms_before, replies_before).await {
+                Ok(0) => {}
+                Ok(removed) => debug!("Removed {} expired translation DM(s) and reply preview(s)", removed),
+                Err(e) => warn!("Failed to remove expired translation DMs: {}", e),
+            }
+        }
+    });
+}
+
+/// Handle a DM: if it replies to a translation DM, translate it and ask for confirmation
+pub async fn handle_message(ctx: &Discord, message: &Message, data: &Data) -> Result<(), Error> {
+    let Some(replied_id) = message.message_reference.as_ref().and_then(|r| r.message_id) else {
+        return Ok(());
+    };
+    let Some(dm) = data.db.get_translation_dm(&replied_id.to_string()).await? else {
+        debug!("DM from {} replies to a message that isn't a translation DM", message.author.id);
+        return Ok(());
+    };
+    if dm.discord_id != message.author.id.to_string() || !data.config.discord.guild_ids.contains(&dm.guild_id) {
+        return Ok(());
+    }
+
+    let text = message.content.trim();
+    if text.is_empty() {
+        return Ok(());
+    }
+
+    let locale = locale_for_user(&data.db, &dm.discord_id).await;
+    let guild_id = GuildId::new(dm.guild_id.parse()?);
+    let channel_id = ChannelId::new(dm.channel_id.parse()?);
+    if postable_member(ctx, guild_id, channel_id, message.author.id).await.is_none() {
+        message.reply(ctx, tr(locale, "dm-reply-not-member")).await?;
+        return Ok(());
+    }
+
+    let target = dominant_language(ctx, data, &dm.guild_id, channel_id).await;
+    let source = detect_language(data, text).await;
+
+    let content = if source == target {
+        text.to_string()
+    } else {
+        let prefs = with_glossary(data, &dm.guild_id, &source, &target, TranslationPreferences::default()).await;
+        match data.translator.translate_with_preferences(text, &source, &target, &prefs).await {
+            Ok(Some(translated)) => translated,
+            Ok(None) => text.to_string(),
+            Err(e) => {
+                warn!("DM reply translation {} -> {} failed: {}", source, target, e);
+                message.reply(ctx, tr(locale, "dm-reply-failed")).await?;
+                return Ok(());
+            }
+        }
+    };
+
+    let preview = tr_args(locale, "dm-reply-preview", &[
+        ("channel", &format!("<#{}>", channel_id)),
+        ("language", &localized_language_name(locale, &target)),
+        ("content", &quote(&content)),
+    ]);
+    let buttons = CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("{}:post", BUTTON_PREFIX))
+            .label(tr(locale, "dm-reply-post"))
+            .style(ButtonStyle::Primary),
+        CreateButton::new(format!("{}:cancel", BUTTON_PREFIX))
+            .label(tr(locale, "dm-reply-cancel"))
+            .style(ButtonStyle::Secondary),
+    ]);
+    let confirm = message
+        .channel_id
+        .send_message(
+            ctx,
+            CreateMessage::new()
+                .content(fit_message(&preview))
+                .reference_message(message)
+                .components(vec![buttons]),
+        )
+        .await?;
+
+    let pending = PendingDmReply {
+        dm,
+        content,
+        language: target,
+    };
+    data.db.add_pending_dm_reply(&confirm.id.to_string(), &pending).await?;
+    Ok(())
+}
+
+/// Handle a press of a Post / Cancel button under a reply preview
+pub async fn handle_component(ctx: &Discord, interaction: &ComponentInteraction, data: &Data) -> Result<(), Error> {
+    let post = interaction.data.custom_id.ends_with(":post");
+    let locale = locale_for_user(&data.db, &interaction.user.id.to_string()).await;
+    let confirm_id = interaction.message.id.to_string();
+
+    // Someone else's press leaves the preview and its buttons for the owner
+    let owner = data.db.get_pending_dm_reply(&confirm_id).await?.map(|pending| pending.dm.discord_id);
+    if owner.is_some_and(|owner| owner != interaction.user.id.to_string()) {
+        let response = CreateInteractionResponseMessage::new()
+            .content(tr(locale, "dm-reply-not-yours"))
+            .ephemeral(true);
+        interaction
+            .create_response(ctx, CreateInteractionResponse::Message(response))
+            .await?;
+        return Ok(());
+    }
+
+    let status = match data.db.take_pending_dm_reply(&confirm_id).await? {
+        None => tr(locale, "dm-reply-already-done"),
+        Some(_) if !post => tr(locale, "dm-reply-cancelled"),
+        Some(pending) => {
+            // Membership and permissions may have changed since the preview
+            let guild_id = GuildId::new(pending.dm.guild_id.parse()?);
+            let channel_id = ChannelId::new(pending.dm.channel_id.parse()?);
+            match postable_member(ctx, guild_id, channel_id, interaction.user.id).await {
+                None => tr(locale, "dm-reply-not-member"),
+                Some(member) => match post_reply(ctx, &pending, &member).await {
+                    Ok(link) => {
+                        info!("Posted DM reply from {} into channel {}", pending.dm.discord_id, pending.dm.channel_id);
+                        tr_args(locale, "dm-reply-posted", &[("link", &link)])
+                    }
+                    Err(e) => {
+                        warn!("Failed to post DM reply into channel {}: {}", pending.dm.channel_id, e);
+                        tr(locale, "dm-reply-webhook-failed")
+                    }
+                },
+            }
+        }
+    };
+
+    let mut content = interaction.message.content.clone();
+    content.push_str("\n\n");
+    content.push_str(&status);
+    let response = CreateInteractionResponseMessage::new()
+        .content(fit_message(&content))
+        .components(vec![]);
+    interaction
+        .create_response(ctx, CreateInteractionResponse::UpdateMessage(response))
+        .await?;
+    Ok(())
+}
+
+/// The user as a guild member, if they can still send messages in the channel
+async fn postable_member(ctx: &Discord, guild_id: GuildId, channel_id: ChannelId, user_id: UserId) -> Option<Member> {
+    let member = guild_id.member(ctx, user_id).await.ok()?;
+    let channel = channel_id.to_channel(ctx).await.ok()?.guild()?;
+
+    // Thread permissions come from the parent channel
+    let (channel, needed) = match channel.kind {
+        ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread => {
+            let parent = channel.parent_id?.to_channel(ctx).await.ok()?.guild()?;
+            (parent, Permissions::SEND_MESSAGES_IN_THREADS)
+        }
+        _ => (channel, Permissions::SEND_MESSAGES),
+    };
+
+    let permissions = ctx.cache.guild(guild_id)?.user_permissions_in(&channel, &member);
+    permissions.contains(Permissions::VIEW_CHANNEL | needed).then_some(member)
+}
+
+/// Post a confirmed reply in its channel under the member's name, returning its link
+async fn post_reply(ctx: &Discord, pending: &PendingDmReply, member: &Member) -> Result<String, Error> {
+    let guild_id = GuildId::new(pending.dm.guild_id.parse()?);
+    let channel_id = ChannelId::new(pending.dm.channel_id.parse()?);
+    let message_id = MessageId::new(pending.dm.message_id.parse()?);
+
+    // Webhooks belong to the parent channel; threads are targeted per message
+    let channel = channel_id.to_channel(ctx).await?.guild();
+    let (webhook_channel, thread) = match channel {
+        Some(c) if matches!(c.kind, ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread) => {
+            (c.parent_id.unwrap_or(channel_id), Some(channel_id))
+        }
+        _ => (channel_id, None),
+    };
+
+    // The header is read by the channel, so it's in the channel's language
+    let locale = supported_locale(&pending.language).unwrap_or(DEFAULT_LOCALE);
+    let original = message_id.link(channel_id, Some(guild_id));
+    let replied_to = match channel_id.message(ctx, message_id).await {
+        Ok(m) => m.author.display_name().to_string(),
+        Err(_) => tr(locale, "mirror-reply-someone"),
+    };
+    let header = tr_args(locale, "mirror-replying-to-link", &[("name", &replied_to), ("link", &original)]);
+    let content = format!("{}\n{}", header, pending.content);
+
+    let mut builder = ExecuteWebhook::new()
+        .content(fit_message(&content))
+        .username(fit_username(member.display_name()))
+        .avatar_url(member.face())
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Some(thread) = thread {
+        builder = builder.in_thread(thread);
+    }
+
+    let webhook = reply_webhook(ctx, webhook_channel).await?;
+    let posted = webhook.execute(ctx, true, builder).await?;
+    Ok(posted
+        .map(|m| m.link())
+        .unwrap_or_else(|| format!("https://discord.com/channels/{}/{}", guild_id, channel_id)))
+}
+
+/// Find Fabrica's reply webhook in a channel, creating it if needed
+async fn reply_webhook(ctx: &Discord, channel_id: ChannelId) -> Result<Webhook, Error> {
+    let bot_id = ctx.cache.current_user().id;
+    let existing = channel_id.webhooks(ctx).await?.into_iter().find(|w| {
+        w.name.as_deref() == Some(WEBHOOK_NAME) && w.token.is_some() && w.user.as_ref().is_some_and(|u| u.id == bot_id)
+    });
+    match existing {
+        Some(webhook) => Ok(webhook),
+        None => Ok(channel_id.create_webhook(ctx, CreateWebhook::new(WEBHOOK_NAME)).await?),
+    }
+}
+
+/// The language most of a channel is written in: a mirror channel's language,
+/// otherwise the most common confidently detected language among recent messages
+async fn dominant_language(ctx: &Discord, data: &Data, guild_id: &str, channel_id: ChannelId) -> String {
+    if let Ok(Some(mirror)) = data.db.get_mirror_channel(guild_id, &channel_id.to_string()).await {
+        return mirror.language;
+    }
+
+    let messages = match channel_id.messages(ctx, GetMessages::new().limit(LANGUAGE_SAMPLE)).await {
+        Ok(messages) => messages,
+        Err(e) => {
+            debug!("Couldn't read channel {} to find its language: {}", channel_id, e);
+            return "en".to_string();
+        }
+    };
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for message in messages.iter().filter(|m| !m.author.bot) {
+        if let Some(detected) = language::detect(&message.content).filter(|d| d.confidence >= language::CONFIDENT) {
+            *counts.entry(detected.code.to_string()).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(language, _)| language)
+        .unwrap_or_else(|| "en".to_string())
+}
+
+/// Quote every line of a preview
+fn quote(text: &str) -> String {
+    text.lines().map(|line| format!("> {}", line)).collect::<Vec<_>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::TranslationDm;
+    use crate::testing::{self, MockDiscord};
+    use poise::serenity_prelude::{Guild, GuildChannel, PermissionOverwrite, PermissionOverwriteType, RoleId};
+    use serde_json::json;
+
+    /// #general (20) where the muted role (51) can read but not write, and a thread (22) in it
+    fn guild_with_muted_role() -> (Guild, GuildChannel) {
+        let general = testing::text_channel(
+            testing::GUILD_ID,
+            20,
+            vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::SEND_MESSAGES | Permissions::SEND_MESSAGES_IN_THREADS,
+                kind: PermissionOverwriteType::Role(RoleId::new(51)),
+            }],
+        );
+        let mut thread = testing::text_channel(testing::GUILD_ID, 22, vec![]);
+        thread.kind = ChannelType::PublicThread;
+        thread.parent_id = Some(ChannelId::new(20));
+
+        let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::SEND_MESSAGES_IN_THREADS;
+        let guild = testing::guild(
+            testing::GUILD_ID,
+            vec![
+                testing::role(testing::GUILD_ID, testing::GUILD_ID, everyone),
+                testing::role(testing::GUILD_ID, 51, Permissions::empty()),
+            ],
+            vec![general],
+            vec![testing::member(testing::GUILD_ID, 100, &[]), testing::member(testing::GUILD_ID, 101, &[51])],
+        );
+        (guild, thread)
+    }
+
+    #[tokio::test]
+    async fn test_postable_member_needs_view_and_send() {
+        let discord = MockDiscord::start().await;
+        let (guild, thread) = guild_with_muted_role();
+        discord.add_guild(&guild);
+        discord.add_channel(&thread);
+
+        let guild_id = GuildId::new(testing::GUILD_ID);
+        let can_post = |channel: u64, user: u64| {
+            let discord = discord.discord.clone();
+            async move { postable_member(&discord, guild_id, ChannelId::new(channel), UserId::new(user)).await.is_some() }
+        };
+        assert!(can_post(20, 100).await);
+        assert!(can_post(22, 100).await);
+        // Muted members can read but not post, in the channel or its threads
+        assert!(!can_post(20, 101).await);
+        assert!(!can_post(22, 101).await);
+        // People who left the server can't post
+        assert!(!can_post(20, 102).await);
+        // Nor can anyone in a channel that no longer exists
+        assert!(!can_post(23, 100).await);
+    }
+
+    fn button_press(user_id: u64, action: &str, confirm_id: u64) -> ComponentInteraction {
+        let mut message = Message::default();
+        message.id = MessageId::new(confirm_id);
+        message.content = "Preview".to_string();
+        serde_json::from_value(json!({
+            "id": "800",
+            "application_id": "1",
+            "type": 3,
+            "token": "interaction-token",
+            "version": 1,
+            "channel_id": "700",
+            "locale": "en-US",
+            "entitlements": [],
+            "user": testing::user(user_id, "reader"),
+            "message": message,
+            "data": {"custom_id": format!("{}:{}", BUTTON_PREFIX, action), "component_type": 2},
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_only_the_owner_can_confirm_a_preview() {
+        let discord = MockDiscord::start().await;
+        let data = testing::data(testing::database("dm-reply-owner").await, testing::translator(str::to_string));
+        let pending = PendingDmReply {
+            dm: TranslationDm {
+                discord_id: "100".to_string(),
+                guild_id: testing::GUILD_ID.to_string(),
+                channel_id: "20".to_string(),
+                message_id: "300".to_string(),
+            },
+            content: "Sounds good".to_string(),
+            language: "en".to_string(),
+        };
+        data.db.add_pending_dm_reply("600", &pending).await.unwrap();
+
+        // Someone else's press is answered privately and leaves the preview alone
+        handle_component(&discord.discord, &button_press(101, "cancel", 600), &data).await.unwrap();
+        let response = discord.requests().pop().unwrap();
+        assert_eq!(response.path, "/interactions/800/interaction-token/callback");
+        assert_eq!(response.body["type"], 4);
+        assert_eq!(response.body["data"]["flags"], 64);
+        assert!(data.db.get_pending_dm_reply("600").await.unwrap().is_some());
+
+        // The owner can still cancel it, once
+        handle_component(&discord.discord, &button_press(100, "cancel", 600), &data).await.unwrap();
+        let response = discord.requests().pop().unwrap();
+        assert_eq!(response.body["type"], 7);
+        assert!(response.body["data"]["content"].as_str().unwrap().ends_with("❌ Cancelled - nothing was posted."));
+        assert!(data.db.get_pending_dm_reply("600").await.unwrap().is_none());
+    }
+}
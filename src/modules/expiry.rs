@@ -0,0 +1,133 @@
+//! Status expiry - Clear statuses people forgot to change
+//!
+//! A background check DMs anyone who has been available or busy for a long
+//! time with "Still here" / "Clear" buttons, and clears the status once it's
+//! older than the configured limit or the person's scheduled hours for the
+//! day have ended. Away statuses, and statuses set by auto status while it
+//! is on, are left alone.
+
+use crate::bot::{Data, Error};
+use crate::db::UserStatus;
+use crate::services::i18n::{locale_for_user, tr, tr_args};
+use crate::services::schedule::{self, working_windows, StaleAction};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{
+    self as serenity, ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateMessage, UserId,
+};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Prefix of the reminder buttons' custom IDs
+pub const BUTTON_PREFIX: &str = "fabrica-status-expiry";
+
+/// Start the periodic stale-status check
+pub fn spawn(ctx: serenity::Context, data: Data) {
+    let every = data.config.status.check_interval_secs;
+    if every == 0 {
+        info!("Status expiry disabled");
+        return;
+    }
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(every));
+        loop {
+            interval.tick().await;
+            if let Err(e) = check(&ctx, &data).await {
+                warn!("Stale status check failed: {}", e);
+            }
+        }
+    });
+}
+
+/// Remind about or clear every stale available/busy status
+async fn check(ctx: &serenity::Context, data: &Data) -> Result<(), Error> {
+    let now = Utc::now();
+    for status in data.db.get_all_statuses().await? {
+        if status.status == "away" {
+            continue;
+        }
+        // Auto status owns its statuses only while it's still turned on
+        if status.auto && data.db.get_user_settings(&status.discord_id).await?.auto_status {
+            continue;
+        }
+        let end = schedule_end(data, &status.discord_id, now).await.map(|end| end.timestamp());
+        match schedule::stale_action(status.updated_at, status.reminded_at, now.timestamp(), &data.config.status, end) {
+            StaleAction::Keep => {}
+            StaleAction::Remind => {
+                // Marked even if the DM fails, so closed DMs aren't retried every check
+                if let Err(e) = send_reminder(ctx, data, &status, now.timestamp()).await {
+                    debug!("Couldn't send status reminder to {}: {}", status.discord_id, e);
+                }
+                data.db.mark_status_reminded(&status.discord_id, now.timestamp()).await?;
+            }
+            StaleAction::Expire => {
+                if data.db.expire_status(&status.discord_id, status.updated_at).await? {
+                    data.boards.request();
+                    info!("Expired {} status of user {}", status.status, status.discord_id);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// When the user's working hours last ended, if they're outside them now
+async fn schedule_end(data: &Data, discord_id: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let settings = data.db.get_user_settings(discord_id).await.ok()?;
+    let windows = working_windows(&data.db, &data.config.discord.guild_ids, discord_id, settings.tz(), now).await;
+    schedule::hours_ended(&windows, now)
+}
+
+/// DM a "still here?" reminder with buttons to keep or clear the status
+async fn send_reminder(ctx: &serenity::Context, data: &Data, status: &UserStatus, now: i64) -> Result<(), Error> {
+    let locale = locale_for_user(&data.db, &status.discord_id).await;
+    let hours = ((now - status.updated_at) / 3600).to_string();
+    let label = tr(locale, &format!("status-label-{}", status.status));
+    let content = tr_args(locale, "status-reminder", &[("status", &label), ("hours", &hours)]);
+    let buttons = CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("{}:keep", BUTTON_PREFIX))
+            .label(tr(locale, "status-reminder-keep"))
+            .style(ButtonStyle::Primary),
+        CreateButton::new(format!("{}:clear", BUTTON_PREFIX))
+            .label(tr(locale, "status-reminder-clear"))
+            .style(ButtonStyle::Secondary),
+    ]);
+
+    let user_id = UserId::new(status.discord_id.parse()?);
+    let channel = user_id.create_dm_channel(ctx).await?;
+    channel
+        .send_message(ctx, CreateMessage::new().content(content).components(vec![buttons]))
+        .await?;
+    Ok(())
+}
+
+/// Handle a press of "Still here" / "Clear" under a reminder
+pub async fn handle_component(ctx: &serenity::Context, interaction: &ComponentInteraction, data: &Data) -> Result<(), Error> {
+    let user_id = interaction.user.id.to_string();
+    let locale = locale_for_user(&data.db, &user_id).await;
+
+    let status = if interaction.data.custom_id.ends_with(":clear") {
+        data.db.clear_status(&user_id).await?;
+        data.boards.request();
+        info!("User {} cleared their status from a reminder", user_id);
+        tr(locale, "status-cleared")
+    } else {
+        match data.db.get_status(&user_id).await? {
+            Some(current) if data.db.refresh_status(&user_id).await? => {
+                info!("User {} kept their {} status from a reminder", user_id, current.status);
+                let label = tr(locale, &format!("status-label-{}", current.status));
+                tr_args(locale, "status-reminder-kept", &[("status", &label)])
+            }
+            _ => tr(locale, "status-reminder-gone"),
+        }
+    };
+
+    let mut content = interaction.message.content.clone();
+    content.push_str("\n\n");
+    content.push_str(&status);
+    let response = CreateInteractionResponseMessage::new().content(content).components(vec![]);
+    interaction
+        .create_response(ctx, CreateInteractionResponse::UpdateMessage(response))
+        .await?;
+    Ok(())
+}
@@ -4,18 +4,13 @@
 
 use crate::bot::{Context, Error};
 use crate::db::WatchLevel;
+use crate::services::i18n::{locale_for, tr_args};
 use tracing::info;
 
 /// Show repository status
 pub async fn repo(ctx: Context<'_>, name: String) -> Result<(), Error> {
     // TODO: Implement GitHub API client
-    ctx.say(format!(
-        "📦 **Repository: {}**\n\n\
-         ⚠️ GitHub integration coming soon!\n\
-         This will show repo stats, recent activity, and open PRs.",
-        name
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "github-repo", &[("repo", &name)])).await?;
 
     Ok(())
 }
@@ -24,31 +19,21 @@ pub async fn repo(ctx: Context<'_>, name: String) -> Result<(), Error> {
 pub async fn commits(ctx: Context<'_>, repo: String, count: Option<u32>) -> Result<(), Error> {
     let count = count.unwrap_or(5);
 
-    ctx.say(format!(
-        "📝 **Recent {} commits for {}**\n\n\
-         ⚠️ GitHub integration coming soon!",
-        count, repo
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "github-commits", &[("count", &count.to_string()), ("repo", &repo)])).await?;
 
     Ok(())
 }
 
 /// Show open pull requests
 pub async fn prs(ctx: Context<'_>, repo: String) -> Result<(), Error> {
-    ctx.say(format!(
-        "🔀 **Open PRs for {}**\n\n\
-         ⚠️ GitHub integration coming soon!",
-        repo
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "github-prs", &[("repo", &repo)])).await?;
 
     Ok(())
 }
 
 /// Watch a GitHub repo in this channel
 pub async fn watch(ctx: Context<'_>, repo: String, level: String) -> Result<(), Error> {
-    let level = WatchLevel::from_str(&level).unwrap_or(WatchLevel::Important);
+    let level = WatchLevel::parse(&level).unwrap_or(WatchLevel::Important);
     let channel_id = ctx.channel_id().to_string();
 
     ctx.data()
@@ -61,15 +46,7 @@ pub async fn watch(ctx: Context<'_>, repo: String, level: String) -> Result<(),
         channel_id, repo, level
     );
 
-    ctx.say(format!(
-        "✅ This channel is now watching **{}** at **{}** level.\n\
-         You'll receive notifications about:\n\
-         {}",
-        repo,
-        level.as_str(),
-        level_description(&level)
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, &format!("github-watching-{}", level.as_str()), &[("repo", &repo)])).await?;
 
     Ok(())
 }
@@ -88,21 +65,7 @@ pub async fn unwatch(ctx: Context<'_>, repo: String) -> Result<(), Error> {
         channel_id, repo
     );
 
-    ctx.say(format!(
-        "✅ This channel is no longer watching **{}**.",
-        repo
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "watch-stopped", &[("name", &repo)])).await?;
 
     Ok(())
 }
-
-/// Get description of what a watch level includes
-fn level_description(level: &WatchLevel) -> &'static str {
-    match level {
-        WatchLevel::All => "• All pushes, PRs, issues, comments, and releases",
-        WatchLevel::Important => "• PRs (opened, merged, closed)\n• Releases\n• Milestones",
-        WatchLevel::Minimal => "• Releases only\n• Merged PRs",
-        WatchLevel::Off => "• Nothing (muted)",
-    }
-}
@@ -0,0 +1,291 @@
+//! Leave - Days off and public holidays
+//!
+//! People record leave as a range of their own local dates, choosing whether
+//! teammates see the reason. Server managers keep public holidays per region
+//! (IN, FR, CA, ...) and people pick the region that applies to them. Days off
+//! drop out of working hours, so they feed `/fabrica team`, overlap and auto
+//! status alike.
+
+use crate::bot::{Context, Error};
+use crate::db::{Database, Holiday, Leave};
+use crate::modules::translation::has_admin_permission;
+use crate::services::i18n::{locale_for, tr, tr_args};
+use crate::services::schedule;
+use chrono::{Datelike, Duration, NaiveDate};
+use poise::serenity_prelude::CreateAllowedMentions;
+use tracing::info;
+
+/// Record leave from `from` to `to` (inclusive, the user's own dates)
+pub async fn add(ctx: Context<'_>, from: String, to: Option<String>, reason: Option<String>, visibility: Option<String>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match ctx.guild_id() {
+        Some(gid) => gid.to_string(),
+        None => {
+            ctx.say(tr(locale, "leave-server-only")).await?;
+            return Ok(());
+        }
+    };
+    let user_id = ctx.author().id.to_string();
+    let settings = ctx.data().db.get_user_settings(&user_id).await?;
+    let today = chrono::Utc::now().with_timezone(&settings.tz()).date_naive();
+
+    let (start, end) = match schedule::parse_date_range(&from, to.as_deref(), today) {
+        Ok(range) => range,
+        Err(e) => {
+            ctx.send(poise::CreateReply::default()
+                .content(tr_args(locale, "leave-invalid-dates", &[("error", &e)]))
+                .ephemeral(true)).await?;
+            return Ok(());
+        }
+    };
+    let team_sees_reason = visibility.map(|v| v.trim().eq_ignore_ascii_case("team")).unwrap_or(false);
+    let leave = Leave {
+        id: 0,
+        guild_id: guild_id.clone(),
+        discord_id: user_id.clone(),
+        start_date: start.format("%Y-%m-%d").to_string(),
+        end_date: end.format("%Y-%m-%d").to_string(),
+        reason: reason.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+        reason_visibility: if team_sees_reason { "team" } else { "private" }.to_string(),
+        imported: false,
+    };
+    let id = ctx.data().db.add_leave(&leave).await?;
+    ctx.data().boards.request();
+
+    info!("User {} added leave {} in guild {}: {} to {}", user_id, id, guild_id, leave.start_date, leave.end_date);
+    let mut msg = tr_args(locale, "leave-added", &[("dates", &format_dates(start, end, locale)), ("id", &id.to_string())]);
+    if leave.reason.is_some() {
+        msg.push('\n');
+        msg.push_str(&tr(locale, if team_sees_reason { "leave-reason-team" } else { "leave-reason-private" }));
+    }
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Remove one of the user's own leave entries
+pub async fn remove(ctx: Context<'_>, id: i64) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match ctx.guild_id() {
+        Some(gid) => gid.to_string(),
+        None => {
+            ctx.say(tr(locale, "leave-server-only")).await?;
+            return Ok(());
+        }
+    };
+    let user_id = ctx.author().id.to_string();
+    let removed = ctx.data().db.remove_leave(&guild_id, &user_id, id).await?;
+    ctx.data().boards.request();
+
+    let key = if removed == 0 { "leave-remove-none" } else { "leave-removed" };
+    if removed > 0 {
+        info!("User {} removed leave {} in guild {}", user_id, id, guild_id);
+    }
+    ctx.send(poise::CreateReply::default()
+        .content(tr_args(locale, key, &[("id", &id.to_string())]))
+        .ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Show who's out this week and next, and the public holidays in that time
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match ctx.guild_id() {
+        Some(gid) => gid.to_string(),
+        None => {
+            ctx.say(tr(locale, "leave-server-only")).await?;
+            return Ok(());
+        }
+    };
+    let user_id = ctx.author().id.to_string();
+    let settings = ctx.data().db.get_user_settings(&user_id).await?;
+    let today = chrono::Utc::now().with_timezone(&settings.tz()).date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let sunday = monday + Duration::days(13);
+    let (from, to) = (monday.format("%Y-%m-%d").to_string(), sunday.format("%Y-%m-%d").to_string());
+
+    let leave = ctx.data().db.get_leave_between(&guild_id, &from, &to).await?;
+    let holidays = ctx.data().db.get_holidays_between(&guild_id, &from, &to).await?;
+
+    let mut response = format!(
+        "{}\n",
+        tr_args(locale, "leave-list-title", &[("from", &format_date(monday, locale)), ("to", &format_date(sunday, locale))])
+    );
+    if leave.is_empty() {
+        response.push_str(&tr(locale, "leave-list-none"));
+        response.push('\n');
+    }
+    let mut own = false;
+    for entry in &leave {
+        let mut line = format!("  <@{}> {}", entry.discord_id, format_dates(parse(&entry.start_date), parse(&entry.end_date), locale));
+        let is_own = entry.discord_id == user_id;
+        if let Some(reason) = entry.reason.as_ref().filter(|_| is_own || entry.reason_public()) {
+            line.push_str(&format!(" · {}", reason));
+        }
+        if is_own {
+            line.push_str(&format!(" `#{}`", entry.id));
+            own = true;
+        }
+        response.push_str(&line);
+        response.push('\n');
+    }
+
+    if !holidays.is_empty() {
+        response.push('\n');
+        response.push_str(&tr(locale, "leave-list-holidays"));
+        response.push('\n');
+        for (date, name, regions) in group_holidays(&holidays) {
+            let line = tr_args(locale, "leave-holiday-line", &[
+                ("date", &format_date(parse(&date), locale)),
+                ("name", &name),
+                ("regions", &regions.join(", ")),
+            ]);
+            response.push_str(&format!("  {}\n", line));
+        }
+    }
+
+    if own {
+        response.push('\n');
+        response.push_str(&tr(locale, "leave-list-remove-hint"));
+    }
+
+    ctx.send(poise::CreateReply::default()
+        .content(response)
+        .allowed_mentions(CreateAllowedMentions::new())
+        .ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Lines for `/fabrica team`: who's out today (their own date) and today's holidays
+pub async fn team_section(db: &Database, guild_id: &str, viewer_today: NaiveDate, locale: &str) -> Result<String, Error> {
+    // Members' dates differ from the viewer's by at most a day
+    let from = (viewer_today - Duration::days(1)).format("%Y-%m-%d").to_string();
+    let to = (viewer_today + Duration::days(1)).format("%Y-%m-%d").to_string();
+
+    let mut lines = Vec::new();
+    for entry in db.get_leave_between(guild_id, &from, &to).await? {
+        let member_today = db.get_user_settings(&entry.discord_id).await?.today();
+        if entry.start_date > member_today || entry.end_date < member_today {
+            continue;
+        }
+        let mut line = format!("  <@{}> {}", entry.discord_id, tr_args(locale, "team-until", &[("time", &format_date(parse(&entry.end_date), locale))]));
+        if let Some(reason) = entry.reason.as_ref().filter(|_| entry.reason_public()) {
+            line.push_str(&format!(" - {}", reason));
+        }
+        lines.push(line);
+    }
+    let today = viewer_today.format("%Y-%m-%d").to_string();
+    for (_, name, regions) in group_holidays(&db.get_holidays_between(guild_id, &today, &today).await?) {
+        let line = tr_args(locale, "team-holiday", &[("name", &name), ("regions", &regions.join(", "))]);
+        lines.push(format!("  {}", line));
+    }
+
+    if lines.is_empty() {
+        return Ok(String::new());
+    }
+    Ok(format!("{}\n{}\n\n", tr(locale, "team-out"), lines.join("\n")))
+}
+
+/// Add or rename a public holiday for a region (server managers)
+pub async fn holiday_add(ctx: Context<'_>, region: String, date: String, name: String) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some((guild_id, region, day)) = holiday_target(ctx, locale, &region, &date).await? else {
+        return Ok(());
+    };
+    let holiday = Holiday {
+        guild_id: guild_id.clone(),
+        region: region.clone(),
+        date: day.format("%Y-%m-%d").to_string(),
+        name: name.trim().to_string(),
+    };
+    ctx.data().db.set_holiday(&holiday, &ctx.author().id.to_string()).await?;
+
+    info!("User {} set {} holiday {} in guild {}: {}", ctx.author().id, region, holiday.date, guild_id, holiday.name);
+    ctx.say(tr_args(locale, "holiday-set", &[("name", &holiday.name), ("date", &format_date(day, locale)), ("region", &region)])).await?;
+    Ok(())
+}
+
+/// Remove a region's public holiday (server managers)
+pub async fn holiday_remove(ctx: Context<'_>, region: String, date: String) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some((guild_id, region, day)) = holiday_target(ctx, locale, &region, &date).await? else {
+        return Ok(());
+    };
+    let removed = ctx.data().db.remove_holiday(&guild_id, &region, &day.format("%Y-%m-%d").to_string()).await?;
+
+    let key = if removed == 0 { "holiday-remove-none" } else { "holiday-removed" };
+    if removed > 0 {
+        info!("User {} removed {} holiday {} in guild {}", ctx.author().id, region, day, guild_id);
+    }
+    ctx.say(tr_args(locale, key, &[("region", &region), ("date", &format_date(day, locale))])).await?;
+    Ok(())
+}
+
+/// Check permission and parse the region and date of a holiday change,
+/// replying with the problem if there is one
+async fn holiday_target(ctx: Context<'_>, locale: &str, region: &str, date: &str) -> Result<Option<(String, String, NaiveDate)>, Error> {
+    let Some(guild_id) = ctx.guild_id().map(|g| g.to_string()) else {
+        ctx.say(tr(locale, "leave-server-only")).await?;
+        return Ok(None);
+    };
+    if !has_admin_permission(&ctx, &guild_id).await {
+        ctx.send(poise::CreateReply::default().content(tr(locale, "holiday-admin-only")).ephemeral(true)).await?;
+        return Ok(None);
+    }
+    let Some(region) = normalize_region(region) else {
+        ctx.send(poise::CreateReply::default().content(tr(locale, "holiday-invalid-region")).ephemeral(true)).await?;
+        return Ok(None);
+    };
+    let today = chrono::Utc::now().date_naive();
+    let Some(day) = schedule::parse_date(date, today) else {
+        ctx.send(poise::CreateReply::default()
+            .content(tr_args(locale, "holiday-invalid-date", &[("date", date.trim())]))
+            .ephemeral(true)).await?;
+        return Ok(None);
+    };
+    Ok(Some((guild_id, region, day)))
+}
+
+/// Upper-case a region code such as "in" or "ca-qc", if it looks like one
+pub(crate) fn normalize_region(input: &str) -> Option<String> {
+    let region = input.trim().to_uppercase();
+    let valid = (2..=6).contains(&region.len())
+        && region.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && region.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    valid.then_some(region)
+}
+
+/// Holidays on the same date with the same name, merged across regions
+fn group_holidays(holidays: &[Holiday]) -> Vec<(String, String, Vec<String>)> {
+    let mut grouped: Vec<(String, String, Vec<String>)> = Vec::new();
+    for holiday in holidays {
+        match grouped.iter_mut().find(|(date, name, _)| *date == holiday.date && *name == holiday.name) {
+            Some((_, _, regions)) => regions.push(holiday.region.clone()),
+            None => grouped.push((holiday.date.clone(), holiday.name.clone(), vec![holiday.region.clone()])),
+        }
+    }
+    grouped
+}
+
+fn parse(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_default()
+}
+
+/// "Mon 21 Dec"
+fn format_date(date: NaiveDate, locale: &str) -> String {
+    let day = tr(locale, &format!("day-short-{}", schedule::weekday_index(date)));
+    format!("{} {}", day, format_day_month(date, locale))
+}
+
+/// "21 Dec" or "21 Dec – 3 Jan"
+fn format_dates(start: NaiveDate, end: NaiveDate, locale: &str) -> String {
+    if start == end {
+        format_day_month(start, locale)
+    } else {
+        format!("{} – {}", format_day_month(start, locale), format_day_month(end, locale))
+    }
+}
+
+/// "21 Dec" with the month name in the reader's language
+fn format_day_month(date: NaiveDate, locale: &str) -> String {
+    format!("{} {}", date.day(), tr(locale, &format!("month-short-{}", date.month())))
+}
@@ -0,0 +1,464 @@
+//! Mirror channels - Linked channels that repost each other in their own language
+//!
+//! Channels linked under the same name form a group. A message posted in one
+//! is translated into every other channel's language and reposted there
+//! through a webhook under the author's name and avatar. Edits and deletions
+//! of the original are applied to its copies, and replies point at the copy
+//! of the replied-to message in each channel.
+
+use crate::bot::{Context, Data, Discord, Error};
+use crate::db::{MirrorChannel, TranslationPreferences};
+use crate::modules::translation::{
+    get_guild_id, has_translation_permission, is_supported_language, localized_language_name, normalize_language, with_glossary,
+};
+use crate::services::i18n::{locale_for, supported_locale, tr, tr_args, DEFAULT_LOCALE};
+use crate::services::messages::{fit_message, fit_username};
+use poise::serenity_prelude::{
+    Builder, ChannelId, CreateAllowedMentions, CreateWebhook, EditWebhookMessage, ExecuteWebhook,
+    GuildId, Message, MessageId, WebhookId,
+};
+use tracing::{debug, info, warn};
+
+/// Name of the webhook created in each mirror channel
+const WEBHOOK_NAME: &str = "Fabrica Mirror";
+
+/// Repost a new message into the other channels of its mirror group
+pub async fn handle_message(
+    ctx: &Discord,
+    message: &Message,
+    data: &Data,
+    guild_id: &str,
+) -> Result<(), Error> {
+    let channel_id = message.channel_id.to_string();
+    let Some(source) = data.db.get_mirror_channel(guild_id, &channel_id).await? else {
+        debug!("Channel {} is in mirror mode but not linked", channel_id);
+        return Ok(());
+    };
+
+    if message.content.trim().is_empty() && message.attachments.is_empty() {
+        return Ok(());
+    }
+
+    let peers = data.db.get_mirror_group(guild_id, &source.link_name).await?;
+    for peer in peers.iter().filter(|p| p.channel_id != source.channel_id) {
+        let content = mirrored_content(message, &source, peer, data).await;
+        let builder = ExecuteWebhook::new()
+            .content(content)
+            .username(author_name(message))
+            .avatar_url(message.author.face())
+            .allowed_mentions(CreateAllowedMentions::new());
+
+        match builder.execute(ctx, (webhook_id(peer)?, peer.webhook_token.as_str(), true)).await {
+            Ok(Some(copy)) => {
+                data.db
+                    .add_mirror_message(&message.id.to_string(), &channel_id, &peer.channel_id, &copy.id.to_string())
+                    .await?;
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to mirror message {} into channel {}: {}", message.id, peer.channel_id, e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-translate the copies of an edited message
+pub async fn handle_edit(
+    ctx: &Discord,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    data: &Data,
+) -> Result<(), Error> {
+    let copies = data.db.get_mirror_copies(&message_id.to_string()).await?;
+    if copies.is_empty() {
+        return Ok(());
+    }
+
+    let guild_id = guild_id.to_string();
+    let Some(source) = data.db.get_mirror_channel(&guild_id, &channel_id.to_string()).await? else {
+        return Ok(());
+    };
+
+    let message = channel_id.message(ctx, message_id).await?;
+
+    for (copy_channel, copy_id) in copies {
+        let Some(peer) = data.db.get_mirror_channel(&guild_id, &copy_channel).await? else {
+            continue;
+        };
+        let Ok(copy_id) = copy_id.parse::<u64>() else { continue };
+
+        let content = mirrored_content(&message, &source, &peer, data).await;
+        let builder = EditWebhookMessage::new()
+            .content(content)
+            .allowed_mentions(CreateAllowedMentions::new());
+        if let Err(e) = builder
+            .execute(ctx, (webhook_id(&peer)?, peer.webhook_token.as_str(), MessageId::new(copy_id)))
+            .await
+        {
+            warn!("Failed to update mirrored copy {} in channel {}: {}", copy_id, peer.channel_id, e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove the copies of a deleted message
+pub async fn handle_delete(
+    ctx: &Discord,
+    guild_id: GuildId,
+    message_id: MessageId,
+    data: &Data,
+) -> Result<(), Error> {
+    let source_id = message_id.to_string();
+    let copies = data.db.get_mirror_copies(&source_id).await?;
+    if copies.is_empty() {
+        return Ok(());
+    }
+
+    let guild_id = guild_id.to_string();
+    for (copy_channel, copy_id) in copies {
+        let Some(peer) = data.db.get_mirror_channel(&guild_id, &copy_channel).await? else {
+            continue;
+        };
+        let Ok(copy_id) = copy_id.parse::<u64>() else { continue };
+
+        if let Err(e) = ctx
+            .http
+            .delete_webhook_message(webhook_id(&peer)?, None, &peer.webhook_token, MessageId::new(copy_id))
+            .await
+        {
+            warn!("Failed to delete mirrored copy {} in channel {}: {}", copy_id, peer.channel_id, e);
+        }
+    }
+
+    data.db.remove_mirror_messages(&source_id).await?;
+    Ok(())
+}
+
+/// Build the text posted into `peer`: reply header, translation, attachment links
+async fn mirrored_content(message: &Message, source: &MirrorChannel, peer: &MirrorChannel, data: &Data) -> String {
+    let mut lines = Vec::new();
+
+    if let Some(header) = reply_header(message, peer, data).await {
+        lines.push(header);
+    }
+
+    let text = message.content.trim();
+    if !text.is_empty() {
+        let prefs = with_glossary(data, &source.guild_id, &source.language, &peer.language, TranslationPreferences::default()).await;
+        match data.translator.translate_with_preferences(text, &source.language, &peer.language, &prefs).await {
+            Ok(Some(translated)) => lines.push(translated),
+            // Already in the peer's language
+            Ok(None) => lines.push(text.to_string()),
+            Err(e) => {
+                warn!("Mirror translation {} -> {} failed: {}", source.language, peer.language, e);
+                lines.push(text.to_string());
+            }
+        }
+    }
+
+    lines.extend(message.attachments.iter().map(|a| a.url.clone()));
+    fit_message(&lines.join("\n"))
+}
+
+/// "Replying to" line linking to the replied-to message's counterpart in
+/// `peer`, in the peer channel's language where there's a catalog for it
+async fn reply_header(message: &Message, peer: &MirrorChannel, data: &Data) -> Option<String> {
+    let replied_id = message.message_reference.as_ref()?.message_id?;
+    let locale = supported_locale(&peer.language).unwrap_or(DEFAULT_LOCALE);
+    let name = match &message.referenced_message {
+        Some(replied) => replied.author.display_name().to_string(),
+        None => tr(locale, "mirror-reply-someone"),
+    };
+
+    match counterpart(data, replied_id, message.channel_id, peer).await {
+        Some(target) => {
+            let link = format!("https://discord.com/channels/{}/{}/{}", peer.guild_id, peer.channel_id, target);
+            Some(tr_args(locale, "mirror-replying-to-link", &[("name", &name), ("link", &link)]))
+        }
+        None => Some(tr_args(locale, "mirror-replying-to", &[("name", &name)])),
+    }
+}
+
+/// Find the message in `peer` corresponding to `replied_id`, which is either
+/// an original or one of Fabrica's copies
+async fn counterpart(data: &Data, replied_id: MessageId, channel_id: ChannelId, peer: &MirrorChannel) -> Option<String> {
+    let replied_id = replied_id.to_string();
+    let (original, original_channel) = match data.db.get_mirror_source(&replied_id).await.ok()? {
+        Some(source) => source,
+        None => (replied_id, channel_id.to_string()),
+    };
+
+    if original_channel == peer.channel_id {
+        return Some(original);
+    }
+
+    data.db
+        .get_mirror_copies(&original)
+        .await
+        .ok()?
+        .into_iter()
+        .find(|(channel, _)| *channel == peer.channel_id)
+        .map(|(_, id)| id)
+}
+
+/// Server nickname if known, otherwise the author's display name
+fn author_name(message: &Message) -> String {
+    let name = message
+        .member
+        .as_ref()
+        .and_then(|m| m.nick.clone())
+        .unwrap_or_else(|| message.author.display_name().to_string());
+    fit_username(&name)
+}
+
+fn webhook_id(channel: &MirrorChannel) -> Result<WebhookId, Error> {
+    Ok(WebhookId::new(channel.webhook_id.parse()?))
+}
+
+// ==================== Commands ====================
+
+/// Link this channel into a mirror group with the language it's written in
+pub async fn link(ctx: Context<'_>, link_name: String, language: String) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match get_guild_id(&ctx) {
+        Some(gid) => gid,
+        None => {
+            ctx.say(tr(locale, "translate-settings-server-only")).await?;
+            return Ok(());
+        }
+    };
+
+    if !has_translation_permission(&ctx, &guild_id, "mode").await {
+        ctx.say(tr(locale, "translate-no-permission")).await?;
+        return Ok(());
+    }
+
+    let link_name = link_name.trim().to_lowercase();
+    if link_name.is_empty() || link_name.chars().count() > 32 {
+        ctx.say(tr(locale, "mirror-invalid-name")).await?;
+        return Ok(());
+    }
+
+    let lang_code = normalize_language(&language);
+    if !is_supported_language(&lang_code) {
+        ctx.say(tr_args(locale, "translate-unsupported", &[("language", &language)])).await?;
+        return Ok(());
+    }
+
+    let channel_id = ctx.channel_id();
+    let existing = ctx.data().db.get_mirror_channel(&guild_id, &channel_id.to_string()).await?;
+
+    // Keep the webhook from a previous link so existing copies stay editable
+    let (webhook_id, webhook_token) = match existing {
+        Some(existing) => (existing.webhook_id, existing.webhook_token),
+        None => {
+            let webhook = match channel_id.create_webhook(ctx, CreateWebhook::new(WEBHOOK_NAME)).await {
+                Ok(webhook) => webhook,
+                Err(e) => {
+                    warn!("Failed to create mirror webhook in channel {}: {}", channel_id, e);
+                    ctx.say(tr(locale, "mirror-webhook-failed")).await?;
+                    return Ok(());
+                }
+            };
+            let url = webhook.url()?;
+            let token = url.rsplit('/').next().unwrap_or_default().to_string();
+            (webhook.id.to_string(), token)
+        }
+    };
+
+    let channel = MirrorChannel {
+        guild_id: guild_id.clone(),
+        link_name: link_name.clone(),
+        channel_id: channel_id.to_string(),
+        language: lang_code.clone(),
+        webhook_id,
+        webhook_token,
+    };
+    let set_by = ctx.author().id.to_string();
+    ctx.data().db.link_mirror_channel(&channel, &set_by).await?;
+    ctx.data().db.set_channel_translation_mode(&guild_id, &channel.channel_id, "mirror", &set_by).await?;
+
+    info!("Channel {} linked to mirror group '{}' as {} by {} (guild {})", channel_id, link_name, lang_code, set_by, guild_id);
+
+    let group = ctx.data().db.get_mirror_group(&guild_id, &link_name).await?;
+    let mut msg = tr_args(locale, "mirror-linked", &[
+        ("name", &link_name),
+        ("language", &localized_language_name(locale, &lang_code)),
+        ("channels", &format_group(locale, &group)),
+    ]);
+    if group.len() < 2 {
+        msg.push_str("\n\n");
+        msg.push_str(&tr_args(locale, "mirror-link-another", &[("name", &link_name)]));
+    }
+    ctx.say(msg).await?;
+    Ok(())
+}
+
+/// Remove this channel from its mirror group and turn translation off
+pub async fn unlink(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match get_guild_id(&ctx) {
+        Some(gid) => gid,
+        None => {
+            ctx.say(tr(locale, "translate-settings-server-only")).await?;
+            return Ok(());
+        }
+    };
+
+    if !has_translation_permission(&ctx, &guild_id, "mode").await {
+        ctx.say(tr(locale, "translate-no-permission")).await?;
+        return Ok(());
+    }
+
+    let channel_id = ctx.channel_id().to_string();
+    let Some(channel) = ctx.data().db.get_mirror_channel(&guild_id, &channel_id).await? else {
+        ctx.say(tr(locale, "mirror-not-linked")).await?;
+        return Ok(());
+    };
+
+    if let Ok(id) = webhook_id(&channel) {
+        if let Err(e) = ctx.http().delete_webhook_with_token(id, &channel.webhook_token, None).await {
+            debug!("Failed to delete mirror webhook {}: {}", channel.webhook_id, e);
+        }
+    }
+
+    let set_by = ctx.author().id.to_string();
+    ctx.data().db.unlink_mirror_channel(&guild_id, &channel_id).await?;
+    ctx.data().db.set_channel_translation_mode(&guild_id, &channel_id, "off", &set_by).await?;
+
+    info!("Channel {} unlinked from mirror group '{}' by {} (guild {})", channel_id, channel.link_name, set_by, guild_id);
+
+    ctx.say(tr_args(locale, "mirror-unlinked", &[("name", &channel.link_name)])).await?;
+    Ok(())
+}
+
+/// Describe a channel's mirror group for `/fabrica translate info`
+pub async fn describe(data: &Data, locale: &str, guild_id: &str, channel_id: &str) -> Result<String, Error> {
+    match data.db.get_mirror_channel(guild_id, channel_id).await? {
+        Some(channel) => {
+            let group = data.db.get_mirror_group(guild_id, &channel.link_name).await?;
+            Ok(tr_args(locale, "mirror-describe", &[
+                ("name", &channel.link_name),
+                ("language", &localized_language_name(locale, &channel.language)),
+                ("channels", &format_group(locale, &group)),
+            ]))
+        }
+        None => Ok(tr(locale, "mirror-describe-unlinked")),
+    }
+}
+
+fn format_group(locale: &str, group: &[MirrorChannel]) -> String {
+    let channels: Vec<String> = group
+        .iter()
+        .map(|c| format!("<#{}> ({})", c.channel_id, localized_language_name(locale, &c.language)))
+        .collect();
+    tr_args(locale, "mirror-channels", &[("channels", &channels.join(", "))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockDiscord};
+    use axum::http::Method;
+    use serde_json::json;
+
+    /// Mirror group "team": 10 in English, 11 in Hindi and 12 in French
+    async fn mirror_data(name: &str) -> Data {
+        let db = testing::database(name).await;
+        let guild = testing::GUILD_ID.to_string();
+        for (channel, language) in [("10", "en"), ("11", "hi"), ("12", "fr")] {
+            let mirror = MirrorChannel {
+                guild_id: guild.clone(),
+                link_name: "team".to_string(),
+                channel_id: channel.to_string(),
+                language: language.to_string(),
+                webhook_id: format!("7{}", channel),
+                webhook_token: format!("token-{}", channel),
+            };
+            db.link_mirror_channel(&mirror, "admin").await.unwrap();
+            db.set_channel_translation_mode(&guild, channel, "mirror", "admin").await.unwrap();
+        }
+        testing::data(db, testing::translator(|text| format!("[{}]", text)))
+    }
+
+    #[tokio::test]
+    async fn test_edit_updates_each_copy() {
+        let discord = MockDiscord::start().await;
+        let data = mirror_data("mirror-edit").await;
+        data.db.add_mirror_message("500", "10", "11", "600").await.unwrap();
+        data.db.add_mirror_message("500", "10", "12", "601").await.unwrap();
+
+        let mut original = Message::default();
+        original.id = MessageId::new(500);
+        original.channel_id = ChannelId::new(10);
+        original.content = "Standup moves to 10am".to_string();
+        discord.add_message(&original);
+
+        let guild_id = GuildId::new(testing::GUILD_ID);
+        handle_edit(&discord.discord, guild_id, ChannelId::new(10), MessageId::new(500), &data).await.unwrap();
+
+        let mut edits: Vec<(String, serde_json::Value)> = discord
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == Method::PATCH)
+            .map(|r| (r.path, r.body["content"].clone()))
+            .collect();
+        edits.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            edits,
+            vec![
+                ("/webhooks/711/token-11/messages/600".to_string(), json!("[Standup moves to 10am]")),
+                ("/webhooks/712/token-12/messages/601".to_string(), json!("[Standup moves to 10am]")),
+            ]
+        );
+
+        // Messages that were never mirrored aren't fetched
+        let before = discord.requests().len();
+        handle_edit(&discord.discord, guild_id, ChannelId::new(10), MessageId::new(501), &data).await.unwrap();
+        assert_eq!(discord.requests().len(), before);
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_copies_and_mapping() {
+        let discord = MockDiscord::start().await;
+        let data = mirror_data("mirror-delete").await;
+        data.db.add_mirror_message("500", "10", "11", "600").await.unwrap();
+        data.db.add_mirror_message("500", "10", "12", "601").await.unwrap();
+
+        handle_delete(&discord.discord, GuildId::new(testing::GUILD_ID), MessageId::new(500), &data).await.unwrap();
+
+        let mut deleted: Vec<String> = discord
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == Method::DELETE)
+            .map(|r| r.path)
+            .collect();
+        deleted.sort();
+        assert_eq!(deleted, vec!["/webhooks/711/token-11/messages/600", "/webhooks/712/token-12/messages/601"]);
+        assert!(data.db.get_mirror_copies("500").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reply_counterpart_in_each_channel() {
+        let data = mirror_data("mirror-counterpart").await;
+        data.db.add_mirror_message("500", "10", "11", "600").await.unwrap();
+        data.db.add_mirror_message("500", "10", "12", "601").await.unwrap();
+        let mut peers = Vec::new();
+        for channel in ["10", "11", "12"] {
+            peers.push(data.db.get_mirror_channel("1", channel).await.unwrap().unwrap());
+        }
+        let (en, hi, fr) = (&peers[0], &peers[1], &peers[2]);
+
+        // Replying to the original points at its copies
+        let original = MessageId::new(500);
+        assert_eq!(counterpart(&data, original, ChannelId::new(10), hi).await.as_deref(), Some("600"));
+        // Replying to a copy points at the original and the other copies
+        let copy = MessageId::new(600);
+        assert_eq!(counterpart(&data, copy, ChannelId::new(11), en).await.as_deref(), Some("500"));
+        assert_eq!(counterpart(&data, copy, ChannelId::new(11), fr).await.as_deref(), Some("601"));
+        // Messages from before the link have no counterpart
+        assert_eq!(counterpart(&data, MessageId::new(499), ChannelId::new(10), fr).await, None);
+    }
+}
@@ -1,6 +1,16 @@
-//! Fabrica modules - Translation, Status, Plane, GitHub
+//! Fabrica modules - Translation, Mirror channels, DM replies, Corrections, Status, Status expiry, Auto status, Overlap, Leave, Calendar import, Standup, Status board, Plane, GitHub
 
+pub mod auto_status;
+pub mod board;
+pub mod calendar_import;
+pub mod corrections;
+pub mod dm_reply;
+pub mod expiry;
 pub mod github;
+pub mod leave;
+pub mod mirror;
+pub mod overlap;
 pub mod plane;
+pub mod standup;
 pub mod status;
 pub mod translation;
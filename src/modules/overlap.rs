@@ -0,0 +1,149 @@
+//! Overlap - When people's working hours line up
+//!
+//! `/fabrica overlap @a @b [week]` intersects everyone's hours in this server
+//! (weekly schedules and day overrides, each in the owner's timezone). Shared
+//! windows are listed longest first in the invoker's timezone and time format,
+//! and the best few are suggested as Discord timestamps so every reader sees
+//! them in their own time.
+
+use crate::bot::{Context, Error};
+use crate::modules::status::format_time_for_user;
+use crate::services::i18n::{locale_for, tr, tr_args};
+use crate::services::schedule::{self, windows_on, Window};
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::{CreateAllowedMentions, User};
+use tracing::info;
+
+/// How far ahead to look for shared hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum Period {
+    /// The rest of today
+    #[name = "today"]
+    Today,
+    /// The next 7 days
+    #[name = "week"]
+    Week,
+}
+
+/// Shared windows listed at most
+const MAX_WINDOWS: usize = 10;
+
+/// Shared windows suggested as meeting slots
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Show shared working hours for today, or the next 7 days with `week`
+pub async fn overlap(ctx: Context<'_>, users: Vec<User>, period: Period) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match ctx.guild_id() {
+        Some(gid) => gid.to_string(),
+        None => {
+            ctx.say(tr(locale, "overlap-server-only")).await?;
+            return Ok(());
+        }
+    };
+
+    let mut ids: Vec<String> = Vec::new();
+    for user in &users {
+        let id = user.id.to_string();
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    if ids.len() < 2 {
+        ctx.send(poise::CreateReply::default().content(tr(locale, "overlap-need-two")).ephemeral(true)).await?;
+        return Ok(());
+    }
+
+    let db = &ctx.data().db;
+    let viewer = db.get_user_settings(&ctx.author().id.to_string()).await?;
+    let viewer_tz = viewer.tz();
+    let now = Utc::now();
+    let week = period == Period::Week;
+    let days = if week { 7 } else { 1 };
+    let end_date = now.with_timezone(&viewer_tz).date_naive() + Duration::days(days);
+    let to = schedule::local_instant(viewer_tz, end_date, "00:00").unwrap_or(now + Duration::days(days));
+
+    // Each person's hours on their own dates covering the period, starting a day
+    // early for overnight shifts still running
+    let guild_ids = [guild_id.clone()];
+    let mut people = Vec::new();
+    let mut off = Vec::new();
+    for id in &ids {
+        let tz = db.get_user_settings(id).await?.tz();
+        let first = now.with_timezone(&tz).date_naive() - Duration::days(1);
+        let last = to.with_timezone(&tz).date_naive();
+        let dates: Vec<_> = first.iter_days().take_while(|date| *date <= last).collect();
+        let windows = windows_on(db, &guild_ids, id, tz, &dates).await;
+        if schedule::shared_windows(std::slice::from_ref(&windows), now, to).is_empty() {
+            off.push(format!("<@{}>", id));
+        }
+        people.push(windows);
+    }
+    let shared = schedule::shared_windows(&people, now, to);
+
+    let mentions = ids.iter().map(|id| format!("<@{}>", id)).collect::<Vec<_>>().join(", ");
+    let title = if week { "overlap-title-week" } else { "overlap-title-today" };
+    let mut response = format!(
+        "{}\n{}\n\n",
+        tr_args(locale, title, &[("users", &mentions)]),
+        tr_args(locale, "overlap-timezone", &[("timezone", &viewer.timezone)])
+    );
+
+    if shared.is_empty() {
+        response.push_str(&tr(locale, "overlap-none"));
+        response.push('\n');
+    } else {
+        for window in shared.iter().take(MAX_WINDOWS) {
+            response.push_str(&format!("  {}\n", format_window(window, &viewer, locale)));
+        }
+        response.push('\n');
+        response.push_str(&tr(locale, "overlap-suggest"));
+        response.push('\n');
+        for (start, end) in shared.iter().take(MAX_SUGGESTIONS) {
+            response.push_str(&format!("  <t:{}:F> – <t:{}:t>\n", start.timestamp(), end.timestamp()));
+        }
+    }
+
+    if !off.is_empty() {
+        response.push('\n');
+        response.push_str(&tr_args(locale, "overlap-off", &[("users", &off.join(", "))]));
+    }
+
+    info!("User {} checked overlap of {} people in guild {}", ctx.author().id, ids.len(), guild_id);
+    ctx.send(
+        poise::CreateReply::default()
+            .content(response)
+            .allowed_mentions(CreateAllowedMentions::new()),
+    )
+    .await?;
+    Ok(())
+}
+
+/// One shared window in the viewer's timezone, e.g. "Mon 15:00 – 18:00 (3h)"
+fn format_window((start, end): &Window, viewer: &crate::db::UserSettings, locale: &str) -> String {
+    let tz = viewer.tz();
+    let (local_start, local_end) = (start.with_timezone(&tz), end.with_timezone(&tz));
+    let mut end_time = format_time_for_user(&local_end.format("%H:%M").to_string(), viewer);
+    let days = (local_end.date_naive() - local_start.date_naive()).num_days();
+    if days > 0 {
+        end_time.push_str(&format!(" +{}d", days));
+    }
+
+    let day = tr(locale, &format!("day-short-{}", schedule::weekday_index(local_start.date_naive())));
+    tr_args(locale, "overlap-window", &[
+        ("day", &day),
+        ("start", &format_time_for_user(&local_start.format("%H:%M").to_string(), viewer)),
+        ("end", &end_time),
+        ("length", &format_length(*start, *end)),
+    ])
+}
+
+/// A duration as "3h", "45m" or "2h 30m"
+fn format_length(start: DateTime<Utc>, end: DateTime<Utc>) -> String {
+    let minutes = (end - start).num_minutes();
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
@@ -4,19 +4,14 @@
 
 use crate::bot::{Context, Error};
 use crate::db::WatchLevel;
+use crate::services::i18n::{locale_for, tr, tr_args};
 use tracing::info;
 
 /// Show project overview
 pub async fn project(ctx: Context<'_>, name: String) -> Result<(), Error> {
     // TODO: Implement Plane API client
     // For now, return a placeholder
-    ctx.say(format!(
-        "📊 **{}**\n\n\
-         ⚠️ Plane integration coming soon!\n\
-         This will show project status, sprint progress, and open issues.",
-        name
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "plane-project", &[("project", &name)])).await?;
 
     Ok(())
 }
@@ -27,36 +22,28 @@ pub async fn issues(
     project: Option<String>,
     status_filter: Option<String>,
 ) -> Result<(), Error> {
-    let project_name = project.unwrap_or_else(|| "all projects".to_string());
+    let locale = locale_for(ctx).await;
+    let project_name = project.unwrap_or_else(|| tr(locale, "plane-all-projects"));
     let filter = status_filter.unwrap_or_else(|| "open".to_string());
 
-    ctx.say(format!(
-        "📋 **Issues for {}** (filter: {})\n\n\
-         ⚠️ Plane integration coming soon!",
-        project_name, filter
-    ))
-    .await?;
+    ctx.say(tr_args(locale, "plane-issues", &[("project", &project_name), ("filter", &filter)])).await?;
 
     Ok(())
 }
 
 /// Show sprint status
 pub async fn sprint(ctx: Context<'_>, project: Option<String>) -> Result<(), Error> {
-    let project_name = project.unwrap_or_else(|| "current".to_string());
+    let locale = locale_for(ctx).await;
+    let project_name = project.unwrap_or_else(|| tr(locale, "plane-current-project"));
 
-    ctx.say(format!(
-        "🏃 **Sprint Status** for {}\n\n\
-         ⚠️ Plane integration coming soon!",
-        project_name
-    ))
-    .await?;
+    ctx.say(tr_args(locale, "plane-sprint", &[("project", &project_name)])).await?;
 
     Ok(())
 }
 
 /// Watch a Plane project in this channel
 pub async fn watch(ctx: Context<'_>, project: String, level: String) -> Result<(), Error> {
-    let level = WatchLevel::from_str(&level).unwrap_or(WatchLevel::Important);
+    let level = WatchLevel::parse(&level).unwrap_or(WatchLevel::Important);
     let channel_id = ctx.channel_id().to_string();
 
     ctx.data()
@@ -69,13 +56,7 @@ pub async fn watch(ctx: Context<'_>, project: String, level: String) -> Result<(
         channel_id, project, level
     );
 
-    ctx.say(format!(
-        "✅ This channel is now watching **{}** at **{}** level.\n\
-         You'll receive notifications about project activity.",
-        project,
-        level.as_str()
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "plane-watching", &[("project", &project), ("level", level.as_str())])).await?;
 
     Ok(())
 }
@@ -94,11 +75,7 @@ pub async fn unwatch(ctx: Context<'_>, project: String) -> Result<(), Error> {
         channel_id, project
     );
 
-    ctx.say(format!(
-        "✅ This channel is no longer watching **{}**.",
-        project
-    ))
-    .await?;
+    ctx.say(tr_args(locale_for(ctx).await, "watch-stopped", &[("name", &project)])).await?;
 
     Ok(())
 }
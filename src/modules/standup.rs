@@ -0,0 +1,547 @@
+//! Standup - Async daily check-ins
+//!
+//! Admins pick the participants, the questions, the weekdays and when the
+//! summary is posted. Each participant is DMed when their own working hours
+//! start (people without hours are asked at 09:00 their time, and nobody is
+//! asked on leave or a holiday). They answer in a modal, get one nudge if they
+//! haven't by a couple of hours later, and the summary is posted in the
+//! standup channel, with a translation for each language read there.
+
+use crate::bot::{Context, Data, Error};
+use crate::db::{StandupConfig, StandupEntry};
+use crate::modules::status::days_to_names;
+use crate::modules::translation::{channel_category, detect_language, has_admin_permission, language_name, with_glossary};
+use crate::services::i18n::{locale_for, locale_for_user, supported_locale, tr, tr_args, DEFAULT_LOCALE};
+use crate::services::messages::MAX_CONTENT_CHARS;
+use crate::services::schedule::{self, is_day_off, parse_days, parse_time, windows_on, Window};
+use crate::services::standup::{self, Answered};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use poise::serenity_prelude::{
+    self as serenity, ActionRowComponent, ButtonStyle, ChannelId, ComponentInteraction, CreateActionRow,
+    CreateAllowedMentions, CreateButton, CreateInputText, CreateInteractionResponse, CreateInteractionResponseMessage,
+    CreateMessage, CreateModal, InputTextStyle, ModalInteraction, UserId,
+};
+use tracing::{debug, info, warn};
+
+/// Prefix of the answer button and modal custom IDs
+pub const CUSTOM_ID_PREFIX: &str = "fabrica-standup";
+
+/// How often standups are checked; prompts go out within this of hours starting
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Hours assumed for participants who haven't set any
+const DEFAULT_HOURS: (&str, &str) = ("09:00", "17:00");
+
+/// Summary time for a new standup
+const DEFAULT_SUMMARY_TIME: &str = "17:00";
+
+/// Discord's limit on a modal answer
+const MAX_ANSWER_CHARS: u16 = 1000;
+
+/// Start the periodic standup check
+pub fn spawn(ctx: serenity::Context, data: Data) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = check(&ctx, &data).await {
+                warn!("Standup check failed: {}", e);
+            }
+        }
+    });
+}
+
+/// Post due summaries, ask participants whose hours have started and nudge
+/// anyone who hasn't answered
+async fn check(ctx: &serenity::Context, data: &Data) -> Result<(), Error> {
+    let now = Utc::now();
+    for config in data.db.get_standup_configs().await? {
+        if !data.config.discord.guild_ids.contains(&config.guild_id) {
+            continue;
+        }
+        if let Err(e) = check_guild(ctx, data, &config, now).await {
+            warn!("Standup check for guild {} failed: {}", config.guild_id, e);
+        }
+    }
+    Ok(())
+}
+
+async fn check_guild(ctx: &serenity::Context, data: &Data, config: &StandupConfig, now: DateTime<Utc>) -> Result<(), Error> {
+    let tz = config.tz();
+    if let Some(run) = standup::due_run(&config.days, tz, &config.summary_time, now) {
+        if !data.db.is_standup_posted(&config.guild_id, &date_key(run)).await? {
+            post_summary(ctx, data, config, run).await?;
+        }
+    }
+
+    let Some(next) = standup::next_run(&config.days, tz, &config.summary_time, now) else {
+        return Ok(());
+    };
+    let Some(summary_at) = standup::summary_at(tz, &config.summary_time, next) else {
+        return Ok(());
+    };
+    for discord_id in data.db.get_standup_participants(&config.guild_id).await? {
+        ask_if_due(ctx, data, config, &discord_id, next, summary_at, now).await?;
+    }
+
+    for entry in data.db.get_standup_entries(&config.guild_id, &date_key(next)).await? {
+        let Some(prompted) = Utc.timestamp_opt(entry.prompted_at, 0).single() else {
+            continue;
+        };
+        if entry.answers.is_some() || entry.nudged_at.is_some() || !standup::nudge_due(prompted, summary_at, now) {
+            continue;
+        }
+        // Marked even if the DM fails, so closed DMs aren't retried every check
+        if let Err(e) = send_prompt(ctx, data, config, &entry, summary_at, true).await {
+            debug!("Couldn't nudge {} about their standup: {}", entry.discord_id, e);
+        }
+        data.db.mark_standup_nudged(&entry.guild_id, &entry.discord_id, &entry.local_date).await?;
+        info!("Nudged user {} about the standup in guild {}", entry.discord_id, entry.guild_id);
+    }
+    Ok(())
+}
+
+/// DM a participant their questions once their hours have started today
+async fn ask_if_due(
+    ctx: &serenity::Context,
+    data: &Data,
+    config: &StandupConfig,
+    discord_id: &str,
+    run: NaiveDate,
+    summary_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), Error> {
+    let tz = data.db.get_user_settings(discord_id).await?.tz();
+    let today = now.with_timezone(&tz).date_naive();
+    if !config.days.contains(&schedule::weekday_index(today)) {
+        return Ok(());
+    }
+    let local_date = date_key(today);
+    if data.db.get_standup_entry(&config.guild_id, discord_id, &local_date).await?.is_some() {
+        return Ok(());
+    }
+    if !schedule::in_hours(&hours_today(data, &config.guild_id, discord_id, tz, today).await, now) {
+        return Ok(());
+    }
+    if !data.db.add_standup_entry(&config.guild_id, discord_id, &local_date, &date_key(run)).await? {
+        return Ok(());
+    }
+
+    let entry = StandupEntry {
+        guild_id: config.guild_id.clone(),
+        discord_id: discord_id.to_string(),
+        local_date,
+        run_date: date_key(run),
+        prompted_at: now.timestamp(),
+        nudged_at: None,
+        answers: None,
+    };
+    match send_prompt(ctx, data, config, &entry, summary_at, false).await {
+        Ok(()) => info!("Asked user {} for their standup in guild {}", discord_id, config.guild_id),
+        Err(e) => debug!("Couldn't ask {} for their standup: {}", discord_id, e),
+    }
+    Ok(())
+}
+
+/// A participant's hours today, or the default hours if they have none set
+async fn hours_today(data: &Data, guild_id: &str, discord_id: &str, tz: Tz, today: NaiveDate) -> Vec<Window> {
+    let db = &data.db;
+    let weekly = db.get_weekly_schedule(guild_id, discord_id).await.unwrap_or_default();
+    let has_override = db.get_schedule_override(guild_id, discord_id, &date_key(today)).await.ok().flatten().is_some();
+    if !weekly.is_empty() || has_override {
+        return windows_on(db, &[guild_id.to_string()], discord_id, tz, &[today]).await;
+    }
+    let region = db.get_user_settings(discord_id).await.ok().and_then(|s| s.holiday_region);
+    if is_day_off(db, guild_id, discord_id, region.as_deref(), today).await {
+        return Vec::new();
+    }
+    schedule::window(tz, today, DEFAULT_HOURS.0, DEFAULT_HOURS.1).into_iter().collect()
+}
+
+/// DM the questions (or a reminder of them) with an "Answer" button
+async fn send_prompt(
+    ctx: &serenity::Context,
+    data: &Data,
+    config: &StandupConfig,
+    entry: &StandupEntry,
+    summary_at: DateTime<Utc>,
+    nudge: bool,
+) -> Result<(), Error> {
+    let locale = locale_for_user(&data.db, &entry.discord_id).await;
+    let questions = config.questions.iter().map(|q| format!("• {}", q)).collect::<Vec<_>>().join("\n");
+    let args = [
+        ("channel", format!("<#{}>", config.channel_id)),
+        ("questions", questions),
+        ("time", format!("<t:{}:t>", summary_at.timestamp())),
+    ];
+    let args: Vec<(&str, &str)> = args.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let content = tr_args(locale, if nudge { "standup-nudge" } else { "standup-prompt" }, &args);
+    let button = CreateButton::new(custom_id(&entry.guild_id, &entry.local_date))
+        .label(tr(locale, "standup-answer"))
+        .emoji('📝')
+        .style(ButtonStyle::Primary);
+
+    let channel = UserId::new(entry.discord_id.parse()?).create_dm_channel(ctx).await?;
+    channel
+        .send_message(ctx, CreateMessage::new().content(content).components(vec![CreateActionRow::Buttons(vec![button])]))
+        .await?;
+    Ok(())
+}
+
+/// Compile and post a summary, then a translation for each language read in the channel
+async fn post_summary(ctx: &serenity::Context, data: &Data, config: &StandupConfig, run: NaiveDate) -> Result<(), Error> {
+    let db = &data.db;
+    let key = date_key(run);
+    let entries = db.get_standup_entries(&config.guild_id, &key).await?;
+    let participants = db.get_standup_participants(&config.guild_id).await?;
+
+    let answered: Vec<Answered> = entries
+        .iter()
+        .filter_map(|e| e.answers.as_deref().map(|answers| Answered { discord_id: &e.discord_id, answers }))
+        .collect();
+    let missing: Vec<String> = entries.iter().filter(|e| e.answers.is_none()).map(|e| e.discord_id.clone()).collect();
+    let off: Vec<String> = participants
+        .into_iter()
+        .filter(|id| !entries.iter().any(|e| e.discord_id == *id))
+        .collect();
+    let compile = |locale: &str| {
+        let date = format!("{} {}", tr(locale, &format!("day-short-{}", schedule::weekday_index(run))), key);
+        standup::compile(locale, &date, &config.questions, &answered, &missing, &off)
+    };
+
+    let summary = compile(DEFAULT_LOCALE);
+    let channel_id = ChannelId::new(config.channel_id.parse()?);
+    let mut first = None;
+    for chunk in standup::split_message(&summary, MAX_CONTENT_CHARS) {
+        let message = CreateMessage::new().content(chunk).allowed_mentions(CreateAllowedMentions::new());
+        match channel_id.send_message(ctx, message).await {
+            Ok(sent) => {
+                first.get_or_insert(sent);
+            }
+            Err(e) => {
+                warn!("Failed to post standup summary in channel {}: {}", config.channel_id, e);
+                break;
+            }
+        }
+    }
+    // Recorded even if posting failed, so a missing permission isn't retried every minute
+    db.mark_standup_posted(&config.guild_id, &key, first.as_ref().map(|m| m.id.to_string()).as_deref()).await?;
+    info!("Posted standup summary for {} in guild {} ({} answered)", key, config.guild_id, answered.len());
+    let Some(first) = first else {
+        return Ok(());
+    };
+
+    let category_id = channel_category(ctx, channel_id).await;
+    let languages = db.get_channel_subscribed_languages(&config.guild_id, &config.channel_id, category_id.as_deref()).await?;
+    let source = detect_language(data, &summary).await;
+    for language in languages.into_iter().filter(|l| *l != source) {
+        let text = compile(supported_locale(&language).unwrap_or(DEFAULT_LOCALE));
+        let Some(translated) = translate_summary(data, &config.guild_id, &text, &source, &language).await else {
+            continue;
+        };
+        let text = format!("🌐 **{}**\n{}", language_name(&language), translated);
+        for chunk in standup::split_message(&text, MAX_CONTENT_CHARS) {
+            let reply = CreateMessage::new()
+                .content(chunk)
+                .reference_message(&first)
+                .allowed_mentions(CreateAllowedMentions::new());
+            if let Err(e) = channel_id.send_message(ctx, reply).await {
+                warn!("Failed to post {} standup summary: {}", language, e);
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Translate a summary one message-sized chunk at a time, so a long summary
+/// stays within what the translator handles well; `None` if any chunk failed
+async fn translate_summary(data: &Data, guild_id: &str, text: &str, source: &str, language: &str) -> Option<String> {
+    let prefs = with_glossary(data, guild_id, source, language, Default::default()).await;
+    let mut translated = Vec::new();
+    for chunk in standup::split_message(text, MAX_CONTENT_CHARS) {
+        match data.translator.translate_with_preferences(&chunk, source, language, &prefs).await {
+            Ok(Some(text)) => translated.push(text),
+            Ok(None) => translated.push(chunk),
+            Err(e) => {
+                warn!("Standup summary translation to {} failed: {}", language, e);
+                return None;
+            }
+        }
+    }
+    Some(translated.join("\n"))
+}
+
+/// Open the answer modal for a pressed "Answer" button
+pub async fn handle_component(ctx: &serenity::Context, interaction: &ComponentInteraction, data: &Data) -> Result<(), Error> {
+    let Some((guild_id, local_date)) = parse_custom_id(&interaction.data.custom_id) else {
+        return Ok(());
+    };
+    let user_id = interaction.user.id.to_string();
+    let locale = locale_for_user(&data.db, &user_id).await;
+
+    let open = open_standup(data, &guild_id, &user_id, &local_date).await?;
+    let Some((config, entry)) = open else {
+        let response = CreateInteractionResponseMessage::new().content(tr(locale, "standup-closed")).ephemeral(true);
+        interaction.create_response(ctx, CreateInteractionResponse::Message(response)).await?;
+        return Ok(());
+    };
+
+    let previous = entry.answers.unwrap_or_default();
+    let inputs = config
+        .questions
+        .iter()
+        .enumerate()
+        .map(|(i, question)| {
+            let mut input = CreateInputText::new(InputTextStyle::Paragraph, question, format!("q{}", i))
+                .required(false)
+                .max_length(MAX_ANSWER_CHARS);
+            if let Some(answer) = previous.get(i).filter(|a| !a.is_empty()) {
+                input = input.value(answer);
+            }
+            CreateActionRow::InputText(input)
+        })
+        .collect();
+    let modal = CreateModal::new(custom_id(&guild_id, &local_date), tr(locale, "standup-modal-title")).components(inputs);
+    interaction.create_response(ctx, CreateInteractionResponse::Modal(modal)).await?;
+    Ok(())
+}
+
+/// Save answers submitted from the modal
+pub async fn handle_modal(ctx: &serenity::Context, interaction: &ModalInteraction, data: &Data) -> Result<(), Error> {
+    let Some((guild_id, local_date)) = parse_custom_id(&interaction.data.custom_id) else {
+        return Ok(());
+    };
+    let user_id = interaction.user.id.to_string();
+    let locale = locale_for_user(&data.db, &user_id).await;
+
+    let reply = match open_standup(data, &guild_id, &user_id, &local_date).await? {
+        None => tr(locale, "standup-closed"),
+        Some((config, entry)) => {
+            let answers: Vec<String> = (0..config.questions.len()).map(|i| input_value(interaction, &format!("q{}", i))).collect();
+            if answers.iter().all(|a| a.is_empty()) {
+                tr(locale, "standup-empty")
+            } else {
+                data.db.set_standup_answers(&guild_id, &user_id, &local_date, &answers).await?;
+                info!("User {} answered the standup in guild {}", user_id, guild_id);
+                let run = NaiveDate::parse_from_str(&entry.run_date, "%Y-%m-%d").unwrap_or_default();
+                let time = standup::summary_at(config.tz(), &config.summary_time, run)
+                    .map(|at| format!("<t:{}:t>", at.timestamp()))
+                    .unwrap_or_else(|| config.summary_time.clone());
+                tr_args(locale, "standup-saved", &[("time", &time)])
+            }
+        }
+    };
+
+    let response = CreateInteractionResponseMessage::new().content(reply).ephemeral(true);
+    interaction.create_response(ctx, CreateInteractionResponse::Message(response)).await?;
+    Ok(())
+}
+
+/// The config and entry for a standup still taking answers
+async fn open_standup(data: &Data, guild_id: &str, discord_id: &str, local_date: &str) -> Result<Option<(StandupConfig, StandupEntry)>, Error> {
+    let Some(config) = data.db.get_standup_config(guild_id).await? else {
+        return Ok(None);
+    };
+    let Some(entry) = data.db.get_standup_entry(guild_id, discord_id, local_date).await? else {
+        return Ok(None);
+    };
+    if data.db.is_standup_posted(guild_id, &entry.run_date).await? {
+        return Ok(None);
+    }
+    Ok(Some((config, entry)))
+}
+
+fn custom_id(guild_id: &str, local_date: &str) -> String {
+    format!("{}:{}:{}", CUSTOM_ID_PREFIX, guild_id, local_date)
+}
+
+fn parse_custom_id(custom_id: &str) -> Option<(String, String)> {
+    let (guild_id, local_date) = custom_id.strip_prefix(CUSTOM_ID_PREFIX)?.strip_prefix(':')?.split_once(':')?;
+    Some((guild_id.to_string(), local_date.to_string()))
+}
+
+/// The value a modal input was submitted with
+fn input_value(interaction: &ModalInteraction, custom_id: &str) -> String {
+    interaction
+        .data
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == custom_id => input.value.clone(),
+            _ => None,
+        })
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn date_key(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+// ==================== Commands ====================
+
+/// Show the standup's channel, schedule, questions and participants
+pub async fn show(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = ctx.guild_id().map(|g| g.to_string()) else {
+        ctx.say(tr(locale, "standup-server-only")).await?;
+        return Ok(());
+    };
+    let db = &ctx.data().db;
+    let msg = match db.get_standup_config(&guild_id).await? {
+        None => tr(locale, "standup-not-set-up"),
+        Some(config) => {
+            let participants = db.get_standup_participants(&guild_id).await?;
+            let people = if participants.is_empty() {
+                tr(locale, "standup-no-participants")
+            } else {
+                participants.iter().map(|id| format!("<@{}>", id)).collect::<Vec<_>>().join(", ")
+            };
+            let questions = config.questions.iter().enumerate().map(|(i, q)| format!("{}. {}", i + 1, q)).collect::<Vec<_>>().join("\n");
+            tr_args(locale, "standup-show", &[
+                ("channel", &format!("<#{}>", config.channel_id)),
+                ("days", &days_to_names(&config.days, locale)),
+                ("time", &config.summary_time),
+                ("timezone", &config.timezone),
+                ("questions", &questions),
+                ("users", &people),
+            ])
+        }
+    };
+    ctx.send(poise::CreateReply::default()
+        .content(msg)
+        .allowed_mentions(CreateAllowedMentions::new())
+        .ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Set up or change the standup's channel, days and summary time (admins)
+pub async fn setup(ctx: Context<'_>, channel: Option<serenity::GuildChannel>, days: Option<String>, time: Option<String>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    let db = &ctx.data().db;
+    let existing = db.get_standup_config(&guild_id).await?;
+
+    let days = match days.as_deref().map(parse_cadence) {
+        Some(Ok(days)) => days,
+        Some(Err(e)) => return reply(ctx, tr_args(locale, "standup-invalid-days", &[("error", &e)])).await,
+        None => existing.as_ref().map(|c| c.days.clone()).unwrap_or_else(|| (0..5).collect()),
+    };
+    let summary_time = match time.as_deref().map(parse_time) {
+        Some(Ok(time)) => time,
+        Some(Err(e)) => return reply(ctx, tr_args(locale, "standup-invalid-time", &[("error", &e)])).await,
+        None => existing.as_ref().map(|c| c.summary_time.clone()).unwrap_or_else(|| DEFAULT_SUMMARY_TIME.to_string()),
+    };
+    let channel_id = channel
+        .map(|c| c.id.to_string())
+        .or_else(|| existing.as_ref().map(|c| c.channel_id.clone()))
+        .unwrap_or_else(|| ctx.channel_id().to_string());
+    let questions = existing
+        .as_ref()
+        .map(|c| c.questions.clone())
+        .unwrap_or_else(|| (1..=3).map(|i| tr(locale, &format!("standup-default-question-{}", i))).collect());
+    let timezone = db.get_user_settings(&ctx.author().id.to_string()).await?.timezone;
+
+    let config = StandupConfig { guild_id: guild_id.clone(), channel_id, questions, days, summary_time, timezone };
+    db.set_standup_config(&config, &ctx.author().id.to_string()).await?;
+    info!("User {} set up the standup in guild {}", ctx.author().id, guild_id);
+    reply(ctx, tr_args(locale, "standup-set-up", &[
+        ("channel", &format!("<#{}>", config.channel_id)),
+        ("days", &days_to_names(&config.days, locale)),
+        ("time", &config.summary_time),
+        ("timezone", &config.timezone),
+    ])).await
+}
+
+/// Replace the standup's questions (admins)
+pub async fn set_questions(ctx: Context<'_>, questions: String) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    let db = &ctx.data().db;
+    let Some(mut config) = db.get_standup_config(&guild_id).await? else {
+        return reply(ctx, tr(locale, "standup-not-set-up")).await;
+    };
+    config.questions = match standup::parse_questions(&questions) {
+        Ok(questions) => questions,
+        Err(e) => return reply(ctx, tr_args(locale, "standup-invalid-questions", &[("error", &e.message(locale))])).await,
+    };
+    db.set_standup_config(&config, &ctx.author().id.to_string()).await?;
+    info!("User {} changed the standup questions in guild {}", ctx.author().id, guild_id);
+    reply(ctx, tr_args(locale, "standup-questions-set", &[("count", &config.questions.len().to_string())])).await
+}
+
+/// Add someone to the standup (admins)
+pub async fn add(ctx: Context<'_>, user: serenity::User) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    ctx.data().db.add_standup_participant(&guild_id, &user.id.to_string(), &ctx.author().id.to_string()).await?;
+    info!("User {} added {} to the standup in guild {}", ctx.author().id, user.id, guild_id);
+    reply(ctx, tr_args(locale, "standup-added", &[("user", &format!("<@{}>", user.id))])).await
+}
+
+/// Take someone out of the standup (admins)
+pub async fn remove(ctx: Context<'_>, user: serenity::User) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    let removed = ctx.data().db.remove_standup_participant(&guild_id, &user.id.to_string()).await?;
+    let key = if removed { "standup-removed" } else { "standup-remove-none" };
+    if removed {
+        info!("User {} removed {} from the standup in guild {}", ctx.author().id, user.id, guild_id);
+    }
+    reply(ctx, tr_args(locale, key, &[("user", &format!("<@{}>", user.id))])).await
+}
+
+/// Stop the standup, keeping its participants (admins)
+pub async fn off(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = admin_guild(ctx, locale).await? else {
+        return Ok(());
+    };
+    let removed = ctx.data().db.remove_standup_config(&guild_id).await?;
+    if removed > 0 {
+        info!("User {} turned off the standup in guild {}", ctx.author().id, guild_id);
+    }
+    reply(ctx, tr(locale, if removed > 0 { "standup-off" } else { "standup-not-set-up" })).await
+}
+
+/// The guild ID, if run in a server by an admin; replies otherwise
+async fn admin_guild(ctx: Context<'_>, locale: &str) -> Result<Option<String>, Error> {
+    let Some(guild_id) = ctx.guild_id().map(|g| g.to_string()) else {
+        ctx.say(tr(locale, "standup-server-only")).await?;
+        return Ok(None);
+    };
+    if !has_admin_permission(&ctx, &guild_id).await {
+        reply(ctx, tr(locale, "standup-admin-only")).await?;
+        return Ok(None);
+    }
+    Ok(Some(guild_id))
+}
+
+async fn reply(ctx: Context<'_>, content: String) -> Result<(), Error> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .allowed_mentions(CreateAllowedMentions::new())
+        .ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Standup days: "weekdays", "daily" or days as `/fabrica hours` takes them
+fn parse_cadence(input: &str) -> Result<Vec<u8>, String> {
+    match input.trim().to_lowercase().as_str() {
+        "weekdays" => Ok((0..5).collect()),
+        "daily" | "every day" | "everyday" => Ok((0..7).collect()),
+        other => parse_days(other),
+    }
+}
@@ -4,20 +4,43 @@
 //! and under each person's control.
 
 use crate::bot::{Context, Error};
-use crate::db::UserStatus;
-use chrono::Local;
+use crate::db::{CalendarLink, UserStatus};
+use crate::services::i18n::{locale_for, tr, tr_args};
+use crate::modules::leave;
+use crate::modules::translation::has_admin_permission;
+use crate::services::{calendar, schedule};
+use chrono::{DateTime, NaiveDate, Utc};
 use tracing::info;
 
+/// How long someone can be busy before they drop out of team views
+const BUSY_VISIBLE_SECS: i64 = 15 * 60;
+
+/// Which calendar feed `/fabrica settings calendar-link` issues
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum CalendarScope {
+    /// Everyone in the server (admins only)
+    #[name = "team"]
+    Team,
+    /// Just the caller
+    #[name = "me"]
+    Me,
+    /// Stop every link the caller issued in this server
+    #[name = "revoke"]
+    Revoke,
+}
+
 /// Set status to available
 pub async fn set_available(ctx: Context<'_>, message: Option<String>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let status = UserStatus::available(&user_id, message.clone());
+    let locale = locale_for(ctx).await;
 
     ctx.data().db.set_status(status).await?;
+    ctx.data().boards.request();
 
     let response = match message {
-        Some(msg) => format!("🟢 You're now **available** - {}", msg),
-        None => "🟢 You're now **available**".to_string(),
+        Some(msg) => tr_args(locale, "status-available-message", &[("message", &msg)]),
+        None => tr(locale, "status-available"),
     };
 
     info!("User {} set status to available", user_id);
@@ -30,12 +53,14 @@ pub async fn set_available(ctx: Context<'_>, message: Option<String>) -> Result<
 pub async fn set_busy(ctx: Context<'_>, message: Option<String>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let status = UserStatus::busy(&user_id, message.clone());
+    let locale = locale_for(ctx).await;
 
     ctx.data().db.set_status(status).await?;
+    ctx.data().boards.request();
 
     let response = match message {
-        Some(msg) => format!("🟡 You're now **busy** - {}", msg),
-        None => "🟡 You're now **busy**".to_string(),
+        Some(msg) => tr_args(locale, "status-busy-message", &[("message", &msg)]),
+        None => tr(locale, "status-busy"),
     };
 
     info!("User {} set status to busy", user_id);
@@ -48,12 +73,14 @@ pub async fn set_busy(ctx: Context<'_>, message: Option<String>) -> Result<(), E
 pub async fn set_away(ctx: Context<'_>, message: Option<String>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let status = UserStatus::away(&user_id, message.clone());
+    let locale = locale_for(ctx).await;
 
     ctx.data().db.set_status(status).await?;
+    ctx.data().boards.request();
 
     let response = match message {
-        Some(msg) => format!("🔴 You're now **away** - {}", msg),
-        None => "🔴 You're now **away**".to_string(),
+        Some(msg) => tr_args(locale, "status-away-message", &[("message", &msg)]),
+        None => tr(locale, "status-away"),
     };
 
     info!("User {} set status to away", user_id);
@@ -66,9 +93,10 @@ pub async fn set_away(ctx: Context<'_>, message: Option<String>) -> Result<(), E
 pub async fn clear(ctx: Context<'_>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     ctx.data().db.clear_status(&user_id).await?;
+    ctx.data().boards.request();
 
     info!("User {} cleared status", user_id);
-    ctx.say("⚫ Your status has been cleared.").await?;
+    ctx.say(tr(locale_for(ctx).await, "status-cleared")).await?;
 
     Ok(())
 }
@@ -77,13 +105,15 @@ pub async fn clear(ctx: Context<'_>) -> Result<(), Error> {
 pub async fn who(ctx: Context<'_>) -> Result<(), Error> {
     let available = ctx.data().db.get_users_by_status("available").await?;
     let busy = ctx.data().db.get_users_by_status("busy").await?;
+    let locale = locale_for(ctx).await;
 
     let mut response = String::from("───────────────────────────────\n");
 
     // Available
-    response.push_str(&format!("🟢 **Available** ({})\n", available.len()));
+    response.push_str(&tr_args(locale, "who-available", &[("count", &available.len().to_string())]));
+    response.push('\n');
     if available.is_empty() {
-        response.push_str("  No one currently available\n");
+        response.push_str(&format!("  {}\n", tr(locale, "who-none-available")));
     } else {
         for status in &available {
             let user_mention = format!("<@{}>", status.discord_id);
@@ -97,9 +127,10 @@ pub async fn who(ctx: Context<'_>) -> Result<(), Error> {
     response.push('\n');
 
     // Busy
-    response.push_str(&format!("🟡 **Busy** ({})\n", busy.len()));
+    response.push_str(&tr_args(locale, "who-busy", &[("count", &busy.len().to_string())]));
+    response.push('\n');
     if busy.is_empty() {
-        response.push_str("  No one currently busy\n");
+        response.push_str(&format!("  {}\n", tr(locale, "who-none-busy")));
     } else {
         for status in &busy {
             let user_mention = format!("<@{}>", status.discord_id);
@@ -119,10 +150,11 @@ pub async fn who(ctx: Context<'_>) -> Result<(), Error> {
 
 /// Show available team members with their schedule
 pub async fn team(ctx: Context<'_>, public: bool) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match ctx.guild_id() {
         Some(gid) => gid.to_string(),
         None => {
-            ctx.say("⚠️ Team status can only be viewed in a server.").await?;
+            ctx.say(tr(locale, "team-server-only")).await?;
             return Ok(());
         }
     };
@@ -131,19 +163,18 @@ pub async fn team(ctx: Context<'_>, public: bool) -> Result<(), Error> {
     let busy = ctx.data().db.get_users_by_status("busy").await?;
     let viewer_id = ctx.author().id.to_string();
     let viewer_settings = ctx.data().db.get_user_settings(&viewer_id).await?;
-    let today = Local::now().format("%Y-%m-%d").to_string();
     let now = chrono::Utc::now().timestamp();
-    let fifteen_minutes = 15 * 60; // seconds
 
     let mut response = String::from("───────────────────────────────\n");
     let mut shown_count = 0;
 
     // Available users - always show
     if !available.is_empty() {
-        response.push_str("🟢 **Available**\n");
+        response.push_str(&tr(locale, "team-available"));
+        response.push('\n');
         for status in &available {
             let member_settings = ctx.data().db.get_user_settings(&status.discord_id).await?;
-            response.push_str(&format_team_member(&status, &member_settings, &viewer_settings, &guild_id, &today, ctx).await);
+            response.push_str(&format_team_member(status, &member_settings, &viewer_settings, &guild_id, locale, ctx).await);
             shown_count += 1;
         }
         response.push('\n');
@@ -154,18 +185,18 @@ pub async fn team(ctx: Context<'_>, public: bool) -> Result<(), Error> {
         let mut result = Vec::new();
         for status in &busy {
             let member_settings = ctx.data().db.get_user_settings(&status.discord_id).await?;
-            let busy_duration = now - status.updated_at;
-            if busy_duration < fifteen_minutes || member_settings.always_show_me {
-                result.push((status, member_settings, busy_duration));
+            if busy_visible(status, &member_settings, now) {
+                result.push((status, member_settings, now - status.updated_at));
             }
         }
         result
     };
 
     if !visible_busy.is_empty() {
-        response.push_str("🟡 **Busy**\n");
+        response.push_str(&tr(locale, "team-busy"));
+        response.push('\n');
         for (status, member_settings, busy_duration) in &visible_busy {
-            let mut line = format_team_member(status, member_settings, &viewer_settings, &guild_id, &today, ctx).await;
+            let mut line = format_team_member(status, member_settings, &viewer_settings, &guild_id, locale, ctx).await;
             // Add how long they've been busy
             let mins = busy_duration / 60;
             if mins > 0 {
@@ -178,10 +209,18 @@ pub async fn team(ctx: Context<'_>, public: bool) -> Result<(), Error> {
         response.push('\n');
     }
 
+    // Out today: leave and public holidays
+    let viewer_today = Utc::now().with_timezone(&viewer_settings.tz()).date_naive();
+    let out = leave::team_section(&ctx.data().db, &guild_id, viewer_today, locale).await?;
+    if !out.is_empty() {
+        response.push_str(&out);
+        shown_count += 1;
+    }
+
     // Away users never shown in /team
 
     if shown_count == 0 {
-        let msg = "No team members are currently visible.";
+        let msg = tr(locale, "team-none-visible");
         if public {
             ctx.say(msg).await?;
         } else {
@@ -201,13 +240,19 @@ pub async fn team(ctx: Context<'_>, public: bool) -> Result<(), Error> {
     Ok(())
 }
 
+/// Whether a busy member still shows in team views: for their first
+/// `BUSY_VISIBLE_SECS` of being busy, or always if they chose to be shown
+pub(crate) fn busy_visible(status: &UserStatus, settings: &crate::db::UserSettings, now: i64) -> bool {
+    now - status.updated_at < BUSY_VISIBLE_SECS || settings.always_show_me
+}
+
 /// Format a team member for display, showing their local time
 async fn format_team_member(
     status: &crate::db::UserStatus,
     member_settings: &crate::db::UserSettings,
     viewer_settings: &crate::db::UserSettings,
     guild_id: &str,
-    today: &str,
+    locale: &str,
     ctx: Context<'_>,
 ) -> String {
     let user_mention = format!("<@{}>", status.discord_id);
@@ -238,22 +283,50 @@ async fn format_team_member(
         }
     }
 
+    if status.auto {
+        line.push(' ');
+        line.push_str(&tr(locale, "team-auto"));
+    }
+
     if let Some(msg) = &status.message {
         line.push_str(&format!(" - {}", msg));
     }
 
-    // Check for today's schedule override (until time)
-    if let Ok(Some((_, end_time))) = ctx.data().db.get_schedule_override(guild_id, &status.discord_id, today).await {
-        let formatted = format_time_for_user(&end_time, viewer_settings);
-        line.push_str(&format!(" (until {})", formatted));
+    // Check for the member's schedule override today (their date), shown in the viewer's timezone
+    let member_today = member_settings.today();
+    if let Ok(Some(day_override)) = ctx.data().db.get_schedule_override(guild_id, &status.discord_id, &member_today).await {
+        let weekly = ctx.data().db.get_weekly_schedule(guild_id, &status.discord_id).await.unwrap_or_default();
+        let windows = NaiveDate::parse_from_str(&member_today, "%Y-%m-%d")
+            .map(|date| schedule::day_windows(&weekly, Some(&day_override), member_settings.tz(), date))
+            .unwrap_or_default();
+        let formatted = match windows.first() {
+            Some((_, end)) => format_instant_for_viewer(*end, viewer_settings),
+            None => format_time_for_user(&day_override.1, viewer_settings),
+        };
+        line.push(' ');
+        line.push_str(&tr_args(locale, "team-until", &[("time", &formatted)]));
     }
 
     line.push('\n');
     line
 }
 
+/// Format an instant in the viewer's timezone and time format, marking times
+/// that fall on another day for the viewer (e.g. "3:00am +1d")
+fn format_instant_for_viewer(instant: DateTime<Utc>, viewer_settings: &crate::db::UserSettings) -> String {
+    let viewer_tz = viewer_settings.tz();
+    let viewer_today = Utc::now().with_timezone(&viewer_tz).date_naive();
+    let (local, days) = schedule::viewer_time(instant, viewer_tz, viewer_today);
+    let time = format_time_for_user(&local.format("%H:%M").to_string(), viewer_settings);
+    if days == 0 {
+        time
+    } else {
+        format!("{} {:+}d", time, days)
+    }
+}
+
 /// Format a time string for display to a user based on their settings
-fn format_time_for_user(time: &str, settings: &crate::db::UserSettings) -> String {
+pub(crate) fn format_time_for_user(time: &str, settings: &crate::db::UserSettings) -> String {
     // time is in HH:MM format
     if settings.is_12h() {
         // Convert to 12h format
@@ -279,21 +352,22 @@ fn format_time_for_user(time: &str, settings: &crate::db::UserSettings) -> Strin
 pub async fn show_settings(ctx: Context<'_>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let settings = ctx.data().db.get_user_settings(&user_id).await?;
+    let locale = locale_for(ctx).await;
 
-    let format_display = if settings.is_12h() { "12-hour (am/pm)" } else { "24-hour" };
-    let always_show_display = if settings.always_show_me { "Yes" } else { "No" };
+    let format_display = tr(locale, if settings.is_12h() { "format-12h" } else { "format-24h" });
+    let always_show_display = tr(locale, if settings.always_show_me { "yes" } else { "no" });
+    let auto_status_display = tr(locale, if settings.auto_status { "yes" } else { "no" });
 
     let response = format!(
-        "⚙️ **Your Settings**\n\n\
-         **Timezone:** {}\n\
-         **Time format:** {}\n\
-         **Always show me:** {}\n\n\
-         Use `/fabrica settings timezone <zone>` to change timezone\n\
-         Use `/fabrica settings format 24h` or `/fabrica settings format 12h` to change format\n\
-         Use `/fabrica settings always-show-me` to toggle visibility in /team",
-        settings.timezone,
-        format_display,
-        always_show_display
+        "{}\n\n{}",
+        tr(locale, "settings-title"),
+        tr_args(locale, "settings-body", &[
+            ("timezone", &settings.timezone),
+            ("format", &format_display),
+            ("always", &always_show_display),
+            ("auto", &auto_status_display),
+            ("region", &settings.holiday_region.clone().unwrap_or_else(|| tr(locale, "region-none"))),
+        ])
     );
 
     ctx.send(poise::CreateReply::default().content(response).ephemeral(true)).await?;
@@ -308,20 +382,118 @@ pub async fn toggle_always_show_me(ctx: Context<'_>) -> Result<(), Error> {
 
     ctx.data().db.set_user_always_show_me(&user_id, new_value).await?;
 
-    let msg = if new_value {
-        "✅ **Always show me** is now **ON**.\nYou'll appear in `/team` even when busy for a long time or away."
-    } else {
-        "✅ **Always show me** is now **OFF**.\nYou'll be hidden from `/team` when busy for more than 15 minutes or away."
-    };
+    let msg = tr(locale_for(ctx).await, if new_value { "always-show-on" } else { "always-show-off" });
 
     info!("User {} set always_show_me to {}", user_id, new_value);
     ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
     Ok(())
 }
 
+/// Toggle auto status
+pub async fn toggle_auto_status(ctx: Context<'_>) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let settings = ctx.data().db.get_user_settings(&user_id).await?;
+    let new_value = !settings.auto_status;
+
+    ctx.data().db.set_user_auto_status(&user_id, new_value).await?;
+
+    // A status auto status set would otherwise never change or expire again
+    if !new_value {
+        if let Some(status) = ctx.data().db.get_status(&user_id).await? {
+            if status.auto {
+                ctx.data().db.clear_status(&user_id).await?;
+                ctx.data().boards.request();
+            }
+        }
+    }
+
+    let msg = tr(locale_for(ctx).await, if new_value { "auto-status-on" } else { "auto-status-off" });
+
+    info!("User {} set auto_status to {}", user_id, new_value);
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Set or clear the region whose public holidays apply to the user
+pub async fn set_holiday_region(ctx: Context<'_>, region: Option<String>) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
+    let region = region.map(|r| r.trim().to_string()).filter(|r| !r.is_empty() && !r.eq_ignore_ascii_case("none"));
+
+    let msg = match region {
+        Some(region) => match leave::normalize_region(&region) {
+            Some(region) => {
+                ctx.data().db.set_user_holiday_region(&user_id, Some(&region)).await?;
+                info!("User {} set holiday region to {}", user_id, region);
+                tr_args(locale, "region-set", &[("region", &region)])
+            }
+            None => tr(locale, "holiday-invalid-region"),
+        },
+        None => {
+            ctx.data().db.set_user_holiday_region(&user_id, None).await?;
+            info!("User {} cleared holiday region", user_id);
+            tr(locale, "region-cleared")
+        }
+    };
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Issue a calendar feed link for the server's team, or with `me` just the user
+pub async fn calendar_link(ctx: Context<'_>, scope: Option<CalendarScope>) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
+    let Some(guild_id) = ctx.guild_id().map(|g| g.to_string()) else {
+        ctx.say(tr(locale, "calendar-server-only")).await?;
+        return Ok(());
+    };
+
+    // Everyone's hours and leave are for admins; others get their own feed
+    let admin = has_admin_permission(&ctx, &guild_id).await;
+    let mine = match scope {
+        Some(CalendarScope::Revoke) => {
+            let removed = ctx.data().db.remove_calendar_links(&guild_id, &user_id).await?;
+            info!("User {} revoked {} calendar link(s) in guild {}", user_id, removed, guild_id);
+            let key = if removed > 0 { "calendar-link-revoked" } else { "calendar-link-none" };
+            ctx.send(poise::CreateReply::default().content(tr(locale, key)).ephemeral(true)).await?;
+            return Ok(());
+        }
+        Some(CalendarScope::Team) if !admin => {
+            ctx.send(poise::CreateReply::default().content(tr(locale, "calendar-team-admin-only")).ephemeral(true)).await?;
+            return Ok(());
+        }
+        Some(CalendarScope::Team) => false,
+        Some(CalendarScope::Me) => true,
+        None => !admin,
+    };
+
+    let token = calendar::new_token()?;
+    let link = CalendarLink {
+        guild_id: guild_id.clone(),
+        discord_id: user_id.clone(),
+        scope: if mine { "user" } else { "team" }.to_string(),
+    };
+    ctx.data().db.set_calendar_link(&link, &calendar::token_hash(&token)).await?;
+
+    let webhooks = &ctx.data().config.webhooks;
+    let base = webhooks
+        .base_url
+        .clone()
+        .unwrap_or_else(|| format!("http://{}:{}", webhooks.host, webhooks.port));
+    let url = format!("{}/calendar/{}.ics", base.trim_end_matches('/'), token);
+
+    info!("User {} issued a {} calendar link in guild {}", user_id, link.scope, guild_id);
+    let key = if mine { "calendar-link-user" } else { "calendar-link-team" };
+    ctx.send(poise::CreateReply::default()
+        .content(tr_args(locale, key, &[("url", &url)]))
+        .ephemeral(true)).await?;
+    Ok(())
+}
+
 /// Set user timezone (admins can set for others)
 pub async fn set_timezone(ctx: Context<'_>, timezone: String, target_user: Option<poise::serenity_prelude::User>) -> Result<(), Error> {
     let caller_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
 
     // Determine target user
     let (target_id, target_mention) = match target_user {
@@ -329,13 +501,13 @@ pub async fn set_timezone(ctx: Context<'_>, timezone: String, target_user: Optio
             // Check if caller is admin
             if !is_global_admin(&ctx) {
                 ctx.send(poise::CreateReply::default()
-                    .content("⚠️ Only admins can set timezone for other users.")
+                    .content(tr(locale, "timezone-admin-only"))
                     .ephemeral(true)).await?;
                 return Ok(());
             }
             (user.id.to_string(), format!("<@{}>", user.id))
         }
-        None => (caller_id.clone(), "your".to_string()),
+        None => (caller_id.clone(), String::new()),
     };
 
     // Validate timezone using chrono-tz
@@ -363,26 +535,28 @@ pub async fn set_timezone(ctx: Context<'_>, timezone: String, target_user: Optio
             "san francisco" | "sf" => "America/Los_Angeles",
             _ => {
                 ctx.send(poise::CreateReply::default()
-                    .content(format!("⚠️ Unknown timezone: `{}`\n\nExamples: `London`, `New York`, `Tokyo`, `Europe/Paris`, `America/Los_Angeles`", tz_str))
+                    .content(tr_args(locale, "timezone-unknown", &[("timezone", tz_str)]))
                     .ephemeral(true)).await?;
                 return Ok(());
             }
         };
         ctx.data().db.set_user_timezone(&target_id, normalized).await?;
+        ctx.data().boards.request();
         info!("User {} set timezone for {} to {} (from {})", caller_id, target_id, normalized, tz_str);
         let msg = if target_user.is_some() {
-            format!("🌍 Set {} timezone to **{}**", target_mention, normalized)
+            tr_args(locale, "timezone-set-for", &[("user", &target_mention), ("timezone", normalized)])
         } else {
-            format!("🌍 Timezone set to **{}**", normalized)
+            tr_args(locale, "timezone-set", &[("timezone", normalized)])
         };
         ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
     } else {
         ctx.data().db.set_user_timezone(&target_id, tz_str).await?;
+        ctx.data().boards.request();
         info!("User {} set timezone for {} to {}", caller_id, target_id, tz_str);
         let msg = if target_user.is_some() {
-            format!("🌍 Set {} timezone to **{}**", target_mention, tz_str)
+            tr_args(locale, "timezone-set-for", &[("user", &target_mention), ("timezone", tz_str)])
         } else {
-            format!("🌍 Timezone set to **{}**", tz_str)
+            tr_args(locale, "timezone-set", &[("timezone", tz_str)])
         };
         ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
     }
@@ -400,13 +574,14 @@ fn is_global_admin(ctx: &Context<'_>) -> bool {
 pub async fn set_time_format(ctx: Context<'_>, format: String) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let fmt = format.trim().to_lowercase();
+    let locale = locale_for(ctx).await;
 
     let normalized = match fmt.as_str() {
         "24h" | "24" | "24-hour" | "24hour" => "24h",
         "12h" | "12" | "12-hour" | "12hour" | "am-pm" | "ampm" | "am/pm" => "12h",
         _ => {
             ctx.send(poise::CreateReply::default()
-                .content("⚠️ Invalid format. Use `24h` or `12h`")
+                .content(tr(locale, "time-format-invalid"))
                 .ephemeral(true)).await?;
             return Ok(());
         }
@@ -415,9 +590,9 @@ pub async fn set_time_format(ctx: Context<'_>, format: String) -> Result<(), Err
     ctx.data().db.set_user_time_format(&user_id, normalized).await?;
     info!("User {} set time format to {}", user_id, normalized);
 
-    let display = if normalized == "12h" { "12-hour (am/pm)" } else { "24-hour" };
+    let display = tr(locale, if normalized == "12h" { "format-12h" } else { "format-24h" });
     ctx.send(poise::CreateReply::default()
-        .content(format!("🕐 Time format set to **{}**", display))
+        .content(tr_args(locale, "time-format-set", &[("format", &display)]))
         .ephemeral(true)).await?;
 
     Ok(())
@@ -427,308 +602,189 @@ pub async fn set_time_format(ctx: Context<'_>, format: String) -> Result<(), Err
 /// Supports:
 /// - "Mon,Tue,Wed,Thu,Fri 9:30 to 23:30"
 /// - "M-F 9:30 to 23:30"
+/// - "M-F 10-14, 20-2" (split shifts; an end before the start runs past midnight)
 /// - "today 9:30 to 23:30"
 /// - "today until 23:30"
 /// - "until 23:30"
+/// - "remove Sat" / "remove Tue 20-2" / "remove M-F 10-14, 20-2" / "remove today"
 pub async fn set_hours(ctx: Context<'_>, schedule: String) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match ctx.guild_id() {
         Some(gid) => gid.to_string(),
         None => {
-            ctx.say("⚠️ Hours can only be set in a server.").await?;
+            ctx.say(tr(locale, "hours-server-only")).await?;
             return Ok(());
         }
     };
     let user_id = ctx.author().id.to_string();
     let schedule = schedule.trim();
+    // Times are in the user's own timezone, and "today" is their local date
+    let today = ctx.data().db.get_user_settings(&user_id).await?.today();
 
     // Parse the schedule
-    match parse_schedule(schedule) {
-        Ok(ParsedSchedule::Weekly { days, start, end }) => {
-            ctx.data().db.set_weekly_schedule(&guild_id, &user_id, &days, &start, &end).await?;
+    match schedule::parse_schedule(schedule) {
+        Ok(schedule::ParsedSchedule::Weekly { days, ranges }) => {
+            // The listed days are replaced, so check the new ranges against
+            // the days they are kept next to
+            let existing = ctx.data().db.get_weekly_schedule(&guild_id, &user_id).await?;
+            let mut week: Vec<schedule::WeeklyRange> = existing.into_iter().filter(|(day, _, _)| !days.contains(day)).collect();
+            for &day in &days {
+                week.extend(ranges.iter().map(|(start, end)| (day, start.clone(), end.clone())));
+            }
+            if let Some((first, second)) = schedule::overnight_clash(&week) {
+                let describe = |(day, start, end): schedule::WeeklyRange| {
+                    format!("{} {}", days_to_names(&[day], locale), ranges_to_names(&[(start, end)], locale))
+                };
+                ctx.say(tr_args(locale, "hours-overnight-clash", &[("first", &describe(first)), ("second", &describe(second))]))
+                    .await?;
+                return Ok(());
+            }
+            ctx.data().db.set_weekly_schedule(&guild_id, &user_id, &days, &ranges).await?;
 
-            let day_names = days_to_names(&days);
-            info!("User {} set weekly schedule in guild {}: {} {}-{}", user_id, guild_id, day_names, start, end);
-            ctx.say(format!("⏰ Set schedule: **{}** from **{}** to **{}**", day_names, start, end)).await?;
+            let day_names = days_to_names(&days, locale);
+            let range_names = ranges_to_names(&ranges, locale);
+            info!("User {} set weekly schedule in guild {}: {} {:?}", user_id, guild_id, day_names, ranges);
+            ctx.say(tr_args(locale, "hours-set-weekly", &[("days", &day_names), ("ranges", &range_names)])).await?;
         }
-        Ok(ParsedSchedule::TodayRange { start, end }) => {
-            let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(schedule::ParsedSchedule::TodayRange { start, end }) => {
             ctx.data().db.set_schedule_override(&guild_id, &user_id, &today, Some(&start), &end).await?;
 
             info!("User {} set today's schedule in guild {}: {}-{}", user_id, guild_id, start, end);
-            ctx.say(format!("⏰ Set for today: **{}** to **{}**", start, end)).await?;
+            ctx.say(tr_args(locale, "hours-set-today", &[("start", &start), ("end", &end)])).await?;
         }
-        Ok(ParsedSchedule::TodayUntil { end }) => {
-            let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(schedule::ParsedSchedule::TodayUntil { end }) => {
             ctx.data().db.set_schedule_override(&guild_id, &user_id, &today, None, &end).await?;
 
             info!("User {} set today until in guild {}: {}", user_id, guild_id, end);
-            ctx.say(format!("⏰ Available today until **{}**", end)).await?;
+            ctx.say(tr_args(locale, "hours-set-until", &[("end", &end)])).await?;
+        }
+        Ok(schedule::ParsedSchedule::RemoveWeekly { days, ranges }) => {
+            let db = &ctx.data().db;
+            let removed = if ranges.is_empty() {
+                db.remove_weekly_schedule(&guild_id, &user_id, &days, None).await?
+            } else {
+                let mut removed = 0;
+                for (start, end) in &ranges {
+                    removed += db.remove_weekly_schedule(&guild_id, &user_id, &days, Some((start, end))).await?;
+                }
+                removed
+            };
+
+            let day_names = days_to_names(&days, locale);
+            let msg = if removed == 0 {
+                tr(locale, "hours-remove-none")
+            } else if ranges.is_empty() {
+                tr_args(locale, "hours-removed-days", &[("days", &day_names)])
+            } else {
+                let range_names = ranges_to_names(&ranges, locale);
+                tr_args(locale, "hours-removed-range", &[("days", &day_names), ("range", &range_names)])
+            };
+            info!("User {} removed {} weekly range(s) in guild {}", user_id, removed, guild_id);
+            ctx.say(msg).await?;
+        }
+        Ok(schedule::ParsedSchedule::RemoveToday) => {
+            let removed = ctx.data().db.remove_schedule_override(&guild_id, &user_id, &today).await?;
+
+            info!("User {} removed today's schedule in guild {}", user_id, guild_id);
+            ctx.say(tr(locale, if removed == 0 { "hours-remove-none" } else { "hours-removed-today" })).await?;
         }
         Err(e) => {
-            ctx.say(format!("⚠️ Couldn't parse schedule: {}\n\n\
-                **Examples:**\n\
-                • `/fabrica hours Mon,Tue,Wed,Thu,Fri 9:30 to 23:30`\n\
-                • `/fabrica hours M-F 9:30 to 23:30`\n\
-                • `/fabrica hours today 9:30 to 23:30`\n\
-                • `/fabrica hours today until 23:30`\n\
-                • `/fabrica hours until 23:30`", e)).await?;
+            ctx.say(tr_args(locale, "hours-parse-error", &[("error", &e)])).await?;
+            return Ok(());
         }
     }
+    ctx.data().boards.request();
 
     Ok(())
 }
 
 /// Show current hours
 pub async fn show_hours(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match ctx.guild_id() {
         Some(gid) => gid.to_string(),
         None => {
-            ctx.say("⚠️ Hours can only be viewed in a server.").await?;
+            ctx.say(tr(locale, "hours-view-server-only")).await?;
             return Ok(());
         }
     };
     let user_id = ctx.author().id.to_string();
 
+    let settings = ctx.data().db.get_user_settings(&user_id).await?;
     let weekly = ctx.data().db.get_weekly_schedule(&guild_id, &user_id).await?;
-    let today = Local::now().format("%Y-%m-%d").to_string();
-    let override_today = ctx.data().db.get_schedule_override(&guild_id, &user_id, &today).await?;
+    let override_today = ctx.data().db.get_schedule_override(&guild_id, &user_id, &settings.today()).await?;
 
-    let mut response = String::from("⏰ **Your Schedule**\n\n");
+    let mut response = format!(
+        "{}\n{}\n\n",
+        tr(locale, "hours-title"),
+        tr_args(locale, "hours-timezone", &[("timezone", &settings.timezone)])
+    );
 
     if let Some((start, end)) = override_today {
-        match start {
-            Some(s) => response.push_str(&format!("**Today:** {} to {}\n\n", s, end)),
-            None => response.push_str(&format!("**Today:** until {}\n\n", end)),
-        }
+        let today_line = match start {
+            Some(s) => tr_args(locale, "hours-today-range", &[("start", &s), ("end", &end)]),
+            None => tr_args(locale, "hours-today-until", &[("end", &end)]),
+        };
+        response.push_str(&format!("{}\n\n", today_line));
     }
 
     if weekly.is_empty() {
-        response.push_str("No weekly schedule set.");
+        response.push_str(&tr(locale, "hours-none"));
     } else {
-        response.push_str("**Weekly:**\n");
+        response.push_str(&format!("{}\n", tr(locale, "hours-weekly")));
+        // Rows come ordered by day, then start; one line per day
+        let mut days: Vec<(u8, Vec<(String, String)>)> = Vec::new();
         for (day, start, end) in weekly {
-            response.push_str(&format!("  {}: {} to {}\n", day_name(day), start, end));
-        }
-    }
-
-    ctx.say(response).await?;
-    Ok(())
-}
-
-// ==================== Parsing ====================
-
-enum ParsedSchedule {
-    Weekly { days: Vec<u8>, start: String, end: String },
-    TodayRange { start: String, end: String },
-    TodayUntil { end: String },
-}
-
-fn parse_schedule(input: &str) -> Result<ParsedSchedule, String> {
-    let input = input.trim().to_lowercase();
-
-    // "until HH:MM" - shorthand for "today until HH:MM"
-    if input.starts_with("until ") {
-        let time = input.strip_prefix("until ").unwrap().trim();
-        let end = parse_time(time)?;
-        return Ok(ParsedSchedule::TodayUntil { end });
-    }
-
-    // "today until HH:MM"
-    if input.starts_with("today until ") {
-        let time = input.strip_prefix("today until ").unwrap().trim();
-        let end = parse_time(time)?;
-        return Ok(ParsedSchedule::TodayUntil { end });
-    }
-
-    // "today HH:MM to HH:MM"
-    if input.starts_with("today ") {
-        let rest = input.strip_prefix("today ").unwrap().trim();
-        let (start, end) = parse_time_range(rest)?;
-        return Ok(ParsedSchedule::TodayRange { start, end });
-    }
-
-    // Weekly schedule: "DAYS HH:MM to HH:MM"
-    // Find where the time part starts (look for a digit)
-    let time_start = input.find(|c: char| c.is_ascii_digit())
-        .ok_or("Could not find time in schedule")?;
-
-    let days_part = input[..time_start].trim();
-    let time_part = input[time_start..].trim();
-
-    let days = parse_days(days_part)?;
-    let (start, end) = parse_time_range(time_part)?;
-
-    Ok(ParsedSchedule::Weekly { days, start, end })
-}
-
-fn parse_days(input: &str) -> Result<Vec<u8>, String> {
-    let input = input.trim().to_lowercase();
-
-    // Check for range format like "m-f"
-    if input.contains('-') && !input.contains(',') {
-        let parts: Vec<&str> = input.split('-').collect();
-        if parts.len() == 2 {
-            let start_day = parse_single_day(parts[0].trim())?;
-            let end_day = parse_single_day(parts[1].trim())?;
-
-            if start_day <= end_day {
-                return Ok((start_day..=end_day).collect());
-            } else {
-                // Wrap around (e.g., Fri-Mon)
-                let mut days: Vec<u8> = (start_day..=6).collect();
-                days.extend(0..=end_day);
-                return Ok(days);
+            match days.last_mut() {
+                Some((last, ranges)) if *last == day => ranges.push((start, end)),
+                _ => days.push((day, vec![(start, end)])),
             }
         }
-    }
-
-    // Comma-separated list
-    let mut days = Vec::new();
-    for part in input.split(',') {
-        let day = parse_single_day(part.trim())?;
-        if !days.contains(&day) {
-            days.push(day);
+        for (day, ranges) in days {
+            let line = tr_args(locale, "hours-weekly-day", &[("day", &day_name(day, locale)), ("ranges", &ranges_to_names(&ranges, locale))]);
+            response.push_str(&format!("  {}\n", line));
         }
     }
 
-    days.sort();
-    Ok(days)
+    ctx.say(response).await?;
+    Ok(())
 }
 
-fn parse_single_day(input: &str) -> Result<u8, String> {
-    match input.to_lowercase().as_str() {
-        "m" | "mon" | "monday" => Ok(0),
-        "tu" | "tue" | "tues" | "tuesday" => Ok(1),
-        "w" | "wed" | "wednesday" => Ok(2),
-        "th" | "thu" | "thur" | "thurs" | "thursday" => Ok(3),
-        "f" | "fri" | "friday" => Ok(4),
-        "sa" | "sat" | "saturday" => Ok(5),
-        "su" | "sun" | "sunday" => Ok(6),
-        _ => Err(format!("Unknown day: {}", input)),
+fn day_name(day: u8, locale: &str) -> String {
+    if day > 6 {
+        return "Unknown".to_string();
     }
+    tr(locale, &format!("day-{}", day))
 }
 
-fn parse_time_range(input: &str) -> Result<(String, String), String> {
-    // Look for "to" or "-" as separator
-    let (start_str, end_str) = if input.contains(" to ") {
-        let parts: Vec<&str> = input.split(" to ").collect();
-        if parts.len() != 2 {
-            return Err("Expected format: HH:MM to HH:MM".to_string());
-        }
-        (parts[0].trim(), parts[1].trim())
-    } else if input.contains('-') && input.matches('-').count() == 1 {
-        // Single dash, might be time separator
-        let parts: Vec<&str> = input.split('-').collect();
-        if parts.len() != 2 {
-            return Err("Expected format: HH:MM-HH:MM".to_string());
-        }
-        (parts[0].trim(), parts[1].trim())
-    } else {
-        return Err("Expected format: HH:MM to HH:MM".to_string());
-    };
-
-    let start = parse_time(start_str)?;
-    let end = parse_time(end_str)?;
-
-    Ok((start, end))
+fn ranges_to_names(ranges: &[(String, String)], locale: &str) -> String {
+    ranges
+        .iter()
+        .map(|(start, end)| {
+            let key = if end <= start { "hours-range-overnight" } else { "hours-range" };
+            tr_args(locale, key, &[("start", start), ("end", end)])
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-fn parse_time(input: &str) -> Result<String, String> {
-    let input = input.trim();
-    let lower = input.to_lowercase();
-
-    // Handle 12-hour format manually (5pm, 5:30pm, 5 pm, 5:30 pm, etc.)
-    let (time_part, is_pm) = if lower.ends_with("pm") {
-        (lower.trim_end_matches("pm").trim(), true)
-    } else if lower.ends_with("am") {
-        (lower.trim_end_matches("am").trim(), false)
-    } else {
-        // No AM/PM suffix - try 24-hour parsing
-        if input.contains(':') {
-            let parts: Vec<&str> = input.split(':').collect();
-            if parts.len() == 2 {
-                if let (Ok(h), Ok(m)) = (parts[0].parse::<u8>(), parts[1].parse::<u8>()) {
-                    if h < 24 && m < 60 {
-                        return Ok(format!("{:02}:{:02}", h, m));
-                    }
-                }
-            }
-        }
-        // Try just a number as hour (e.g., "17" -> "17:00")
-        if let Ok(h) = input.parse::<u8>() {
-            if h < 24 {
-                return Ok(format!("{:02}:00", h));
-            }
-        }
-        return Err(format!("Invalid time format: {}", input));
-    };
-
-    // Parse the time part (could be "5", "5:30", etc.)
-    let (hour, minute) = if time_part.contains(':') {
-        let parts: Vec<&str> = time_part.split(':').collect();
-        if parts.len() != 2 {
-            return Err(format!("Invalid time format: {}", input));
-        }
-        let h: u8 = parts[0].parse().map_err(|_| format!("Invalid hour: {}", parts[0]))?;
-        let m: u8 = parts[1].parse().map_err(|_| format!("Invalid minute: {}", parts[1]))?;
-        (h, m)
-    } else {
-        let h: u8 = time_part.parse().map_err(|_| format!("Invalid hour: {}", time_part))?;
-        (h, 0)
-    };
-
-    // Validate and convert to 24-hour
-    if hour > 12 || minute >= 60 {
-        return Err(format!("Invalid time: {}", input));
-    }
-
-    let hour_24 = if is_pm {
-        if hour == 12 { 12 } else { hour + 12 }
-    } else {
-        if hour == 12 { 0 } else { hour }
-    };
-
-    Ok(format!("{:02}:{:02}", hour_24, minute))
-}
-
-fn day_name(day: u8) -> &'static str {
-    match day {
-        0 => "Monday",
-        1 => "Tuesday",
-        2 => "Wednesday",
-        3 => "Thursday",
-        4 => "Friday",
-        5 => "Saturday",
-        6 => "Sunday",
-        _ => "Unknown",
-    }
-}
-
-fn days_to_names(days: &[u8]) -> String {
+pub(crate) fn days_to_names(days: &[u8], locale: &str) -> String {
     // Check for common patterns
-    if days == &[0, 1, 2, 3, 4] {
-        return "Mon-Fri".to_string();
+    if days == [0, 1, 2, 3, 4] {
+        return tr(locale, "days-weekdays");
     }
-    if days == &[0, 1, 2, 3, 4, 5, 6] {
-        return "Every day".to_string();
+    if days == [0, 1, 2, 3, 4, 5, 6] {
+        return tr(locale, "days-every-day");
     }
-    if days == &[5, 6] {
-        return "Sat-Sun".to_string();
+    if days == [5, 6] {
+        return tr(locale, "days-weekend");
     }
 
     // Otherwise list them
-    let names: Vec<&str> = days.iter().map(|&d| {
-        match d {
-            0 => "Mon",
-            1 => "Tue",
-            2 => "Wed",
-            3 => "Thu",
-            4 => "Fri",
-            5 => "Sat",
-            6 => "Sun",
-            _ => "?",
-        }
-    }).collect();
+    let names: Vec<String> = days
+        .iter()
+        .map(|&d| if d <= 6 { tr(locale, &format!("day-short-{}", d)) } else { "?".to_string() })
+        .collect();
 
     names.join(", ")
 }
@@ -5,15 +5,25 @@
 //! - silent: DM translations only (including English - must subscribe)
 //! - on: DM translations + public English translations
 //! - transparent: All translations shown publicly in channel
-
-use crate::bot::{Context, Data, Error};
-use crate::services::translator::TranslatorService;
+//! - mirror: Messages reposted, translated, in linked channels (see [`super::mirror`])
+
+use crate::bot::{Context, Data, Discord, Error};
+use crate::db::{TranslationDm, TranslationPreferences};
+use crate::modules::{corrections, mirror};
+use crate::services::attachments::{self, TextDocument};
+use crate::services::language;
+use crate::services::i18n::{locale_for, supported_locale, tr, tr_args, DEFAULT_LOCALE};
 use poise::serenity_prelude::{self as serenity, Message, RoleId};
 use tracing::{debug, error, info, warn};
 
+/// Longest quote of a replied-to message shown in a translation DM
+const MAX_QUOTE_CHARS: usize = 120;
+/// Leaves room in the 4096-character embed description for the quote and link
+const MAX_DM_TRANSLATION_CHARS: usize = 3600;
+
 /// Handle incoming messages for translation
 pub async fn handle_message(
-    ctx: &serenity::Context,
+    ctx: &Discord,
     message: &Message,
     data: &Data,
 ) -> Result<(), Error> {
@@ -32,74 +42,76 @@ pub async fn handle_message(
         return Ok(());
     }
 
-    let content = message.content.trim();
-    if content.is_empty() {
-        return Ok(());
+    // Mirror channels repost into their linked channels instead
+    if mode == "mirror" {
+        return mirror::handle_message(ctx, message, data, &guild_id).await;
     }
 
-    // Detect language - use whatlang first, fall back to LLM if uncertain
-    let detected = whatlang::detect(content);
-    let (whatlang_code, confidence) = detected
-        .map(|info| (info.lang().code(), info.confidence()))
-        .unwrap_or(("unknown", 0.0));
-
-    info!("whatlang detected: '{}' (confidence: {:.2}) for: {}", whatlang_code, confidence, truncate_str(content, 50));
-
-    // If whatlang is confident, use its result
-    let lang_code = if confidence >= 0.8 {
-        // Convert 3-letter to 2-letter code
-        whatlang_to_iso(whatlang_code).to_string()
+    // Opted-in channels also translate captions and attached text documents
+    let translate_attachments = data.db.is_attachment_translation_enabled(&guild_id, &channel_id).await.unwrap_or(false);
+    let (text, documents) = if translate_attachments {
+        let text = attachments::with_captions(&message.content, &attachments::captions(message));
+        let documents = attachments::fetch_documents(message, data.config.translation.max_attachment_bytes).await;
+        (text, documents)
     } else {
-        // Fall back to LLM for uncertain detections
-        info!("whatlang uncertain, asking LLM...");
-        let translator = TranslatorService::new(&data.config.translation);
-        match translator.detect_language(content).await {
-            Ok(code) => {
-                info!("LLM detected language: '{}'", code);
-                code
-            }
-            Err(e) => {
-                warn!("LLM language detection failed: {}, defaulting to English", e);
-                "en".to_string()
-            }
-        }
+        (message.content.trim().to_string(), Vec::new())
+    };
+
+    // Detect from the text, or from the first document when there is none
+    let content = match (text.is_empty(), documents.first()) {
+        (false, _) => text.as_str(),
+        (true, Some(document)) => document.text.as_str(),
+        (true, None) => return Ok(()),
     };
 
+    let lang_code = detect_language(data, content).await;
+
     let is_english = lang_code == "en" || lang_code == "eng";
 
     if is_english {
         // English message - handle based on mode
-        handle_english_message(ctx, message, data, &guild_id, &mode).await?;
+        handle_english_message(ctx, message, &text, &documents, data, &guild_id, &mode).await?;
     } else {
         // Non-English message - translate to English
-        handle_non_english_message(ctx, message, data, &guild_id, &lang_code, &mode).await?;
+        handle_non_english_message(ctx, message, &text, &documents, data, &guild_id, &lang_code, &mode).await?;
     }
 
     Ok(())
 }
 
+/// Detect a text's language - whatlang first, falling back to the LLM if uncertain
+pub(crate) async fn detect_language(data: &Data, content: &str) -> String {
+    language::detect_with_fallback(&data.translator, content).await
+}
+
 /// Handle English messages - translate to other languages based on mode
+///
+/// `text` is the message content plus any captions; `documents` are attached
+/// text files (both only when the channel translates attachments).
 async fn handle_english_message(
-    ctx: &serenity::Context,
+    ctx: &Discord,
     message: &Message,
+    text: &str,
+    documents: &[TextDocument],
     data: &Data,
     guild_id: &str,
     mode: &str,
 ) -> Result<(), Error> {
     let channel_id = message.channel_id.to_string();
-    info!("handle_english_message called (mode={}) for: {}", mode, truncate_str(&message.content, 50));
+    info!("handle_english_message called (mode={}) for: {}", mode, truncate_str(text, 50));
 
     // Don't translate bot commands
     if message.content.starts_with('/') {
         return Ok(());
     }
 
-    let translator = TranslatorService::new(&data.config.translation);
+    let translator = &data.translator;
 
     match mode {
         "transparent" => {
             // Get all languages subscribed to in this channel (excluding English)
-            let all_languages = data.db.get_channel_subscribed_languages(guild_id, &channel_id).await?;
+            let category_id = channel_category(ctx, message.channel_id).await;
+            let all_languages = data.db.get_channel_subscribed_languages(guild_id, &channel_id, category_id.as_deref()).await?;
             debug!("All subscribed languages in channel {}: {:?}", channel_id, all_languages);
 
             let languages: Vec<String> = all_languages
@@ -111,10 +123,17 @@ async fn handle_english_message(
 
             // Translate to each language and post publicly
             let mut translations = Vec::new();
+            let mut files = Vec::new();
             for target_lang in languages {
-                match translator.translate(&message.content, "en", &target_lang).await {
+                let prefs = with_glossary(data, guild_id, "en", &target_lang, TranslationPreferences::default()).await;
+                files.extend(translate_documents(data, documents, "en", &target_lang, &prefs).await);
+                if text.is_empty() {
+                    continue;
+                }
+                match translator.translate_with_preferences(text, "en", &target_lang, &prefs).await {
                     Ok(Some(translated)) => {
-                        let lang_name = language_name(&target_lang);
+                        // Each language is labelled in itself where there's a catalog for it
+                        let lang_name = localized_language_name(reader_locale(&target_lang), &target_lang);
                         translations.push(format!("**{}:** {}", lang_name, translated));
                     }
                     Ok(None) => {
@@ -126,9 +145,13 @@ async fn handle_english_message(
                 }
             }
 
-            if !translations.is_empty() {
-                let reply = format!("🌐 {}", translations.join("\n"));
-                if let Err(e) = message.reply(ctx, reply).await {
+            if !translations.is_empty() || !files.is_empty() {
+                // Files alone go out without an empty translation line
+                let mut reply = serenity::CreateMessage::new().reference_message(message).add_files(files);
+                if !translations.is_empty() {
+                    reply = reply.content(format!("🌐 {}", translations.join("\n")));
+                }
+                if let Err(e) = message.channel_id.send_message(ctx, reply).await {
                     error!("Failed to post translations: {}", e);
                 }
             } else {
@@ -137,17 +160,24 @@ async fn handle_english_message(
         }
         "silent" | "on" => {
             // Get non-English subscriptions for this channel
-            let subscriptions = data.db.get_channel_non_english_subscriptions(guild_id, &channel_id).await?;
+            let category_id = channel_category(ctx, message.channel_id).await;
+            let subscriptions = data.db.get_channel_non_english_subscriptions(guild_id, &channel_id, category_id.as_deref()).await?;
+            let readers = can_view_channel(ctx, message, subscriptions.iter().map(|(id, _)| id.clone()).collect()).await;
+            let subscriptions: Vec<(String, String)> = subscriptions.into_iter().filter(|(id, _)| readers.contains(id)).collect();
             if subscriptions.is_empty() {
                 return Ok(());
             }
 
-            // Group by (language, dialect) - look up each user's dialect preference
-            // Key: (language, Option<dialect>), Value: Vec<discord_id>
-            let mut by_lang_dialect: std::collections::HashMap<(String, Option<String>), Vec<String>> = std::collections::HashMap::new();
+            // Group by (language, preferences) - look up each user's dialect and formality
+            // Key: (language, TranslationPreferences), Value: Vec<discord_id>
+            let mut by_lang_prefs: std::collections::HashMap<(String, TranslationPreferences), Vec<String>> = std::collections::HashMap::new();
             for (discord_id, language) in subscriptions {
-                let dialect = data.db.get_dialect_preference(&discord_id, &language).await.ok().flatten();
-                by_lang_dialect.entry((language, dialect)).or_default().push(discord_id);
+                // Readers who understand English don't need it translated
+                if data.db.understands_language(&discord_id, "en").await.unwrap_or(false) {
+                    continue;
+                }
+                let prefs = data.db.get_translation_preferences(&discord_id, &language).await.unwrap_or_default();
+                by_lang_prefs.entry((language, prefs)).or_default().push(discord_id);
             }
 
             let channel_name = message
@@ -156,21 +186,31 @@ async fn handle_english_message(
                 .await
                 .unwrap_or_else(|_| "channel".to_string());
 
-            // Translate and DM for each (language, dialect) combination
-            for ((target_lang, dialect), subscribers) in by_lang_dialect {
-                let translated = match translator.translate_with_dialect(
-                    &message.content,
-                    "en",
-                    &target_lang,
-                    dialect.as_deref()
-                ).await {
-                    Ok(Some(t)) => t,
-                    Ok(None) => continue,
-                    Err(e) => {
-                        warn!("Translation to {} (dialect: {:?}) failed: {}", target_lang, dialect, e);
-                        continue;
+            // Translate and DM for each (language, preferences) combination
+            for ((target_lang, prefs), subscribers) in by_lang_prefs {
+                let prefs = with_glossary(data, guild_id, "en", &target_lang, prefs).await;
+                let translated = if text.is_empty() {
+                    None
+                } else {
+                    match translator.translate_with_preferences(text, "en", &target_lang, &prefs).await {
+                        Ok(t) => t,
+                        Err(e) => {
+                            warn!("Translation to {} ({:?}) failed: {}", target_lang, prefs, e);
+                            continue;
+                        }
                     }
                 };
+                let files = translate_documents(data, documents, "en", &target_lang, &prefs).await;
+                if translated.is_none() && files.is_empty() {
+                    continue;
+                }
+
+                let quote = reply_quote(data, message, "en", &target_lang, &prefs).await;
+                let locale = reader_locale(&target_lang);
+                let mut dm = translation_dm(locale, message, &channel_name, translated.as_deref().unwrap_or_default(), quote.as_deref(), &files);
+                if translated.is_some() {
+                    dm = dm.components(vec![corrections::suggest_button(guild_id, message, "en", &target_lang)]);
+                }
 
                 for subscriber_id in &subscribers {
                     // Skip author unless debug mode
@@ -181,18 +221,7 @@ async fn handle_english_message(
                         }
                     }
 
-                    if let Ok(user_id) = subscriber_id.parse::<u64>() {
-                        let user = serenity::UserId::new(user_id);
-                        if let Ok(dm_channel) = user.create_dm_channel(ctx).await {
-                            let dm_content = format!(
-                                "[#{}] **{}** said:\n{}",
-                                channel_name,
-                                message.author.name,
-                                translated
-                            );
-                            let _ = dm_channel.say(ctx, &dm_content).await;
-                        }
-                    }
+                    send_dm(ctx, data, subscriber_id, &dm, message, guild_id).await;
                 }
             }
         }
@@ -203,53 +232,82 @@ async fn handle_english_message(
 }
 
 /// Handle non-English messages - translate to English based on mode
+#[allow(clippy::too_many_arguments)]
 async fn handle_non_english_message(
-    ctx: &serenity::Context,
+    ctx: &Discord,
     message: &Message,
+    text: &str,
+    documents: &[TextDocument],
     data: &Data,
     guild_id: &str,
     source_lang: &str,
     mode: &str,
 ) -> Result<(), Error> {
     let channel_id = message.channel_id.to_string();
-    info!("handle_non_english_message called (mode={}, lang={}) for: {}", mode, source_lang, truncate_str(&message.content, 50));
+    info!("handle_non_english_message called (mode={}, lang={}) for: {}", mode, source_lang, truncate_str(text, 50));
 
     // Translate to English
-    let translator = TranslatorService::new(&data.config.translation);
-    let translated = match translator.translate(&message.content, source_lang, "en").await {
-        Ok(Some(t)) => t,
-        Ok(None) => {
-            debug!("No translation needed - text already in target language");
-            return Ok(());
-        }
-        Err(e) => {
-            warn!("Translation failed: {}", e);
-            if let Err(e) = message.react(ctx, '⚠').await {
-                debug!("Failed to add reaction: {}", e);
+    let prefs = with_glossary(data, guild_id, source_lang, "en", TranslationPreferences::default()).await;
+    let translated = if text.is_empty() {
+        None
+    } else {
+        match data.translator.translate_with_preferences(text, source_lang, "en", &prefs).await {
+            Ok(Some(t)) => Some(t),
+            Ok(None) => {
+                debug!("No translation needed - text already in target language");
+                None
+            }
+            Err(e) => {
+                warn!("Translation failed: {}", e);
+                if let Err(e) = message.react(ctx, '⚠').await {
+                    debug!("Failed to add reaction: {}", e);
+                }
+                return Ok(());
             }
-            return Ok(());
         }
     };
 
     // Skip if translation is too similar to original (likely misdetected English)
-    let original_lower = message.content.to_lowercase();
-    let translated_lower = translated.to_lowercase();
-    if original_lower == translated_lower || similarity(&original_lower, &translated_lower) > 0.85 {
-        debug!("Skipping translation - too similar to original (likely misdetected language)");
+    let translated = translated.filter(|translated| {
+        let similar = language::looks_untranslated(text, translated);
+        if similar {
+            debug!("Skipping translation - too similar to original (likely misdetected language)");
+        }
+        !similar
+    });
+
+    let files = translate_documents(data, documents, source_lang, "en", &prefs).await;
+    if translated.is_none() && files.is_empty() {
         return Ok(());
     }
+    let translated = translated.unwrap_or_default();
 
     match mode {
         "silent" => {
             // DM English subscribers for this channel only
-            let en_subscribers = data.db.get_channel_subscribers_for_language(guild_id, &channel_id, "en").await?;
+            let category_id = channel_category(ctx, message.channel_id).await;
+            let en_subscribers = data.db.get_channel_subscribers_for_language(guild_id, &channel_id, category_id.as_deref(), "en").await?;
+            let en_subscribers = can_view_channel(ctx, message, en_subscribers).await;
+            if en_subscribers.is_empty() {
+                return Ok(());
+            }
             let channel_name = message
                 .channel_id
                 .name(ctx)
                 .await
                 .unwrap_or_else(|_| "channel".to_string());
+            let quote = reply_quote(data, message, source_lang, "en", &TranslationPreferences::default()).await;
+            let mut dm = translation_dm(reader_locale("en"), message, &channel_name, &translated, quote.as_deref(), &files);
+            if !translated.is_empty() {
+                dm = dm.components(vec![corrections::suggest_button(guild_id, message, source_lang, "en")]);
+            }
 
             for subscriber_id in en_subscribers {
+                // Readers who understand the source language don't need it translated
+                if data.db.understands_language(&subscriber_id, source_lang).await.unwrap_or(false) {
+                    continue;
+                }
+
                 // Skip author unless debug mode
                 if subscriber_id == message.author.id.to_string() {
                     let debug_mode = data.db.get_translation_debug_mode(guild_id, &subscriber_id, &channel_id).await.unwrap_or(false);
@@ -258,24 +316,20 @@ async fn handle_non_english_message(
                     }
                 }
 
-                if let Ok(user_id) = subscriber_id.parse::<u64>() {
-                    let user = serenity::UserId::new(user_id);
-                    if let Ok(dm_channel) = user.create_dm_channel(ctx).await {
-                        let dm_content = format!(
-                            "[#{}] **{}** said:\n{}",
-                            channel_name,
-                            message.author.name,
-                            translated
-                        );
-                        let _ = dm_channel.say(ctx, &dm_content).await;
-                    }
-                }
+                send_dm(ctx, data, &subscriber_id, &dm, message, guild_id).await;
             }
         }
         "on" | "transparent" => {
             // Post translation publicly in channel
-            let translation_msg = format!("🌐 **Translation:** {}", translated);
-            if let Err(e) = message.reply(ctx, translation_msg).await {
+            let reply = serenity::CreateMessage::new().reference_message(message).add_files(files);
+            let reply = if translated.is_empty() {
+                reply
+            } else {
+                reply
+                    .content(tr_args(reader_locale("en"), "translation-public", &[("translation", &translated)]))
+                    .components(vec![corrections::suggest_button(guild_id, message, source_lang, "en")])
+            };
+            if let Err(e) = message.channel_id.send_message(ctx, reply).await {
                 error!("Failed to post translation: {}", e);
             }
         }
@@ -285,19 +339,220 @@ async fn handle_non_english_message(
     Ok(())
 }
 
+/// Add the guild glossary for a language pair to a reader's preferences
+pub(crate) async fn with_glossary(
+    data: &Data,
+    guild_id: &str,
+    from: &str,
+    to: &str,
+    prefs: TranslationPreferences,
+) -> TranslationPreferences {
+    let terms = data.db.get_glossary_for(guild_id, from, to).await.unwrap_or_default();
+    let glossary: Vec<String> = terms.iter().map(|(term, translation)| format!("{} = {}", term, translation)).collect();
+    TranslationPreferences {
+        glossary: (!glossary.is_empty()).then(|| glossary.join("\n")),
+        ..prefs
+    }
+}
+
+/// Translate attached text documents, returning them as files named for the language
+async fn translate_documents(
+    data: &Data,
+    documents: &[TextDocument],
+    from: &str,
+    to: &str,
+    prefs: &TranslationPreferences,
+) -> Vec<serenity::CreateAttachment> {
+    let mut files = Vec::new();
+    'documents: for document in documents {
+        // One request per chunk keeps each reply under the model's token limit
+        let mut parts = Vec::new();
+        let mut changed = false;
+        for chunk in attachments::chunk_document(&document.text, attachments::DOCUMENT_CHUNK_CHARS) {
+            match data.translator.translate_with_preferences(&chunk, from, to, prefs).await {
+                Ok(Some(translated)) => {
+                    changed = true;
+                    parts.push(translated);
+                }
+                Ok(None) => parts.push(chunk),
+                Err(e) => {
+                    warn!("Translation of {} to {} failed: {}", document.filename, to, e);
+                    continue 'documents;
+                }
+            }
+        }
+        if !changed {
+            debug!("Document {} is already in {}", document.filename, to);
+            continue;
+        }
+        files.push(serenity::CreateAttachment::bytes(
+            parts.join("\n\n").into_bytes(),
+            attachments::translated_filename(&document.filename, to),
+        ));
+    }
+    files
+}
+
+/// Build a translation DM: an embed with the author, a jump link to the
+/// original and, for replies, a quote of the message being replied to
+fn translation_dm(
+    locale: &str,
+    message: &Message,
+    channel_name: &str,
+    translated: &str,
+    quote: Option<&str>,
+    files: &[serenity::CreateAttachment],
+) -> serenity::CreateMessage {
+    let link = message.link();
+    let mut description = String::new();
+    if let Some(quote) = quote {
+        description.push_str(quote);
+        description.push_str("\n\n");
+    }
+    if !translated.is_empty() {
+        description.push_str(truncate_str(translated, MAX_DM_TRANSLATION_CHARS));
+        description.push_str("\n\n");
+    }
+    description.push_str(&tr_args(locale, "translation-dm-jump", &[("link", &link)]));
+
+    let author = serenity::CreateEmbedAuthor::new(&message.author.name).icon_url(message.author.face());
+    let embed = serenity::CreateEmbed::new()
+        .author(author)
+        .title(format!("#{}", channel_name))
+        .url(link)
+        .description(description)
+        .footer(serenity::CreateEmbedFooter::new(tr(locale, "translation-dm-footer")))
+        .timestamp(message.timestamp);
+
+    serenity::CreateMessage::new().embed(embed).add_files(files.to_vec())
+}
+
+/// One-line quote of the message being replied to, translated for the reader
+async fn reply_quote(data: &Data, message: &Message, fallback_lang: &str, to: &str, prefs: &TranslationPreferences) -> Option<String> {
+    let referenced = message.referenced_message.as_ref()?;
+    let line = referenced.content.lines().map(str::trim).find(|l| !l.is_empty())?;
+    let line = if line.chars().count() > MAX_QUOTE_CHARS {
+        format!("{}…", truncate_str(line, MAX_QUOTE_CHARS - 1))
+    } else {
+        line.to_string()
+    };
+
+    // Short lines are often misdetected, so fall back to the reply's own language
+    let from = language::detect(&line)
+        .filter(|d| d.confidence >= language::CONFIDENT)
+        .map(|d| d.code.to_string())
+        .unwrap_or_else(|| fallback_lang.to_string());
+
+    let quoted = if from == to {
+        line
+    } else {
+        match data.translator.translate_with_preferences(&line, &from, to, prefs).await {
+            Ok(Some(translated)) => translated.lines().next().unwrap_or_default().to_string(),
+            Ok(None) => line,
+            Err(e) => {
+                warn!("Translating reply quote to {} failed: {}", to, e);
+                line
+            }
+        }
+    };
+
+    Some(format!("> ↪ **{}**: {}", referenced.author.name, quoted))
+}
+
+/// The subscribers who can see the channel a message was posted in
+///
+/// Category- and guild-wide subscriptions also match channels a subscriber
+/// has no access to, so each one is checked against the channel, or its
+/// parent for threads. Anyone who can't be checked is left out.
+async fn can_view_channel(ctx: &Discord, message: &Message, subscribers: Vec<String>) -> Vec<String> {
+    let Some(guild_id) = message.guild_id else { return Vec::new() };
+    if subscribers.is_empty() {
+        return subscribers;
+    }
+
+    let Some(mut channel) = message.channel_id.to_channel(ctx).await.ok().and_then(|c| c.guild()) else {
+        warn!("Couldn't look up channel {}; not sending translation DMs", message.channel_id);
+        return Vec::new();
+    };
+    if channel.thread_metadata.is_some() {
+        let parent = match channel.parent_id {
+            Some(parent) => parent.to_channel(ctx).await.ok().and_then(|c| c.guild()),
+            None => None,
+        };
+        let Some(parent) = parent else {
+            warn!("Couldn't look up the parent of thread {}; not sending translation DMs", message.channel_id);
+            return Vec::new();
+        };
+        channel = parent;
+    }
+
+    let mut members = Vec::new();
+    for subscriber_id in subscribers {
+        let Ok(user_id) = subscriber_id.parse::<u64>() else { continue };
+        match guild_id.member(ctx, serenity::UserId::new(user_id)).await {
+            Ok(member) => members.push((subscriber_id, member)),
+            Err(e) => debug!("Not sending a translation to {}: {}", subscriber_id, e),
+        }
+    }
+
+    let Some(guild) = ctx.cache.guild(guild_id) else {
+        warn!("Guild {} isn't cached; not sending translation DMs", guild_id);
+        return Vec::new();
+    };
+    visible_to(&guild, &channel, members)
+}
+
+/// IDs of the members with VIEW_CHANNEL in a (non-thread) channel
+fn visible_to(guild: &serenity::Guild, channel: &serenity::GuildChannel, members: Vec<(String, serenity::Member)>) -> Vec<String> {
+    members
+        .into_iter()
+        .filter(|(_, member)| guild.user_permissions_in(channel, member).view_channel())
+        .map(|(id, _)| id)
+        .collect()
+}
+
+/// DM a translation to a subscriber, remembering the original so they can reply to it
+async fn send_dm(
+    ctx: &Discord,
+    data: &Data,
+    subscriber_id: &str,
+    dm: &serenity::CreateMessage,
+    message: &Message,
+    guild_id: &str,
+) {
+    let Ok(user_id) = subscriber_id.parse::<u64>() else { return };
+    let user = serenity::UserId::new(user_id);
+    let Ok(dm_channel) = user.create_dm_channel(ctx).await else { return };
+    match dm_channel.send_message(ctx, dm.clone()).await {
+        Ok(sent) => {
+            let record = TranslationDm {
+                discord_id: subscriber_id.to_string(),
+                guild_id: guild_id.to_string(),
+                channel_id: message.channel_id.to_string(),
+                message_id: message.id.to_string(),
+            };
+            if let Err(e) = data.db.add_translation_dm(&sent.id.to_string(), &record).await {
+                warn!("Failed to record translation DM {}: {}", sent.id, e);
+            }
+        }
+        Err(e) => debug!("Failed to DM {}: {}", subscriber_id, e),
+    }
+}
+
 // ==================== Commands ====================
 
 /// Get guild_id from context, returning error message if in DM
-fn get_guild_id(ctx: &Context<'_>) -> Option<String> {
+pub(crate) fn get_guild_id(ctx: &Context<'_>) -> Option<String> {
     ctx.guild_id().map(|gid| gid.to_string())
 }
 
 /// Subscribe to translations in this channel (can subscribe to multiple languages)
-pub async fn subscribe(ctx: Context<'_>, language: String) -> Result<(), Error> {
+pub async fn subscribe(ctx: Context<'_>, language: String, scope: Option<String>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ Translation subscriptions are only available in servers.").await?;
+            ctx.say(tr(locale, "translate-server-only")).await?;
             return Ok(());
         }
     };
@@ -305,24 +560,44 @@ pub async fn subscribe(ctx: Context<'_>, language: String) -> Result<(), Error>
     let lang_code = normalize_language(&language);
 
     if !is_supported_language(&lang_code) {
-        ctx.say(format!(
-            "Language '{}' is not currently supported.\n\
-             Supported: English (en), Hindi (hi), French (fr), Spanish (es), German (de), Filipino (fil), Portuguese (pt), Korean (ko)",
-            language
-        ))
-        .await?;
+        ctx.say(tr_args(locale, "translate-unsupported", &[("language", &language)])).await?;
         return Ok(());
     }
 
     let user_id = ctx.author().id.to_string();
     let channel_id = ctx.channel_id().to_string();
 
+    // Category- and server-wide subscriptions
+    let Some((scope, scope_id)) = resolve_scope(ctx, locale, &guild_id, scope.as_deref()).await? else {
+        return Ok(());
+    };
+    if let Some(scope_id) = scope_id {
+        let scope_name = tr(locale, &format!("scope-{}", scope));
+        let existing = ctx.data().db.get_scoped_subscriptions(&guild_id, &user_id, scope, &scope_id).await?;
+        if existing.contains(&lang_code) {
+            ctx.say(tr_args(locale, "translate-already-subscribed-scope", &[
+                ("language", &localized_language_name(locale, &lang_code)),
+                ("scope", &scope_name),
+            ])).await?;
+            return Ok(());
+        }
+
+        ctx.data().db.add_scoped_subscription(&guild_id, &user_id, scope, &scope_id, &lang_code).await?;
+        info!("User {} subscribed to {} translations in {} {} (guild {})", user_id, lang_code, scope, scope_id, guild_id);
+
+        let all_subs = ctx.data().db.get_scoped_subscriptions(&guild_id, &user_id, scope, &scope_id).await?;
+        let all_names: Vec<String> = all_subs.iter().map(|l| localized_language_name(locale, l)).collect();
+        ctx.say(tr_args(locale, "translate-subscribed-scope", &[
+            ("language", &localized_language_name(locale, &lang_code)),
+            ("languages", &all_names.join(", ")),
+            ("scope", &scope_name),
+        ])).await?;
+        return Ok(());
+    }
+
     // Check if already subscribed
     if ctx.data().db.has_translation_subscription(&guild_id, &user_id, &channel_id, &lang_code).await? {
-        ctx.say(format!(
-            "📖 You're already subscribed to **{}** translations in this channel.",
-            language_name(&lang_code)
-        )).await?;
+        ctx.say(tr_args(locale, "translate-already-subscribed", &[("language", &localized_language_name(locale, &lang_code))])).await?;
         return Ok(());
     }
 
@@ -332,26 +607,24 @@ pub async fn subscribe(ctx: Context<'_>, language: String) -> Result<(), Error>
 
     // Show all current subscriptions
     let all_subs = ctx.data().db.get_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
-    let all_names: Vec<&str> = all_subs.iter().map(|l| language_name(l)).collect();
-
-    let msg = format!(
-        "✅ Subscribed to **{}** translations in this channel.\n\
-         Your subscriptions: **{}**\n\n\
-         ⚠️ *Translations are machine-generated and may contain inaccuracies.*",
-        language_name(&lang_code),
-        all_names.join(", ")
-    );
+    let all_names: Vec<String> = all_subs.iter().map(|l| localized_language_name(locale, l)).collect();
+
+    let msg = tr_args(locale, "translate-subscribed", &[
+        ("language", &localized_language_name(locale, &lang_code)),
+        ("languages", &all_names.join(", ")),
+    ]);
     ctx.say(msg).await?;
 
     Ok(())
 }
 
 /// Unsubscribe from translations in this channel (optionally specify a language, or 'all' to remove all)
-pub async fn unsubscribe(ctx: Context<'_>, language: Option<String>) -> Result<(), Error> {
+pub async fn unsubscribe(ctx: Context<'_>, language: Option<String>, scope: Option<String>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ Translation subscriptions are only available in servers.").await?;
+            ctx.say(tr(locale, "translate-server-only")).await?;
             return Ok(());
         }
     };
@@ -359,19 +632,23 @@ pub async fn unsubscribe(ctx: Context<'_>, language: Option<String>) -> Result<(
     let user_id = ctx.author().id.to_string();
     let channel_id = ctx.channel_id().to_string();
 
+    let Some((scope, scope_id)) = resolve_scope(ctx, locale, &guild_id, scope.as_deref()).await? else {
+        return Ok(());
+    };
+    if let Some(scope_id) = scope_id {
+        return unsubscribe_scoped(ctx, locale, &guild_id, scope, &scope_id, language).await;
+    }
+
     match language {
         Some(lang) if lang.to_lowercase() == "all" => {
             ctx.data().db.remove_all_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
             info!("User {} unsubscribed from all translations in channel {} (guild {})", user_id, channel_id, guild_id);
-            ctx.say("✅ Unsubscribed from all translation DMs in this channel.").await?;
+            ctx.say(tr(locale, "translate-unsubscribed-all")).await?;
         }
         Some(lang) => {
             let lang_code = normalize_language(&lang);
             if !ctx.data().db.has_translation_subscription(&guild_id, &user_id, &channel_id, &lang_code).await? {
-                ctx.say(format!(
-                    "📖 You're not subscribed to **{}** translations in this channel.",
-                    language_name(&lang_code)
-                )).await?;
+                ctx.say(tr_args(locale, "translate-not-subscribed", &[("language", &localized_language_name(locale, &lang_code))])).await?;
                 return Ok(());
             }
 
@@ -381,82 +658,223 @@ pub async fn unsubscribe(ctx: Context<'_>, language: Option<String>) -> Result<(
             // Show remaining subscriptions
             let remaining = ctx.data().db.get_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
             if remaining.is_empty() {
-                ctx.say(format!(
-                    "✅ Unsubscribed from **{}** translations. You have no remaining subscriptions in this channel.",
-                    language_name(&lang_code)
-                )).await?;
+                ctx.say(tr_args(locale, "translate-unsubscribed-none-left", &[("language", &localized_language_name(locale, &lang_code))])).await?;
             } else {
-                let names: Vec<&str> = remaining.iter().map(|l| language_name(l)).collect();
-                ctx.say(format!(
-                    "✅ Unsubscribed from **{}** translations.\n\
-                     Remaining subscriptions: **{}**",
-                    language_name(&lang_code),
-                    names.join(", ")
-                )).await?;
+                let names: Vec<String> = remaining.iter().map(|l| localized_language_name(locale, l)).collect();
+                ctx.say(tr_args(locale, "translate-unsubscribed", &[
+                    ("language", &localized_language_name(locale, &lang_code)),
+                    ("languages", &names.join(", ")),
+                ])).await?;
             }
         }
         None => {
             // No language specified - show current subscriptions and ask for clarification
             let subs = ctx.data().db.get_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
             if subs.is_empty() {
-                ctx.say("📖 You have no translation subscriptions in this channel.").await?;
+                ctx.say(tr(locale, "translate-no-subscriptions")).await?;
             } else {
-                let names: Vec<&str> = subs.iter().map(|l| language_name(l)).collect();
-                ctx.say(format!(
-                    "📖 Your subscriptions: **{}**\n\
-                     To unsubscribe, use `/fabrica translate unsubscribe <language>` or `all` to remove all.",
-                    names.join(", ")
-                )).await?;
+                let names: Vec<String> = subs.iter().map(|l| localized_language_name(locale, l)).collect();
+                ctx.say(tr_args(locale, "translate-unsubscribe-help", &[("languages", &names.join(", "))])).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Unsubscribe from a category- or server-wide subscription
+async fn unsubscribe_scoped(
+    ctx: Context<'_>,
+    locale: &str,
+    guild_id: &str,
+    scope: &str,
+    scope_id: &str,
+    language: Option<String>,
+) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let scope_name = tr(locale, &format!("scope-{}", scope));
+    let subs = ctx.data().db.get_scoped_subscriptions(guild_id, &user_id, scope, scope_id).await?;
+
+    match language {
+        Some(lang) if lang.to_lowercase() == "all" => {
+            ctx.data().db.remove_scoped_subscription(guild_id, &user_id, scope, scope_id, None).await?;
+            info!("User {} unsubscribed from all translations in {} {} (guild {})", user_id, scope, scope_id, guild_id);
+            ctx.say(tr_args(locale, "translate-unsubscribed-all-scope", &[("scope", &scope_name)])).await?;
+        }
+        Some(lang) => {
+            let lang_code = normalize_language(&lang);
+            let language_name = localized_language_name(locale, &lang_code);
+            let args = [("language", language_name.as_str()), ("scope", scope_name.as_str())];
+            if !subs.contains(&lang_code) {
+                ctx.say(tr_args(locale, "translate-not-subscribed-scope", &args)).await?;
+                return Ok(());
             }
+            ctx.data().db.remove_scoped_subscription(guild_id, &user_id, scope, scope_id, Some(&lang_code)).await?;
+            info!("User {} unsubscribed from {} translations in {} {} (guild {})", user_id, lang_code, scope, scope_id, guild_id);
+            ctx.say(tr_args(locale, "translate-unsubscribed-scope", &args)).await?;
+        }
+        None if subs.is_empty() => {
+            ctx.say(tr_args(locale, "translate-no-subscriptions-scope", &[("scope", &scope_name)])).await?;
+        }
+        None => {
+            let names: Vec<String> = subs.iter().map(|l| localized_language_name(locale, l)).collect();
+            ctx.say(tr_args(locale, "translate-unsubscribe-help-scope", &[
+                ("languages", &names.join(", ")),
+                ("scope", &scope_name),
+            ])).await?;
         }
     }
 
     Ok(())
 }
 
+/// Turn category- and server-wide subscriptions off (or back on) in this channel
+pub async fn opt_out(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match get_guild_id(&ctx) {
+        Some(gid) => gid,
+        None => {
+            ctx.say(tr(locale, "translate-server-only")).await?;
+            return Ok(());
+        }
+    };
+
+    let user_id = ctx.author().id.to_string();
+    let channel_id = ctx.channel_id().to_string();
+    let opted_out = !ctx.data().db.is_subscription_opted_out(&guild_id, &user_id, &channel_id).await?;
+    ctx.data().db.set_subscription_opt_out(&guild_id, &user_id, &channel_id, opted_out).await?;
+
+    info!("User {} {} broader subscriptions in channel {} (guild {})",
+          user_id, if opted_out { "opted out of" } else { "opted back into" }, channel_id, guild_id);
+
+    let msg = tr(locale, if opted_out { "translate-opted-out" } else { "translate-opted-in" });
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+    Ok(())
+}
+
+/// Parse a subscription scope and find the ID it covers: `None` ID for the
+/// channel itself, the category ID, or the guild ID. Replies and returns
+/// `None` when the scope is invalid or the channel has no category.
+async fn resolve_scope(
+    ctx: Context<'_>,
+    locale: &str,
+    guild_id: &str,
+    scope: Option<&str>,
+) -> Result<Option<(&'static str, Option<String>)>, Error> {
+    let scope = match scope.map(normalize_scope) {
+        None => "channel",
+        Some(Some(scope)) => scope,
+        Some(None) => {
+            ctx.say(tr_args(locale, "translate-invalid-scope", &[("scope", scope.unwrap_or_default())])).await?;
+            return Ok(None);
+        }
+    };
+
+    let scope_id = match scope {
+        "guild" => Some(guild_id.to_string()),
+        "category" => match channel_category(ctx.serenity_context(), ctx.channel_id()).await {
+            Some(category) => Some(category),
+            None => {
+                ctx.say(tr(locale, "translate-no-category")).await?;
+                return Ok(None);
+            }
+        },
+        _ => None,
+    };
+    Ok(Some((scope, scope_id)))
+}
+
+/// Category a channel belongs to; threads use their parent channel's category
+pub(crate) async fn channel_category(cache_http: impl serenity::CacheHttp, channel_id: serenity::ChannelId) -> Option<String> {
+    let channel = channel_id.to_channel(&cache_http).await.ok()?.guild()?;
+    let parent = channel.parent_id?;
+    if channel.thread_metadata.is_some() {
+        let parent = parent.to_channel(&cache_http).await.ok()?.guild()?;
+        return parent.parent_id.map(|id| id.to_string());
+    }
+    Some(parent.to_string())
+}
+
 /// Show translation status for this channel
 pub async fn status(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ Translation subscriptions are only available in servers.").await?;
+            ctx.say(tr(locale, "translate-server-only")).await?;
             return Ok(());
         }
     };
 
     let user_id = ctx.author().id.to_string();
     let channel_id = ctx.channel_id().to_string();
-    let subscriptions = ctx.data().db.get_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
+    let category_id = channel_category(ctx.serenity_context(), ctx.channel_id()).await;
+
+    // One entry per language, from its narrowest scope
+    let mut effective: Vec<(String, String)> = Vec::new();
+    for (language, scope) in ctx.data().db.get_effective_subscriptions(&guild_id, &user_id, &channel_id, category_id.as_deref()).await? {
+        if !effective.iter().any(|(l, _)| *l == language) {
+            effective.push((language, scope));
+        }
+    }
+
     let debug_mode = ctx.data().db.get_translation_debug_mode(&guild_id, &user_id, &channel_id).await.unwrap_or(false);
+    let opted_out = ctx.data().db.is_subscription_opted_out(&guild_id, &user_id, &channel_id).await.unwrap_or(false);
     let channel_mode = ctx.data().db.get_channel_translation_mode(&guild_id, &channel_id).await?;
+    let understood = ctx.data().db.get_understood_languages(&user_id).await.unwrap_or_default();
 
-    if subscriptions.is_empty() {
-        ctx.say(format!(
-            "📖 You have no translation subscriptions in this channel.\n\
-             Channel mode: **{}**",
-            channel_mode
-        )).await?;
+    let mut msg = if effective.is_empty() {
+        tr(locale, "translate-status-none")
     } else {
-        let names: Vec<&str> = subscriptions.iter().map(|l| language_name(l)).collect();
-        let debug_status = if debug_mode { "\n🔧 Debug mode: **ON**" } else { "" };
-        ctx.say(format!(
-            "📖 Your subscriptions: **{}**\n\
-             Channel mode: **{}**{}",
-            names.join(", "),
-            channel_mode,
-            debug_status
-        )).await?;
+        let names: Vec<String> = effective
+            .iter()
+            .map(|(l, scope)| {
+                let scope = tr(locale, &format!("translate-status-scope-{}", scope));
+                format!("{} ({})", localized_language_name(locale, l), scope)
+            })
+            .collect();
+        tr_args(locale, "translate-status-subscriptions", &[("languages", &names.join(", "))])
+    };
+    msg.push('\n');
+    msg.push_str(&tr_args(locale, "translate-status-mode", &[("mode", &channel_mode)]));
+    if debug_mode && !effective.is_empty() {
+        msg.push('\n');
+        msg.push_str(&tr(locale, "translate-status-debug"));
+    }
+    if opted_out {
+        msg.push('\n');
+        msg.push_str(&tr(locale, "translate-status-opted-out"));
+    }
+    if !understood.is_empty() {
+        msg.push('\n');
+        msg.push_str(&tr_args(locale, "translate-status-understood", &[("languages", &format_language_list(locale, &understood))]));
     }
 
+    // Show dialect/formality/script for subscribed languages that have them
+    for (lang, _) in &effective {
+        let prefs = ctx.data().db.get_translation_preferences(&user_id, lang).await.unwrap_or_default();
+        let parts: Vec<String> = [("dialect", &prefs.dialect), ("formality", &prefs.formality), ("script", &prefs.script)]
+            .into_iter()
+            .filter_map(|(kind, value)| {
+                value.as_ref().map(|value| tr_args(locale, &format!("translate-status-{}", kind), &[("value", value)]))
+            })
+            .collect();
+        if !parts.is_empty() {
+            msg.push_str(&format!("\n• **{}** - {}", localized_language_name(locale, lang), parts.join(", ")));
+        }
+    }
+
+    ctx.say(msg).await?;
     Ok(())
 }
 
 /// Toggle debug mode (receive translations of your own messages) for this channel
 pub async fn debug(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ Translation subscriptions are only available in servers.").await?;
+            ctx.say(tr(locale, "translate-server-only")).await?;
             return Ok(());
         }
     };
@@ -467,7 +885,7 @@ pub async fn debug(ctx: Context<'_>) -> Result<(), Error> {
     // Check if subscribed first
     let subscriptions = ctx.data().db.get_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
     if subscriptions.is_empty() {
-        ctx.say("⚠️ You need to subscribe to translations first with `/fabrica translate subscribe <language>`").await?;
+        ctx.say(tr(locale, "debug-not-subscribed")).await?;
         return Ok(());
     }
 
@@ -477,10 +895,10 @@ pub async fn debug(ctx: Context<'_>) -> Result<(), Error> {
 
     if new_state {
         info!("User {} enabled translation debug mode in channel {} (guild {})", user_id, channel_id, guild_id);
-        ctx.say("🔧 Debug mode **ON** - You'll receive DM translations of your own messages in this channel.").await?;
+        ctx.say(tr(locale, "debug-on")).await?;
     } else {
         info!("User {} disabled translation debug mode in channel {} (guild {})", user_id, channel_id, guild_id);
-        ctx.say("🔧 Debug mode **OFF** - You won't receive translations of your own messages in this channel.").await?;
+        ctx.say(tr(locale, "debug-off")).await?;
     }
 
     Ok(())
@@ -489,10 +907,11 @@ pub async fn debug(ctx: Context<'_>) -> Result<(), Error> {
 /// Set dialect preference for a language
 pub async fn set_dialect(ctx: Context<'_>, language: String, dialect: String) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
 
     // Normalize language code
     let lang_code = normalize_language(&language);
-    let lang_name = language_name(&lang_code);
+    let lang_name = localized_language_name(locale, &lang_code);
 
     // Store the dialect preference
     ctx.data().db.set_dialect_preference(&user_id, &lang_code, &dialect).await?;
@@ -500,13 +919,7 @@ pub async fn set_dialect(ctx: Context<'_>, language: String, dialect: String) ->
     info!("User {} set dialect preference: {} -> {}", user_id, lang_code, dialect);
 
     ctx.send(poise::CreateReply::default()
-        .content(format!(
-            "🗣️ Dialect preference set!\n\
-             **Language:** {}\n\
-             **Dialect:** {}\n\n\
-             When others translate to {} for you, they'll use your preferred dialect.",
-            lang_name, dialect, lang_name
-        ))
+        .content(tr_args(locale, "dialect-set", &[("language", &lang_name), ("dialect", &dialect)]))
         .ephemeral(true)).await?;
 
     Ok(())
@@ -515,25 +928,19 @@ pub async fn set_dialect(ctx: Context<'_>, language: String, dialect: String) ->
 /// Show current dialect preferences
 pub async fn show_dialects(ctx: Context<'_>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
     let prefs = ctx.data().db.get_all_dialect_preferences(&user_id).await?;
 
-    if prefs.is_empty() {
-        ctx.send(poise::CreateReply::default()
-            .content("🗣️ You have no dialect preferences set.\n\n\
-                     Use `/fabrica translate dialect <language> <dialect>` to set one.\n\
-                     Examples:\n\
-                     • `/fabrica translate dialect filipino bisaya`\n\
-                     • `/fabrica translate dialect chinese cantonese`\n\
-                     • `/fabrica translate dialect spanish mexican`")
-            .ephemeral(true)).await?;
+    let msg = if prefs.is_empty() {
+        tr(locale, "dialect-none")
     } else {
-        let mut msg = String::from("🗣️ **Your Dialect Preferences**\n\n");
-        for (lang, dialect) in &prefs {
-            msg.push_str(&format!("• **{}**: {}\n", language_name(lang), dialect));
-        }
-        msg.push_str("\nUse `/fabrica translate dialect <language> <dialect>` to change.");
-        ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
-    }
+        let list: Vec<String> = prefs
+            .iter()
+            .map(|(lang, dialect)| format!("• **{}**: {}", localized_language_name(locale, lang), dialect))
+            .collect();
+        tr_args(locale, "dialect-show", &[("list", &list.join("\n"))])
+    };
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
 
     Ok(())
 }
@@ -541,36 +948,183 @@ pub async fn show_dialects(ctx: Context<'_>) -> Result<(), Error> {
 /// Clear dialect preference for a language
 pub async fn clear_dialect(ctx: Context<'_>, language: String) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
     let lang_code = normalize_language(&language);
-    let lang_name = language_name(&lang_code);
 
     ctx.data().db.clear_dialect_preference(&user_id, &lang_code).await?;
 
     info!("User {} cleared dialect preference for {}", user_id, lang_code);
 
     ctx.send(poise::CreateReply::default()
-        .content(format!("🗣️ Cleared dialect preference for **{}**. Default dialect will be used.", lang_name))
+        .content(tr_args(locale, "dialect-cleared", &[("language", &localized_language_name(locale, &lang_code))]))
         .ephemeral(true)).await?;
 
     Ok(())
 }
 
+/// Set or show formality preference for a language
+pub async fn set_formality(ctx: Context<'_>, language: Option<String>, level: Option<String>) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
+
+    let (language, level) = match (language, level) {
+        (Some(language), Some(level)) => (language, level),
+        _ => {
+            let prefs = ctx.data().db.get_all_formality_preferences(&user_id).await?;
+            let mut msg = if prefs.is_empty() {
+                tr(locale, "formality-none")
+            } else {
+                let list: Vec<String> = prefs
+                    .iter()
+                    .map(|(lang, formality)| format!("• **{}**: {}", localized_language_name(locale, lang), formality))
+                    .collect();
+                tr_args(locale, "formality-show", &[("list", &list.join("\n"))])
+            };
+            msg.push_str("\n\n");
+            msg.push_str(&tr(locale, "formality-help"));
+            ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+            return Ok(());
+        }
+    };
+
+    let lang_code = normalize_language(&language);
+    let lang_name = localized_language_name(locale, &lang_code);
+
+    let formality = match normalize_formality(&level) {
+        Some(f) => f,
+        None => {
+            ctx.send(poise::CreateReply::default()
+                .content(tr(locale, "formality-invalid"))
+                .ephemeral(true)).await?;
+            return Ok(());
+        }
+    };
+
+    ctx.data().db.set_formality_preference(&user_id, &lang_code, formality).await?;
+
+    info!("User {} set formality preference: {} -> {:?}", user_id, lang_code, formality);
+
+    let msg = match formality {
+        Some(f) => tr_args(locale, "formality-set", &[("language", &lang_name), ("formality", f)]),
+        None => tr_args(locale, "formality-cleared", &[("language", &lang_name)]),
+    };
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+/// Set or show script preference for a language with more than one script
+pub async fn set_script(ctx: Context<'_>, language: Option<String>, script: Option<String>) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
+
+    let (language, script) = match (language, script) {
+        (Some(language), Some(script)) => (language, script),
+        _ => {
+            let prefs = ctx.data().db.get_all_script_preferences(&user_id).await?;
+            let mut msg = if prefs.is_empty() {
+                tr(locale, "script-none")
+            } else {
+                let list: Vec<String> = prefs
+                    .iter()
+                    .map(|(lang, script)| format!("• **{}**: {}", localized_language_name(locale, lang), script))
+                    .collect();
+                tr_args(locale, "script-show", &[("list", &list.join("\n"))])
+            };
+            msg.push_str("\n\n");
+            msg.push_str(&tr(locale, "script-help"));
+            ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+            return Ok(());
+        }
+    };
+
+    let lang_code = normalize_language(&language);
+    let lang_name = localized_language_name(locale, &lang_code);
+
+    if !ctx.data().translator.prompts().has_romanization(&lang_code) {
+        ctx.send(poise::CreateReply::default()
+            .content(tr_args(locale, "script-single", &[("language", &lang_name)]))
+            .ephemeral(true)).await?;
+        return Ok(());
+    }
+
+    let script = match normalize_script(&script) {
+        Some(s) => s,
+        None => {
+            ctx.send(poise::CreateReply::default()
+                .content(tr(locale, "script-invalid"))
+                .ephemeral(true)).await?;
+            return Ok(());
+        }
+    };
+
+    ctx.data().db.set_script_preference(&user_id, &lang_code, script).await?;
+
+    info!("User {} set script preference: {} -> {:?}", user_id, lang_code, script);
+
+    let msg = match script {
+        Some(s) => tr_args(locale, &format!("script-set-{}", s), &[("language", &lang_name)]),
+        None => tr_args(locale, "script-cleared", &[("language", &lang_name)]),
+    };
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+
+    Ok(())
+}
+
+/// Set or show the languages a user reads without translation
+pub async fn set_understood(ctx: Context<'_>, languages: Option<String>) -> Result<(), Error> {
+    let user_id = ctx.author().id.to_string();
+    let locale = locale_for(ctx).await;
+
+    let Some(languages) = languages else {
+        let understood = ctx.data().db.get_understood_languages(&user_id).await?;
+        let msg = if understood.is_empty() {
+            tr(locale, "understand-none")
+        } else {
+            tr_args(locale, "understand-show", &[("languages", &format_language_list(locale, &understood))])
+        };
+        ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+        return Ok(());
+    };
+
+    let codes = match parse_language_list(&languages) {
+        Ok(codes) => codes,
+        Err(unknown) => {
+            ctx.send(poise::CreateReply::default()
+                .content(tr_args(locale, "translate-unsupported", &[("language", &unknown)]))
+                .ephemeral(true)).await?;
+            return Ok(());
+        }
+    };
+
+    ctx.data().db.set_understood_languages(&user_id, &codes).await?;
+
+    info!("User {} set understood languages: {:?}", user_id, codes);
+
+    let msg = if codes.is_empty() {
+        tr(locale, "understand-cleared")
+    } else {
+        tr_args(locale, "understand-set", &[("languages", &format_language_list(locale, &codes))])
+    };
+    ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
+
+    Ok(())
+}
+
 /// Set default translation language
 pub async fn set_default(ctx: Context<'_>, language: String) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let lang_code = normalize_language(&language);
-    let lang_name = language_name(&lang_code);
 
     ctx.data().db.set_default_language(&user_id, &lang_code).await?;
 
     info!("User {} set default language to {}", user_id, lang_code);
 
+    // Replies follow the new default language from here on
+    let locale = locale_for(ctx).await;
+    let lang_name = localized_language_name(locale, &lang_code);
     ctx.send(poise::CreateReply::default()
-        .content(format!(
-            "🌐 Default language set to **{}**!\n\n\
-             Now `/fabrica translate last` will translate to {} by default.",
-            lang_name, lang_name
-        ))
+        .content(tr_args(locale, "default-set", &[("language", &lang_name)]))
         .ephemeral(true)).await?;
 
     Ok(())
@@ -580,14 +1134,11 @@ pub async fn set_default(ctx: Context<'_>, language: String) -> Result<(), Error
 pub async fn show_default(ctx: Context<'_>) -> Result<(), Error> {
     let user_id = ctx.author().id.to_string();
     let default = ctx.data().db.get_default_language(&user_id).await?;
+    let locale = locale_for(ctx).await;
 
-    let msg = if let Some(lang) = default {
-        let lang_name = language_name(&lang);
-        format!("🌐 Your default translation language is **{}**.", lang_name)
-    } else {
-        "🌐 You haven't set a default language yet.\n\n\
-         Use `/fabrica translate default <language>` to set one.\n\
-         Without a default, `/fabrica translate last` uses your first subscription.".to_string()
+    let msg = match default {
+        Some(lang) => tr_args(locale, "default-show", &[("language", &localized_language_name(locale, &lang))]),
+        None => tr(locale, "default-none"),
     };
 
     ctx.send(poise::CreateReply::default().content(msg).ephemeral(true)).await?;
@@ -597,60 +1148,51 @@ pub async fn show_default(ctx: Context<'_>) -> Result<(), Error> {
 
 /// Set translation mode for channel
 pub async fn set_mode(ctx: Context<'_>, mode: String) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ Translation settings are only available in servers.").await?;
+            ctx.say(tr(locale, "translate-settings-server-only")).await?;
             return Ok(());
         }
     };
 
     // Check if user has permission (guild role or MANAGE_CHANNELS)
     if !has_translation_permission(&ctx, &guild_id, "mode").await {
-        ctx.say("⚠️ You need a configured role or MANAGE_CHANNELS permission to change translation settings.\n\
-                 Server admins can configure roles with `/fabrica server allow mode @role`").await?;
+        ctx.say(tr(locale, "translate-no-permission")).await?;
         return Ok(());
     }
 
     let mode_lower = mode.to_lowercase();
-    if !matches!(mode_lower.as_str(), "off" | "silent" | "on" | "transparent") {
-        ctx.say("⚠️ Invalid mode. Available modes:\n\
-                 • **off** - No translation\n\
-                 • **silent** - DM translations only (subscribe to `en` for English translations)\n\
-                 • **on** - DM translations + public English translations\n\
-                 • **transparent** - All translations shown publicly").await?;
+    let channel_id = ctx.channel_id().to_string();
+
+    // Mirror mode needs a link; re-enabling a channel that already has one is fine
+    if mode_lower == "mirror" && ctx.data().db.get_mirror_channel(&guild_id, &channel_id).await?.is_none() {
+        ctx.say(tr(locale, "mirror-not-linked-yet")).await?;
+        return Ok(());
+    }
+
+    if !matches!(mode_lower.as_str(), "off" | "silent" | "on" | "transparent" | "mirror") {
+        ctx.say(tr(locale, "mode-invalid")).await?;
         return Ok(());
     }
 
-    let channel_id = ctx.channel_id().to_string();
     let set_by = ctx.author().id.to_string();
     ctx.data().db.set_channel_translation_mode(&guild_id, &channel_id, &mode_lower, &set_by).await?;
 
     info!("Channel {} translation mode set to {} by {} (guild {})", channel_id, mode_lower, set_by, guild_id);
 
-    let description = match mode_lower.as_str() {
-        "off" => "Translation is **disabled**. Messages will not be processed.",
-        "silent" => "Translation mode: **silent**\n\
-                     • Non-English → English: DM to English subscribers only\n\
-                     • English → Other: DM to language subscribers",
-        "on" => "Translation mode: **on**\n\
-                 • Non-English → English: Posted publicly\n\
-                 • English → Other: DM to language subscribers",
-        "transparent" => "Translation mode: **transparent**\n\
-                         • All translations posted publicly in channel",
-        _ => "Mode set.",
-    };
-
-    ctx.say(format!("✅ {}", description)).await?;
+    ctx.say(tr(locale, &format!("mode-set-{}", mode_lower))).await?;
     Ok(())
 }
 
 /// Show current channel translation mode
 pub async fn show_mode(ctx: Context<'_>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ Translation settings are only available in servers.").await?;
+            ctx.say(tr(locale, "translate-settings-server-only")).await?;
             return Ok(());
         }
     };
@@ -659,14 +1201,65 @@ pub async fn show_mode(ctx: Context<'_>) -> Result<(), Error> {
     let mode = ctx.data().db.get_channel_translation_mode(&guild_id, &channel_id).await?;
 
     let description = match mode.as_str() {
-        "off" => "**off** - No translation",
-        "silent" => "**silent** - DM translations only",
-        "on" => "**on** - DM + public English translations",
-        "transparent" => "**transparent** - All translations public",
-        _ => &mode,
+        "off" | "silent" | "on" | "transparent" => tr(locale, &format!("mode-describe-{}", mode)),
+        "mirror" => mirror::describe(ctx.data(), locale, &guild_id, &channel_id).await?,
+        _ => mode,
     };
 
-    ctx.say(format!("📖 Channel translation mode: {}", description)).await?;
+    let mut reply = tr_args(locale, "mode-show", &[("mode", &description)]);
+    if ctx.data().db.is_attachment_translation_enabled(&guild_id, &channel_id).await? {
+        reply.push('\n');
+        reply.push_str(&tr(locale, "mode-show-attachments"));
+    }
+
+    ctx.say(reply).await?;
+    Ok(())
+}
+
+/// Turn translation of attachments, embeds and image alt text on or off for this channel
+pub async fn set_attachments(ctx: Context<'_>, enabled: Option<String>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
+    let guild_id = match get_guild_id(&ctx) {
+        Some(gid) => gid,
+        None => {
+            ctx.say(tr(locale, "translate-settings-server-only")).await?;
+            return Ok(());
+        }
+    };
+
+    let channel_id = ctx.channel_id().to_string();
+    let current = ctx.data().db.is_attachment_translation_enabled(&guild_id, &channel_id).await?;
+
+    let Some(enabled) = enabled else {
+        ctx.say(tr(locale, if current { "attachments-show-on" } else { "attachments-show-off" })).await?;
+        return Ok(());
+    };
+
+    if !has_translation_permission(&ctx, &guild_id, "mode").await {
+        ctx.say(tr(locale, "translate-no-permission")).await?;
+        return Ok(());
+    }
+
+    let enabled = match enabled.to_lowercase().as_str() {
+        "on" | "yes" | "true" | "enable" => true,
+        "off" | "no" | "false" | "disable" => false,
+        _ => {
+            ctx.say(tr(locale, "attachments-invalid")).await?;
+            return Ok(());
+        }
+    };
+
+    let set_by = ctx.author().id.to_string();
+    ctx.data().db.set_attachment_translation(&guild_id, &channel_id, enabled, &set_by).await?;
+
+    info!("Channel {} attachment translation set to {} by {} (guild {})", channel_id, enabled, set_by, guild_id);
+
+    if enabled {
+        let max_kb = ctx.data().config.translation.max_attachment_bytes / 1024;
+        ctx.say(tr_args(locale, "attachments-on", &[("size", &max_kb.to_string())])).await?;
+    } else {
+        ctx.say(tr(locale, "attachments-off")).await?;
+    }
     Ok(())
 }
 
@@ -676,8 +1269,20 @@ fn is_global_admin(ctx: &Context<'_>) -> bool {
     ctx.data().config.discord.admin_ids.contains(&user_id)
 }
 
+/// Resolve the invoking member's permissions in the current channel
+///
+/// Slash command interactions carry resolved permissions; prefix commands
+/// fall back to computing them from the guild cache.
+fn member_permissions(ctx: &Context<'_>, member: &serenity::Member) -> Option<serenity::Permissions> {
+    member.permissions.or_else(|| {
+        let guild = ctx.guild()?;
+        let channel = guild.channels.get(&ctx.channel_id())?;
+        Some(guild.user_permissions_in(channel, member))
+    })
+}
+
 /// Check if user has a configured role permission or MANAGE_CHANNELS permission
-async fn has_translation_permission(ctx: &Context<'_>, guild_id: &str, permission: &str) -> bool {
+pub(crate) async fn has_translation_permission(ctx: &Context<'_>, guild_id: &str, permission: &str) -> bool {
     // Global admins bypass all permission checks
     if is_global_admin(ctx) {
         return true;
@@ -685,7 +1290,7 @@ async fn has_translation_permission(ctx: &Context<'_>, guild_id: &str, permissio
 
     // Check for MANAGE_CHANNELS permission (always grants access)
     if let Some(member) = ctx.author_member().await {
-        if let Ok(perms) = member.permissions(ctx) {
+        if let Some(perms) = member_permissions(ctx, &member) {
             if perms.manage_channels() {
                 return true;
             }
@@ -733,10 +1338,11 @@ async fn has_translation_permission(ctx: &Context<'_>, guild_id: &str, permissio
 
 /// Show recent messages translated to user's subscribed language
 pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>) -> Result<(), Error> {
+    let locale = locale_for(ctx).await;
     let guild_id = match get_guild_id(&ctx) {
         Some(gid) => gid,
         None => {
-            ctx.say("⚠️ This command is only available in servers.").await?;
+            ctx.say(tr(locale, "error-server-only")).await?;
             return Ok(());
         }
     };
@@ -752,9 +1358,15 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
         default
     } else {
         // Fall back to subscriptions
-        let subscriptions = ctx.data().db.get_translation_subscriptions(&guild_id, &user_id, &channel_id).await?;
+        let category_id = channel_category(ctx.serenity_context(), ctx.channel_id()).await;
+        let subscriptions: Vec<String> = ctx.data().db
+            .get_effective_subscriptions(&guild_id, &user_id, &channel_id, category_id.as_deref())
+            .await?
+            .into_iter()
+            .map(|(language, _)| language)
+            .collect();
         if subscriptions.is_empty() {
-            ctx.say("⚠️ Set a default language with `/fabrica translate default <language>`, or specify one: `/fabrica translate last <count> <language>`").await?;
+            ctx.say(tr(locale, "last-no-language")).await?;
             return Ok(());
         }
 
@@ -765,8 +1377,10 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
             .clone()
     };
 
-    // Check if user has a dialect preference for this language
-    let dialect = ctx.data().db.get_dialect_preference(&user_id, &target_lang).await.ok().flatten();
+    // Check if user has dialect/formality preferences for this language
+    let prefs = ctx.data().db.get_translation_preferences(&user_id, &target_lang).await.unwrap_or_default();
+    // Messages in languages the user reads are shown as written
+    let understood = ctx.data().db.get_understood_languages(&user_id).await.unwrap_or_default();
 
     // Defer the reply since this might take a while
     ctx.defer().await?;
@@ -810,7 +1424,7 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
     };
 
     if messages.is_empty() {
-        ctx.say("📭 No new messages to show.").await?;
+        ctx.say(tr(locale, "last-no-new")).await?;
         return Ok(());
     }
 
@@ -830,7 +1444,7 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
         .collect();
 
     if filtered_messages.is_empty() {
-        ctx.say("📭 No messages to translate.").await?;
+        ctx.say(tr(locale, "last-nothing")).await?;
         return Ok(());
     }
 
@@ -838,16 +1452,20 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
     let mut chronological: Vec<_> = filtered_messages.into_iter().collect();
     chronological.reverse();
 
-    let translator = TranslatorService::new(&ctx.data().config.translation);
-    let target_lang_name = language_name(&target_lang);
-    let target_display = if let Some(ref d) = dialect {
+    let translator = &ctx.data().translator;
+    let target_lang_name = localized_language_name(locale, &target_lang);
+    let target_display = if let Some(ref d) = prefs.dialect {
         format!("{} ({})", target_lang_name, d)
     } else {
-        target_lang_name.to_string()
+        target_lang_name
     };
 
     // Build the translated output
-    let mut output = format!("📜 **Last {} messages translated to {}:**\n\n", chronological.len(), target_display);
+    let mut output = tr_args(locale, "last-header", &[
+        ("count", &chronological.len().to_string()),
+        ("language", &target_display),
+    ]);
+    output.push_str("\n\n");
     let mut translations_added = 0;
 
     for msg in &chronological {
@@ -862,16 +1480,14 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
         }
 
         // Detect source language (convert 3-letter whatlang codes to 2-letter ISO codes)
-        let detected = whatlang::detect(content);
-        let source_lang = detected
-            .map(|info| whatlang_to_iso(info.lang().code()))
-            .unwrap_or("en");
+        let source_lang = language::detect(content).map(|d| d.code).unwrap_or("en");
 
-        // Translate if needed (with dialect preference)
-        let translated_content = if source_lang == target_lang {
+        // Translate if needed (with dialect/formality preferences)
+        let translated_content = if source_lang == target_lang || understood.iter().any(|l| l == source_lang) {
             content.to_string()
         } else {
-            match translator.translate_with_dialect(content, source_lang, &target_lang, dialect.as_deref()).await {
+            let message_prefs = with_glossary(ctx.data(), &guild_id, source_lang, &target_lang, prefs.clone()).await;
+            match translator.translate_with_preferences(content, source_lang, &target_lang, &message_prefs).await {
                 Ok(Some(t)) => t,
                 Ok(None) => content.to_string(),
                 Err(_) => content.to_string(),
@@ -884,13 +1500,14 @@ pub async fn last(ctx: Context<'_>, count: Option<u32>, language: Option<String>
 
         // Check if we're approaching Discord's message limit (2000 chars)
         if output.len() > 1800 {
-            output.push_str("\n_...truncated due to length_");
+            output.push('\n');
+            output.push_str(&tr(locale, "last-truncated"));
             break;
         }
     }
 
     if translations_added == 0 {
-        ctx.say("📭 No translatable messages found.").await?;
+        ctx.say(tr(locale, "last-none-translatable")).await?;
         return Ok(());
     }
 
@@ -920,7 +1537,7 @@ pub async fn has_admin_permission(ctx: &Context<'_>, guild_id: &str) -> bool {
 
     // Server admin (ADMINISTRATOR permission) always has access
     if let Some(member) = ctx.author_member().await {
-        if let Ok(perms) = member.permissions(ctx) {
+        if let Some(perms) = member_permissions(ctx, &member) {
             if perms.administrator() {
                 return true;
             }
@@ -951,7 +1568,7 @@ pub async fn has_admin_permission(ctx: &Context<'_>, guild_id: &str) -> bool {
 // ==================== Helpers ====================
 
 /// Normalize language input to language code
-fn normalize_language(input: &str) -> String {
+pub(crate) fn normalize_language(input: &str) -> String {
     match input.to_lowercase().as_str() {
         // English
         "english" | "en" | "eng" => "en".to_string(),
@@ -973,13 +1590,85 @@ fn normalize_language(input: &str) -> String {
     }
 }
 
+/// Parse a comma- or space-separated list of languages into codes;
+/// "none" gives an empty list, an unsupported entry is returned as the error
+fn parse_language_list(input: &str) -> Result<Vec<String>, String> {
+    let mut codes = Vec::new();
+    for item in input.split([',', ' ']).map(str::trim).filter(|i| !i.is_empty()) {
+        if matches!(item.to_lowercase().as_str(), "none" | "clear" | "off") {
+            return Ok(Vec::new());
+        }
+        let code = normalize_language(item);
+        if !is_supported_language(&code) {
+            return Err(item.to_string());
+        }
+        if !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    Ok(codes)
+}
+
+/// Language names in the reply locale, comma-separated
+fn format_language_list(locale: &str, codes: &[String]) -> String {
+    codes.iter().map(|c| localized_language_name(locale, c)).collect::<Vec<_>>().join(", ")
+}
+
+/// Normalize subscription scope input
+fn normalize_scope(input: &str) -> Option<&'static str> {
+    match input.trim().to_lowercase().as_str() {
+        "channel" | "here" => Some("channel"),
+        "category" | "cat" => Some("category"),
+        "server" | "guild" | "everywhere" => Some("guild"),
+        _ => None,
+    }
+}
+
+/// Normalize formality input; Ok(None) clears the preference
+fn normalize_formality(input: &str) -> Option<Option<&'static str>> {
+    match input.trim().to_lowercase().as_str() {
+        "formal" | "polite" | "respectful" | "aap" | "vous" | "usted" | "sie" => Some(Some("formal")),
+        "neutral" | "normal" => Some(Some("neutral")),
+        "casual" | "informal" | "familiar" | "tum" | "tu" | "tú" | "du" => Some(Some("casual")),
+        "default" | "clear" | "none" | "off" => Some(None),
+        _ => None,
+    }
+}
+
+/// Normalize script input; Some(None) clears the preference
+fn normalize_script(input: &str) -> Option<Option<&'static str>> {
+    match input.trim().to_lowercase().as_str() {
+        "native" | "devanagari" | "hangul" | "देवनागरी" => Some(Some("native")),
+        "romanized" | "romanised" | "roman" | "latin" | "hinglish" => Some(Some("romanized")),
+        "both" | "dual" => Some(Some("both")),
+        "default" | "clear" | "none" | "off" => Some(None),
+        _ => None,
+    }
+}
+
+/// Locale for text shown to readers of a language, e.g. a translation DM's
+/// links and footer, falling back to English where there's no catalog
+fn reader_locale(language: &str) -> &'static str {
+    supported_locale(language).unwrap_or(DEFAULT_LOCALE)
+}
+
+/// Language name in the reply locale, falling back to the English name
+pub(crate) fn localized_language_name(locale: &str, code: &str) -> String {
+    let name = tr(locale, &format!("language-{}", code));
+    if name.starts_with("language-") {
+        language_name(code).to_string()
+    } else {
+        name
+    }
+}
+
 /// Check if a language is supported
-fn is_supported_language(code: &str) -> bool {
+pub(crate) fn is_supported_language(code: &str) -> bool {
     matches!(code, "en" | "hi" | "fr" | "es" | "de" | "fil" | "pt" | "ko")
 }
 
 /// Get human-readable language name
-fn language_name(code: &str) -> &'static str {
+pub(crate) fn language_name(code: &str) -> &'static str {
     match code {
         "en" => "English",
         "hi" => "Hindi",
@@ -993,40 +1682,6 @@ fn language_name(code: &str) -> &'static str {
     }
 }
 
-/// Convert whatlang 3-letter codes to ISO 639-1 2-letter codes
-fn whatlang_to_iso(code: &str) -> &str {
-    match code {
-        "eng" => "en",
-        "hin" => "hi",
-        "fra" => "fr",
-        "spa" => "es",
-        "deu" => "de",
-        "kor" => "ko",
-        "tgl" => "fil",  // Tagalog -> Filipino
-        "cmn" | "zho" => "zh",
-        "jpn" => "ja",
-        "rus" => "ru",
-        "ara" => "ar",
-        "por" => "pt",
-        "ita" => "it",
-        "nld" => "nl",
-        "pol" => "pl",
-        "tur" => "tr",
-        "vie" => "vi",
-        "tha" => "th",
-        "ind" => "id",
-        "ukr" => "uk",
-        "ces" => "cs",
-        "ell" => "el",
-        "heb" => "he",
-        "swe" => "sv",
-        "dan" => "da",
-        "fin" => "fi",
-        "nor" => "no",
-        other => other,
-    }
-}
-
 /// Truncate a string to at most n characters (UTF-8 safe)
 fn truncate_str(s: &str, max_chars: usize) -> &str {
     match s.char_indices().nth(max_chars) {
@@ -1035,24 +1690,193 @@ fn truncate_str(s: &str, max_chars: usize) -> &str {
     }
 }
 
-/// Calculate similarity ratio between two strings (0.0 to 1.0)
-fn similarity(a: &str, b: &str) -> f64 {
-    if a == b {
-        return 1.0;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TranslationConfig;
+    use crate::services::pipeline::TranslationPipeline;
+    use crate::services::translator::TranslatorService;
+    use crate::testing::{self, MockDiscord};
+
+    #[test]
+    fn test_parse_language_list() {
+        assert_eq!(parse_language_list("English, hindi").unwrap(), vec!["en".to_string(), "hi".to_string()]);
+        // Spaces separate too, and repeats are dropped
+        assert_eq!(parse_language_list("fr es  fr").unwrap(), vec!["fr".to_string(), "es".to_string()]);
+        assert_eq!(parse_language_list("none").unwrap(), Vec::<String>::new());
+        assert_eq!(parse_language_list("hindi, klingon"), Err("klingon".to_string()));
     }
-    if a.is_empty() || b.is_empty() {
-        return 0.0;
+
+    #[test]
+    fn test_format_language_list() {
+        let codes = vec!["en".to_string(), "hi".to_string()];
+        assert_eq!(format_language_list("en", &codes), "English, Hindi");
+        assert_eq!(format_language_list("hi", &codes), "अंग्रेज़ी, हिन्दी");
+        assert_eq!(format_language_list("en", &[]), "");
     }
 
-    let a_words: Vec<&str> = a.split_whitespace().collect();
-    let b_words: Vec<&str> = b.split_whitespace().collect();
+    /// Model the message-flow fixtures are recorded with
+    const FIXTURE_MODEL: &str = "mistralai/mistral-small-3.2-24b-instruct";
+    const HINGLISH: &str = "kal ki meeting cancel ho gayi hai, sab log shaam tak apna update bhej dena";
+    const ENGLISH: &str = "The release is moving to Friday, please finish your reviews by Thursday";
+
+    /// Posts a Hinglish and an English message in #general (200), a silent
+    /// channel read by an English subscriber (100) and a formal Hindi one
+    /// (101), through the translation pipeline
+    async fn run_message_flow(config: TranslationConfig, name: &str) -> MockDiscord {
+        let mut general = testing::text_channel(testing::GUILD_ID, 200, vec![]);
+        general.name = "general".to_string();
+        let discord = MockDiscord::start().await;
+        discord.add_guild(&testing::guild(
+            testing::GUILD_ID,
+            vec![testing::role(testing::GUILD_ID, testing::GUILD_ID, serenity::Permissions::VIEW_CHANNEL)],
+            vec![general],
+            vec![testing::member(testing::GUILD_ID, 100, &[]), testing::member(testing::GUILD_ID, 101, &[])],
+        ));
+
+        let db = testing::database(name).await;
+        let (guild, channel) = ("1", "200");
+        db.set_channel_translation_mode(guild, channel, "silent", "admin").await.unwrap();
+        db.add_translation_subscription(guild, "100", channel, "en").await.unwrap();
+        db.add_translation_subscription(guild, "101", channel, "hi").await.unwrap();
+        db.set_formality_preference("101", "hi", Some("formal")).await.unwrap();
+        let data = testing::data(db, TranslatorService::new(&config));
+
+        let (pipeline, workers) = TranslationPipeline::new(&config);
+        workers.spawn(data);
+        for (id, content) in [(300, HINGLISH), (301, ENGLISH)] {
+            let mut message = Message::default();
+            message.id = serenity::MessageId::new(id);
+            message.guild_id = Some(serenity::GuildId::new(testing::GUILD_ID));
+            message.channel_id = serenity::ChannelId::new(200);
+            message.author = testing::user(102, "author");
+            message.content = content.to_string();
+            pipeline.submit(&discord.discord, &message).await;
+        }
+        testing::wait_for_jobs(&pipeline, 2).await;
+        assert_eq!(pipeline.stats().failed, 0);
+        discord
+    }
 
-    let matching = a_words.iter().filter(|w| b_words.contains(w)).count();
-    let total = a_words.len().max(b_words.len());
+    /// Replays `fixtures/translation` through the pipeline: Hinglish is too
+    /// uncertain for whatlang so the recorded detection decides, and the
+    /// Hindi reader's formality reaches the recorded translation prompt
+    #[tokio::test]
+    async fn test_message_flow_replays_recorded_fixtures() {
+        let config: TranslationConfig = toml::from_str(&format!(
+            "backend = \"replay\"\nmodel = \"{}\"\nfixtures_dir = \"{}/fixtures/translation\"",
+            FIXTURE_MODEL,
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        let discord = run_message_flow(config.clone(), "message-flow-replay").await;
+
+        let dms = discord.posted(100);
+        assert_eq!(dms.len(), 1);
+        let embed = &dms[0]["embeds"][0];
+        assert_eq!(embed["title"], "#general");
+        assert_eq!(
+            embed["description"],
+            "Tomorrow's meeting has been cancelled, everyone please send your update by evening\n\n\
+             [Jump to message](https://discord.com/channels/1/200/300)"
+        );
+        assert_eq!(embed["footer"]["text"], "Reply to this message to answer in the channel");
+
+        let dms = discord.posted(101);
+        assert_eq!(dms.len(), 1);
+        let embed = &dms[0]["embeds"][0];
+        assert_eq!(
+            embed["description"],
+            "रिलीज़ शुक्रवार तक टल गई है, कृपया गुरुवार तक अपने रिव्यू पूरे कर लें\n\n\
+             [संदेश पर जाएँ](https://discord.com/channels/1/200/301)"
+        );
+        assert_eq!(embed["footer"]["text"], "चैनल में जवाब देने के लिए इस संदेश का जवाब दें");
+
+        // Anything not recorded fails instead of reaching the network
+        assert!(TranslatorService::new(&config).translate("Unrecorded message", "en", "hi").await.is_err());
+    }
 
-    if total == 0 {
-        return 0.0;
+    /// Records the fixtures replayed above. Needs an OpenRouter key:
+    /// `OPENROUTER_API_KEY=... cargo test record_message_flow_fixtures -- --ignored`
+    #[tokio::test]
+    #[ignore = "calls OpenRouter"]
+    async fn record_message_flow_fixtures() {
+        let mut config: TranslationConfig = toml::from_str(&format!(
+            "backend = \"record\"\nmodel = \"{}\"\nfixtures_dir = \"{}/fixtures/translation\"",
+            FIXTURE_MODEL,
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        config.openrouter_api_key = std::env::var("OPENROUTER_API_KEY").expect("OPENROUTER_API_KEY is required");
+        if let Ok(url) = std::env::var("OPENROUTER_URL") {
+            config.openrouter_url = url;
+        }
+        let discord = run_message_flow(config, "message-flow-record").await;
+        assert_eq!((discord.posted(100).len(), discord.posted(101).len()), (1, 1));
     }
 
-    matching as f64 / total as f64
+    #[tokio::test]
+    async fn test_scoped_subscribers_only_get_channels_they_can_view() {
+        use poise::serenity_prelude::{PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+
+        // #private (20) hides from @everyone except the staff role; #general (21) is open
+        let (everyone, staff) = (testing::GUILD_ID, 50);
+        let private = testing::text_channel(
+            testing::GUILD_ID,
+            20,
+            vec![
+                PermissionOverwrite {
+                    allow: Permissions::empty(),
+                    deny: Permissions::VIEW_CHANNEL,
+                    kind: PermissionOverwriteType::Role(RoleId::new(everyone)),
+                },
+                PermissionOverwrite {
+                    allow: Permissions::VIEW_CHANNEL,
+                    deny: Permissions::empty(),
+                    kind: PermissionOverwriteType::Role(RoleId::new(staff)),
+                },
+            ],
+        );
+        let discord = MockDiscord::start().await;
+        discord.add_guild(&testing::guild(
+            testing::GUILD_ID,
+            vec![
+                testing::role(testing::GUILD_ID, everyone, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+                testing::role(testing::GUILD_ID, staff, Permissions::empty()),
+            ],
+            vec![private, testing::text_channel(testing::GUILD_ID, 21, vec![])],
+            vec![testing::member(testing::GUILD_ID, 100, &[staff]), testing::member(testing::GUILD_ID, 101, &[])],
+        ));
+
+        let db = testing::database("translation-visibility").await;
+        let guild = testing::GUILD_ID.to_string();
+        for (reader, channel) in [("100", "20"), ("101", "21")] {
+            db.add_scoped_subscription(&guild, reader, "guild", &guild, "hi").await.unwrap();
+            db.set_channel_translation_mode(&guild, channel, "silent", "admin").await.unwrap();
+        }
+        let data = testing::data(
+            db,
+            testing::translator(|_| "रिलीज़ शुक्रवार तक टल गई है, कृपया गुरुवार तक अपने रिव्यू पूरे कर लें".to_string()),
+        );
+
+        let message = |id: u64, channel: u64| {
+            let mut message = Message::default();
+            message.id = serenity::MessageId::new(id);
+            message.guild_id = Some(serenity::GuildId::new(testing::GUILD_ID));
+            message.channel_id = serenity::ChannelId::new(channel);
+            message.author = testing::user(999, "author");
+            message.content = "The release is moving to Friday, please finish your reviews by Thursday".to_string();
+            message
+        };
+
+        // Only the staff member hears about #private
+        handle_message(&discord.discord, &message(300, 20), &data).await.unwrap();
+        assert_eq!(discord.posted(100).len(), 1);
+        assert!(discord.posted(101).is_empty());
+
+        // Both hear about #general
+        handle_message(&discord.discord, &message(301, 21), &data).await.unwrap();
+        assert_eq!(discord.posted(100).len(), 2);
+        assert_eq!(discord.posted(101).len(), 1);
+    }
 }
@@ -0,0 +1,209 @@
+//! Translatable parts of a message beyond its text
+//!
+//! Channels that opt in also get attached text documents, embed titles and
+//! descriptions, and image alt text translated. Captions are quoted under the
+//! message text so they are translated in the same request; documents are
+//! translated separately and sent back as files.
+
+use poise::serenity_prelude::{Attachment, Message};
+use tracing::{debug, warn};
+
+/// File extensions treated as text documents
+const TEXT_EXTENSIONS: &[&str] = &["txt", "md", "markdown"];
+
+/// Longest piece of a document sent in one translation request, so the
+/// translated piece fits the model's reply limit
+pub const DOCUMENT_CHUNK_CHARS: usize = 2000;
+
+/// A downloaded text attachment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextDocument {
+    pub filename: String,
+    pub text: String,
+}
+
+/// Whether an attachment is a text document no larger than `max_bytes`
+pub fn is_text_document(filename: &str, content_type: Option<&str>, size: u32, max_bytes: usize) -> bool {
+    if size as usize > max_bytes {
+        return false;
+    }
+    let by_type = content_type
+        .map(|t| t.starts_with("text/plain") || t.starts_with("text/markdown"))
+        .unwrap_or(false);
+    let by_extension = filename
+        .rsplit_once('.')
+        .map(|(_, ext)| TEXT_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false);
+    by_type || by_extension
+}
+
+/// Embed titles/descriptions and image alt text carried by a message
+pub fn captions(message: &Message) -> Vec<String> {
+    let mut captions = Vec::new();
+
+    for embed in &message.embeds {
+        let parts: Vec<&str> = [embed.title.as_deref(), embed.description.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .collect();
+        if !parts.is_empty() {
+            captions.push(parts.join("\n"));
+        }
+    }
+
+    captions.extend(
+        message
+            .attachments
+            .iter()
+            .filter_map(|a| a.description.as_deref())
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .map(str::to_string),
+    );
+
+    captions
+}
+
+/// Message text followed by each caption as a quote block
+pub fn with_captions(text: &str, captions: &[String]) -> String {
+    let mut out = text.trim().to_string();
+    for caption in captions {
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        let quoted: Vec<String> = caption.lines().map(|line| format!("> {}", line)).collect();
+        out.push_str(&quoted.join("\n"));
+    }
+    out
+}
+
+/// Split a document into pieces of at most `max_chars`, on paragraph
+/// boundaries where possible, then on line boundaries, then mid-line
+pub fn chunk_document(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    for paragraph in text.split("\n\n").map(str::trim_end).filter(|p| !p.trim().is_empty()) {
+        for piece in split_long(paragraph, max_chars) {
+            let needed = if current.is_empty() { 0 } else { 2 };
+            if !current.is_empty() && current.chars().count() + needed + piece.chars().count() > max_chars {
+                chunks.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push_str("\n\n");
+            }
+            current.push_str(&piece);
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Break a paragraph longer than `max_chars` into lines, and lines into pieces
+fn split_long(paragraph: &str, max_chars: usize) -> Vec<String> {
+    if paragraph.chars().count() <= max_chars {
+        return vec![paragraph.to_string()];
+    }
+    let mut pieces: Vec<String> = Vec::new();
+    for line in paragraph.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        for part in chars.chunks(max_chars.max(1)) {
+            let part: String = part.iter().collect();
+            match pieces.last_mut() {
+                Some(last) if last.chars().count() + 1 + part.chars().count() <= max_chars => {
+                    last.push('\n');
+                    last.push_str(&part);
+                }
+                _ => pieces.push(part),
+            }
+        }
+    }
+    pieces
+}
+
+/// Name for a translated copy of a document: `notes.md` -> `notes.hi.md`
+pub fn translated_filename(filename: &str, language: &str) -> String {
+    match filename.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{}.{}.{}", stem, language, ext),
+        _ => format!("{}.{}.txt", filename, language),
+    }
+}
+
+/// Download a message's text documents, skipping oversized or non-UTF-8 files
+pub async fn fetch_documents(message: &Message, max_bytes: usize) -> Vec<TextDocument> {
+    let mut documents = Vec::new();
+    for attachment in &message.attachments {
+        if !is_text_document(&attachment.filename, attachment.content_type.as_deref(), attachment.size, max_bytes) {
+            continue;
+        }
+        match download_text(attachment).await {
+            Some(text) if !text.trim().is_empty() => documents.push(TextDocument {
+                filename: attachment.filename.clone(),
+                text,
+            }),
+            _ => debug!("Skipping attachment {} (empty or not UTF-8)", attachment.filename),
+        }
+    }
+    documents
+}
+
+async fn download_text(attachment: &Attachment) -> Option<String> {
+    match attachment.download().await {
+        Ok(bytes) => String::from_utf8(bytes).ok(),
+        Err(e) => {
+            warn!("Failed to download attachment {}: {}", attachment.filename, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_text_document() {
+        assert!(is_text_document("notes.md", None, 100, 8192));
+        assert!(is_text_document("README.TXT", None, 100, 8192));
+        assert!(is_text_document("log", Some("text/plain; charset=utf-8"), 100, 8192));
+        assert!(!is_text_document("notes.md", None, 10_000, 8192));
+        assert!(!is_text_document("photo.png", Some("image/png"), 100, 8192));
+        assert!(!is_text_document("script.rs", Some("text/x-rust"), 100, 8192));
+    }
+
+    #[test]
+    fn test_with_captions() {
+        let captions = vec!["Release notes\nv2 is out".to_string(), "A cat on a keyboard".to_string()];
+        assert_eq!(
+            with_captions("Look at this", &captions),
+            "Look at this\n\n> Release notes\n> v2 is out\n\n> A cat on a keyboard"
+        );
+        assert_eq!(with_captions("", &captions[1..]), "> A cat on a keyboard");
+        assert_eq!(with_captions("Just text", &[]), "Just text");
+    }
+
+    #[test]
+    fn test_chunk_document() {
+        let text = "First paragraph.\n\nSecond paragraph.\n\nThird one.";
+        assert_eq!(chunk_document(text, 100), vec![text.to_string()]);
+        assert_eq!(
+            chunk_document(text, 40),
+            vec!["First paragraph.\n\nSecond paragraph.".to_string(), "Third one.".to_string()]
+        );
+        // Paragraphs longer than the limit split on lines, then mid-line
+        assert_eq!(chunk_document("line one\nline two", 10), vec!["line one".to_string(), "line two".to_string()]);
+        assert_eq!(chunk_document("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert!(chunk_document("\n\n  \n\n", 10).is_empty());
+    }
+
+    #[test]
+    fn test_translated_filename() {
+        assert_eq!(translated_filename("notes.md", "hi"), "notes.hi.md");
+        assert_eq!(translated_filename("archive.tar.txt", "fr"), "archive.tar.fr.txt");
+        assert_eq!(translated_filename("LICENSE", "de"), "LICENSE.de.txt");
+        assert_eq!(translated_filename(".env", "ko"), ".env.ko.txt");
+    }
+}
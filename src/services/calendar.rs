@@ -0,0 +1,389 @@
+//! iCalendar feeds of working hours, leave and public holidays
+//!
+//! Weekly hours become recurring events in the owner's timezone, with the
+//! weeks' exceptions (day overrides, leave, holidays) as EXDATEs. Overrides
+//! are one-off events and leave and holidays are all-day events. Text is
+//! formatted by the caller, so this module knows nothing about locales.
+
+use crate::services::schedule;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use sha2::{Digest, Sha256};
+
+/// Someone whose hours are in the feed
+#[derive(Debug, Clone)]
+pub struct Person {
+    pub discord_id: String,
+    pub tz: Tz,
+    /// `(day, start, end)` weekly ranges, 0 = Monday
+    pub weekly: Vec<(u8, String, String)>,
+    /// `(date, start, end)` day overrides
+    pub overrides: Vec<(NaiveDate, Option<String>, String)>,
+    /// Days without working hours: leave and public holidays
+    pub days_off: Vec<NaiveDate>,
+    /// Title of working-hours events
+    pub working_summary: String,
+}
+
+/// An all-day event, such as leave or a public holiday
+#[derive(Debug, Clone)]
+pub struct AllDay {
+    pub uid: String,
+    pub start: NaiveDate,
+    /// Last day, inclusive
+    pub end: NaiveDate,
+    pub summary: String,
+}
+
+/// Everything in one feed
+#[derive(Debug, Clone)]
+pub struct Calendar {
+    pub name: String,
+    /// Keeps event UIDs unique across feeds, e.g. the guild ID
+    pub uid_scope: String,
+    pub people: Vec<Person>,
+    pub all_day: Vec<AllDay>,
+}
+
+/// A new random feed token, URL-safe
+pub fn new_token() -> Result<String, getrandom::Error> {
+    let mut bytes = [0u8; 24];
+    getrandom::getrandom(&mut bytes)?;
+    Ok(hex::encode(bytes))
+}
+
+/// The hash stored in place of a token
+pub fn token_hash(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Render a calendar as an iCalendar (RFC 5545) document
+pub fn render(calendar: &Calendar, now: DateTime<Utc>) -> String {
+    let stamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Palace Fabrica//Team Hours//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(&calendar.name)),
+    ];
+
+    let mut zones: Vec<Tz> = Vec::new();
+    for person in calendar.people.iter().filter(|p| !p.weekly.is_empty()) {
+        if !zones.contains(&person.tz) {
+            zones.push(person.tz);
+        }
+    }
+    for tz in zones {
+        lines.extend(vtimezone(tz, now.year()));
+    }
+
+    for person in &calendar.people {
+        lines.extend(weekly_events(calendar, person, &stamp, now));
+        lines.extend(override_events(calendar, person, &stamp));
+    }
+    for event in &calendar.all_day {
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}-{}@fabrica", event.uid, calendar.uid_scope),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART;VALUE=DATE:{}", event.start.format("%Y%m%d")),
+            format!("DTEND;VALUE=DATE:{}", (event.end + Duration::days(1)).format("%Y%m%d")),
+            format!("SUMMARY:{}", escape(&event.summary)),
+            "TRANSP:TRANSPARENT".to_string(),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect::<Vec<_>>().join("\r\n") + "\r\n"
+}
+
+/// One recurring event per weekly range, starting this week (owner's dates)
+fn weekly_events(calendar: &Calendar, person: &Person, stamp: &str, now: DateTime<Utc>) -> Vec<String> {
+    let tz = person.tz;
+    let today = now.with_timezone(&tz).date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+
+    let mut lines = Vec::new();
+    for (day, start, end) in &person.weekly {
+        let (Some(start_time), Some(end_time)) = (schedule::parse_hhmm(start), schedule::parse_hhmm(end)) else {
+            continue;
+        };
+        let date = monday + Duration::days(*day as i64);
+        let end_date = if end_time <= start_time { date + Duration::days(1) } else { date };
+
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:weekly-{}-{}-{}-{}@fabrica", person.discord_id, day, start.replace(':', ""), calendar.uid_scope),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART;TZID={}:{}", tz.name(), local(date.and_time(start_time))),
+            format!("DTEND;TZID={}:{}", tz.name(), local(end_date.and_time(end_time))),
+            format!("RRULE:FREQ=WEEKLY;BYDAY={}", BYDAY[*day as usize % 7]),
+        ]);
+        // Days replaced by an override or taken off
+        let mut skipped: Vec<NaiveDate> = person
+            .overrides
+            .iter()
+            .map(|(date, _, _)| *date)
+            .chain(person.days_off.iter().copied())
+            .filter(|d| *d >= date && schedule::weekday_index(*d) == *day)
+            .collect();
+        skipped.sort();
+        skipped.dedup();
+        for skipped_date in skipped {
+            lines.push(format!("EXDATE;TZID={}:{}", tz.name(), local(skipped_date.and_time(start_time))));
+        }
+        lines.extend([format!("SUMMARY:{}", escape(&person.working_summary)), "END:VEVENT".to_string()]);
+    }
+    lines
+}
+
+/// One-off events for day overrides, skipping days off
+fn override_events(calendar: &Calendar, person: &Person, stamp: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (date, start, end) in &person.overrides {
+        if person.days_off.contains(date) {
+            continue;
+        }
+        let day_override = (start.clone(), end.clone());
+        for (window_start, window_end) in schedule::day_windows(&person.weekly, Some(&day_override), person.tz, *date) {
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!("UID:override-{}-{}-{}@fabrica", person.discord_id, date.format("%Y%m%d"), calendar.uid_scope),
+                format!("DTSTAMP:{}", stamp),
+                format!("DTSTART:{}", window_start.format("%Y%m%dT%H%M%SZ")),
+                format!("DTEND:{}", window_end.format("%Y%m%dT%H%M%SZ")),
+                format!("SUMMARY:{}", escape(&person.working_summary)),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+    }
+    lines
+}
+
+const BYDAY: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+fn local(datetime: NaiveDateTime) -> String {
+    datetime.format("%Y%m%dT%H%M%S").to_string()
+}
+
+/// A VTIMEZONE for `tz` with its daylight-saving rules as of `year`
+///
+/// Transitions are found by stepping through the year, then written as
+/// yearly rules on the nth (or last) weekday of the month, the way zones
+/// define them. The rules start in 1970 so they cover every date a feed
+/// uses, including the weeks of `year` before its first transition.
+pub fn vtimezone(tz: Tz, year: i32) -> Vec<String> {
+    let offset_at = |instant: DateTime<Utc>| tz.offset_from_utc_datetime(&instant.naive_utc()).fix().local_minus_utc();
+    let Some(start) = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).single() else {
+        return Vec::new();
+    };
+
+    // (instant, offset before, offset after), found to the minute
+    let mut transitions = Vec::new();
+    let mut previous = offset_at(start);
+    let mut hour = start;
+    while hour.year() == year {
+        let next = hour + Duration::hours(1);
+        let offset = offset_at(next);
+        if offset != previous {
+            let mut at = hour;
+            while offset_at(at) == previous {
+                at += Duration::minutes(1);
+            }
+            transitions.push((at, previous, offset));
+            previous = offset;
+        }
+        hour = next;
+    }
+
+    let mut lines = vec!["BEGIN:VTIMEZONE".to_string(), format!("TZID:{}", tz.name())];
+    if transitions.is_empty() {
+        let name = start.with_timezone(&tz).format("%Z").to_string();
+        lines.extend(zone_rule("STANDARD", "19700101T000000", previous, previous, &name, None));
+    } else {
+        let largest = transitions.iter().map(|(_, _, to)| *to).max().unwrap_or(previous);
+        for (at, from, to) in &transitions {
+            // DTSTART is the wall-clock time before the change
+            let wall = at.naive_utc() + Duration::seconds(*from as i64);
+            let kind = if *to == largest && transitions.len() > 1 { "DAYLIGHT" } else { "STANDARD" };
+            let name = at.with_timezone(&tz).format("%Z").to_string();
+            let rule = format!("FREQ=YEARLY;BYMONTH={};BYDAY={}", wall.month(), nth_weekday(wall.date()));
+            let first = same_weekday_in(1970, wall.date()).and_time(wall.time());
+            lines.extend(zone_rule(kind, &local(first), *from, *to, &name, Some(&rule)));
+        }
+    }
+    lines.push("END:VTIMEZONE".to_string());
+    lines
+}
+
+fn zone_rule(kind: &str, start: &str, from: i32, to: i32, name: &str, rule: Option<&str>) -> Vec<String> {
+    let mut lines = vec![
+        format!("BEGIN:{}", kind),
+        format!("DTSTART:{}", start),
+        format!("TZOFFSETFROM:{}", utc_offset(from)),
+        format!("TZOFFSETTO:{}", utc_offset(to)),
+        format!("TZNAME:{}", escape(name)),
+    ];
+    if let Some(rule) = rule {
+        lines.push(format!("RRULE:{}", rule));
+    }
+    lines.push(format!("END:{}", kind));
+    lines
+}
+
+/// "2SU" for the second Sunday of the month, "-1SU" for the last
+fn nth_weekday(date: NaiveDate) -> String {
+    let day = BYDAY[date.weekday().num_days_from_monday() as usize];
+    if (date + Duration::days(7)).month() != date.month() {
+        format!("-1{}", day)
+    } else {
+        format!("{}{}", (date.day() - 1) / 7 + 1, day)
+    }
+}
+
+/// The date in `year` that falls on the same nth (or last) weekday of the month
+fn same_weekday_in(year: i32, date: NaiveDate) -> NaiveDate {
+    let last = (date + Duration::days(7)).month() != date.month();
+    let Some(first) = NaiveDate::from_ymd_opt(year, date.month(), 1) else {
+        return date;
+    };
+    let shift = (date.weekday().num_days_from_monday() + 7 - first.weekday().num_days_from_monday()) % 7;
+    let mut day = first + Duration::days(shift as i64 + (date.day() as i64 - 1) / 7 * 7);
+    while day.month() != date.month() {
+        day -= Duration::days(7);
+    }
+    while last && (day + Duration::days(7)).month() == date.month() {
+        day += Duration::days(7);
+    }
+    day
+}
+
+/// "+0530", "-0400"
+fn utc_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+}
+
+/// Escape a TEXT value
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line to 75 octets, continuing with a leading space
+pub fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person(tz: &str) -> Person {
+        Person {
+            discord_id: "42".to_string(),
+            tz: tz.parse().unwrap(),
+            weekly: vec![(0, "10:00".to_string(), "14:00".to_string()), (0, "20:00".to_string(), "02:00".to_string())],
+            overrides: Vec::new(),
+            days_off: Vec::new(),
+            working_summary: "Asha: working".to_string(),
+        }
+    }
+
+    fn calendar(people: Vec<Person>, all_day: Vec<AllDay>) -> Calendar {
+        Calendar {
+            name: "Team hours".to_string(),
+            uid_scope: "7".to_string(),
+            people,
+            all_day,
+        }
+    }
+
+    /// Wednesday 2026-03-11 in UTC
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 11, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_weekly_hours_recur_in_owner_timezone() {
+        let ics = render(&calendar(vec![person("Asia/Kolkata")], Vec::new()), now());
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Asia/Kolkata:20260309T100000\r\nDTEND;TZID=Asia/Kolkata:20260309T140000\r\nRRULE:FREQ=WEEKLY;BYDAY=MO\r\n"));
+        // The overnight shift ends the next morning
+        assert!(ics.contains("DTSTART;TZID=Asia/Kolkata:20260309T200000\r\nDTEND;TZID=Asia/Kolkata:20260310T020000\r\n"));
+        assert!(ics.contains("UID:weekly-42-0-2000-7@fabrica"));
+        assert!(ics.contains("TZID:Asia/Kolkata\r\nBEGIN:STANDARD\r\nDTSTART:19700101T000000\r\nTZOFFSETFROM:+0530\r\nTZOFFSETTO:+0530\r\n"));
+    }
+
+    #[test]
+    fn test_overrides_and_days_off_are_excluded() {
+        let mut asha = person("UTC");
+        let next_monday = NaiveDate::from_ymd_opt(2026, 3, 16).unwrap();
+        let monday_after = NaiveDate::from_ymd_opt(2026, 3, 23).unwrap();
+        asha.overrides = vec![(next_monday, None, "12:00".to_string())];
+        asha.days_off = vec![monday_after];
+        let leave = AllDay {
+            uid: "leave-1".to_string(),
+            start: monday_after,
+            end: monday_after + Duration::days(4),
+            summary: "Asha: on leave (family, trip)".to_string(),
+        };
+        let ics = render(&calendar(vec![asha], vec![leave]), now());
+
+        assert!(ics.contains("EXDATE;TZID=UTC:20260316T100000\r\nEXDATE;TZID=UTC:20260323T100000\r\n"));
+        assert!(ics.contains("EXDATE;TZID=UTC:20260316T200000\r\nEXDATE;TZID=UTC:20260323T200000\r\n"));
+        // "until 12:00" starts at the day's first weekly start
+        assert!(ics.contains("DTSTART:20260316T100000Z\r\nDTEND:20260316T120000Z\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260323\r\nDTEND;VALUE=DATE:20260328\r\nSUMMARY:Asha: on leave (family\\, trip)\r\n"));
+    }
+
+    #[test]
+    fn test_vtimezone_rules() {
+        let london = vtimezone("Europe/London".parse().unwrap(), 2026).join("\n");
+        assert!(london.contains(
+            "BEGIN:DAYLIGHT\nDTSTART:19700329T010000\nTZOFFSETFROM:+0000\nTZOFFSETTO:+0100\nTZNAME:BST\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\nEND:DAYLIGHT"
+        ));
+        assert!(london.contains(
+            "BEGIN:STANDARD\nDTSTART:19701025T020000\nTZOFFSETFROM:+0100\nTZOFFSETTO:+0000\nTZNAME:GMT\nRRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\nEND:STANDARD"
+        ));
+
+        let new_york = vtimezone("America/New_York".parse().unwrap(), 2026).join("\n");
+        assert!(new_york.contains("DTSTART:19700308T020000\nTZOFFSETFROM:-0500\nTZOFFSETTO:-0400\nTZNAME:EDT\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU"));
+        assert!(new_york.contains("RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU"));
+    }
+
+    #[test]
+    fn test_tokens() {
+        let (a, b) = (new_token().unwrap(), new_token().unwrap());
+        assert_eq!(a.len(), 48);
+        assert_ne!(a, b);
+        assert_eq!(token_hash(&a), token_hash(&a));
+        assert_ne!(token_hash(&a), a);
+    }
+
+    #[test]
+    fn test_escape_and_fold() {
+        assert_eq!(escape("a;b,c\\d\ne"), "a\\;b\\,c\\\\d\\ne");
+        let long = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold(&long);
+        assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
+}
@@ -0,0 +1,756 @@
+//! Out-of-office imported from a user's own calendar
+//!
+//! Users point Fabrica at their calendar's `.ics` address (or upload a file)
+//! and events marked busy or out of office turn into leave and schedule
+//! overrides. All-day and multi-day busy events become leave; out-of-office
+//! events covering the start or end of a working day trim that day's hours.
+//! Imported entries are flagged, so each sync replaces the previous one and
+//! never touches leave or overrides set by hand.
+
+use crate::db::{Database, Leave};
+use crate::services::schedule::{self, Window};
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use tracing::{info, warn};
+
+/// Largest calendar accepted, from a URL or an upload
+pub const MAX_BYTES: usize = 2 * 1024 * 1024;
+
+/// Redirects followed when fetching a calendar
+const MAX_REDIRECTS: usize = 5;
+
+/// How far ahead events are imported
+const HORIZON_DAYS: i64 = 180;
+
+/// Timed busy events at least this long count even without out-of-office
+const MIN_BUSY_HOURS: i64 = 24;
+
+/// When an event happens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Span {
+    /// Whole days, first and last inclusive
+    AllDay(NaiveDate, NaiveDate),
+    Timed(DateTime<Utc>, DateTime<Utc>),
+}
+
+/// A busy or out-of-office event from a calendar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub summary: Option<String>,
+    pub span: Span,
+    /// Marked out of office rather than just busy
+    pub out_of_office: bool,
+}
+
+/// What a calendar adds to a user's schedule
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plan {
+    /// Leave ranges (first and last day inclusive) with the event's summary
+    pub leave: Vec<(NaiveDate, NaiveDate, Option<String>)>,
+    /// Days whose hours shrink to a single `(start, end)` range
+    pub overrides: Vec<(NaiveDate, String, String)>,
+}
+
+/// A content line: name (upper-cased), parameters and value
+type Property = (String, Vec<(String, String)>, String);
+
+/// Busy and out-of-office events in an iCalendar document
+///
+/// Free, tentative, working-elsewhere and cancelled events are skipped.
+/// Times with an unknown `TZID` (e.g. Outlook's "India Standard Time") or no
+/// timezone are read in `default_tz`. Recurring events count only their first
+/// occurrence.
+pub fn parse_events(text: &str, default_tz: Tz) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut current: Option<Vec<Property>> = None;
+    let mut nested = 0;
+
+    for line in unfold(text) {
+        let Some((name, params, value)) = parse_line(&line) else {
+            continue;
+        };
+        match (name.as_str(), value.to_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") => {
+                current = Some(Vec::new());
+                nested = 0;
+            }
+            ("END", "VEVENT") => {
+                if let Some(event) = current.take().and_then(|props| to_event(&props, default_tz)) {
+                    events.push(event);
+                }
+            }
+            // Alarms and the like inside an event have their own properties
+            ("BEGIN", _) if current.is_some() => nested += 1,
+            ("END", _) if current.is_some() => nested -= 1,
+            _ => {
+                if let (Some(props), 0) = (current.as_mut(), nested) {
+                    props.push((name, params, value));
+                }
+            }
+        }
+    }
+    events
+}
+
+/// Join folded lines (a line break followed by a space or tab)
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.split('\n') {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if !raw.is_empty() => lines.push(raw.to_string()),
+            _ => {}
+        }
+    }
+    lines
+}
+
+/// Split `NAME;PARAM=value:VALUE` into a property
+fn parse_line(line: &str) -> Option<Property> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        (c == ':' && !in_quotes).then_some(i)
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.trim().to_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+    Some((name, params, value.to_string()))
+}
+
+fn to_event(props: &[Property], default_tz: Tz) -> Option<Event> {
+    let prop = |name: &str| props.iter().find(|(n, ..)| n == name);
+    let upper = |name: &str| prop(name).map(|(_, _, v)| v.trim().to_uppercase());
+
+    if upper("STATUS").as_deref() == Some("CANCELLED") {
+        return None;
+    }
+    let busy_status = upper("X-MICROSOFT-CDO-BUSYSTATUS").or_else(|| upper("X-MICROSOFT-CDO-INTENDEDSTATUS"));
+    match busy_status.as_deref() {
+        Some("FREE" | "TENTATIVE" | "WORKINGELSEWHERE") => return None,
+        None if upper("TRANSP").as_deref() == Some("TRANSPARENT") => return None,
+        _ => {}
+    }
+
+    let (_, start_params, start_value) = prop("DTSTART")?;
+    let start = parse_time(start_params, start_value, default_tz)?;
+    let end = match prop("DTEND") {
+        Some((_, params, value)) => Some(parse_time(params, value, default_tz)?),
+        None => None,
+    };
+    let duration = prop("DURATION").and_then(|(_, _, v)| parse_duration(v));
+
+    let span = match (start, end) {
+        (Time::Date(first), Some(Time::Date(end))) => Span::AllDay(first, (end - Duration::days(1)).max(first)),
+        (Time::Date(first), None) => {
+            let days = duration.map(|d| d.num_days()).unwrap_or(1).max(1);
+            Span::AllDay(first, first + Duration::days(days - 1))
+        }
+        (Time::At(start), Some(Time::At(end))) => Span::Timed(start, end),
+        (Time::At(start), None) => Span::Timed(start, start + duration?),
+        _ => return None,
+    };
+    if let Span::Timed(start, end) = span {
+        if end <= start {
+            return None;
+        }
+    }
+
+    Some(Event {
+        summary: prop("SUMMARY").map(|(_, _, v)| unescape(v)).filter(|s| !s.is_empty()),
+        span,
+        out_of_office: busy_status.as_deref() == Some("OOF"),
+    })
+}
+
+enum Time {
+    Date(NaiveDate),
+    At(DateTime<Utc>),
+}
+
+/// A `DATE` or `DATE-TIME` value, in UTC, its `TZID` or `default_tz`
+fn parse_time(params: &[(String, String)], value: &str, default_tz: Tz) -> Option<Time> {
+    let value = value.trim();
+    let is_date = params.iter().any(|(k, v)| k == "VALUE" && v.eq_ignore_ascii_case("DATE")) || value.len() == 8;
+    if is_date {
+        return NaiveDate::parse_from_str(value, "%Y%m%d").ok().map(Time::Date);
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Time::At(Utc.from_utc_datetime(&naive)));
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    let tz = params
+        .iter()
+        .find(|(k, _)| k == "TZID")
+        .and_then(|(_, v)| v.trim_start_matches('/').parse::<Tz>().ok())
+        .unwrap_or(default_tz);
+    let time = naive.format("%H:%M").to_string();
+    schedule::local_instant(tz, naive.date(), &time).map(Time::At)
+}
+
+/// An iCalendar duration such as `P1D`, `PT2H30M` or `P1W`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim().trim_start_matches('+');
+    let rest = value.strip_prefix('P')?;
+    let (mut total, mut number, mut in_time) = (Duration::zero(), String::new(), false);
+    for c in rest.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            _ => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match (c, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(total)
+}
+
+/// Undo iCalendar text escaping
+fn unescape(value: &str) -> String {
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push(' '),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+/// Turn events into leave and overrides from `today` on, given the user's
+/// weekly hours and timezone
+///
+/// A day is leave when events cover all of its hours (or, on a day without
+/// hours, all of it). Out-of-office events, and busy ones lasting a day or
+/// more, that cover only the start or end of the hours leave an override for
+/// the rest. Gaps in the middle of the day are ignored, as an override holds
+/// one range.
+pub fn plan(events: &[Event], weekly: &[(u8, String, String)], tz: Tz, today: NaiveDate) -> Plan {
+    let last = today + Duration::days(HORIZON_DAYS);
+    let mut leave_days: BTreeMap<NaiveDate, Option<String>> = BTreeMap::new();
+    let mut timed: Vec<(Window, &Event)> = Vec::new();
+
+    for event in events {
+        match event.span {
+            Span::AllDay(first, end) => {
+                for date in first.max(today).iter_days().take_while(|d| *d <= end.min(last)) {
+                    leave_days.entry(date).or_insert_with(|| event.summary.clone());
+                }
+            }
+            Span::Timed(start, end) => {
+                if event.out_of_office || end - start >= Duration::hours(MIN_BUSY_HOURS) {
+                    timed.push(((start, end), event));
+                }
+            }
+        }
+    }
+
+    let busy = schedule::merge(&timed.iter().map(|(w, _)| *w).collect::<Vec<_>>());
+    let mut overrides = Vec::new();
+    let mut dates: Vec<NaiveDate> = Vec::new();
+    for (start, end) in &busy {
+        // A day early, for hours running past midnight into the event
+        let first = start.with_timezone(&tz).date_naive() - Duration::days(1);
+        let end_date = end.with_timezone(&tz).date_naive();
+        dates.extend(first.max(today).iter_days().take_while(|d| *d <= end_date.min(last)));
+    }
+    dates.sort();
+    dates.dedup();
+
+    for date in dates {
+        if leave_days.contains_key(&date) {
+            continue;
+        }
+        let summary = || {
+            let day = schedule::window(tz, date, "00:00", "00:00");
+            timed
+                .iter()
+                .find(|((s, e), _)| day.map(|(ds, de)| *s < de && *e > ds).unwrap_or(false))
+                .and_then(|(_, event)| event.summary.clone())
+        };
+        let hours = schedule::day_windows(weekly, None, tz, date);
+        let remaining = subtract(&hours, &busy);
+        let whole_day = schedule::window(tz, date, "00:00", "00:00")
+            .map(|(ds, de)| busy.iter().any(|(s, e)| *s <= ds && *e >= de))
+            .unwrap_or(false);
+
+        if whole_day || (!hours.is_empty() && remaining.is_empty()) {
+            leave_days.insert(date, summary());
+        } else if remaining != hours && remaining.len() == 1 {
+            let (start, end) = (remaining[0].0.with_timezone(&tz), remaining[0].1.with_timezone(&tz));
+            if start.date_naive() == date {
+                overrides.push((date, start.format("%H:%M").to_string(), end.format("%H:%M").to_string()));
+            }
+        }
+    }
+
+    // Consecutive days from the same event make one leave entry
+    let mut leave: Vec<(NaiveDate, NaiveDate, Option<String>)> = Vec::new();
+    for (date, summary) in leave_days {
+        match leave.last_mut() {
+            Some((_, end, last)) if *end + Duration::days(1) == date && *last == summary => *end = date,
+            _ => leave.push((date, date, summary)),
+        }
+    }
+    Plan { leave, overrides }
+}
+
+/// The parts of `windows` not covered by `busy`
+fn subtract(windows: &[Window], busy: &[Window]) -> Vec<Window> {
+    let mut remaining = windows.to_vec();
+    for (busy_start, busy_end) in busy {
+        remaining = remaining
+            .into_iter()
+            .flat_map(|(start, end)| {
+                if *busy_start >= end || *busy_end <= start {
+                    return vec![(start, end)];
+                }
+                let mut parts = Vec::new();
+                if start < *busy_start {
+                    parts.push((start, *busy_start));
+                }
+                if *busy_end < end {
+                    parts.push((*busy_end, end));
+                }
+                parts
+            })
+            .collect();
+    }
+    remaining
+}
+
+/// Whether a URL looks like a calendar address Fabrica can fetch
+pub fn is_calendar_url(url: &str) -> bool {
+    let lower = url.trim().to_lowercase();
+    ["https://", "http://", "webcal://", "webcals://"].iter().any(|scheme| lower.starts_with(scheme))
+}
+
+/// Download a calendar, refusing anything over `MAX_BYTES`
+///
+/// Every hop of a redirect is resolved and checked, and only public addresses
+/// are reached unless the host is in `allowed_hosts`, so a calendar URL can't
+/// be used to probe the bot's own network.
+pub async fn fetch(url: &str, allowed_hosts: &[String]) -> Result<String> {
+    let url = url.trim();
+    let url = match url.split_once("://") {
+        Some((scheme, rest)) if scheme.eq_ignore_ascii_case("webcal") || scheme.eq_ignore_ascii_case("webcals") => {
+            format!("https://{}", rest)
+        }
+        _ => url.to_string(),
+    };
+    let mut url = reqwest::Url::parse(&url)?;
+    let mut redirects = 0;
+    let mut response = loop {
+        let response = get_public(&url, allowed_hosts).await?;
+        if !response.status().is_redirection() {
+            break response.error_for_status()?;
+        }
+        redirects += 1;
+        if redirects > MAX_REDIRECTS {
+            bail!("too many redirects");
+        }
+        let Some(location) = response.headers().get(reqwest::header::LOCATION).and_then(|l| l.to_str().ok()) else {
+            bail!("redirect without a location");
+        };
+        url = url.join(location)?;
+    };
+    if response.content_length().unwrap_or(0) as usize > MAX_BYTES {
+        bail!("calendar is larger than {} MB", MAX_BYTES / (1024 * 1024));
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if body.len() > MAX_BYTES {
+            bail!("calendar is larger than {} MB", MAX_BYTES / (1024 * 1024));
+        }
+    }
+    let text = String::from_utf8(body)?;
+    if !text.contains("BEGIN:VCALENDAR") {
+        bail!("not an iCalendar file");
+    }
+    Ok(text)
+}
+
+/// One request without following redirects, made only to the addresses
+/// checked here so a second DNS answer can't swap in another
+async fn get_public(url: &reqwest::Url, allowed_hosts: &[String]) -> Result<reqwest::Response> {
+    if !matches!(url.scheme(), "http" | "https") {
+        bail!("unsupported scheme {}", url.scheme());
+    }
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        bail!("calendar URL has no host");
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port)).await?.collect();
+    let allowed = allowed_hosts.iter().any(|h| h.eq_ignore_ascii_case(host));
+    if addrs.is_empty() {
+        bail!("{} did not resolve", host);
+    }
+    if !allowed && !addrs.iter().all(|addr| is_public(addr.ip())) {
+        bail!("{} resolves to a private address", host);
+    }
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .resolve_to_addrs(host, &addrs)
+        .timeout(std::time::Duration::from_secs(30))
+        .build()?;
+    Ok(client.get(url.clone()).send().await?)
+}
+
+/// Whether an address is on the public internet, rather than loopback,
+/// private, link-local, unique-local or otherwise reserved
+///
+/// IPv6 addresses that carry an IPv4 one (mapped, NAT64 and 6to4) are
+/// judged by the IPv4 address they reach.
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            !(v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_multicast()
+                || v4.is_documentation()
+                // Carrier-grade NAT, 100.64.0.0/10
+                || (a == 100 && (64..128).contains(&b))
+                || a == 0)
+        }
+        IpAddr::V6(v6) => match embedded_v4(v6) {
+            Some(v4) => is_public(IpAddr::V4(v4)),
+            None => {
+                let first = v6.segments()[0];
+                !(v6.is_loopback()
+                    || v6.is_unspecified()
+                    || v6.is_multicast()
+                    // Unique local fc00::/7 and link-local fe80::/10
+                    || (first & 0xfe00) == 0xfc00
+                    || (first & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
+
+/// The IPv4 address an IPv4-mapped `::ffff:0:0/96`, NAT64 `64:ff9b::/96`
+/// or 6to4 `2002::/16` address reaches
+fn embedded_v4(v6: Ipv6Addr) -> Option<Ipv4Addr> {
+    let s = v6.segments();
+    let v4 = |high: u16, low: u16| Ipv4Addr::from(((high as u32) << 16) | low as u32);
+    if let Some(v4) = v6.to_ipv4_mapped() {
+        Some(v4)
+    } else if s[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        Some(v4(s[6], s[7]))
+    } else if s[0] == 0x2002 {
+        Some(v4(s[1], s[2]))
+    } else {
+        None
+    }
+}
+
+/// Replace a user's imported leave and overrides with what a calendar holds,
+/// returning how many leave entries and overrides it made
+pub async fn sync(db: &Database, guild_id: &str, discord_id: &str, text: &str, now: DateTime<Utc>) -> Result<(usize, usize)> {
+    let tz = db.get_user_settings(discord_id).await?.tz();
+    let today = now.with_timezone(&tz).date_naive();
+    let weekly = db.get_weekly_schedule(guild_id, discord_id).await?;
+    let plan = plan(&parse_events(text, tz), &weekly, tz, today);
+
+    let leave: Vec<Leave> = plan
+        .leave
+        .iter()
+        .map(|(start, end, summary)| Leave {
+            id: 0,
+            guild_id: guild_id.to_string(),
+            discord_id: discord_id.to_string(),
+            start_date: start.format("%Y-%m-%d").to_string(),
+            end_date: end.format("%Y-%m-%d").to_string(),
+            reason: summary.clone(),
+            reason_visibility: "private".to_string(),
+            imported: true,
+        })
+        .collect();
+    let overrides: Vec<_> = plan
+        .overrides
+        .iter()
+        .map(|(date, start, end)| (date.format("%Y-%m-%d").to_string(), Some(start.clone()), end.clone()))
+        .collect();
+    let from = today.format("%Y-%m-%d").to_string();
+    db.replace_imported_absences(guild_id, discord_id, &from, &leave, &overrides).await?;
+    Ok((leave.len(), overrides.len()))
+}
+
+/// Fetch and sync every registered calendar, recording each outcome
+pub async fn poll_all(db: &Database, allowed_hosts: &[String], now: DateTime<Utc>) -> Result<()> {
+    for import in db.get_calendar_imports().await? {
+        let result = match fetch(&import.url, allowed_hosts).await {
+            Ok(text) => sync(db, &import.guild_id, &import.discord_id, &text, now).await,
+            Err(e) => Err(e),
+        };
+        let error = match result {
+            Ok((leave, overrides)) => {
+                info!(
+                    "Imported calendar of user {} in guild {}: {} leave, {} overrides",
+                    import.discord_id, import.guild_id, leave, overrides
+                );
+                None
+            }
+            Err(e) => {
+                warn!("Calendar import for user {} in guild {} failed: {}", import.discord_id, import.guild_id, e);
+                Some(e.to_string())
+            }
+        };
+        // One failed write shouldn't stop everyone else's imports
+        if let Err(e) = db.record_calendar_import(&import.guild_id, &import.discord_id, error.as_deref()).await {
+            warn!("Couldn't record calendar import for user {} in guild {}: {}", import.discord_id, import.guild_id, e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTLOOK: &str = include_str!("../../fixtures/ics/outlook-oof.ics");
+    const GOOGLE: &str = include_str!("../../fixtures/ics/google-vacation.ics");
+
+    fn kolkata() -> Tz {
+        "Asia/Kolkata".parse().unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    /// Monday to Friday, 09:00-17:00
+    fn weekdays() -> Vec<(u8, String, String)> {
+        (0..5).map(|day| (day, "09:00".to_string(), "17:00".to_string())).collect()
+    }
+
+    #[test]
+    fn test_parse_outlook_events() {
+        let events = parse_events(OUTLOOK, kolkata());
+        // Tentative and cancelled events are dropped
+        assert_eq!(events.len(), 5);
+
+        // Outlook's Windows timezone name falls back to the user's timezone
+        let dentist = &events[0];
+        assert_eq!(dentist.summary.as_deref(), Some("Out of office - dentist"));
+        assert!(dentist.out_of_office);
+        assert_eq!(
+            dentist.span,
+            Span::Timed(Utc.with_ymd_and_hms(2026, 3, 16, 3, 30, 0).unwrap(), Utc.with_ymd_and_hms(2026, 3, 16, 6, 0, 0).unwrap())
+        );
+        // The alarm's DESCRIPTION doesn't leak into the event
+        assert!(events.iter().all(|e| e.summary.as_deref() != Some("REMINDER")));
+        assert!(!events[3].out_of_office);
+    }
+
+    #[test]
+    fn test_parse_google_events() {
+        let events = parse_events(GOOGLE, kolkata());
+        // The transparent birthday is free time
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].summary.as_deref(), Some("Vacation, Goa"));
+        assert_eq!(events[0].span, Span::AllDay(date("2026-03-25"), date("2026-03-31")));
+        assert_eq!(
+            events[1].span,
+            Span::Timed(Utc.with_ymd_and_hms(2026, 4, 6, 3, 30, 0).unwrap(), Utc.with_ymd_and_hms(2026, 4, 8, 12, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("P1D"), Some(Duration::days(1)));
+        assert_eq!(parse_duration("PT2H30M"), Some(Duration::minutes(150)));
+        assert_eq!(parse_duration("P1W"), Some(Duration::weeks(1)));
+        assert_eq!(parse_duration("P2DT9H"), Some(Duration::hours(57)));
+        assert_eq!(parse_duration("2H"), None);
+    }
+
+    #[test]
+    fn test_plan_outlook_trims_hours_and_books_leave() {
+        let events = parse_events(OUTLOOK, kolkata());
+        let plan = plan(&events, &weekdays(), kolkata(), date("2026-03-14"));
+        assert_eq!(plan.leave, vec![(date("2026-03-19"), date("2026-03-20"), Some("Conference trip".to_string()))]);
+        assert_eq!(
+            plan.overrides,
+            vec![
+                // Dentist until 11:30, then working
+                (date("2026-03-16"), "11:30".to_string(), "17:00".to_string()),
+                // Leaving at 16:30
+                (date("2026-03-17"), "09:00".to_string(), "16:30".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_google_vacation_and_offsite() {
+        let events = parse_events(GOOGLE, kolkata());
+        let plan = plan(&events, &weekdays(), kolkata(), date("2026-03-14"));
+        // The past trip and the one-hour interview are left alone
+        assert_eq!(
+            plan.leave,
+            vec![
+                (date("2026-03-25"), date("2026-03-31"), Some("Vacation, Goa".to_string())),
+                (date("2026-04-06"), date("2026-04-08"), Some("Offsite".to_string())),
+            ]
+        );
+        assert!(plan.overrides.is_empty());
+    }
+
+    #[test]
+    fn test_plan_starts_today() {
+        let events = parse_events(GOOGLE, kolkata());
+        let plan = plan(&events, &weekdays(), kolkata(), date("2026-03-28"));
+        assert_eq!(plan.leave[0], (date("2026-03-28"), date("2026-03-31"), Some("Vacation, Goa".to_string())));
+    }
+
+    #[test]
+    fn test_unfold_crlf() {
+        let lines = unfold("SUMMARY:Long\r\n  day\r\nEND:VEVENT\r\n");
+        assert_eq!(lines, vec!["SUMMARY:Long day".to_string(), "END:VEVENT".to_string()]);
+    }
+
+    #[test]
+    fn test_is_calendar_url() {
+        assert!(is_calendar_url("https://calendar.google.com/calendar/ical/x/basic.ics"));
+        assert!(is_calendar_url("webcal://outlook.office365.com/owa/calendar/x/calendar.ics"));
+        assert!(!is_calendar_url("file:///etc/passwd"));
+        assert!(!is_calendar_url("off"));
+    }
+
+    #[test]
+    fn test_is_public() {
+        for ip in ["8.8.8.8", "2606:4700::1111", "64:ff9b::808:808", "2002:808:808::1"] {
+            assert!(is_public(ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in ["127.0.0.1", "10.1.2.3", "172.16.0.1", "192.168.1.1", "169.254.169.254", "100.64.0.1", "0.0.0.0", "::1", "fd00::1", "fe80::1", "::ffff:127.0.0.1",
+                   "64:ff9b::7f00:1", "64:ff9b::a9fe:a9fe", "2002:a00:1::1", "2002:c0a8:101::1"] {
+            assert!(!is_public(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_refuses_private_addresses() {
+        use axum::{response::Redirect, routing::get, Router};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let to_localhost = format!("http://localhost:{}/cal.ics", addr.port());
+        let app = Router::new()
+            .route("/cal.ics", get(|| async { GOOGLE }))
+            .route("/moved.ics", get(move || async move { Redirect::temporary(&to_localhost) }));
+        let server = tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let local = vec!["127.0.0.1".to_string()];
+        assert!(fetch(&format!("http://{}/cal.ics", addr), &[]).await.is_err());
+        assert!(fetch(&format!("http://{}/cal.ics", addr), &local).await.is_ok());
+        // Each redirect hop is checked on its own
+        assert!(fetch(&format!("http://{}/moved.ics", addr), &local).await.is_err());
+        assert!(fetch("file:///etc/passwd", &local).await.is_err());
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_poll_imports_from_local_server() {
+        use axum::{routing::get, Router};
+
+        let app = Router::new().route("/cal.ics", get(|| async { GOOGLE }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/cal.ics", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let local = vec!["127.0.0.1".to_string()];
+        let path = std::env::temp_dir().join(format!("fabrica-calendar-import-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let db = Database::new(path.to_str().unwrap()).await.unwrap();
+        db.migrate().await.unwrap();
+        db.set_user_timezone("42", "Asia/Kolkata").await.unwrap();
+        db.set_weekly_schedule("7", "42", &[0, 1, 2, 3, 4], &[("09:00".to_string(), "17:00".to_string())]).await.unwrap();
+        db.set_calendar_import("7", "42", &url).await.unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 3, 14, 6, 0, 0).unwrap();
+
+        // Polling twice replaces rather than duplicates
+        poll_all(&db, &local, now).await.unwrap();
+        poll_all(&db, &local, now).await.unwrap();
+        let leave = db.get_leave_between("7", "2026-03-01", "2026-12-31").await.unwrap();
+        assert_eq!(leave.len(), 2);
+        assert!(leave.iter().all(|l| l.imported && !l.reason_public()));
+        let import = db.get_calendar_import("7", "42").await.unwrap().unwrap();
+        assert!(import.last_synced_at.is_some());
+        assert_eq!(import.last_error, None);
+
+        // An unreachable calendar keeps what was imported and records the error
+        server.abort();
+        let _ = server.await;
+        poll_all(&db, &local, now).await.unwrap();
+        assert_eq!(db.get_leave_between("7", "2026-03-01", "2026-12-31").await.unwrap().len(), 2);
+        assert!(db.get_calendar_import("7", "42").await.unwrap().unwrap().last_error.is_some());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_poll_carries_on_when_recording_fails() {
+        use axum::{routing::get, Router};
+
+        let app = Router::new().route("/cal.ics", get(|| async { GOOGLE }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/cal.ics", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let local = vec!["127.0.0.1".to_string()];
+        let path = std::env::temp_dir().join(format!("fabrica-calendar-record-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let db = Database::new(path.to_str().unwrap()).await.unwrap();
+        db.migrate().await.unwrap();
+        for user in ["42", "43"] {
+            db.set_calendar_import("7", user, &url).await.unwrap();
+        }
+        // Recording 42's sync fails, wherever it comes in the loop
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TRIGGER fail_record BEFORE UPDATE ON calendar_imports WHEN OLD.discord_id = '42'
+             BEGIN SELECT RAISE(ABORT, 'locked'); END;",
+        )
+        .unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 3, 14, 6, 0, 0).unwrap();
+
+        poll_all(&db, &local, now).await.unwrap();
+        assert!(db.get_calendar_import("7", "42").await.unwrap().unwrap().last_synced_at.is_none());
+        assert!(db.get_calendar_import("7", "43").await.unwrap().unwrap().last_synced_at.is_some());
+
+        server.abort();
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,390 @@
+//! Offline evaluation of language detection and translation
+//!
+//! Runs a corpus of messages through the same steps as the bot: whatlang
+//! detection with LLM fallback, translation into English (or from English
+//! into a reader's language), and the similarity filter that drops
+//! "translations" of misdetected text. The corpus is JSON Lines, one case per
+//! line:
+//!
+//! ```text
+//! {"id": "hinglish-meeting", "category": "hinglish", "text": "kal ki meeting cancel ho gayi hai",
+//!  "lang": "hi", "llm_lang": "hi", "translations": {"en": "Tomorrow's meeting has been cancelled"}}
+//! ```
+//!
+//! With the mock model, `llm_lang` answers detection requests and
+//! `translations` answers translation requests by target language; a missing
+//! target gets `NO_TRANSLATION_NEEDED`.
+
+use crate::services::language::{self, Detection};
+use crate::services::prompts::{ChatMessage, PromptPurpose, PromptTemplates, PromptVars, TEXT_CLOSE, TEXT_OPEN};
+use crate::services::translator::{ScriptedReplies, TranslatorService};
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+
+/// Target for English messages when a case doesn't name one
+const DEFAULT_TARGET: &str = "hi";
+
+/// One corpus entry
+#[derive(Debug, Clone, Deserialize)]
+pub struct EvalCase {
+    pub id: String,
+    pub category: String,
+    pub text: String,
+    /// Language the text is actually written in
+    pub lang: String,
+    /// Reader language for English messages (default Hindi)
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Mock model's answer to a detection request
+    #[serde(default)]
+    pub llm_lang: Option<String>,
+    /// Mock model's translation by target language
+    #[serde(default)]
+    pub translations: HashMap<String, String>,
+}
+
+/// Parse a JSON Lines corpus, skipping blank lines and `#` comments
+pub fn parse_corpus(source: &str) -> Result<Vec<EvalCase>> {
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| serde_json::from_str(line).with_context(|| format!("corpus line {}", i + 1)))
+        .collect()
+}
+
+/// Offline stand-in for the model that answers from the corpus
+pub struct MockModel {
+    cases: HashMap<String, EvalCase>,
+    detect_prompt: String,
+    /// Target language of the translation being requested
+    target: Mutex<String>,
+}
+
+impl MockModel {
+    pub fn new(cases: &[EvalCase], prompts: &PromptTemplates) -> Arc<Self> {
+        let detect_prompt = prompts
+            .render_chat(PromptPurpose::Detect, "", &PromptVars::default())
+            .first()
+            .map(|m| m.content.clone())
+            .unwrap_or_default();
+        Arc::new(Self {
+            cases: cases.iter().map(|c| (c.text.clone(), c.clone())).collect(),
+            detect_prompt,
+            target: Mutex::new(String::new()),
+        })
+    }
+
+    /// Backend for [`TranslatorService::with_scripted_backend`]
+    pub fn backend(self: &Arc<Self>) -> ScriptedReplies {
+        let model = self.clone();
+        Arc::new(move |messages: &[ChatMessage]| Ok(model.reply(messages)))
+    }
+
+    fn set_target(&self, target: &str) {
+        *self.target.lock().unwrap() = target.to_string();
+    }
+
+    fn reply(&self, messages: &[ChatMessage]) -> String {
+        let text = messages
+            .iter()
+            .find(|m| m.role == "user")
+            .map(|m| m.content.replace(TEXT_OPEN, "").replace(TEXT_CLOSE, "").trim().to_string())
+            .unwrap_or_default();
+        let Some(case) = self.cases.get(&text) else {
+            return "NO_TRANSLATION_NEEDED".to_string();
+        };
+
+        if messages.first().is_some_and(|m| m.content == self.detect_prompt) {
+            return case.llm_lang.clone().unwrap_or_else(|| "en".to_string());
+        }
+        let target = self.target.lock().unwrap().clone();
+        case.translations
+            .get(&target)
+            .cloned()
+            .unwrap_or_else(|| "NO_TRANSLATION_NEEDED".to_string())
+    }
+}
+
+/// What happened to a case's translation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Translated(String),
+    /// Dropped by the similarity filter as a likely misdetection
+    Skipped(String),
+    /// The model said the text was already in the target language
+    NotNeeded,
+    Failed(String),
+}
+
+impl Outcome {
+    fn label(&self) -> &'static str {
+        match self {
+            Outcome::Translated(_) => "translated",
+            Outcome::Skipped(_) => "skipped",
+            Outcome::NotNeeded => "not-needed",
+            Outcome::Failed(_) => "failed",
+        }
+    }
+}
+
+/// Result of running one case
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub id: String,
+    pub category: String,
+    pub expected: String,
+    pub whatlang: Option<Detection>,
+    pub detected: String,
+    /// Whether whatlang wasn't confident and the model was asked
+    pub used_llm: bool,
+    pub target: String,
+    pub outcome: Outcome,
+}
+
+/// Run every case through detection, translation and the similarity filter
+///
+/// `mock` must be the model behind `translator` when it is a [`MockModel`],
+/// so it knows which language each translation request is for.
+pub async fn run(cases: &[EvalCase], translator: &TranslatorService, mock: Option<&MockModel>) -> Vec<CaseResult> {
+    let mut results = Vec::new();
+    for case in cases {
+        let text = case.text.trim();
+        let whatlang = language::detect(text);
+        let used_llm = !whatlang.is_some_and(|d| d.confidence >= language::CONFIDENT);
+        let detected = language::detect_with_fallback(translator, text).await;
+
+        // English goes out to readers; everything else comes in to English
+        let (from, target) = if detected == "en" {
+            ("en".to_string(), case.target.clone().unwrap_or_else(|| DEFAULT_TARGET.to_string()))
+        } else {
+            (detected.clone(), "en".to_string())
+        };
+        if let Some(mock) = mock {
+            mock.set_target(&target);
+        }
+
+        let outcome = match translator.translate(text, &from, &target).await {
+            Ok(Some(translated)) if from != "en" && language::looks_untranslated(text, &translated) => {
+                Outcome::Skipped(translated)
+            }
+            Ok(Some(translated)) => Outcome::Translated(translated),
+            Ok(None) => Outcome::NotNeeded,
+            Err(e) => Outcome::Failed(e.to_string()),
+        };
+
+        results.push(CaseResult {
+            id: case.id.clone(),
+            category: case.category.clone(),
+            expected: case.lang.clone(),
+            whatlang,
+            detected,
+            used_llm,
+            target,
+            outcome,
+        });
+    }
+    results
+}
+
+/// Counts for a set of results
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tally {
+    pub total: usize,
+    /// whatlang's guess was right, whatever its confidence
+    pub whatlang_correct: usize,
+    /// The final language (after any LLM fallback) was right
+    pub detected_correct: usize,
+    pub llm_fallbacks: usize,
+    pub translated: usize,
+    pub skipped: usize,
+    pub not_needed: usize,
+    pub failed: usize,
+}
+
+impl Tally {
+    fn add(&mut self, result: &CaseResult) {
+        self.total += 1;
+        if result.whatlang.is_some_and(|d| d.code == result.expected) {
+            self.whatlang_correct += 1;
+        }
+        if result.detected == result.expected {
+            self.detected_correct += 1;
+        }
+        if result.used_llm {
+            self.llm_fallbacks += 1;
+        }
+        match result.outcome {
+            Outcome::Translated(_) => self.translated += 1,
+            Outcome::Skipped(_) => self.skipped += 1,
+            Outcome::NotNeeded => self.not_needed += 1,
+            Outcome::Failed(_) => self.failed += 1,
+        }
+    }
+
+    pub fn detection_accuracy(&self) -> f64 {
+        ratio(self.detected_correct, self.total)
+    }
+
+    pub fn skip_rate(&self) -> f64 {
+        ratio(self.skipped, self.total)
+    }
+}
+
+fn ratio(n: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        n as f64 / total as f64
+    }
+}
+
+/// Overall tally and one per category
+pub fn summarize(results: &[CaseResult]) -> (Tally, BTreeMap<String, Tally>) {
+    let mut overall = Tally::default();
+    let mut by_category: BTreeMap<String, Tally> = BTreeMap::new();
+    for result in results {
+        overall.add(result);
+        by_category.entry(result.category.clone()).or_default().add(result);
+    }
+    (overall, by_category)
+}
+
+/// One tab-separated line per case, stable across runs so outputs can be diffed
+pub fn format_results(results: &[CaseResult]) -> String {
+    let mut out = String::from("id\tcategory\texpected\twhatlang\tdetected\ttarget\toutcome\toutput\n");
+    for r in results {
+        let whatlang = r
+            .whatlang
+            .map(|d| format!("{}:{:.2}", d.code, d.confidence))
+            .unwrap_or_else(|| "-".to_string());
+        let detected = if r.used_llm { format!("{}*", r.detected) } else { r.detected.clone() };
+        let output = match &r.outcome {
+            Outcome::Translated(text) | Outcome::Skipped(text) | Outcome::Failed(text) => escape(text),
+            Outcome::NotNeeded => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            r.id,
+            r.category,
+            r.expected,
+            whatlang,
+            detected,
+            r.target,
+            r.outcome.label(),
+            output
+        );
+    }
+    out
+}
+
+/// Human-readable accuracy and skip-rate report
+pub fn format_summary(overall: &Tally, by_category: &BTreeMap<String, Tally>) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{:<10} {:>5} {:>9} {:>9} {:>5} {:>7} {:>6}", "category", "cases", "whatlang", "detected", "llm", "skipped", "failed");
+    let mut row = |name: &str, t: &Tally| {
+        let _ = writeln!(
+            out,
+            "{:<10} {:>5} {:>8.0}% {:>8.0}% {:>5} {:>6.0}% {:>6}",
+            name,
+            t.total,
+            ratio(t.whatlang_correct, t.total) * 100.0,
+            t.detection_accuracy() * 100.0,
+            t.llm_fallbacks,
+            t.skip_rate() * 100.0,
+            t.failed
+        );
+    };
+    for (category, tally) in by_category {
+        row(category, tally);
+    }
+    row("all", overall);
+    out
+}
+
+/// Keep each result on one line
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n").replace('\t', "\\t")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TranslationConfig;
+
+    const CORPUS: &str = include_str!("../../eval/corpus.jsonl");
+
+    async fn run_mock(cases: &[EvalCase]) -> Vec<CaseResult> {
+        let config: TranslationConfig = toml::from_str("").unwrap();
+        let model = MockModel::new(cases, &PromptTemplates::load(&config.prompts));
+        let translator = TranslatorService::with_scripted_backend(&config, model.backend());
+        run(cases, &translator, Some(&model)).await
+    }
+
+    fn result<'a>(results: &'a [CaseResult], id: &str) -> &'a CaseResult {
+        results.iter().find(|r| r.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_parse_corpus() {
+        let cases = parse_corpus("# comment\n\n{\"id\": \"a\", \"category\": \"x\", \"text\": \"hi\", \"lang\": \"en\"}\n").unwrap();
+        assert_eq!(cases.len(), 1);
+        assert!(cases[0].translations.is_empty());
+        assert!(parse_corpus("{not json}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_corpus_with_mock_model() {
+        let cases = parse_corpus(CORPUS).unwrap();
+        let results = run_mock(&cases).await;
+        let (overall, by_category) = summarize(&results);
+
+        assert_eq!(overall.failed, 0, "{}", format_results(&results));
+        assert!(overall.detection_accuracy() >= 0.9, "{}", format_summary(&overall, &by_category));
+        for category in ["english", "hindi", "hinglish", "filipino", "korean", "code"] {
+            assert!(by_category.contains_key(category), "corpus has no {} cases", category);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hinglish_needs_llm_fallback() {
+        let cases = parse_corpus(CORPUS).unwrap();
+        let results = run_mock(&cases).await;
+        let hinglish = result(&results, "hinglish-meeting");
+        assert!(hinglish.used_llm);
+        assert_eq!(hinglish.detected, "hi");
+        assert_eq!(hinglish.target, "en");
+    }
+
+    #[tokio::test]
+    async fn test_misdetected_english_is_skipped() {
+        let cases = parse_corpus(CORPUS).unwrap();
+        let results = run_mock(&cases).await;
+        // whatlang is confident a file path is French; the unchanged "translation" is dropped
+        let path = result(&results, "code-path");
+        assert!(!path.used_llm);
+        assert_eq!(path.detected, "fr");
+        assert!(matches!(path.outcome, Outcome::Skipped(_)));
+    }
+
+    #[test]
+    fn test_format_results_is_one_line_per_case() {
+        let results = vec![CaseResult {
+            id: "code".to_string(),
+            category: "code".to_string(),
+            expected: "en".to_string(),
+            whatlang: Some(Detection { code: "en", confidence: 0.5 }),
+            detected: "en".to_string(),
+            used_llm: true,
+            target: "hi".to_string(),
+            outcome: Outcome::Translated("इसे आज़माएँ:\n```rust\nfoo()\n```".to_string()),
+        }];
+        let formatted = format_results(&results);
+        assert_eq!(formatted.lines().count(), 2);
+        assert!(formatted.ends_with("code\tcode\ten\ten:0.50\ten*\thi\ttranslated\tइसे आज़माएँ:\\n```rust\\nfoo()\\n```\n"));
+    }
+}
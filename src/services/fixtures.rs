@@ -0,0 +1,97 @@
+//! Recorded model requests and responses
+//!
+//! The "record" backend sends requests to OpenRouter as usual and saves each
+//! request with its response body as a JSON file; the "replay" backend
+//! answers from those files without touching the network, so tests and
+//! `fabrica eval` run hermetically. Fixtures are keyed by a hash of the full
+//! request (model, messages and token limit), so any change to a prompt
+//! needs a new recording.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// A recorded request/response pair as stored on disk
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    request: Value,
+    response: Value,
+}
+
+/// File a request is recorded in: `<dir>/<purpose>-<hash>.json`
+pub fn fixture_path(dir: &str, purpose: &str, request: &impl Serialize) -> Result<PathBuf> {
+    let body = serde_json::to_vec(request)?;
+    let hash = hex::encode(Sha256::digest(&body));
+    let purpose: String = purpose
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    Ok(Path::new(dir).join(format!("{}-{}.json", purpose, &hash[..16])))
+}
+
+/// Save a request and the response body it got
+pub fn record(dir: &str, purpose: &str, request: &impl Serialize, response: &Value) -> Result<()> {
+    let path = fixture_path(dir, purpose, request)?;
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create fixtures dir {}", dir))?;
+    let fixture = Fixture {
+        request: serde_json::to_value(request)?,
+        response: response.clone(),
+    };
+    std::fs::write(&path, serde_json::to_string_pretty(&fixture)? + "\n")
+        .with_context(|| format!("Failed to write fixture {}", path.display()))
+}
+
+/// The recorded response body for a request
+pub fn replay(dir: &str, purpose: &str, request: &impl Serialize) -> Result<Value> {
+    let path = fixture_path(dir, purpose, request)?;
+    if !path.exists() {
+        bail!(
+            "No {} fixture for this request ({}); record one with backend = \"record\"",
+            purpose,
+            path.display()
+        );
+    }
+    let source = std::fs::read_to_string(&path).with_context(|| format!("Failed to read fixture {}", path.display()))?;
+    let fixture: Fixture = serde_json::from_str(&source).with_context(|| format!("Invalid fixture {}", path.display()))?;
+    Ok(fixture.response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn temp_dir(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("fabrica-fixtures-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_record_then_replay() {
+        let dir = temp_dir("roundtrip");
+        let request = json!({"model": "m", "messages": [{"role": "user", "content": "Hello"}], "max_tokens": 10});
+        let response = json!({"choices": [{"message": {"content": "नमस्ते"}}]});
+
+        assert!(replay(&dir, "translation", &request).unwrap_err().to_string().contains("backend = \"record\""));
+        record(&dir, "translation", &request, &response).unwrap();
+        assert_eq!(replay(&dir, "translation", &request).unwrap(), response);
+
+        // A different request is a different fixture
+        let other = json!({"model": "m", "messages": [{"role": "user", "content": "Bye"}], "max_tokens": 10});
+        assert!(replay(&dir, "translation", &other).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_fixture_path() {
+        let request = json!({"model": "m"});
+        let path = fixture_path("fixtures", "language detection", &request).unwrap();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(name.starts_with("language-detection-"));
+        assert!(name.ends_with(".json"));
+        assert_eq!(path, fixture_path("fixtures", "language detection", &request).unwrap());
+    }
+}
@@ -0,0 +1,309 @@
+//! Localization of Fabrica's own replies
+//!
+//! Message catalogs live in `locales/<locale>.ftl` and use a subset of
+//! Project Fluent syntax:
+//!
+//! ```text
+//! # Comment
+//! status-available = 🟢 You're now **available**
+//! status-available-message = 🟢 You're now **available** - { $message }
+//! multi-line =
+//!     First line
+//!     Second line
+//! command-fabrica-team =
+//!     .description = Show available team members
+//!     .param-visibility = Make visible to everyone
+//! ```
+//!
+//! Messages are looked up in the requested locale, then English, then the
+//! message ID itself is returned so a missing entry is visible but harmless.
+
+use crate::bot::{Context, Data, Error};
+use crate::db::Database;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tracing::debug;
+
+/// Locale used when nothing else matches
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Built-in catalogs, embedded at compile time
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../../locales/en.ftl")),
+    ("hi", include_str!("../../locales/hi.ftl")),
+];
+
+/// Parsed message catalogs for every shipped locale
+#[derive(Debug)]
+pub struct Locales {
+    bundles: HashMap<&'static str, HashMap<String, String>>,
+}
+
+impl Locales {
+    /// Parse the embedded catalogs
+    pub fn builtin() -> Self {
+        let bundles = CATALOGS
+            .iter()
+            .map(|(locale, source)| (*locale, parse_ftl(source)))
+            .collect();
+        Self { bundles }
+    }
+
+    /// Look up a message without falling back to other locales
+    fn lookup(&self, locale: &str, id: &str) -> Option<&str> {
+        self.bundles.get(locale)?.get(id).map(String::as_str)
+    }
+
+    /// Format a message, falling back to English and then to the ID
+    pub fn format(&self, locale: &str, id: &str, args: &[(&str, &str)]) -> String {
+        match self.lookup(locale, id).or_else(|| self.lookup(DEFAULT_LOCALE, id)) {
+            Some(pattern) => interpolate(pattern, args),
+            None => {
+                debug!("Missing localization for {}", id);
+                id.to_string()
+            }
+        }
+    }
+}
+
+/// Shared catalogs, parsed on first use
+pub fn locales() -> &'static Locales {
+    static LOCALES: OnceLock<Locales> = OnceLock::new();
+    LOCALES.get_or_init(Locales::builtin)
+}
+
+/// Format a message with no arguments
+pub fn tr(locale: &str, id: &str) -> String {
+    locales().format(locale, id, &[])
+}
+
+/// Format a message with `{ $name }` arguments
+pub fn tr_args(locale: &str, id: &str, args: &[(&str, &str)]) -> String {
+    locales().format(locale, id, args)
+}
+
+/// Map a language code or Discord locale (e.g. "hi", "en-US") to a shipped locale
+pub fn supported_locale(tag: &str) -> Option<&'static str> {
+    let primary = tag.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+    CATALOGS
+        .iter()
+        .map(|(locale, _)| *locale)
+        .find(|locale| *locale == primary)
+}
+
+/// Pick the reply locale for the invoking user: their default translation
+/// language if we have a catalog for it, otherwise the Discord client locale
+pub async fn locale_for(ctx: Context<'_>) -> &'static str {
+    let user_id = ctx.author().id.to_string();
+    if let Some(locale) = default_locale(&ctx.data().db, &user_id).await {
+        return locale;
+    }
+    ctx.locale().and_then(supported_locale).unwrap_or(DEFAULT_LOCALE)
+}
+
+/// Pick the locale for messages Fabrica sends a user on its own (e.g. DMs),
+/// where there's no Discord client locale to fall back on
+pub async fn locale_for_user(db: &Database, discord_id: &str) -> &'static str {
+    default_locale(db, discord_id).await.unwrap_or(DEFAULT_LOCALE)
+}
+
+/// The catalog matching a user's default translation language, if any
+async fn default_locale(db: &Database, discord_id: &str) -> Option<&'static str> {
+    let language = db.get_default_language(discord_id).await.ok().flatten()?;
+    supported_locale(&language)
+}
+
+/// Fill Discord name/description localizations from the catalogs
+///
+/// Each command is keyed by its path, e.g. `command-fabrica-translate-subscribe`.
+/// The message value (if any) localizes the name, `.description` the
+/// description, and `.param-<name>` each parameter description.
+pub fn localize_commands(commands: &mut [poise::Command<Data, Error>]) {
+    let locales = locales();
+    for command in commands {
+        localize_command(locales, command, "command");
+    }
+}
+
+fn localize_command(locales: &Locales, command: &mut poise::Command<Data, Error>, prefix: &str) {
+    let key = format!("{}-{}", prefix, command.name);
+
+    for (locale, _) in CATALOGS.iter().filter(|(l, _)| *l != DEFAULT_LOCALE) {
+        if let Some(name) = locales.lookup(locale, &key) {
+            command.name_localizations.insert(locale.to_string(), name.to_string());
+        }
+        if let Some(description) = locales.lookup(locale, &format!("{}.description", key)) {
+            command.description_localizations.insert(locale.to_string(), description.to_string());
+        }
+        for parameter in &mut command.parameters {
+            if let Some(description) = locales.lookup(locale, &format!("{}.param-{}", key, parameter.name)) {
+                parameter.description_localizations.insert(locale.to_string(), description.to_string());
+            }
+        }
+    }
+
+    for subcommand in &mut command.subcommands {
+        localize_command(locales, subcommand, &key);
+    }
+}
+
+/// Parse Fluent-style `id = value` messages, multi-line values and `.attr` attributes
+fn parse_ftl(source: &str) -> HashMap<String, String> {
+    let mut messages = HashMap::new();
+    let mut message_id = String::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for line in source.lines() {
+        if line.starts_with('#') {
+            flush_entry(&mut messages, current.take());
+            continue;
+        }
+        if line.trim().is_empty() {
+            if let Some((_, lines)) = &mut current {
+                lines.push("");
+            }
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            let trimmed = line.trim_start();
+            let attribute = trimmed
+                .strip_prefix('.')
+                .and_then(|rest| rest.split_once('='))
+                .filter(|(name, _)| is_identifier(name.trim()));
+            match attribute {
+                Some((name, value)) if !message_id.is_empty() => {
+                    flush_entry(&mut messages, current.take());
+                    current = Some((format!("{}.{}", message_id, name.trim()), vec![value.trim()]));
+                }
+                _ => {
+                    if let Some((_, lines)) = &mut current {
+                        lines.push(line);
+                    }
+                }
+            }
+            continue;
+        }
+        if let Some((id, value)) = line.split_once('=') {
+            flush_entry(&mut messages, current.take());
+            message_id = id.trim().to_string();
+            current = Some((message_id.clone(), vec![value.trim()]));
+        }
+    }
+    flush_entry(&mut messages, current);
+    messages
+}
+
+/// Join a message's first line and dedented continuation lines
+fn flush_entry(messages: &mut HashMap<String, String>, entry: Option<(String, Vec<&str>)>) {
+    let Some((id, lines)) = entry else { return };
+    let (first, rest) = lines.split_first().map(|(f, r)| (*f, r)).unwrap_or_default();
+
+    let indent = rest
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut parts: Vec<&str> = Vec::new();
+    if !first.is_empty() {
+        parts.push(first);
+    }
+    parts.extend(rest.iter().map(|l| if l.len() >= indent { &l[indent..] } else { l.trim_start() }));
+
+    let value = parts.join("\n").trim_end().to_string();
+    if !value.is_empty() {
+        messages.insert(id, value);
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Replace `{ $name }` placeholders; `{ "x" }` inserts a literal
+fn interpolate(pattern: &str, args: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let placeable = &rest[start..start + len + 1];
+        let inner = placeable[1..placeable.len() - 1].trim();
+
+        if let Some(name) = inner.strip_prefix('$') {
+            match args.iter().find(|(k, _)| *k == name) {
+                Some((_, value)) => out.push_str(value),
+                None => out.push_str(placeable),
+            }
+        } else if inner.len() >= 2 && inner.starts_with('"') && inner.ends_with('"') {
+            out.push_str(&inner[1..inner.len() - 1]);
+        } else {
+            out.push_str(placeable);
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ftl() {
+        let messages = parse_ftl(
+            "# comment\n\
+             hello = Hello { $name }\n\
+             multi =\n    First\n\n      Indented\n    Last\n\
+             command-x =\n    .description = Does x\n    .param-y = The y\n",
+        );
+        assert_eq!(messages["hello"], "Hello { $name }");
+        assert_eq!(messages["multi"], "First\n\n  Indented\nLast");
+        assert_eq!(messages["command-x.description"], "Does x");
+        assert_eq!(messages["command-x.param-y"], "The y");
+        assert!(!messages.contains_key("command-x"));
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(interpolate("Hi { $name }!", &[("name", "Asha")]), "Hi Asha!");
+        assert_eq!(interpolate("{$a}{$b}", &[("a", "1"), ("b", "2")]), "12");
+        assert_eq!(interpolate("Keep { $missing }", &[]), "Keep { $missing }");
+        assert_eq!(interpolate("Brace { \"{\" }", &[]), "Brace {");
+    }
+
+    #[test]
+    fn test_locale_fallback() {
+        let locales = Locales::builtin();
+        assert_eq!(locales.format("hi", "no-such-message", &[]), "no-such-message");
+        assert_eq!(locales.format("xx", "status-cleared", &[]), locales.format("en", "status-cleared", &[]));
+        assert_ne!(locales.format("hi", "status-cleared", &[]), locales.format("en", "status-cleared", &[]));
+        assert_eq!(supported_locale("en-US"), Some("en"));
+        assert_eq!(supported_locale("hi"), Some("hi"));
+        assert_eq!(supported_locale("fr"), None);
+    }
+
+    #[test]
+    fn test_catalogs_have_same_messages() {
+        let en = parse_ftl(CATALOGS[0].1);
+        let hi = parse_ftl(CATALOGS[1].1);
+        let missing: Vec<_> = en.keys().filter(|k| !k.starts_with("command-") && !hi.contains_key(*k)).collect();
+        assert!(missing.is_empty(), "hi.ftl is missing {:?}", missing);
+        let extra: Vec<_> = hi.keys().filter(|k| !k.starts_with("command-") && !en.contains_key(*k)).collect();
+        assert!(extra.is_empty(), "hi.ftl has unknown {:?}", extra);
+
+        // Discord rejects command and option descriptions over 100 characters
+        let too_long: Vec<_> = hi
+            .iter()
+            .filter(|(k, v)| k.starts_with("command-") && v.chars().count() > 100)
+            .map(|(k, _)| k)
+            .collect();
+        assert!(too_long.is_empty(), "descriptions too long: {:?}", too_long);
+    }
+}
@@ -0,0 +1,142 @@
+//! Language detection helpers shared by the bot and the evaluation harness
+//!
+//! whatlang gives a fast first guess; callers fall back to the LLM when its
+//! confidence is below [`CONFIDENT`]. A "translation" that is nearly identical
+//! to its source usually means the source language was misdetected, which
+//! [`looks_untranslated`] checks for.
+
+use crate::services::translator::TranslatorService;
+use tracing::{info, warn};
+
+/// whatlang confidence at or above which its guess is trusted
+pub const CONFIDENT: f64 = 0.8;
+/// Word overlap above which a translation is treated as unchanged
+pub const MAX_SIMILARITY: f64 = 0.85;
+
+/// A whatlang guess with its code converted to ISO 639-1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    pub code: &'static str,
+    pub confidence: f64,
+}
+
+/// Detect a text's language with whatlang
+pub fn detect(text: &str) -> Option<Detection> {
+    whatlang::detect(text).map(|info| Detection {
+        code: whatlang_to_iso(info.lang().code()),
+        confidence: info.confidence(),
+    })
+}
+
+/// Detect with whatlang, asking the LLM when whatlang isn't confident
+pub async fn detect_with_fallback(translator: &TranslatorService, text: &str) -> String {
+    let (code, confidence) = detect(text).map(|d| (d.code, d.confidence)).unwrap_or(("unknown", 0.0));
+    let preview: String = text.chars().take(50).collect();
+    info!("whatlang detected: '{}' (confidence: {:.2}) for: {}", code, confidence, preview);
+
+    if confidence >= CONFIDENT {
+        return code.to_string();
+    }
+
+    info!("whatlang uncertain, asking LLM...");
+    match translator.detect_language(text).await {
+        Ok(code) => {
+            info!("LLM detected language: '{}'", code);
+            code
+        }
+        Err(e) => {
+            warn!("LLM language detection failed: {}, defaulting to English", e);
+            "en".to_string()
+        }
+    }
+}
+
+/// Convert whatlang 3-letter codes to ISO 639-1 2-letter codes
+pub fn whatlang_to_iso(code: &str) -> &str {
+    match code {
+        "eng" => "en",
+        "hin" => "hi",
+        "fra" => "fr",
+        "spa" => "es",
+        "deu" => "de",
+        "kor" => "ko",
+        "tgl" => "fil",  // Tagalog -> Filipino
+        "cmn" | "zho" => "zh",
+        "jpn" => "ja",
+        "rus" => "ru",
+        "ara" => "ar",
+        "por" => "pt",
+        "ita" => "it",
+        "nld" => "nl",
+        "pol" => "pl",
+        "tur" => "tr",
+        "vie" => "vi",
+        "tha" => "th",
+        "ind" => "id",
+        "ukr" => "uk",
+        "ces" => "cs",
+        "ell" => "el",
+        "heb" => "he",
+        "swe" => "sv",
+        "dan" => "da",
+        "fin" => "fi",
+        "nor" => "no",
+        other => other,
+    }
+}
+
+/// Calculate similarity ratio between two strings (0.0 to 1.0)
+pub fn similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let a_words: Vec<&str> = a.split_whitespace().collect();
+    let b_words: Vec<&str> = b.split_whitespace().collect();
+
+    let matching = a_words.iter().filter(|w| b_words.contains(w)).count();
+    let total = a_words.len().max(b_words.len());
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    matching as f64 / total as f64
+}
+
+/// Whether a translation is (nearly) the source text unchanged
+pub fn looks_untranslated(original: &str, translated: &str) -> bool {
+    let original = original.to_lowercase();
+    let translated = translated.to_lowercase();
+    original == translated || similarity(&original, &translated) > MAX_SIMILARITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whatlang_to_iso() {
+        assert_eq!(whatlang_to_iso("hin"), "hi");
+        assert_eq!(whatlang_to_iso("tgl"), "fil");
+        assert_eq!(whatlang_to_iso("kor"), "ko");
+        assert_eq!(whatlang_to_iso("xyz"), "xyz");
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("the build is green", "the build is green"), 1.0);
+        assert_eq!(similarity("", "anything"), 0.0);
+        assert_eq!(similarity("the build is green", "the build is red"), 0.75);
+        assert_eq!(similarity("ship it", "भेज दो"), 0.0);
+    }
+
+    #[test]
+    fn test_looks_untranslated() {
+        assert!(looks_untranslated("LGTM, merging now", "lgtm, merging now"));
+        assert!(!looks_untranslated("kal meeting hai", "There is a meeting tomorrow"));
+    }
+}
@@ -0,0 +1,37 @@
+//! Discord message limits and fitting text inside them
+
+/// Discord's message length limit
+pub const MAX_CONTENT_CHARS: usize = 2000;
+/// Discord's webhook username length limit
+pub const MAX_USERNAME_CHARS: usize = 80;
+
+/// Truncate to Discord's message limit, marking the cut with an ellipsis
+pub fn fit_message(content: &str) -> String {
+    if content.chars().count() <= MAX_CONTENT_CHARS {
+        return content.to_string();
+    }
+    let mut truncated: String = content.chars().take(MAX_CONTENT_CHARS - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Truncate a display name to what a webhook accepts
+pub fn fit_username(name: &str) -> String {
+    name.chars().take(MAX_USERNAME_CHARS).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_message() {
+        assert_eq!(fit_message("short"), "short");
+        let exact = "a".repeat(MAX_CONTENT_CHARS);
+        assert_eq!(fit_message(&exact), exact);
+        let long = "ह".repeat(MAX_CONTENT_CHARS + 10);
+        let fitted = fit_message(&long);
+        assert_eq!(fitted.chars().count(), MAX_CONTENT_CHARS);
+        assert!(fitted.ends_with('…'));
+    }
+}
@@ -1,3 +1,16 @@
 //! External service integrations
 
+pub mod attachments;
+pub mod calendar;
+pub mod calendar_import;
+pub mod eval;
+pub mod fixtures;
+pub mod i18n;
+pub mod language;
+pub mod messages;
+pub mod pipeline;
+pub mod prompts;
+pub mod schedule;
+pub mod standup;
+pub mod sanitize;
 pub mod translator;
@@ -0,0 +1,324 @@
+//! Translation pipeline - Bounded worker pool for incoming messages
+//!
+//! Messages are routed to a fixed number of workers by channel, so every
+//! message from one channel is handled by the same worker in arrival order.
+//! Each worker has a bounded queue; when it fills up, the gateway handler
+//! waits for space instead of spawning more concurrent LLM calls.
+//!
+//! Edits and deletions go through the same queues so mirrored channels see
+//! them after the message they refer to. Replies to translation DMs are
+//! translated on the workers too, keeping LLM calls off the gateway handler.
+
+use crate::bot::{Data, Discord};
+use crate::config::TranslationConfig;
+use crate::modules::{dm_reply, mirror, translation};
+use poise::serenity_prelude::{ChannelId, GuildId, Message, MessageId};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+/// A message event waiting to be handled
+struct Job {
+    discord: Discord,
+    event: JobEvent,
+}
+
+enum JobEvent {
+    Message(Box<Message>),
+    DmReply(Box<Message>),
+    Edit { guild_id: GuildId, channel_id: ChannelId, message_id: MessageId },
+    Delete { guild_id: GuildId, message_id: MessageId },
+}
+
+impl JobEvent {
+    fn message_id(&self) -> MessageId {
+        match self {
+            JobEvent::Message(message) | JobEvent::DmReply(message) => message.id,
+            JobEvent::Edit { message_id, .. } | JobEvent::Delete { message_id, .. } => *message_id,
+        }
+    }
+}
+
+/// Counters describing pipeline throughput and backpressure
+#[derive(Debug, Default)]
+struct Metrics {
+    enqueued: AtomicU64,
+    completed: AtomicU64,
+    failed: AtomicU64,
+    backpressure_waits: AtomicU64,
+    max_depth: AtomicU64,
+}
+
+/// Point-in-time snapshot of pipeline metrics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineStats {
+    pub workers: usize,
+    pub capacity: usize,
+    pub queued: u64,
+    pub max_depth: u64,
+    pub enqueued: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub backpressure_waits: u64,
+}
+
+/// Handle to the translation worker pool
+#[derive(Debug, Clone)]
+pub struct TranslationPipeline {
+    senders: Arc<Vec<mpsc::Sender<Job>>>,
+    metrics: Arc<Metrics>,
+    capacity: usize,
+}
+
+/// Receiving ends of the worker queues, consumed by [`PipelineWorkers::spawn`]
+pub struct PipelineWorkers {
+    receivers: Vec<mpsc::Receiver<Job>>,
+    metrics: Arc<Metrics>,
+}
+
+impl TranslationPipeline {
+    /// Create the worker queues; workers are started separately once the
+    /// shared bot data exists
+    pub fn new(config: &TranslationConfig) -> (Self, PipelineWorkers) {
+        let workers = config.workers.max(1);
+        let per_worker = (config.queue_size / workers).max(1);
+        let metrics = Arc::new(Metrics::default());
+
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            (0..workers).map(|_| mpsc::channel(per_worker)).unzip();
+
+        let pipeline = Self {
+            senders: Arc::new(senders),
+            metrics: metrics.clone(),
+            capacity: per_worker * workers,
+        };
+
+        (pipeline, PipelineWorkers { receivers, metrics })
+    }
+
+    /// Queue a message for translation, waiting if its worker is saturated
+    pub async fn submit(&self, discord: &Discord, message: &Message) {
+        self.enqueue(discord, message.channel_id, JobEvent::Message(Box::new(message.clone()))).await;
+    }
+
+    /// Queue a DM that may reply to a translation DM
+    pub async fn submit_dm_reply(&self, discord: &Discord, message: &Message) {
+        self.enqueue(discord, message.channel_id, JobEvent::DmReply(Box::new(message.clone()))).await;
+    }
+
+    /// Queue an edited message so its mirrored copies are updated
+    pub async fn submit_edit(&self, discord: &Discord, guild_id: GuildId, channel_id: ChannelId, message_id: MessageId) {
+        self.enqueue(discord, channel_id, JobEvent::Edit { guild_id, channel_id, message_id }).await;
+    }
+
+    /// Queue a deleted message so its mirrored copies are removed
+    pub async fn submit_delete(&self, discord: &Discord, guild_id: GuildId, channel_id: ChannelId, message_id: MessageId) {
+        self.enqueue(discord, channel_id, JobEvent::Delete { guild_id, message_id }).await;
+    }
+
+    async fn enqueue(&self, discord: &Discord, channel_id: ChannelId, event: JobEvent) {
+        let index = worker_for_channel(channel_id, self.senders.len());
+        let sender = &self.senders[index];
+        let message_id = event.message_id();
+        let job = Job { discord: discord.clone(), event };
+
+        // Counted before sending so a worker can't finish it first and leave
+        // the queue depth briefly negative
+        self.metrics.enqueued.fetch_add(1, Ordering::Relaxed);
+
+        let result = match sender.try_send(job) {
+            Ok(()) => Ok(()),
+            Err(mpsc::error::TrySendError::Full(job)) => {
+                self.metrics.backpressure_waits.fetch_add(1, Ordering::Relaxed);
+                self.metrics.max_depth.fetch_max(self.queued(), Ordering::Relaxed);
+                warn!(
+                    "Translation worker {} is full ({} slots), waiting for space",
+                    index,
+                    sender.max_capacity()
+                );
+                sender.send(job).await.map_err(|_| ())
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => Err(()),
+        };
+
+        if result.is_err() {
+            self.metrics.enqueued.fetch_sub(1, Ordering::Relaxed);
+            error!("Translation worker {} has stopped; dropping message {}", index, message_id);
+            return;
+        }
+
+        self.metrics.max_depth.fetch_max(self.queued(), Ordering::Relaxed);
+    }
+
+    /// Number of messages waiting or being processed
+    fn queued(&self) -> u64 {
+        let enqueued = self.metrics.enqueued.load(Ordering::Relaxed);
+        let done = self.metrics.completed.load(Ordering::Relaxed) + self.metrics.failed.load(Ordering::Relaxed);
+        enqueued.saturating_sub(done)
+    }
+
+    /// Snapshot the current pipeline metrics
+    pub fn stats(&self) -> PipelineStats {
+        PipelineStats {
+            workers: self.senders.len(),
+            capacity: self.capacity,
+            queued: self.queued(),
+            max_depth: self.metrics.max_depth.load(Ordering::Relaxed),
+            enqueued: self.metrics.enqueued.load(Ordering::Relaxed),
+            completed: self.metrics.completed.load(Ordering::Relaxed),
+            failed: self.metrics.failed.load(Ordering::Relaxed),
+            backpressure_waits: self.metrics.backpressure_waits.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl PipelineWorkers {
+    /// Start one task per worker queue
+    pub fn spawn(self, data: Data) {
+        let data = Arc::new(data);
+        info!("Starting {} translation worker(s)", self.receivers.len());
+
+        for (index, mut receiver) in self.receivers.into_iter().enumerate() {
+            let data = data.clone();
+            let metrics = self.metrics.clone();
+
+            tokio::spawn(async move {
+                while let Some(job) = receiver.recv().await {
+                    let result = match &job.event {
+                        JobEvent::Message(message) => translation::handle_message(&job.discord, message, &data).await,
+                        JobEvent::DmReply(message) => dm_reply::handle_message(&job.discord, message, &data).await,
+                        JobEvent::Edit { guild_id, channel_id, message_id } => {
+                            mirror::handle_edit(&job.discord, *guild_id, *channel_id, *message_id, &data).await
+                        }
+                        JobEvent::Delete { guild_id, message_id } => {
+                            mirror::handle_delete(&job.discord, *guild_id, *message_id, &data).await
+                        }
+                    };
+                    match result {
+                        Ok(()) => {
+                            metrics.completed.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(e) => {
+                            metrics.failed.fetch_add(1, Ordering::Relaxed);
+                            error!("Translation error for message {}: {}", job.event.message_id(), e);
+                        }
+                    }
+                }
+                info!("Translation worker {} stopped", index);
+            });
+        }
+    }
+}
+
+/// Pick the worker responsible for a channel so its messages stay ordered
+fn worker_for_channel(channel_id: ChannelId, workers: usize) -> usize {
+    (channel_id.get() % workers as u64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::translator::TranslatorService;
+    use crate::testing::{self, MockDiscord};
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_worker_for_channel_is_stable() {
+        let channel = ChannelId::new(1234567890);
+        let first = worker_for_channel(channel, 4);
+        assert_eq!(worker_for_channel(channel, 4), first);
+        assert!(first < 4);
+        assert_eq!(worker_for_channel(channel, 1), 0);
+    }
+
+    #[test]
+    fn test_queue_capacity_split_across_workers() {
+        let config: TranslationConfig = toml::from_str("workers = 3\nqueue_size = 10").unwrap();
+        let (pipeline, workers) = TranslationPipeline::new(&config);
+        assert_eq!(workers.receivers.len(), 3);
+        assert_eq!(pipeline.stats().capacity, 9);
+        assert_eq!(pipeline.stats().queued, 0);
+    }
+
+    /// Translates "… réunion numéro N …" to "Meeting number N is starting now"
+    fn numbered_translator() -> TranslatorService {
+        testing::translator(|text| {
+            let number: String = text.chars().filter(char::is_ascii_digit).collect();
+            format!("Meeting number {} is starting now", number)
+        })
+    }
+
+    fn french_message(id: u64, channel_id: u64) -> Message {
+        let mut message = Message::default();
+        message.id = MessageId::new(id);
+        message.guild_id = Some(GuildId::new(testing::GUILD_ID));
+        message.channel_id = ChannelId::new(channel_id);
+        message.content = format!("Bonjour à tous, la réunion numéro {} commence maintenant dans la grande salle", id);
+        message
+    }
+
+    #[tokio::test]
+    async fn test_channel_messages_complete_in_order_across_workers() {
+        let discord = MockDiscord::start().await;
+        let db = testing::database("pipeline-order").await;
+        let guild = testing::GUILD_ID.to_string();
+        for channel in ["10", "11"] {
+            db.set_channel_translation_mode(&guild, channel, "transparent", "admin").await.unwrap();
+        }
+        let data = testing::data(db, numbered_translator());
+
+        let config: TranslationConfig = toml::from_str("workers = 2\nqueue_size = 4").unwrap();
+        let (pipeline, workers) = TranslationPipeline::new(&config);
+        workers.spawn(data);
+
+        // Channels 10 and 11 land on different workers
+        for id in 1..=12 {
+            pipeline.submit(&discord.discord, &french_message(id, 10 + id % 2)).await;
+        }
+        testing::wait_for_jobs(&pipeline, 12).await;
+
+        let stats = pipeline.stats();
+        assert_eq!((stats.enqueued, stats.completed, stats.failed, stats.queued), (12, 12, 0, 0));
+        for (channel, ids) in [(10, [2, 4, 6, 8, 10, 12]), (11, [1, 3, 5, 7, 9, 11])] {
+            let posted: Vec<Value> = discord.posted(channel).into_iter().map(|body| body["content"].clone()).collect();
+            let expected: Vec<Value> = ids
+                .iter()
+                .map(|id| json!(format!("🌐 **Translation:** Meeting number {} is starting now", id)))
+                .collect();
+            assert_eq!(posted, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_waits_instead_of_dropping() {
+        let discord = MockDiscord::start().await;
+        let db = testing::database("pipeline-backpressure").await;
+        db.set_channel_translation_mode(&testing::GUILD_ID.to_string(), "10", "transparent", "admin").await.unwrap();
+        let data = testing::data(db, numbered_translator());
+
+        let config: TranslationConfig = toml::from_str("workers = 1\nqueue_size = 1").unwrap();
+        let (pipeline, workers) = TranslationPipeline::new(&config);
+
+        // No workers yet, so the second message has to wait for space
+        pipeline.submit(&discord.discord, &french_message(1, 10)).await;
+        let waiting = {
+            let (pipeline, discord) = (pipeline.clone(), discord.discord.clone());
+            tokio::spawn(async move { pipeline.submit(&discord, &french_message(2, 10)).await })
+        };
+        while pipeline.stats().backpressure_waits == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!waiting.is_finished());
+        let stats = pipeline.stats();
+        assert_eq!((stats.enqueued, stats.queued, stats.max_depth), (2, 2, 2));
+
+        workers.spawn(data);
+        waiting.await.unwrap();
+        testing::wait_for_jobs(&pipeline, 2).await;
+
+        let stats = pipeline.stats();
+        assert_eq!((stats.completed, stats.failed, stats.backpressure_waits), (2, 0, 1));
+        assert_eq!(discord.posted(10).len(), 2);
+    }
+}
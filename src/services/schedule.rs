@@ -0,0 +1,906 @@
+//! Working-hours arithmetic
+//!
+//! Schedules are stored as `HH:MM` strings per weekday (0 = Monday) plus
+//! one-off overrides per date. These helpers turn them into instants in the
+//! owner's timezone.
+
+use crate::config::StatusConfig;
+use crate::db::Database;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// What to do with an available or busy status that may have been forgotten
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleAction {
+    Keep,
+    /// Ask the user whether they're still there
+    Remind,
+    /// Clear the status
+    Expire,
+}
+
+/// Decide whether a status set at `updated_at` should be kept, reminded about or cleared
+pub fn stale_action(
+    updated_at: i64,
+    reminded_at: Option<i64>,
+    now: i64,
+    config: &StatusConfig,
+    schedule_end: Option<i64>,
+) -> StaleAction {
+    let age = now - updated_at;
+    let hours = |h: u64| h as i64 * 3600;
+
+    if config.expire_after_hours > 0 && age >= hours(config.expire_after_hours) {
+        return StaleAction::Expire;
+    }
+    // Only statuses set before the day's hours ended; one set afterwards is deliberate
+    if let Some(end) = schedule_end.filter(|_| config.expire_at_schedule_end) {
+        if updated_at < end && now >= end {
+            return StaleAction::Expire;
+        }
+    }
+    if config.remind_after_hours > 0 && reminded_at.is_none() && age >= hours(config.remind_after_hours) {
+        return StaleAction::Remind;
+    }
+    StaleAction::Keep
+}
+
+/// Weekday index used by the schedule tables (0 = Monday)
+pub fn weekday_index(date: NaiveDate) -> u8 {
+    date.weekday().num_days_from_monday() as u8
+}
+
+/// Parse a stored `HH:MM` time
+pub fn parse_hhmm(time: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(time, "%H:%M").ok()
+}
+
+/// The instant a local date and `HH:MM` time falls on in a timezone
+///
+/// A time skipped by a DST change resolves to the first valid instant after it.
+pub fn local_instant(tz: Tz, date: NaiveDate, time: &str) -> Option<DateTime<Utc>> {
+    let naive = date.and_time(parse_hhmm(time)?);
+    let local = tz
+        .from_local_datetime(&naive)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(naive + chrono::Duration::hours(1))).earliest())?;
+    Some(local.with_timezone(&Utc))
+}
+
+/// A span of working time
+pub type Window = (DateTime<Utc>, DateTime<Utc>);
+
+/// The window for a range starting on a local date; an end at or before the
+/// start (e.g. 20:00-02:00) runs past midnight into the next day
+pub fn window(tz: Tz, date: NaiveDate, start: &str, end: &str) -> Option<Window> {
+    let overnight = parse_hhmm(end)? <= parse_hhmm(start)?;
+    let end_date = if overnight { date.succ_opt()? } else { date };
+    Some((local_instant(tz, date, start)?, local_instant(tz, end_date, end)?))
+}
+
+/// Working hours starting on one of the owner's local dates
+///
+/// `weekly` is `(day, start, end)` rows, several per day for split shifts,
+/// and `day_override` the override for that date, which replaces the weekly
+/// hours. An override with only an end ("until 23:30") starts at the day's
+/// first weekly start, or at midnight without one.
+pub fn day_windows(
+    weekly: &[(u8, String, String)],
+    day_override: Option<&(Option<String>, String)>,
+    tz: Tz,
+    date: NaiveDate,
+) -> Vec<Window> {
+    let day = weekday_index(date);
+    let mut weekly_day = weekly.iter().filter(|(d, _, _)| *d == day).peekable();
+    match day_override {
+        Some((start, end)) => {
+            let start = start
+                .as_deref()
+                .or(weekly_day.peek().map(|(_, start, _)| start.as_str()))
+                .unwrap_or("00:00");
+            window(tz, date, start, end).into_iter().collect()
+        }
+        None => weekly_day.filter_map(|(_, start, end)| window(tz, date, start, end)).collect(),
+    }
+}
+
+/// When working hours last ended, if `now` is outside them
+pub fn hours_ended(windows: &[Window], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if in_hours(windows, now) {
+        return None;
+    }
+    windows.iter().map(|(_, end)| *end).filter(|end| *end <= now).max()
+}
+
+/// Wall-clock time of an instant in the viewer's timezone, and the number of
+/// days it lies after `viewer_today`
+pub fn viewer_time(instant: DateTime<Utc>, viewer: Tz, viewer_today: NaiveDate) -> (NaiveTime, i64) {
+    let local = instant.with_timezone(&viewer);
+    (local.time(), (local.date_naive() - viewer_today).num_days())
+}
+
+/// Whether `now` falls inside any window
+pub fn in_hours(windows: &[Window], now: DateTime<Utc>) -> bool {
+    windows.iter().any(|(start, end)| *start <= now && now < *end)
+}
+
+/// Sort windows and join any that overlap or touch
+pub fn merge(windows: &[Window]) -> Vec<Window> {
+    let mut sorted = windows.to_vec();
+    sorted.sort();
+    let mut merged: Vec<Window> = Vec::new();
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Time everyone is working between `from` and `to`, longest first
+///
+/// Each entry of `people` is one person's windows; a person without any
+/// leaves no shared time.
+pub fn shared_windows(people: &[Vec<Window>], from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<Window> {
+    let mut shared = vec![(from, to)];
+    for windows in people {
+        let windows = merge(windows);
+        shared = shared
+            .iter()
+            .flat_map(|(a_start, a_end)| {
+                windows
+                    .iter()
+                    .map(move |(b_start, b_end)| (*a_start.max(b_start), *a_end.min(b_end)))
+                    .filter(|(start, end)| start < end)
+            })
+            .collect();
+    }
+    shared.sort_by_key(|(start, end)| (std::cmp::Reverse(*end - *start), *start));
+    shared
+}
+
+/// The most recent start or end of working hours at or before `now`
+pub fn last_boundary(windows: &[Window], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    windows
+        .iter()
+        .flat_map(|(start, end)| [*start, *end])
+        .filter(|boundary| *boundary <= now)
+        .max()
+}
+
+/// A user's current status as far as auto status cares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentStatus<'a> {
+    pub status: &'a str,
+    /// Set by auto status rather than by the user
+    pub auto: bool,
+    pub updated_at: i64,
+}
+
+/// The status auto status should switch a user to, if any
+///
+/// Inside working hours that's available, outside them away, and away all
+/// day on leave or a holiday (`day_off` is when that day started). A status
+/// the user set by hand wins until the next start or end of their hours.
+pub fn auto_status(
+    current: Option<CurrentStatus<'_>>,
+    windows: &[Window],
+    day_off: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Option<&'static str> {
+    let (wanted, boundary) = match day_off {
+        Some(start) => ("away", Some(start)),
+        None if windows.is_empty() => return None,
+        None if in_hours(windows, now) => ("available", last_boundary(windows, now)),
+        None => ("away", last_boundary(windows, now)),
+    };
+    match current {
+        Some(current) if current.auto => (current.status != wanted).then_some(wanted),
+        Some(current) => {
+            let boundary = boundary?;
+            (boundary.timestamp() > current.updated_at && current.status != wanted).then_some(wanted)
+        }
+        None => Some(wanted),
+    }
+}
+
+/// Parse a date such as "2026-12-20", "20 Dec", "Dec 20", "today" or "tomorrow"
+///
+/// A date without a year is the next one on or after `today`.
+pub fn parse_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    match input.as_str() {
+        "today" => return Some(today),
+        "tomorrow" => return today.succ_opt(),
+        _ => {}
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    let parts: Vec<&str> = input.split(|c: char| c.is_whitespace() || c == '-' || c == '/').filter(|p| !p.is_empty()).collect();
+    let (day, month) = match parts.as_slice() {
+        [a, b] => match (a.parse::<u32>(), b.parse::<u32>()) {
+            (Ok(day), Err(_)) => (day, month_number(b)?),
+            (Err(_), Ok(day)) => (day, month_number(a)?),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day);
+    match this_year {
+        Some(date) if date >= today => Some(date),
+        _ => NaiveDate::from_ymd_opt(today.year() + 1, month, day),
+    }
+}
+
+/// Whether a date as typed names its year, rather than being "20 Dec" or "today"
+fn has_year(input: &str) -> bool {
+    NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d").is_ok()
+}
+
+/// Month number from a name or its first three letters
+fn month_number(name: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    let prefix = name.get(..3)?;
+    MONTHS.iter().position(|m| *m == prefix).map(|i| i as u32 + 1)
+}
+
+/// Parse the first and last day of leave; the last defaults to the first
+///
+/// A first day without a year is taken as the last one on or before the last
+/// day, so "30 Dec" to "2 Jan" stays one range even once 30 Dec has passed.
+pub fn parse_date_range(from: &str, to: Option<&str>, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+    let mut start = parse_date(from, today).ok_or_else(|| format!("Invalid date: {}", from.trim()))?;
+    let end = match to {
+        Some(to) => parse_date(to, today).ok_or_else(|| format!("Invalid date: {}", to.trim()))?,
+        None => start,
+    };
+    if end < start && !has_year(from) {
+        start = [end.year(), end.year() - 1]
+            .into_iter()
+            .filter_map(|year| start.with_year(year))
+            .find(|date| *date <= end)
+            .unwrap_or(start);
+    }
+    if end < start {
+        return Err("The last day is before the first".to_string());
+    }
+    Ok((start, end))
+}
+
+// ==================== Loading ====================
+
+/// A user's working hours yesterday and today (their local dates) across all servers
+pub async fn working_windows(db: &Database, guild_ids: &[String], discord_id: &str, tz: Tz, now: DateTime<Utc>) -> Vec<Window> {
+    let today = now.with_timezone(&tz).date_naive();
+    windows_on(db, guild_ids, discord_id, tz, &[today - Duration::days(1), today]).await
+}
+
+/// A user's working hours starting on the given local dates across the given
+/// servers, leaving out days on leave and public holidays
+pub async fn windows_on(db: &Database, guild_ids: &[String], discord_id: &str, tz: Tz, dates: &[NaiveDate]) -> Vec<Window> {
+    let region = db.get_user_settings(discord_id).await.ok().and_then(|s| s.holiday_region);
+    let mut windows = Vec::new();
+    for guild_id in guild_ids {
+        let weekly = db.get_weekly_schedule(guild_id, discord_id).await.unwrap_or_default();
+        for date in dates {
+            if is_day_off(db, guild_id, discord_id, region.as_deref(), *date).await {
+                continue;
+            }
+            let key = date.format("%Y-%m-%d").to_string();
+            let day_override = db.get_schedule_override(guild_id, discord_id, &key).await.ok().flatten();
+            windows.extend(day_windows(&weekly, day_override.as_ref(), tz, *date));
+        }
+    }
+    windows
+}
+
+/// Whether a local date is a day off for the user in a server: leave, or a
+/// public holiday in their region
+pub async fn is_day_off(db: &Database, guild_id: &str, discord_id: &str, region: Option<&str>, date: NaiveDate) -> bool {
+    let key = date.format("%Y-%m-%d").to_string();
+    if db.is_on_leave(guild_id, discord_id, &key).await.unwrap_or(false) {
+        return true;
+    }
+    match region {
+        Some(region) => matches!(db.get_holiday(guild_id, region, &key).await, Ok(Some(_))),
+        None => false,
+    }
+}
+
+// ==================== Parsing ====================
+
+/// A parsed `/fabrica hours` schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsedSchedule {
+    Weekly { days: Vec<u8>, ranges: Vec<(String, String)> },
+    TodayRange { start: String, end: String },
+    TodayUntil { end: String },
+    /// Remove the listed ranges on some days, or every range when none are listed
+    RemoveWeekly { days: Vec<u8>, ranges: Vec<(String, String)> },
+    RemoveToday,
+}
+
+/// Parse a `/fabrica hours` schedule such as "M-F 10-14, 20-2" or "remove Sat"
+pub fn parse_schedule(input: &str) -> Result<ParsedSchedule, String> {
+    let input = input.trim().to_lowercase();
+
+    // "remove today" / "remove DAYS [HH:MM to HH:MM[, HH:MM to HH:MM...]]"
+    if let Some(rest) = input.strip_prefix("remove ") {
+        let rest = rest.trim();
+        if rest == "today" {
+            return Ok(ParsedSchedule::RemoveToday);
+        }
+        return match rest.find(|c: char| c.is_ascii_digit()) {
+            Some(time_start) => Ok(ParsedSchedule::RemoveWeekly {
+                days: parse_days(&rest[..time_start])?,
+                ranges: parse_ranges(&rest[time_start..])?,
+            }),
+            None => Ok(ParsedSchedule::RemoveWeekly { days: parse_days(rest)?, ranges: Vec::new() }),
+        };
+    }
+
+    // "until HH:MM" - shorthand for "today until HH:MM"
+    if input.starts_with("until ") {
+        let time = input.strip_prefix("until ").unwrap().trim();
+        let end = parse_time(time)?;
+        return Ok(ParsedSchedule::TodayUntil { end });
+    }
+
+    // "today until HH:MM"
+    if input.starts_with("today until ") {
+        let time = input.strip_prefix("today until ").unwrap().trim();
+        let end = parse_time(time)?;
+        return Ok(ParsedSchedule::TodayUntil { end });
+    }
+
+    // "today HH:MM to HH:MM"
+    if input.starts_with("today ") {
+        let rest = input.strip_prefix("today ").unwrap().trim();
+        if rest.contains(',') {
+            return Err("Only one range can be set for today".to_string());
+        }
+        let (start, end) = parse_time_range(rest)?;
+        return Ok(ParsedSchedule::TodayRange { start, end });
+    }
+
+    // Weekly schedule: "DAYS HH:MM to HH:MM[, HH:MM to HH:MM...]"
+    // Find where the time part starts (look for a digit)
+    let time_start = input.find(|c: char| c.is_ascii_digit())
+        .ok_or("Could not find time in schedule")?;
+
+    let days_part = input[..time_start].trim();
+    let time_part = input[time_start..].trim();
+
+    let days = parse_days(days_part)?;
+    let ranges = parse_ranges(time_part)?;
+
+    Ok(ParsedSchedule::Weekly { days, ranges })
+}
+
+/// Parse a comma-separated list of time ranges, rejecting overlaps and repeats
+fn parse_ranges(input: &str) -> Result<Vec<(String, String)>, String> {
+    let ranges = input
+        .split(',')
+        .map(|range| parse_time_range(range.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (i, a) in ranges.iter().enumerate() {
+        if let Some(b) = ranges[i + 1..].iter().find(|b| ranges_overlap(a, b)) {
+            return Err(format!("{}-{} overlaps {}-{}", a.0, a.1, b.0, b.1));
+        }
+    }
+    Ok(ranges)
+}
+
+/// Whether two `HH:MM` ranges share any time; an end at or before the start
+/// runs past midnight
+fn ranges_overlap(a: &(String, String), b: &(String, String)) -> bool {
+    let span = |(start, end): &(String, String)| {
+        let (start, end) = (minutes(start), minutes(end));
+        (start, if end <= start { end + 24 * 60 } else { end })
+    };
+    let (a, b) = (span(a), span(b));
+    // Compare on the same day and with either range shifted a day later
+    [0, 24 * 60, -24 * 60]
+        .iter()
+        .any(|shift| a.0 < b.1 + shift && b.0 + shift < a.1)
+}
+
+/// A `(day, start, end)` row of a weekly schedule
+pub type WeeklyRange = (u8, String, String);
+
+/// Find an overnight range in a weekly schedule that runs into the next
+/// day's hours, such as Mon 20:00-02:00 with Tue 01:00-05:00
+///
+/// Sunday's hours run into Monday.
+pub fn overnight_clash(week: &[WeeklyRange]) -> Option<(WeeklyRange, WeeklyRange)> {
+    for a in week.iter().filter(|(_, start, end)| end <= start) {
+        let next = (a.0 + 1) % 7;
+        let spill = minutes(&a.2);
+        if let Some(b) = week.iter().find(|b| b.0 == next && minutes(&b.1) < spill) {
+            return Some((a.clone(), b.clone()));
+        }
+    }
+    None
+}
+
+fn minutes(time: &str) -> i32 {
+    let (h, m) = time.split_once(':').unwrap_or((time, "0"));
+    h.parse::<i32>().unwrap_or(0) * 60 + m.parse::<i32>().unwrap_or(0)
+}
+
+/// Parse days such as "M-F", "Fri-Mon" or "mon,wed" into weekday numbers
+pub fn parse_days(input: &str) -> Result<Vec<u8>, String> {
+    let input = input.trim().to_lowercase();
+
+    // Check for range format like "m-f"
+    if input.contains('-') && !input.contains(',') {
+        let parts: Vec<&str> = input.split('-').collect();
+        if parts.len() == 2 {
+            let start_day = parse_single_day(parts[0].trim())?;
+            let end_day = parse_single_day(parts[1].trim())?;
+
+            if start_day <= end_day {
+                return Ok((start_day..=end_day).collect());
+            } else {
+                // Wrap around (e.g., Fri-Mon)
+                let mut days: Vec<u8> = (start_day..=6).collect();
+                days.extend(0..=end_day);
+                return Ok(days);
+            }
+        }
+    }
+
+    // Comma-separated list
+    let mut days = Vec::new();
+    for part in input.split(',') {
+        let day = parse_single_day(part.trim())?;
+        if !days.contains(&day) {
+            days.push(day);
+        }
+    }
+
+    days.sort();
+    Ok(days)
+}
+
+fn parse_single_day(input: &str) -> Result<u8, String> {
+    match input.to_lowercase().as_str() {
+        "m" | "mon" | "monday" => Ok(0),
+        "tu" | "tue" | "tues" | "tuesday" => Ok(1),
+        "w" | "wed" | "wednesday" => Ok(2),
+        "th" | "thu" | "thur" | "thurs" | "thursday" => Ok(3),
+        "f" | "fri" | "friday" => Ok(4),
+        "sa" | "sat" | "saturday" => Ok(5),
+        "su" | "sun" | "sunday" => Ok(6),
+        _ => Err(format!("Unknown day: {}", input)),
+    }
+}
+
+/// Parse "HH:MM to HH:MM" or "HH-HH"
+pub fn parse_time_range(input: &str) -> Result<(String, String), String> {
+    // Look for "to" or "-" as separator
+    let (start_str, end_str) = if input.contains(" to ") {
+        let parts: Vec<&str> = input.split(" to ").collect();
+        if parts.len() != 2 {
+            return Err("Expected format: HH:MM to HH:MM".to_string());
+        }
+        (parts[0].trim(), parts[1].trim())
+    } else if input.contains('-') && input.matches('-').count() == 1 {
+        // Single dash, might be time separator
+        let parts: Vec<&str> = input.split('-').collect();
+        if parts.len() != 2 {
+            return Err("Expected format: HH:MM-HH:MM".to_string());
+        }
+        (parts[0].trim(), parts[1].trim())
+    } else {
+        return Err("Expected format: HH:MM to HH:MM".to_string());
+    };
+
+    let start = parse_time(start_str)?;
+    let end = parse_time(end_str)?;
+
+    Ok((start, end))
+}
+
+/// Parse a 24-hour or am/pm time into `HH:MM`
+pub fn parse_time(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    let lower = input.to_lowercase();
+
+    // Handle 12-hour format manually (5pm, 5:30pm, 5 pm, 5:30 pm, etc.)
+    let (time_part, is_pm) = if lower.ends_with("pm") {
+        (lower.trim_end_matches("pm").trim(), true)
+    } else if lower.ends_with("am") {
+        (lower.trim_end_matches("am").trim(), false)
+    } else {
+        // No AM/PM suffix - try 24-hour parsing
+        if input.contains(':') {
+            let parts: Vec<&str> = input.split(':').collect();
+            if parts.len() == 2 {
+                if let (Ok(h), Ok(m)) = (parts[0].parse::<u8>(), parts[1].parse::<u8>()) {
+                    if h < 24 && m < 60 {
+                        return Ok(format!("{:02}:{:02}", h, m));
+                    }
+                }
+            }
+        }
+        // Try just a number as hour (e.g., "17" -> "17:00")
+        if let Ok(h) = input.parse::<u8>() {
+            if h < 24 {
+                return Ok(format!("{:02}:00", h));
+            }
+        }
+        return Err(format!("Invalid time format: {}", input));
+    };
+
+    // Parse the time part (could be "5", "5:30", etc.)
+    let (hour, minute) = if time_part.contains(':') {
+        let parts: Vec<&str> = time_part.split(':').collect();
+        if parts.len() != 2 {
+            return Err(format!("Invalid time format: {}", input));
+        }
+        let h: u8 = parts[0].parse().map_err(|_| format!("Invalid hour: {}", parts[0]))?;
+        let m: u8 = parts[1].parse().map_err(|_| format!("Invalid minute: {}", parts[1]))?;
+        (h, m)
+    } else {
+        let h: u8 = time_part.parse().map_err(|_| format!("Invalid hour: {}", time_part))?;
+        (h, 0)
+    };
+
+    // Validate and convert to 24-hour
+    if hour > 12 || minute >= 60 {
+        return Err(format!("Invalid time: {}", input));
+    }
+
+    let hour_24 = if is_pm {
+        if hour == 12 { 12 } else { hour + 12 }
+    } else {
+        if hour == 12 { 0 } else { hour }
+    };
+
+    Ok(format!("{:02}:{:02}", hour_24, minute))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> StatusConfig {
+        StatusConfig {
+            expire_after_hours: 8,
+            remind_after_hours: 7,
+            expire_at_schedule_end: true,
+            check_interval_secs: 300,
+            import_interval_secs: 3600,
+            import_allowed_hosts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_stale_action_by_age() {
+        let hour = 3600;
+        assert_eq!(stale_action(0, None, 6 * hour, &config(), None), StaleAction::Keep);
+        assert_eq!(stale_action(0, None, 7 * hour, &config(), None), StaleAction::Remind);
+        assert_eq!(stale_action(0, Some(7 * hour), 7 * hour + 60, &config(), None), StaleAction::Keep);
+        assert_eq!(stale_action(0, Some(7 * hour), 8 * hour, &config(), None), StaleAction::Expire);
+
+        let never = StatusConfig {
+            expire_after_hours: 0,
+            remind_after_hours: 0,
+            ..config()
+        };
+        assert_eq!(stale_action(0, None, 100 * hour, &never, None), StaleAction::Keep);
+    }
+
+    #[test]
+    fn test_stale_action_at_schedule_end() {
+        let end = 10_000;
+        assert_eq!(stale_action(5_000, None, end - 1, &config(), Some(end)), StaleAction::Keep);
+        assert_eq!(stale_action(5_000, None, end, &config(), Some(end)), StaleAction::Expire);
+        // Set after the day's hours ended: kept
+        assert_eq!(stale_action(end + 1, None, end + 60, &config(), Some(end)), StaleAction::Keep);
+
+        let no_schedule = StatusConfig {
+            expire_at_schedule_end: false,
+            ..config()
+        };
+        assert_eq!(stale_action(5_000, None, end, &no_schedule, Some(end)), StaleAction::Keep);
+    }
+
+    #[test]
+    fn test_day_windows_use_owner_timezone() {
+        let tz: Tz = "Asia/Kolkata".parse().unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2026, 3, 11).unwrap();
+        let weekly = vec![(1, "09:00".to_string(), "17:00".to_string()), (2, "10:00".to_string(), "18:30".to_string())];
+
+        let windows = day_windows(&weekly, None, tz, wednesday);
+        assert_eq!(windows, vec![(
+            Utc.with_ymd_and_hms(2026, 3, 11, 4, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 11, 13, 0, 0).unwrap()
+        )]);
+
+        let today_override = (Some("11:00".to_string()), "12:00".to_string());
+        let windows = day_windows(&weekly, Some(&today_override), tz, wednesday);
+        assert_eq!(windows, vec![(
+            Utc.with_ymd_and_hms(2026, 3, 11, 5, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 11, 6, 30, 0).unwrap()
+        )]);
+
+        assert!(day_windows(&[], None, tz, wednesday).is_empty());
+    }
+
+    #[test]
+    fn test_split_and_overnight_shifts() {
+        let tz: Tz = "UTC".parse().unwrap();
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+        let weekly = vec![(0, "10:00".to_string(), "14:00".to_string()), (0, "20:00".to_string(), "02:00".to_string())];
+        let windows = day_windows(&weekly, None, tz, monday);
+        let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+        assert_eq!(windows, vec![(at(9, 10), at(9, 14)), (at(9, 20), at(10, 2))]);
+
+        // Monday night's shift is still running early on Tuesday
+        assert!(in_hours(&windows, at(10, 1)));
+        assert!(!in_hours(&windows, at(9, 15)));
+        assert_eq!(hours_ended(&windows, at(9, 15)), Some(at(9, 14)));
+        assert_eq!(hours_ended(&windows, at(9, 21)), None);
+        assert_eq!(hours_ended(&windows, at(10, 3)), Some(at(10, 2)));
+    }
+
+    #[test]
+    fn test_viewer_time_across_zones_and_dst() {
+        let india: Tz = "Asia/Kolkata".parse().unwrap();
+        let london: Tz = "Europe/London".parse().unwrap();
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        // The Friday before the UK clocks change: GMT, 5h30 behind India
+        let friday = NaiveDate::from_ymd_opt(2026, 3, 27).unwrap();
+        let end = local_instant(india, friday, "23:30").unwrap();
+        assert_eq!(viewer_time(end, london, friday), (time(18, 0), 0));
+        assert_eq!(viewer_time(end, tokyo, friday), (time(3, 0), 1));
+
+        // The Monday after: BST, 4h30 behind India
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 30).unwrap();
+        let end = local_instant(india, monday, "23:30").unwrap();
+        assert_eq!(viewer_time(end, london, monday), (time(19, 0), 0));
+
+        // Late in London is the next morning in India
+        let end = local_instant(london, monday, "22:00").unwrap();
+        assert_eq!(viewer_time(end, india, monday), (time(2, 30), 1));
+    }
+
+    #[test]
+    fn test_shared_windows_across_timezones() {
+        let at = |d, h, m| Utc.with_ymd_and_hms(2026, 3, d, h, m, 0).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+        let india: Tz = "Asia/Kolkata".parse().unwrap();
+        let london: Tz = "Europe/London".parse().unwrap();
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        let monday_hours = |ranges: &[(&str, &str)]| -> Vec<(u8, String, String)> {
+            ranges.iter().map(|(start, end)| (0, start.to_string(), end.to_string())).collect()
+        };
+
+        // 04:30-08:30 and 14:30-20:30 UTC
+        let asha = day_windows(&monday_hours(&[("10:00", "14:00"), ("20:00", "02:00")]), None, india, monday);
+        // 07:00-08:00 and 15:00-18:00 UTC
+        let ben = day_windows(&monday_hours(&[("07:00", "08:00"), ("15:00", "18:00")]), None, london, monday);
+        // 11:00-16:00 UTC, the day after the US clocks changed
+        let cara = day_windows(&monday_hours(&[("07:00", "12:00")]), None, new_york, monday);
+
+        // Longest first, even though it's later in the day
+        let (from, to) = (at(9, 0, 0), at(10, 0, 0));
+        assert_eq!(shared_windows(&[asha.clone(), ben.clone()], from, to), vec![(at(9, 15, 0), at(9, 18, 0)), (at(9, 7, 0), at(9, 8, 0))]);
+        assert_eq!(shared_windows(&[asha.clone(), ben.clone(), cara], from, to), vec![(at(9, 15, 0), at(9, 16, 0))]);
+
+        // Clipped to the period, and nothing shared with someone who isn't working
+        assert_eq!(shared_windows(&[asha.clone(), ben], at(9, 16, 30), to), vec![(at(9, 16, 30), at(9, 18, 0))]);
+        assert!(shared_windows(&[asha, Vec::new()], from, to).is_empty());
+    }
+
+    #[test]
+    fn test_merge_joins_touching_windows() {
+        let at = |h| Utc.with_ymd_and_hms(2026, 3, 9, h, 0, 0).unwrap();
+        assert_eq!(merge(&[(at(12), at(14)), (at(9), at(12)), (at(13), at(15)), (at(18), at(20))]), vec![(at(9), at(15)), (at(18), at(20))]);
+    }
+
+    #[test]
+    fn test_local_instant_in_dst_gap() {
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 3, 29).unwrap();
+        // 01:30 doesn't exist on the day clocks go forward; it becomes 02:30 BST (01:30 UTC)
+        assert_eq!(local_instant(tz, date, "01:30"), Some(Utc.with_ymd_and_hms(2026, 3, 29, 1, 30, 0).unwrap()));
+        assert_eq!(local_instant(tz, date, "12:00"), Some(Utc.with_ymd_and_hms(2026, 3, 29, 11, 0, 0).unwrap()));
+        assert_eq!(local_instant(tz, date, "25:00"), None);
+    }
+
+    fn windows() -> Vec<Window> {
+        let tz: Tz = "Asia/Kolkata".parse().unwrap();
+        let weekly = vec![(2, "09:00".to_string(), "17:00".to_string())];
+        let date = NaiveDate::from_ymd_opt(2026, 3, 11).unwrap();
+        day_windows(&weekly, None, tz, date)
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        // Times in India (UTC+5:30) on Wednesday 2026-03-11
+        Utc.with_ymd_and_hms(2026, 3, 11, hour, minute, 0).unwrap() - chrono::Duration::minutes(330)
+    }
+
+    #[test]
+    fn test_day_windows_with_until_override() {
+        let tz: Tz = "UTC".parse().unwrap();
+        let weekly = vec![(2, "09:00".to_string(), "17:00".to_string())];
+        let wednesday = NaiveDate::from_ymd_opt(2026, 3, 11).unwrap();
+        let until = (None, "20:00".to_string());
+        let (start, end) = day_windows(&weekly, Some(&until), tz, wednesday)[0];
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 3, 11, 9, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 3, 11, 20, 0, 0).unwrap());
+
+        let thursday = wednesday.succ_opt().unwrap();
+        assert!(day_windows(&weekly, None, tz, thursday).is_empty());
+        let (start, _) = day_windows(&weekly, Some(&until), tz, thursday)[0];
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 3, 12, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_auto_status_follows_hours() {
+        let windows = windows();
+        assert_eq!(auto_status(None, &windows, None, at(8, 0)), Some("away"));
+        assert_eq!(auto_status(None, &windows, None, at(9, 0)), Some("available"));
+        let auto = |status| CurrentStatus { status, auto: true, updated_at: 0 };
+        assert_eq!(auto_status(Some(auto("available")), &windows, None, at(12, 0)), None);
+        assert_eq!(auto_status(Some(auto("available")), &windows, None, at(17, 0)), Some("away"));
+        assert_eq!(auto_status(None, &[], None, at(12, 0)), None);
+    }
+
+    #[test]
+    fn test_manual_status_wins_until_next_boundary() {
+        let windows = windows();
+        let busy = CurrentStatus {
+            status: "busy",
+            auto: false,
+            updated_at: at(10, 0).timestamp(),
+        };
+        assert_eq!(auto_status(Some(busy), &windows, None, at(16, 59)), None);
+        assert_eq!(auto_status(Some(busy), &windows, None, at(17, 0)), Some("away"));
+
+        // Manual away in the evening lasts until hours start
+        let away = CurrentStatus {
+            status: "away",
+            auto: false,
+            updated_at: at(18, 0).timestamp(),
+        };
+        assert_eq!(auto_status(Some(away), &windows, None, at(20, 0)), None);
+    }
+
+    #[test]
+    fn test_auto_status_away_on_day_off() {
+        let windows = windows();
+        let midnight = at(0, 0);
+        assert_eq!(auto_status(None, &[], Some(midnight), at(12, 0)), Some("away"));
+        let auto = CurrentStatus { status: "available", auto: true, updated_at: 0 };
+        assert_eq!(auto_status(Some(auto), &windows, Some(midnight), at(12, 0)), Some("away"));
+
+        // Set by hand during the day off: kept
+        let busy = CurrentStatus {
+            status: "busy",
+            auto: false,
+            updated_at: at(10, 0).timestamp(),
+        };
+        assert_eq!(auto_status(Some(busy), &[], Some(midnight), at(12, 0)), None);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("9:30").unwrap(), "09:30");
+        assert_eq!(parse_time("17").unwrap(), "17:00");
+        assert_eq!(parse_time("5pm").unwrap(), "17:00");
+        assert_eq!(parse_time("12:15 am").unwrap(), "00:15");
+        assert!(parse_time("25:00").is_err());
+        assert!(parse_time("13pm").is_err());
+    }
+
+    #[test]
+    fn test_parse_time_range() {
+        let range = |s: &str, e: &str| (s.to_string(), e.to_string());
+        assert_eq!(parse_time_range("9:30 to 17:30").unwrap(), range("09:30", "17:30"));
+        assert_eq!(parse_time_range("10-14").unwrap(), range("10:00", "14:00"));
+        assert_eq!(parse_time_range("20-2").unwrap(), range("20:00", "02:00"));
+        assert!(parse_time_range("10").is_err());
+        assert!(parse_time_range("10-12-14").is_err());
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        let range = |s: &str, e: &str| (s.to_string(), e.to_string());
+        assert_eq!(
+            parse_schedule("M-F 10-14, 20-2").unwrap(),
+            ParsedSchedule::Weekly { days: vec![0, 1, 2, 3, 4], ranges: vec![range("10:00", "14:00"), range("20:00", "02:00")] }
+        );
+        assert_eq!(
+            parse_schedule("today 9 to 17").unwrap(),
+            ParsedSchedule::TodayRange { start: "09:00".into(), end: "17:00".into() }
+        );
+        assert_eq!(parse_schedule("until 5pm").unwrap(), ParsedSchedule::TodayUntil { end: "17:00".into() });
+        assert_eq!(parse_schedule("remove today").unwrap(), ParsedSchedule::RemoveToday);
+        assert_eq!(parse_schedule("remove Sat").unwrap(), ParsedSchedule::RemoveWeekly { days: vec![5], ranges: vec![] });
+        // The remove path splits range lists like the add path
+        assert_eq!(
+            parse_schedule("remove M-F 10-14, 20-2").unwrap(),
+            ParsedSchedule::RemoveWeekly { days: vec![0, 1, 2, 3, 4], ranges: vec![range("10:00", "14:00"), range("20:00", "02:00")] }
+        );
+        assert!(parse_schedule("today 9-12, 13-17").is_err());
+        assert!(parse_schedule("M-F").is_err());
+    }
+
+    #[test]
+    fn test_parse_schedule_rejects_overlaps() {
+        assert!(parse_schedule("M-F 9-17, 9-17").is_err());
+        assert!(parse_schedule("M-F 9-13, 12-17").is_err());
+        // Overnight ranges overlap the early hours they run into
+        assert!(parse_schedule("M-F 20-2, 1-5").is_err());
+        assert!(parse_schedule("M-F 22-6, 23-1").is_err());
+        // Touching ranges are fine
+        assert!(parse_schedule("M-F 9-13, 13-17").is_ok());
+        assert!(parse_schedule("M-F 10-14, 20-2").is_ok());
+    }
+
+    #[test]
+    fn test_overnight_clash_with_next_day() {
+        let row = |day, start: &str, end: &str| (day, start.to_string(), end.to_string());
+        let clash = overnight_clash(&[row(0, "20:00", "02:00"), row(1, "01:00", "05:00")]);
+        assert_eq!(clash, Some((row(0, "20:00", "02:00"), row(1, "01:00", "05:00"))));
+        // Sunday runs into Monday
+        assert!(overnight_clash(&[row(0, "01:00", "05:00"), row(6, "22:00", "03:00")]).is_some());
+        // Starting as the overnight range ends is fine, as is a free next day
+        assert_eq!(overnight_clash(&[row(0, "20:00", "02:00"), row(1, "02:00", "05:00")]), None);
+        assert_eq!(overnight_clash(&[row(0, "20:00", "02:00"), row(2, "01:00", "05:00")]), None);
+        assert_eq!(overnight_clash(&[row(0, "09:00", "17:00"), row(1, "01:00", "05:00")]), None);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 12, 1).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert_eq!(parse_date("today", today), Some(today));
+        assert_eq!(parse_date("Tomorrow", today), date(2026, 12, 2));
+        assert_eq!(parse_date("2027-03-04", today), date(2027, 3, 4));
+        assert_eq!(parse_date("20 Dec", today), date(2026, 12, 20));
+        assert_eq!(parse_date("december 20", today), date(2026, 12, 20));
+        assert_eq!(parse_date("3-jan", today), date(2027, 1, 3));
+        assert_eq!(parse_date("31 Feb", today), None);
+        assert_eq!(parse_date("someday", today), None);
+    }
+
+    #[test]
+    fn test_parse_date_range() {
+        let today = NaiveDate::from_ymd_opt(2026, 12, 1).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(parse_date_range("20 Dec", Some("3 Jan"), today), Ok((date(2026, 12, 20), date(2027, 1, 3))));
+        assert_eq!(parse_date_range("tomorrow", None, today), Ok((date(2026, 12, 2), date(2026, 12, 2))));
+        assert!(parse_date_range("2026-12-20", Some("2026-12-10"), today).is_err());
+        assert!(parse_date_range("20 Dec", Some("never"), today).is_err());
+
+        // A range across new year entered once its first day has passed
+        let new_years_eve = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+        assert_eq!(parse_date_range("30 Dec", Some("2 Jan"), new_years_eve), Ok((date(2026, 12, 30), date(2027, 1, 2))));
+        assert_eq!(parse_date_range("2 Jan", Some("30 Dec"), new_years_eve), Ok((date(2027, 1, 2), date(2027, 12, 30))));
+    }
+
+    #[tokio::test]
+    async fn test_weekly_hours_survive_restart() {
+        let path = std::env::temp_dir().join(format!("fabrica-schedule-restart-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let db = crate::db::Database::new(path.to_str().unwrap()).await.unwrap();
+        db.migrate().await.unwrap();
+        db.set_weekly_schedule("7", "42", &[0, 2], &[("09:00".to_string(), "12:00".to_string()), ("13:00".to_string(), "17:00".to_string())]).await.unwrap();
+
+        // Running the migrations again must not rebuild or drop the weekly hours
+        db.migrate().await.unwrap();
+        assert_eq!(db.get_weekly_schedule("7", "42").await.unwrap().len(), 4);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,264 @@
+//! Async standup timing and summaries
+//!
+//! A guild's standup runs on chosen weekdays with a summary posted at a fixed
+//! time in the guild's standup timezone. Participants are asked when their own
+//! working hours start; their answers go into the next summary due after
+//! they were asked.
+
+use crate::services::i18n::{tr, tr_args};
+use crate::services::schedule;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+/// Most questions a standup asks (a modal holds at most five inputs)
+pub const MAX_QUESTIONS: usize = 5;
+
+/// Longest question (Discord's limit on a modal input label)
+pub const MAX_QUESTION_CHARS: usize = 45;
+
+/// How long after being asked an unanswered participant is nudged
+const NUDGE_AFTER: Duration = Duration::hours(2);
+
+/// Nudges go out at least this long before the summary...
+const NUDGE_BEFORE_SUMMARY: Duration = Duration::hours(1);
+
+/// ...but never sooner than this after being asked
+const MIN_NUDGE_AFTER: Duration = Duration::minutes(30);
+
+/// When the summary for a standup date is posted
+pub fn summary_at(tz: Tz, summary_time: &str, date: NaiveDate) -> Option<DateTime<Utc>> {
+    schedule::local_instant(tz, date, summary_time)
+}
+
+/// The standup date whose summary is next due after `at`
+pub fn next_run(days: &[u8], tz: Tz, summary_time: &str, at: DateTime<Utc>) -> Option<NaiveDate> {
+    let today = at.with_timezone(&tz).date_naive();
+    (0..8)
+        .map(|offset| today + Duration::days(offset))
+        .filter(|date| days.contains(&schedule::weekday_index(*date)))
+        .find(|date| summary_at(tz, summary_time, *date).is_some_and(|summary| summary > at))
+}
+
+/// The standup date whose summary fell due in the last day, if any
+pub fn due_run(days: &[u8], tz: Tz, summary_time: &str, now: DateTime<Utc>) -> Option<NaiveDate> {
+    let today = now.with_timezone(&tz).date_naive();
+    [today, today - Duration::days(1)]
+        .into_iter()
+        .filter(|date| days.contains(&schedule::weekday_index(*date)))
+        .find(|date| summary_at(tz, summary_time, *date).is_some_and(|summary| summary <= now && now - summary < Duration::days(1)))
+}
+
+/// Whether an unanswered participant asked at `prompted` should be nudged
+/// now, for a summary due at `summary`
+pub fn nudge_due(prompted: DateTime<Utc>, summary: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    let nudge_at = (prompted + NUDGE_AFTER).min(summary - NUDGE_BEFORE_SUMMARY).max(prompted + MIN_NUDGE_AFTER);
+    nudge_at <= now && now < summary
+}
+
+/// Why a set of standup questions was refused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuestionsError {
+    Empty,
+    TooMany,
+    /// The first question over `MAX_QUESTION_CHARS`
+    TooLong(String),
+}
+
+impl QuestionsError {
+    /// The problem in the reader's language
+    pub fn message(&self, locale: &str) -> String {
+        match self {
+            Self::Empty => tr(locale, "standup-questions-empty"),
+            Self::TooMany => tr_args(locale, "standup-questions-too-many", &[("max", &MAX_QUESTIONS.to_string())]),
+            Self::TooLong(question) => tr_args(
+                locale,
+                "standup-questions-too-long",
+                &[("question", question), ("max", &MAX_QUESTION_CHARS.to_string())],
+            ),
+        }
+    }
+}
+
+/// Split questions written as `first | second | third`
+pub fn parse_questions(input: &str) -> Result<Vec<String>, QuestionsError> {
+    let questions: Vec<String> = input
+        .split(['|', '\n'])
+        .map(|q| q.trim().to_string())
+        .filter(|q| !q.is_empty())
+        .collect();
+    if questions.is_empty() {
+        return Err(QuestionsError::Empty);
+    }
+    if questions.len() > MAX_QUESTIONS {
+        return Err(QuestionsError::TooMany);
+    }
+    if let Some(long) = questions.iter().find(|q| q.chars().count() > MAX_QUESTION_CHARS) {
+        return Err(QuestionsError::TooLong(long.clone()));
+    }
+    Ok(questions)
+}
+
+/// One participant's part of a summary
+pub struct Answered<'a> {
+    pub discord_id: &'a str,
+    pub answers: &'a [String],
+}
+
+/// A standup summary: everyone's answers under the questions, then who
+/// didn't answer and who was off
+pub fn compile(locale: &str, date: &str, questions: &[String], answered: &[Answered], missing: &[String], off: &[String]) -> String {
+    let mut summary = tr_args(locale, "standup-summary-title", &[("date", date)]);
+    summary.push('\n');
+    if answered.is_empty() {
+        summary.push_str(&tr(locale, "standup-summary-none"));
+        summary.push('\n');
+    }
+    for person in answered {
+        summary.push_str(&format!("\n**<@{}>**\n", person.discord_id));
+        for (question, answer) in questions.iter().zip(person.answers) {
+            if answer.trim().is_empty() {
+                continue;
+            }
+            summary.push_str(&format!("__{}__\n", question));
+            for line in answer.trim().lines() {
+                summary.push_str(&format!("> {}\n", line));
+            }
+        }
+    }
+    let mentions = |ids: &[String]| ids.iter().map(|id| format!("<@{}>", id)).collect::<Vec<_>>().join(", ");
+    if !missing.is_empty() {
+        summary.push('\n');
+        summary.push_str(&tr_args(locale, "standup-summary-missing", &[("users", &mentions(missing))]));
+    }
+    if !off.is_empty() {
+        summary.push('\n');
+        summary.push_str(&tr_args(locale, "standup-summary-off", &[("users", &mentions(off))]));
+    }
+    summary.trim_end().to_string()
+}
+
+/// Split text into messages of at most `max_chars`, between lines where possible
+pub fn split_message(text: &str, max_chars: usize) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut current = String::new();
+    for line in text.lines() {
+        let mut line: String = line.to_string();
+        while line.chars().count() > max_chars {
+            let head: String = line.chars().take(max_chars).collect();
+            line = line.chars().skip(max_chars).collect();
+            if !current.is_empty() {
+                messages.push(std::mem::take(&mut current));
+            }
+            messages.push(head);
+        }
+        if current.chars().count() + line.chars().count() + 1 > max_chars && !current.is_empty() {
+            messages.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&line);
+    }
+    if !current.trim().is_empty() {
+        messages.push(current);
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn paris() -> Tz {
+        "Europe/Paris".parse().unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    const WEEKDAYS: [u8; 5] = [0, 1, 2, 3, 4];
+
+    #[test]
+    fn test_next_run() {
+        // Wednesday 2026-03-11, 08:00 in Paris: today's summary at 17:00 is next
+        let morning = Utc.with_ymd_and_hms(2026, 3, 11, 7, 0, 0).unwrap();
+        assert_eq!(next_run(&WEEKDAYS, paris(), "17:00", morning), Some(date("2026-03-11")));
+        // After 17:00 Paris, tomorrow's
+        let evening = Utc.with_ymd_and_hms(2026, 3, 11, 16, 30, 0).unwrap();
+        assert_eq!(next_run(&WEEKDAYS, paris(), "17:00", evening), Some(date("2026-03-12")));
+        // Friday evening rolls over the weekend
+        let friday = Utc.with_ymd_and_hms(2026, 3, 13, 17, 0, 0).unwrap();
+        assert_eq!(next_run(&WEEKDAYS, paris(), "17:00", friday), Some(date("2026-03-16")));
+        assert_eq!(next_run(&[], paris(), "17:00", friday), None);
+    }
+
+    #[test]
+    fn test_due_run() {
+        let before = Utc.with_ymd_and_hms(2026, 3, 11, 15, 59, 0).unwrap();
+        // Tuesday's summary is still due within a day of 17:00 Tuesday
+        assert_eq!(due_run(&WEEKDAYS, paris(), "17:00", before), Some(date("2026-03-10")));
+        let after = Utc.with_ymd_and_hms(2026, 3, 11, 16, 0, 0).unwrap();
+        assert_eq!(due_run(&WEEKDAYS, paris(), "17:00", after), Some(date("2026-03-11")));
+        // Nothing ran on Sunday, and Friday's is over a day old by Sunday evening
+        let sunday = Utc.with_ymd_and_hms(2026, 3, 15, 18, 0, 0).unwrap();
+        assert_eq!(due_run(&WEEKDAYS, paris(), "17:00", sunday), None);
+    }
+
+    #[test]
+    fn test_nudge_due() {
+        let prompted = Utc.with_ymd_and_hms(2026, 3, 11, 8, 0, 0).unwrap();
+        let summary = Utc.with_ymd_and_hms(2026, 3, 11, 16, 0, 0).unwrap();
+        assert!(!nudge_due(prompted, summary, prompted + Duration::minutes(119)));
+        assert!(nudge_due(prompted, summary, prompted + Duration::hours(2)));
+        assert!(!nudge_due(prompted, summary, summary));
+
+        // Asked late: nudged an hour before the summary
+        let late = Utc.with_ymd_and_hms(2026, 3, 11, 13, 0, 0).unwrap();
+        assert!(nudge_due(late, summary, Utc.with_ymd_and_hms(2026, 3, 11, 15, 0, 0).unwrap()));
+        // Asked just before: given half an hour first
+        let last_minute = Utc.with_ymd_and_hms(2026, 3, 11, 15, 20, 0).unwrap();
+        assert!(!nudge_due(last_minute, summary, last_minute + Duration::minutes(20)));
+        assert!(nudge_due(last_minute, summary, last_minute + Duration::minutes(30)));
+    }
+
+    #[test]
+    fn test_parse_questions() {
+        assert_eq!(
+            parse_questions("Yesterday? | Today? |  | Blockers?").unwrap(),
+            vec!["Yesterday?".to_string(), "Today?".to_string(), "Blockers?".to_string()]
+        );
+        assert_eq!(parse_questions(" | "), Err(QuestionsError::Empty));
+        assert_eq!(parse_questions("a|b|c|d|e|f"), Err(QuestionsError::TooMany));
+        assert_eq!(parse_questions(&"x".repeat(46)), Err(QuestionsError::TooLong("x".repeat(46))));
+        assert_eq!(QuestionsError::TooMany.message("en"), "At most 5 questions");
+    }
+
+    #[test]
+    fn test_compile() {
+        let questions = vec!["Done?".to_string(), "Blocked?".to_string()];
+        let answers = vec!["Shipped the importer\nFixed CI".to_string(), String::new()];
+        let summary = compile(
+            "en",
+            "Wed 11 Mar",
+            &questions,
+            &[Answered { discord_id: "1", answers: &answers }],
+            &["2".to_string()],
+            &["3".to_string()],
+        );
+        assert!(summary.contains("**<@1>**\n__Done?__\n> Shipped the importer\n> Fixed CI\n"));
+        // Blank answers are left out
+        assert!(!summary.contains("Blocked?"));
+        assert!(summary.contains("<@2>"));
+        assert!(summary.ends_with("<@3>"));
+    }
+
+    #[test]
+    fn test_split_message() {
+        let text = "aaaa\nbbbb\ncccc";
+        assert_eq!(split_message(text, 9), vec!["aaaa\nbbbb".to_string(), "cccc".to_string()]);
+        assert_eq!(split_message(text, 100), vec![text.to_string()]);
+        assert_eq!(split_message("abcdefgh", 3), vec!["abc".to_string(), "def".to_string(), "gh".to_string()]);
+    }
+}
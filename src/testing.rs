@@ -0,0 +1,292 @@
+//! Test helpers - Bot data and a stand-in for the Discord API
+//!
+//! [`MockDiscord`] answers the REST routes the message handlers use from a
+//! local server and records every request, so handlers run against a real
+//! serenity HTTP client and a seeded cache without a bot token.
+
+use crate::bot::{Data, Discord};
+use crate::config::{Config, TranslationConfig};
+use crate::db::Database;
+use crate::modules::board;
+use crate::services::pipeline::TranslationPipeline;
+use crate::services::prompts::ChatMessage;
+use crate::services::translator::{ScriptedReplies, TranslatorService};
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{Method, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    Json, Router,
+};
+use poise::serenity_prelude::{
+    self as serenity, ChannelId, ChannelType, GuildChannel, GuildCreateEvent, GuildId, Member, Message,
+    PermissionOverwrite, Permissions, PrivateChannel, Role, RoleId, User, UserId,
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Guild the test bot data is configured for
+pub(crate) const GUILD_ID: u64 = 1;
+
+/// A fresh, migrated database in the temp dir
+pub(crate) async fn database(name: &str) -> Database {
+    let path = std::env::temp_dir().join(format!("fabrica-{}-{}.db", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    db.migrate().await.unwrap();
+    db
+}
+
+/// Bot data for [`GUILD_ID`] with the given database and translator
+pub(crate) fn data(db: Database, translator: TranslatorService) -> Data {
+    let config: Config = toml::from_str(&format!(
+        "[discord]\ntoken = \"test\"\napplication_id = 1\nguild_ids = [\"{}\"]\n\
+         [database]\n[translation]\n[plane]\nurl = \"http://localhost\"\napi_key = \"\"\n[github]\n[webhooks]",
+        GUILD_ID
+    ))
+    .unwrap();
+    let (pipeline, _) = TranslationPipeline::new(&config.translation);
+    Data {
+        config,
+        db,
+        translator: Arc::new(translator),
+        pipeline,
+        boards: board::BoardRefresh::default(),
+    }
+}
+
+/// A translator answering every request with `reply` applied to the text
+/// between the prompt's `<source_text>` tags
+pub(crate) fn translator(reply: impl Fn(&str) -> String + Send + Sync + 'static) -> TranslatorService {
+    let config: TranslationConfig = toml::from_str("").unwrap();
+    let replies: ScriptedReplies = Arc::new(move |messages: &[ChatMessage]| {
+        let content = messages.last().map(|m| m.content.as_str()).unwrap_or_default();
+        let text = content
+            .trim()
+            .trim_start_matches("<source_text>")
+            .trim_end_matches("</source_text>")
+            .trim();
+        Ok(reply(text))
+    });
+    TranslatorService::with_scripted_backend(&config, replies)
+}
+
+/// Wait until a pipeline has handled `count` jobs
+pub(crate) async fn wait_for_jobs(pipeline: &TranslationPipeline, count: u64) {
+    for _ in 0..500 {
+        let stats = pipeline.stats();
+        if stats.completed + stats.failed >= count {
+            return;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    panic!("Pipeline didn't finish: {:?}", pipeline.stats());
+}
+
+/// A request received by [`MockDiscord`]
+#[derive(Debug, Clone)]
+pub(crate) struct Request {
+    pub method: Method,
+    /// Path below `/api/v10`, without the query string
+    pub path: String,
+    pub body: Value,
+}
+
+/// What the mock serves, and what it has been sent
+#[derive(Default)]
+struct Routes {
+    channels: HashMap<u64, Value>,
+    messages: HashMap<(u64, u64), Value>,
+    members: HashMap<(u64, u64), Value>,
+    requests: Vec<Request>,
+    next_id: u64,
+}
+
+/// Local Discord REST API with a matching [`Discord`] handle
+///
+/// DM channels get the recipient's user ID, so DMs show up in
+/// [`MockDiscord::posted`] under the user they were sent to.
+pub(crate) struct MockDiscord {
+    pub discord: Discord,
+    routes: Arc<Mutex<Routes>>,
+    server: JoinHandle<()>,
+}
+
+impl MockDiscord {
+    pub async fn start() -> Self {
+        let routes = Arc::new(Mutex::new(Routes {
+            next_id: 900_000,
+            ..Default::default()
+        }));
+        let app = Router::new().fallback(respond).with_state(routes.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let http = serenity::HttpBuilder::new("Bot test").proxy(url).ratelimiter_disabled(true).build();
+        let discord = Discord {
+            http: Arc::new(http),
+            cache: Arc::new(serenity::Cache::new()),
+        };
+        Self { discord, routes, server }
+    }
+
+    /// Put a guild in the cache and serve its channels and members
+    pub fn add_guild(&self, guild: &serenity::Guild) {
+        for channel in guild.channels.values() {
+            self.add_channel(channel);
+        }
+        for member in guild.members.values() {
+            self.add_member(member);
+        }
+        let mut event: GuildCreateEvent = serde_json::from_value(serde_json::to_value(guild).unwrap()).unwrap();
+        self.discord.cache.update(&mut event);
+    }
+
+    /// Serve a channel without caching it
+    pub fn add_channel(&self, channel: &GuildChannel) {
+        let value = serde_json::to_value(channel).unwrap();
+        self.routes.lock().unwrap().channels.insert(channel.id.get(), value);
+    }
+
+    /// Serve a member without caching it
+    pub fn add_member(&self, member: &Member) {
+        let value = serde_json::to_value(member).unwrap();
+        self.routes.lock().unwrap().members.insert((member.guild_id.get(), member.user.id.get()), value);
+    }
+
+    /// Serve a channel message
+    pub fn add_message(&self, message: &Message) {
+        let value = serde_json::to_value(message).unwrap();
+        self.routes.lock().unwrap().messages.insert((message.channel_id.get(), message.id.get()), value);
+    }
+
+    /// Every request received so far
+    pub fn requests(&self) -> Vec<Request> {
+        self.routes.lock().unwrap().requests.clone()
+    }
+
+    /// Bodies of the messages posted in a channel, or DMed to a user
+    pub fn posted(&self, channel_id: u64) -> Vec<Value> {
+        let path = format!("/channels/{}/messages", channel_id);
+        self.requests()
+            .into_iter()
+            .filter(|r| r.method == Method::POST && r.path == path)
+            .map(|r| r.body)
+            .collect()
+    }
+}
+
+impl Drop for MockDiscord {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn respond(State(routes): State<Arc<Mutex<Routes>>>, method: Method, uri: Uri, body: Bytes) -> Response {
+    let path = uri.path().trim_start_matches("/api/v10").to_string();
+    let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+    let mut routes = routes.lock().unwrap();
+    routes.requests.push(Request {
+        method: method.clone(),
+        path: path.clone(),
+        body: body.clone(),
+    });
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let id = |s: &str| s.parse::<u64>().unwrap_or_default();
+    let found = match (method.as_str(), segments.as_slice()) {
+        ("GET", ["channels", channel]) => routes.channels.get(&id(channel)).cloned(),
+        ("GET", ["channels", channel, "messages", message]) | ("PATCH", ["channels", channel, "messages", message]) => {
+            routes.messages.get(&(id(channel), id(message))).cloned()
+        }
+        ("GET", ["guilds", guild, "members", user]) => routes.members.get(&(id(guild), id(user))).cloned(),
+        ("POST", ["users", "@me", "channels"]) => {
+            let recipient = body["recipient_id"].as_str().map(id).unwrap_or_default();
+            Some(dm_channel(recipient))
+        }
+        ("POST", ["channels", channel, "messages"]) => {
+            routes.next_id += 1;
+            Some(message(routes.next_id, id(channel), &body))
+        }
+        ("POST", ["webhooks", webhook, _]) | ("PATCH", ["webhooks", webhook, _, "messages", _]) => {
+            routes.next_id += 1;
+            Some(message(routes.next_id, id(webhook), &body))
+        }
+        ("DELETE", _) | ("PUT", _) | ("POST", ["interactions", ..]) => return StatusCode::NO_CONTENT.into_response(),
+        _ => None,
+    };
+
+    match found {
+        Some(value) => Json(value).into_response(),
+        None => (StatusCode::NOT_FOUND, Json(json!({"code": 10003, "message": "Unknown"}))).into_response(),
+    }
+}
+
+fn dm_channel(recipient: u64) -> Value {
+    let mut channel = PrivateChannel::default();
+    channel.id = ChannelId::new(recipient);
+    channel.kind = ChannelType::Private;
+    channel.recipient = user(recipient, "reader");
+    serde_json::to_value(channel).unwrap()
+}
+
+fn message(id: u64, channel_id: u64, body: &Value) -> Value {
+    let mut message = serde_json::to_value(Message::default()).unwrap();
+    message["id"] = json!(id.to_string());
+    message["channel_id"] = json!(channel_id.to_string());
+    message["content"] = body["content"].clone();
+    message
+}
+
+/// A user with a name
+pub(crate) fn user(id: u64, name: &str) -> User {
+    let mut user = User::default();
+    user.id = UserId::new(id);
+    user.name = name.to_string();
+    user
+}
+
+/// A guild member with roles
+pub(crate) fn member(guild_id: u64, user_id: u64, roles: &[u64]) -> Member {
+    let mut member = Member::default();
+    member.guild_id = GuildId::new(guild_id);
+    member.user = user(user_id, &format!("user{}", user_id));
+    member.roles = roles.iter().map(|r| RoleId::new(*r)).collect();
+    member
+}
+
+/// A role; the @everyone role has the guild's ID
+pub(crate) fn role(guild_id: u64, role_id: u64, permissions: Permissions) -> Role {
+    let mut role = Role::default();
+    role.id = RoleId::new(role_id);
+    role.guild_id = GuildId::new(guild_id);
+    role.permissions = permissions;
+    role
+}
+
+/// A text channel with permission overwrites
+pub(crate) fn text_channel(guild_id: u64, channel_id: u64, overwrites: Vec<PermissionOverwrite>) -> GuildChannel {
+    let mut channel = GuildChannel::default();
+    channel.id = ChannelId::new(channel_id);
+    channel.guild_id = GuildId::new(guild_id);
+    channel.kind = ChannelType::Text;
+    channel.name = format!("channel-{}", channel_id);
+    channel.permission_overwrites = overwrites;
+    channel
+}
+
+/// A guild with roles, channels and members, owned by someone else
+pub(crate) fn guild(guild_id: u64, roles: Vec<Role>, channels: Vec<GuildChannel>, members: Vec<Member>) -> serenity::Guild {
+    let mut guild = serenity::Guild::default();
+    guild.id = GuildId::new(guild_id);
+    guild.owner_id = UserId::new(999_999);
+    guild.roles = roles.into_iter().map(|r| (r.id, r)).collect();
+    guild.channels = channels.into_iter().map(|c| (c.id, c)).collect();
+    guild.members = members.into_iter().map(|m| (m.user.id, m)).collect();
+    guild
+}
@@ -0,0 +1,344 @@
+//! Calendar feeds - Team hours, leave and holidays as `.ics`
+//!
+//! `GET /calendar/<token>.ics` serves the feed a link from
+//! `/fabrica settings calendar-link` was issued for: everyone in the guild,
+//! or just the link's owner. The token is the only credential, as calendar
+//! apps can't send headers, so a link stops working once its owner leaves
+//! the server, and a team link while its owner isn't an admin.
+
+use super::server::WebhookState;
+use crate::db::{CalendarLink, Database};
+use crate::services::calendar::{self, AllDay, Calendar, Person};
+use crate::services::i18n::{locale_for_user, tr, tr_args};
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{Duration, NaiveDate, Utc};
+use poise::serenity_prelude::{self as serenity, GuildId, Member, Role, RoleId, UserId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::{info, warn};
+
+/// How long a looked-up member is reused before asking Discord again
+const NAME_TTL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// Names kept before expired ones are dropped
+const MAX_NAMES: usize = 5000;
+
+/// A display name, `None` for someone no longer in the server, and when it was looked up
+type CachedName = (Option<String>, Instant);
+
+/// Display names by guild and user
+#[derive(Clone, Default)]
+pub struct NameCache(Arc<Mutex<HashMap<(GuildId, UserId), CachedName>>>);
+
+impl NameCache {
+    /// A name looked up less than [`NAME_TTL`] ago
+    fn get(&self, key: &(GuildId, UserId)) -> Option<Option<String>> {
+        let names = self.0.lock().unwrap();
+        names.get(key).filter(|(_, at)| at.elapsed() < NAME_TTL).map(|(name, _)| name.clone())
+    }
+
+    /// Remember a name, dropping expired ones once the cache is full, and
+    /// everything if it is still full after that
+    fn insert(&self, key: (GuildId, UserId), name: Option<String>) {
+        let mut names = self.0.lock().unwrap();
+        if names.len() >= MAX_NAMES {
+            names.retain(|_, (_, at)| at.elapsed() < NAME_TTL);
+            if names.len() >= MAX_NAMES {
+                names.clear();
+            }
+        }
+        names.insert(key, (name, Instant::now()));
+    }
+}
+
+/// Serve the feed for a calendar link
+pub async fn calendar_feed(State(state): State<Arc<WebhookState>>, Path(file): Path<String>) -> Response {
+    let token = file.strip_suffix(".ics").unwrap_or(&file);
+    let link = match state.db.get_calendar_link(&calendar::token_hash(token)).await {
+        Ok(Some(link)) => link,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            warn!("Calendar link lookup failed: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    // The link is only good while its owner is still in the server
+    match member_name(&state, &link.guild_id, &link.discord_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            warn!("Calendar link owner lookup failed: {}", e);
+            return StatusCode::SERVICE_UNAVAILABLE.into_response();
+        }
+    }
+
+    // Everyone's hours are only for admins, so check again on every fetch
+    if link.scope == "team" {
+        match owner_is_admin(&state, &link).await {
+            Ok(true) => {}
+            Ok(false) => {
+                info!("Refused team calendar of user {} in guild {}: no longer an admin", link.discord_id, link.guild_id);
+                return StatusCode::NOT_FOUND.into_response();
+            }
+            Err(e) => {
+                warn!("Calendar link admin check failed: {}", e);
+                return StatusCode::SERVICE_UNAVAILABLE.into_response();
+            }
+        }
+    }
+
+    match build(&state, &link).await {
+        Ok(feed) => {
+            info!("Served {} calendar of user {} in guild {}", link.scope, link.discord_id, link.guild_id);
+            ([(header::CONTENT_TYPE, "text/calendar; charset=utf-8")], feed).into_response()
+        }
+        Err(e) => {
+            warn!("Calendar feed failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Gather schedules, overrides, leave and holidays for a link and render them
+async fn build(state: &WebhookState, link: &CalendarLink) -> anyhow::Result<String> {
+    let db: &Database = &state.db;
+    let locale = locale_for_user(db, &link.discord_id).await;
+    let team = link.scope == "team";
+    let wanted = |id: &str| team || id == link.discord_id;
+
+    // Past week onwards, so recent changes still show
+    let from = (Utc::now().date_naive() - Duration::days(7)).format("%Y-%m-%d").to_string();
+    let weekly = db.get_guild_weekly_schedules(&link.guild_id).await?;
+    let overrides = db.get_guild_schedule_overrides(&link.guild_id, &from).await?;
+    let leave = db.get_leave_between(&link.guild_id, &from, "9999-12-31").await?;
+    let holidays = db.get_holidays_between(&link.guild_id, &from, "9999-12-31").await?;
+
+    let mut ids: Vec<String> = Vec::new();
+    let everyone = weekly
+        .iter()
+        .map(|(id, ..)| id)
+        .chain(overrides.iter().map(|(id, ..)| id))
+        .chain(leave.iter().map(|l| &l.discord_id));
+    for id in everyone {
+        if wanted(id) && !ids.contains(id) {
+            ids.push(id.clone());
+        }
+    }
+
+    let mut names = HashMap::new();
+    let mut people = Vec::new();
+    let mut all_day = Vec::new();
+    let mut regions = Vec::new();
+    for id in &ids {
+        let name = match member_name(state, &link.guild_id, id).await {
+            Ok(Some(name)) => name,
+            _ => id.clone(),
+        };
+        let settings = db.get_user_settings(id).await?;
+        let region = settings.holiday_region.clone();
+
+        let mut days_off: Vec<NaiveDate> = Vec::new();
+        for entry in leave.iter().filter(|l| l.discord_id == *id) {
+            let (start, end) = (parse(&entry.start_date), parse(&entry.end_date));
+            days_off.extend(start.iter_days().take_while(|d| *d <= end));
+            let reason = entry.reason.as_ref().filter(|_| !team || entry.reason_public());
+            let summary = match reason {
+                Some(reason) => tr_args(locale, "calendar-leave-reason", &[("name", &name), ("reason", reason)]),
+                None => tr_args(locale, "calendar-leave", &[("name", &name)]),
+            };
+            all_day.push(AllDay { uid: format!("leave-{}", entry.id), start, end, summary });
+        }
+        if let Some(region) = &region {
+            days_off.extend(holidays.iter().filter(|h| h.region == *region).map(|h| parse(&h.date)));
+            regions.push(region.clone());
+        }
+
+        people.push(Person {
+            discord_id: id.clone(),
+            tz: settings.tz(),
+            weekly: weekly.iter().filter(|(d, ..)| d == id).map(|(_, day, start, end)| (*day, start.clone(), end.clone())).collect(),
+            overrides: overrides
+                .iter()
+                .filter(|(d, ..)| d == id)
+                .map(|(_, date, start, end)| (parse(date), start.clone(), end.clone()))
+                .collect(),
+            days_off,
+            working_summary: tr_args(locale, "calendar-working", &[("name", &name)]),
+        });
+        names.insert(id.clone(), name);
+    }
+
+    // The team feed has every holiday; a personal one only the owner's region's
+    for holiday in holidays.iter().filter(|h| team || regions.contains(&h.region)) {
+        let date = parse(&holiday.date);
+        all_day.push(AllDay {
+            uid: format!("holiday-{}-{}", holiday.region, holiday.date),
+            start: date,
+            end: date,
+            summary: tr_args(locale, "calendar-holiday", &[("name", &holiday.name), ("region", &holiday.region)]),
+        });
+    }
+
+    let name = if team {
+        tr(locale, "calendar-name-team")
+    } else {
+        let owner = names.get(&link.discord_id).cloned().unwrap_or_else(|| link.discord_id.clone());
+        tr_args(locale, "calendar-name-user", &[("name", &owner)])
+    };
+    let calendar = Calendar {
+        name,
+        uid_scope: link.guild_id.clone(),
+        people,
+        all_day,
+    };
+    Ok(calendar::render(&calendar, Utc::now()))
+}
+
+/// A member's server nickname or Discord name, or `None` if they aren't in the server
+async fn member_name(state: &WebhookState, guild_id: &str, discord_id: &str) -> Result<Option<String>, serenity::Error> {
+    let (Ok(guild), Ok(user)) = (guild_id.parse::<u64>(), discord_id.parse::<u64>()) else {
+        return Ok(None);
+    };
+    let key = (GuildId::new(guild), UserId::new(user));
+    if let Some(name) = state.names.get(&key) {
+        return Ok(name);
+    }
+
+    let cached = state
+        .cache
+        .guild(key.0)
+        .and_then(|g| g.members.get(&key.1).map(|m| m.display_name().to_string()));
+    let name = match cached {
+        Some(name) => Some(name),
+        None => match state.http.get_member(key.0, key.1).await {
+            Ok(member) => Some(member.display_name().to_string()),
+            Err(serenity::Error::Http(e)) if e.status_code().is_some_and(|s| s.as_u16() == 404) => None,
+            Err(e) => return Err(e),
+        },
+    };
+    state.names.insert(key, name.clone());
+    Ok(name)
+}
+
+/// Whether a link's owner is a global admin, has ADMINISTRATOR in the
+/// server or holds a role with the bot's admin permission
+async fn owner_is_admin(state: &WebhookState, link: &CalendarLink) -> anyhow::Result<bool> {
+    if state.admin_ids.contains(&link.discord_id) {
+        return Ok(true);
+    }
+    let admin_roles = state.db.get_roles_with_permission(&link.guild_id, "admin").await?;
+    if admin_roles.iter().any(|r| r == "everyone") {
+        return Ok(true);
+    }
+    let (Ok(guild), Ok(user)) = (link.guild_id.parse::<u64>(), link.discord_id.parse::<u64>()) else {
+        return Ok(false);
+    };
+    let (guild_id, user_id) = (GuildId::new(guild), UserId::new(user));
+
+    let cached = state.cache.guild(guild_id).and_then(|g| g.members.get(&user_id).cloned());
+    let member = match cached {
+        Some(member) => member,
+        None => state.http.get_member(guild_id, user_id).await?,
+    };
+    if member.roles.iter().any(|r| admin_roles.contains(&r.to_string())) {
+        return Ok(true);
+    }
+
+    let cached = state
+        .cache
+        .guild(guild_id)
+        .map(|g| is_administrator(g.owner_id, &g.roles, &member));
+    match cached {
+        Some(admin) => Ok(admin),
+        None => {
+            let guild = state.http.get_guild(guild_id).await?;
+            Ok(is_administrator(guild.owner_id, &guild.roles, &member))
+        }
+    }
+}
+
+/// Whether a member owns the server or has a role with ADMINISTRATOR,
+/// counting @everyone
+fn is_administrator(owner_id: UserId, roles: &HashMap<RoleId, Role>, member: &Member) -> bool {
+    let everyone = RoleId::new(member.guild_id.get());
+    member.user.id == owner_id
+        || roles
+            .values()
+            .filter(|r| r.id == everyone || member.roles.contains(&r.id))
+            .any(|r| r.permissions.administrator())
+}
+
+fn parse(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockDiscord, GUILD_ID};
+    use poise::serenity_prelude::Permissions;
+
+    #[tokio::test]
+    async fn test_team_link_needs_an_admin_on_every_fetch() {
+        let db = testing::database("calendar-feed").await;
+        let discord = MockDiscord::start().await;
+        // 100 has the bot's admin role, 101 ADMINISTRATOR and 102 neither
+        let roles = vec![
+            testing::role(GUILD_ID, GUILD_ID, Permissions::VIEW_CHANNEL),
+            testing::role(GUILD_ID, 60, Permissions::empty()),
+            testing::role(GUILD_ID, 61, Permissions::ADMINISTRATOR),
+        ];
+        let members = vec![
+            testing::member(GUILD_ID, 100, &[60]),
+            testing::member(GUILD_ID, 101, &[61]),
+            testing::member(GUILD_ID, 102, &[]),
+        ];
+        discord.add_guild(&testing::guild(GUILD_ID, roles, vec![], members));
+        db.add_guild_permission(&GUILD_ID.to_string(), "60", "admin", "999999").await.unwrap();
+
+        let state = Arc::new(WebhookState {
+            db: db.clone(),
+            http: discord.discord.http.clone(),
+            cache: discord.discord.cache.clone(),
+            admin_ids: vec![],
+            names: NameCache::default(),
+        });
+        for (user, scope) in [("100", "team"), ("101", "team"), ("102", "team"), ("102", "user")] {
+            let link = CalendarLink {
+                guild_id: GUILD_ID.to_string(),
+                discord_id: user.to_string(),
+                scope: scope.to_string(),
+            };
+            db.set_calendar_link(&link, &calendar::token_hash(&format!("{}-{}", user, scope))).await.unwrap();
+        }
+        let fetch = |token: &str| calendar_feed(State(state.clone()), Path(format!("{}.ics", token)));
+
+        assert_eq!(fetch("100-team").await.status(), StatusCode::OK);
+        assert_eq!(fetch("101-team").await.status(), StatusCode::OK);
+        assert_eq!(fetch("102-team").await.status(), StatusCode::NOT_FOUND);
+        assert_eq!(fetch("102-user").await.status(), StatusCode::OK);
+
+        // Losing the admin role stops the team link straight away
+        db.remove_guild_permission(&GUILD_ID.to_string(), "60", "admin").await.unwrap();
+        assert_eq!(fetch("100-team").await.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_name_cache_drops_expired_names_when_full() {
+        let names = NameCache::default();
+        let expired = Instant::now() - NAME_TTL;
+        let key = |user| (GuildId::new(GUILD_ID), UserId::new(user));
+        names.0.lock().unwrap().extend((1..=MAX_NAMES as u64).map(|user| (key(user), (None, expired))));
+        assert_eq!(names.get(&key(1)), None);
+
+        names.insert(key(1), Some("Asha".to_string()));
+        assert_eq!(names.0.lock().unwrap().len(), 1);
+        assert_eq!(names.get(&key(1)), Some(Some("Asha".to_string())));
+    }
+}
@@ -1,5 +1,6 @@
-//! Webhook server for receiving GitHub and Plane notifications
+//! Webhook server for receiving GitHub and Plane notifications and serving calendar feeds
 
+mod calendar;
 mod server;
 
 pub use server::start_server;
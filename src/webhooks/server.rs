@@ -1,5 +1,6 @@
-//! HTTP server for receiving webhooks from GitHub and Plane
+//! HTTP server for receiving webhooks from GitHub and Plane, and serving calendar feeds
 
+use super::calendar::{calendar_feed, NameCache};
 use crate::config::Config;
 use crate::db::Database;
 use axum::{
@@ -8,6 +9,7 @@ use axum::{
     routing::{get, post},
     Router,
 };
+use poise::serenity_prelude::{Cache, Http};
 use serde::Deserialize;
 use std::sync::Arc;
 use tokio::task::JoinHandle;
@@ -16,19 +18,32 @@ use tracing::{error, info};
 /// Shared state for webhook handlers
 #[derive(Clone)]
 pub struct WebhookState {
-    pub config: Config,
     pub db: Database,
+    /// The bot's Discord client, for member lookups
+    pub http: Arc<Http>,
+    pub cache: Arc<Cache>,
+    /// Global admins from the config, who may keep team calendar links
+    pub admin_ids: Vec<String>,
+    /// Display names looked up for calendar feeds
+    pub names: NameCache,
 }
 
 /// Start the webhook server in the background
-pub fn start_server(config: Config, db: Database) -> JoinHandle<()> {
-    let state = WebhookState { config: config.clone(), db };
+pub fn start_server(config: Config, db: Database, http: Arc<Http>, cache: Arc<Cache>) -> JoinHandle<()> {
+    let state = WebhookState {
+        db,
+        http,
+        cache,
+        admin_ids: config.discord.admin_ids.clone(),
+        names: NameCache::default(),
+    };
 
     tokio::spawn(async move {
         let app = Router::new()
             .route("/health", get(health))
             .route("/webhooks/github", post(github_webhook))
             .route("/webhooks/plane", post(plane_webhook))
+            .route("/calendar/:file", get(calendar_feed))
             .with_state(Arc::new(state));
 
         let addr = format!("{}:{}", config.webhooks.host, config.webhooks.port);